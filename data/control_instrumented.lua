@@ -51,6 +51,13 @@ function assert_player_crafting_queue_size(expected_size)
     log_print(string.format("tick %d: expected crafting queue size %d, but found %d", game.tick, expected_size, game.player.crafting_queue_size))
   end
 end
+function assert_player_crafting_queue_item(position, expected_index, expected_recipe, expected_count, expected_prerequisite)
+  local item = (game.player.crafting_queue or {})[position]
+  if item == nil or item.index ~= expected_index or item.recipe ~= expected_recipe or item.count ~= expected_count or item.prerequisite ~= expected_prerequisite then
+    local found = item and string.format("#%d %s x%d prerequisite %s", item.index, item.recipe, item.count, tostring(item.prerequisite)) or "nothing"
+    log_print(string.format("tick %d: expected crafting queue item %d to be #%d %s x%d prerequisite %s, but found %s", game.tick, position, expected_index, expected_recipe, expected_count, tostring(expected_prerequisite), found))
+  end
+end
 function assert_player_crafting_progress(expected_progress)
   if game.player.crafting_queue_progress ~= expected_progress then
     log_print(string.format("tick %d: expected crafting progress %a, but found %a", game.tick, expected_progress, game.player.crafting_queue_progress))
@@ -61,6 +68,16 @@ function assert_player_inventory_item_count(expected_item, expected_amount)
     log_print(string.format("tick %d: expected inventory item %s count %d, but found %d", game.tick, expected_item, expected_amount, game.player.get_main_inventory().get_item_count(expected_item)))
   end
 end
+function assert_player_cursor_stack(expected_item, expected_count)
+  local stack = game.player.cursor_stack
+  local item, count = nil, 0
+  if stack.valid_for_read then
+    item, count = stack.name, stack.count
+  end
+  if item ~= expected_item or count ~= expected_count then
+    log_print(string.format("tick %d: expected cursor stack %s x%d, but found %s x%d", game.tick, tostring(expected_item), expected_count, tostring(item), count))
+  end
+end
 function assert_miner_mining_progress(x, y, expected_progress)
   local miner = game.surfaces[1].find_entity("burner-mining-drill", {x, y})
   if miner.mining_progress ~= expected_progress then
@@ -97,6 +114,75 @@ function assert_furnace_heat(x, y, expected_heat)
     log_print(string.format("tick %d: expected furnace at (%d, %d) heat %a, but found %a", game.tick, x, y, expected_heat, furnace.burner.heat))
   end
 end
+function assert_furnace_output(x, y, expected_item, expected_amount)
+  local furnace = game.surfaces[1].find_entity("stone-furnace", {x, y})
+  local stack = furnace.get_output_inventory()[1]
+  local item, amount = nil, 0
+  if stack.valid_for_read then
+    item, amount = stack.name, stack.count
+  end
+  if item ~= expected_item or amount ~= expected_amount then
+    log_print(string.format("tick %d: expected furnace at (%d, %d) output %s x%d, but found %s x%d", game.tick, x, y, tostring(expected_item), expected_amount, tostring(item), amount))
+  end
+end
+-- entities given by name and map position in fixed point units
+function assert_mining_progress(name, x, y, expected_progress)
+  local entity = game.surfaces[1].find_entity(name, {x / 256, y / 256})
+  if entity.mining_progress ~= expected_progress then
+    log_print(string.format("tick %d: expected %s at (%d, %d) mining progress %a, but found %a", game.tick, name, x, y, expected_progress, entity.mining_progress))
+  end
+end
+function assert_energy(name, x, y, expected_energy)
+  local entity = game.surfaces[1].find_entity(name, {x / 256, y / 256})
+  if entity.energy ~= expected_energy then
+    log_print(string.format("tick %d: expected %s at (%d, %d) energy %a, but found %a", game.tick, name, x, y, expected_energy, entity.energy))
+  end
+end
+function assert_burner_heat(name, x, y, expected_heat)
+  local entity = game.surfaces[1].find_entity(name, {x / 256, y / 256})
+  if entity.burner.heat ~= expected_heat then
+    log_print(string.format("tick %d: expected %s at (%d, %d) heat %a, but found %a", game.tick, name, x, y, expected_heat, entity.burner.heat))
+  end
+end
+function assert_fluid_amount(name, x, y, index, expected_amount)
+  local entity = game.surfaces[1].find_entity(name, {x / 256, y / 256})
+  local fluid = entity.fluidbox[index]
+  local amount = fluid and fluid.amount or 0
+  if amount ~= expected_amount then
+    log_print(string.format("tick %d: expected %s at (%d, %d) fluid box %d amount %a, but found %a", game.tick, name, x, y, index, expected_amount, amount))
+  end
+end
+function assert_crafting_progress(name, x, y, expected_progress)
+  local entity = game.surfaces[1].find_entity(name, {x / 256, y / 256})
+  if entity.crafting_progress ~= expected_progress then
+    log_print(string.format("tick %d: expected %s at (%d, %d) crafting progress %a, but found %a", game.tick, name, x, y, expected_progress, entity.crafting_progress))
+  end
+end
+function assert_belt_item_count(name, x, y, lane, expected_count)
+  local entity = game.surfaces[1].find_entity(name, {x / 256, y / 256})
+  local count = entity.get_transport_line(lane).get_item_count()
+  if count ~= expected_count then
+    log_print(string.format("tick %d: expected %s at (%d, %d) lane %d item count %d, but found %d", game.tick, name, x, y, lane, expected_count, count))
+  end
+end
+function assert_inserter_hand(name, x, y, expected_item, expected_count)
+  local entity = game.surfaces[1].find_entity(name, {x / 256, y / 256})
+  local stack = entity.held_stack
+  local item, count = nil, 0
+  if stack.valid_for_read then
+    item, count = stack.name, stack.count
+  end
+  if item ~= expected_item or count ~= expected_count then
+    log_print(string.format("tick %d: expected %s at (%d, %d) holding %s x%d, but found %s x%d", game.tick, name, x, y, tostring(expected_item), expected_count, tostring(item), count))
+  end
+end
+function assert_research(expected_technology, expected_progress)
+  local force = game.player.force
+  local technology = force.current_research and force.current_research.name
+  if technology ~= expected_technology or force.research_progress ~= expected_progress then
+    log_print(string.format("tick %d: expected research %s progress %a, but found %s progress %a", game.tick, tostring(expected_technology), expected_progress, tostring(technology), force.research_progress))
+  end
+end
 function log_print(message)
   log(message)
   game.print(message)
@@ -0,0 +1,29 @@
+-- Global table of the level script of a freeplay game after freeplay's and silo-script's on_init, in serpent.block format.
+-- Hand-written after data/freeplay.lua and the game's silo-script, which keeps its state in global.silo_script; the crash site ship parts are left out.
+{
+  created_items = {
+    ["iron-plate"] = 8,
+    wood = 1,
+    pistol = 1,
+    ["firearm-magazine"] = 10,
+    ["burner-mining-drill"] = 1,
+    ["stone-furnace"] = 1
+  },
+  respawn_items = {
+    pistol = 1,
+    ["firearm-magazine"] = 10
+  },
+  crashed_ship_items = {
+    ["firearm-magazine"] = 8
+  },
+  crashed_debris_items = {
+    ["iron-plate"] = 8
+  },
+  silo_script = {
+    tracked_items = {
+      satellite = true
+    },
+    finished = {},
+    no_victory = false
+  }
+}
@@ -327,6 +327,62 @@ pub fn replay_derive_enum_u32(input: TokenStream) -> TokenStream {
 
   TokenStream::from(expanded)
 }
+#[proc_macro_derive(PrototypeIdU8, attributes(prototype_type))]
+pub fn derive_prototype_id_u8(input: TokenStream) -> TokenStream {
+  derive_prototype_id(input, quote! { u8 }, quote! { read_u8 }, quote! { write_u8 })
+}
+#[proc_macro_derive(PrototypeIdU16, attributes(prototype_type))]
+pub fn derive_prototype_id_u16(input: TokenStream) -> TokenStream {
+  derive_prototype_id(input, quote! { u16 }, quote! { read_u16 }, quote! { write_u16 })
+}
+fn derive_prototype_id(input: TokenStream, id_type: proc_macro2::TokenStream, read_fn: proc_macro2::TokenStream, write_fn: proc_macro2::TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  let name = &input.ident;
+
+  let prototype_type: syn::Ident = input.attrs.iter().find(|attr| attr.path().is_ident("prototype_type")).expect("No prototype_type attribute found on PrototypeId").parse_args().expect("Unable to parse prototype_type as identifier");
+
+  let expanded = quote! {
+    impl crate::registry::PrototypeId for #name {
+      const PROTOTYPE_TYPE: crate::registry::PrototypeType = crate::registry::PrototypeType::#prototype_type;
+      fn all() -> &'static [Self] { &Self::ALL }
+      fn from_static_id(id: u32) -> Option<Self> { Self::from_u32(id) }
+      fn static_id(self) -> u32 { self.to_u32().unwrap() }
+      fn prototype_name(self) -> &'static str { self.name() }
+    }
+    impl crate::map::MapReadWrite for #name {
+      fn map_read<R: std::io::BufRead + std::io::Seek>(r: &mut crate::map::MapDeserialiser<R>) -> crate::Result<Self> {
+        let value = r.stream.#read_fn()?;
+        r.prototype_registry.to_static(u32::from(value)).ok_or_else(|| r.stream.error_at(format!("value {:#x} is not a valid {}", value, stringify!(#name)), 1))
+      }
+      fn map_write(&self, w: &mut crate::map::MapSerialiser) -> crate::Result<()> {
+        let value = w.prototype_registry.to_save_id(*self).ok_or_else(|| w.stream.error_at(format!("{:?} has no id in the prototype registry", self), 0))?;
+        w.stream.#write_fn(value as #id_type)
+      }
+    }
+    impl crate::replay::ReplayReadWrite for #name {
+      fn replay_read<R: std::io::BufRead + std::io::Seek>(r: &mut crate::replay::ReplayDeserialiser<R>) -> crate::Result<Self> {
+        let value = r.stream.#read_fn()?;
+        r.prototype_registry.to_static(u32::from(value)).ok_or_else(|| r.stream.error_at(format!("value {:#x} is not a valid {}", value, stringify!(#name)), 1))
+      }
+      fn replay_write(&self, w: &mut crate::replay::ReplaySerialiser) -> crate::Result<()> {
+        let value = w.prototype_registry.to_save_id(*self).ok_or_else(|| w.stream.error_at(format!("{:?} has no id in the prototype registry", self), 0))?;
+        w.stream.#write_fn(value as #id_type)
+      }
+    }
+    impl crate::map::MapReadWrite for crate::registry::Prototype<#name> {
+      fn map_read<R: std::io::BufRead + std::io::Seek>(r: &mut crate::map::MapDeserialiser<R>) -> crate::Result<Self> {
+        let value = r.stream.#read_fn()?;
+        Ok(r.prototype_registry.resolve(u32::from(value)))
+      }
+      fn map_write(&self, w: &mut crate::map::MapSerialiser) -> crate::Result<()> {
+        let value = w.prototype_registry.save_id(*self).ok_or_else(|| w.stream.error_at(format!("{:?} has no id in the prototype registry", self), 0))?;
+        w.stream.#write_fn(value as #id_type)
+      }
+    }
+  };
+
+  TokenStream::from(expanded)
+}
 #[proc_macro_derive(ReplayReadWriteTaggedUnion, attributes(tag_type))]
 pub fn replay_derive_readwrite_union(input: TokenStream) -> TokenStream {
   let input = parse_macro_input!(input as DeriveInput);
@@ -20,3 +20,45 @@ mod tile;
 pub use tile::Tile;
 mod virtualsignal;
 pub use virtualsignal::VirtualSignal;
+
+
+/// Error returned when parsing a prototype enum from a name it doesn't know.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownPrototypeName {
+  pub prototype_type: &'static str,
+  pub name: String,
+}
+impl std::fmt::Display for UnknownPrototypeName {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "unknown {} \"{}\"", self.prototype_type, self.name)
+  }
+}
+impl std::error::Error for UnknownPrototypeName {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_names_round_trip() {
+    for item in Item::iter() {
+      assert_eq!(item.to_string().parse::<Item>(), Ok(item));
+    }
+    for recipe in Recipe::iter() {
+      assert_eq!(recipe.to_string().parse::<Recipe>(), Ok(recipe));
+    }
+    for technology in Technology::iter() {
+      assert_eq!(technology.to_string().parse::<Technology>(), Ok(technology));
+    }
+    assert_eq!(Item::IronPlate.to_string(), "iron-plate");
+    assert_eq!(format!("{}", Fluid::CrudeOil), "crude-oil");
+  }
+
+  #[test]
+  fn test_unknown_name() {
+    let error = "modded-plate".parse::<Item>().unwrap_err();
+    assert_eq!(error, UnknownPrototypeName { prototype_type: "Item", name: "modded-plate".to_owned() });
+    assert_eq!(error.to_string(), r#"unknown Item "modded-plate""#);
+    assert_eq!(Entity::try_from_name("modded-chest"), None);
+  }
+}
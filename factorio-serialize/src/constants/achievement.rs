@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::{MapReadWriteEnumU16, ReplayReadWriteEnumU16};
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU16, MapReadWriteEnumU16)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(Achievement)]
 pub enum Achievement {
   GettingOnTrack = 1,
   EcoUnfriendly = 2,
@@ -47,6 +50,50 @@ pub enum Achievement {
   SoLongAndThanksForAllTheFish = 38,
 }
 impl Achievement {
+  pub const ALL: [Achievement; 38] = [
+    Achievement::GettingOnTrack,
+    Achievement::EcoUnfriendly,
+    Achievement::TechManiac,
+    Achievement::SmokeMeAKipperIWillBeBackForBreakfast,
+    Achievement::ItStinksAndTheyDontLikeIt,
+    Achievement::AutomatedConstruction,
+    Achievement::YouAreDoingItRight,
+    Achievement::AutomatedCleanup,
+    Achievement::YouHaveGotAPackage,
+    Achievement::DeliveryService,
+    Achievement::TransFactorioExpress,
+    Achievement::WatchYourStep,
+    Achievement::Golem,
+    Achievement::GettingOnTrackLikeAPro,
+    Achievement::MassProduction1,
+    Achievement::MassProduction2,
+    Achievement::MassProduction3,
+    Achievement::CircuitVeteran1,
+    Achievement::CircuitVeteran2,
+    Achievement::CircuitVeteran3,
+    Achievement::ComputerAge1,
+    Achievement::ComputerAge2,
+    Achievement::ComputerAge3,
+    Achievement::IronThrone1,
+    Achievement::IronThrone2,
+    Achievement::IronThrone3,
+    Achievement::Solaris,
+    Achievement::Steamrolled,
+    Achievement::Pyromaniac,
+    Achievement::RunForrestRun,
+    Achievement::Minions,
+    Achievement::LazyBastard,
+    Achievement::SteamAllTheWay,
+    Achievement::RainingBullets,
+    Achievement::LogisticNetworkEmbargo,
+    Achievement::NoTimeForChitchat,
+    Achievement::ThereIsNoSpoon,
+    Achievement::SoLongAndThanksForAllTheFish,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Achievement> {
+    Achievement::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Achievement::GettingOnTrack => "getting-on-track",
@@ -89,47 +136,61 @@ impl Achievement {
       Achievement::SoLongAndThanksForAllTheFish => "so-long-and-thanks-for-all-the-fish",
     }
   }
-  pub fn from_name(name: &str) -> Achievement {
+  pub fn try_from_name(name: &str) -> Option<Achievement> {
     match name {
-      "getting-on-track" => Achievement::GettingOnTrack,
-      "eco-unfriendly" => Achievement::EcoUnfriendly,
-      "tech-maniac" => Achievement::TechManiac,
-      "smoke-me-a-kipper-i-will-be-back-for-breakfast" => Achievement::SmokeMeAKipperIWillBeBackForBreakfast,
-      "it-stinks-and-they-dont-like-it" => Achievement::ItStinksAndTheyDontLikeIt,
-      "automated-construction" => Achievement::AutomatedConstruction,
-      "you-are-doing-it-right" => Achievement::YouAreDoingItRight,
-      "automated-cleanup" => Achievement::AutomatedCleanup,
-      "you-have-got-a-package" => Achievement::YouHaveGotAPackage,
-      "delivery-service" => Achievement::DeliveryService,
-      "trans-factorio-express" => Achievement::TransFactorioExpress,
-      "watch-your-step" => Achievement::WatchYourStep,
-      "golem" => Achievement::Golem,
-      "getting-on-track-like-a-pro" => Achievement::GettingOnTrackLikeAPro,
-      "mass-production-1" => Achievement::MassProduction1,
-      "mass-production-2" => Achievement::MassProduction2,
-      "mass-production-3" => Achievement::MassProduction3,
-      "circuit-veteran-1" => Achievement::CircuitVeteran1,
-      "circuit-veteran-2" => Achievement::CircuitVeteran2,
-      "circuit-veteran-3" => Achievement::CircuitVeteran3,
-      "computer-age-1" => Achievement::ComputerAge1,
-      "computer-age-2" => Achievement::ComputerAge2,
-      "computer-age-3" => Achievement::ComputerAge3,
-      "iron-throne-1" => Achievement::IronThrone1,
-      "iron-throne-2" => Achievement::IronThrone2,
-      "iron-throne-3" => Achievement::IronThrone3,
-      "solaris" => Achievement::Solaris,
-      "steamrolled" => Achievement::Steamrolled,
-      "pyromaniac" => Achievement::Pyromaniac,
-      "run-forrest-run" => Achievement::RunForrestRun,
-      "minions" => Achievement::Minions,
-      "lazy-bastard" => Achievement::LazyBastard,
-      "steam-all-the-way" => Achievement::SteamAllTheWay,
-      "raining-bullets" => Achievement::RainingBullets,
-      "logistic-network-embargo" => Achievement::LogisticNetworkEmbargo,
-      "no-time-for-chitchat" => Achievement::NoTimeForChitchat,
-      "there-is-no-spoon" => Achievement::ThereIsNoSpoon,
-      "so-long-and-thanks-for-all-the-fish" => Achievement::SoLongAndThanksForAllTheFish,
-      name => panic!("unknown Achievement \"{name}\""),
+      "getting-on-track" => Some(Achievement::GettingOnTrack),
+      "eco-unfriendly" => Some(Achievement::EcoUnfriendly),
+      "tech-maniac" => Some(Achievement::TechManiac),
+      "smoke-me-a-kipper-i-will-be-back-for-breakfast" => Some(Achievement::SmokeMeAKipperIWillBeBackForBreakfast),
+      "it-stinks-and-they-dont-like-it" => Some(Achievement::ItStinksAndTheyDontLikeIt),
+      "automated-construction" => Some(Achievement::AutomatedConstruction),
+      "you-are-doing-it-right" => Some(Achievement::YouAreDoingItRight),
+      "automated-cleanup" => Some(Achievement::AutomatedCleanup),
+      "you-have-got-a-package" => Some(Achievement::YouHaveGotAPackage),
+      "delivery-service" => Some(Achievement::DeliveryService),
+      "trans-factorio-express" => Some(Achievement::TransFactorioExpress),
+      "watch-your-step" => Some(Achievement::WatchYourStep),
+      "golem" => Some(Achievement::Golem),
+      "getting-on-track-like-a-pro" => Some(Achievement::GettingOnTrackLikeAPro),
+      "mass-production-1" => Some(Achievement::MassProduction1),
+      "mass-production-2" => Some(Achievement::MassProduction2),
+      "mass-production-3" => Some(Achievement::MassProduction3),
+      "circuit-veteran-1" => Some(Achievement::CircuitVeteran1),
+      "circuit-veteran-2" => Some(Achievement::CircuitVeteran2),
+      "circuit-veteran-3" => Some(Achievement::CircuitVeteran3),
+      "computer-age-1" => Some(Achievement::ComputerAge1),
+      "computer-age-2" => Some(Achievement::ComputerAge2),
+      "computer-age-3" => Some(Achievement::ComputerAge3),
+      "iron-throne-1" => Some(Achievement::IronThrone1),
+      "iron-throne-2" => Some(Achievement::IronThrone2),
+      "iron-throne-3" => Some(Achievement::IronThrone3),
+      "solaris" => Some(Achievement::Solaris),
+      "steamrolled" => Some(Achievement::Steamrolled),
+      "pyromaniac" => Some(Achievement::Pyromaniac),
+      "run-forrest-run" => Some(Achievement::RunForrestRun),
+      "minions" => Some(Achievement::Minions),
+      "lazy-bastard" => Some(Achievement::LazyBastard),
+      "steam-all-the-way" => Some(Achievement::SteamAllTheWay),
+      "raining-bullets" => Some(Achievement::RainingBullets),
+      "logistic-network-embargo" => Some(Achievement::LogisticNetworkEmbargo),
+      "no-time-for-chitchat" => Some(Achievement::NoTimeForChitchat),
+      "there-is-no-spoon" => Some(Achievement::ThereIsNoSpoon),
+      "so-long-and-thanks-for-all-the-fish" => Some(Achievement::SoLongAndThanksForAllTheFish),
+      _ => None,
     }
   }
-}
\ No newline at end of file
+  pub fn from_name(name: &str) -> Achievement {
+    Achievement::try_from_name(name).unwrap_or_else(|| panic!("unknown Achievement \"{name}\""))
+  }
+}
+impl std::fmt::Display for Achievement {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Achievement {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Achievement, UnknownPrototypeName> {
+    Achievement::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Achievement", name: name.to_owned() })
+  }
+}
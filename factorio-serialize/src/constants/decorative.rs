@@ -1,10 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU8;
+use factorio_serialize_derive::PrototypeIdU8;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
+
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU8)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU8)]
+#[prototype_type(Decorative)]
 pub enum Decorative {
   BrownHairyGrass = 1,
   GreenHairyGrass = 2,
@@ -51,6 +55,55 @@ pub enum Decorative {
   SmallShipWreckGrass = 43,
 }
 impl Decorative {
+  pub const ALL: [Decorative; 43] = [
+    Decorative::BrownHairyGrass,
+    Decorative::GreenHairyGrass,
+    Decorative::BrownCarpetGrass,
+    Decorative::GreenCarpetGrass,
+    Decorative::GreenSmallGrass,
+    Decorative::MuddyStump,
+    Decorative::GreenAsterisk,
+    Decorative::BrownAsteriskMini,
+    Decorative::GreenAsteriskMini,
+    Decorative::BrownAsterisk,
+    Decorative::RedAsterisk,
+    Decorative::EnemyDecal,
+    Decorative::EnemyDecalTransparent,
+    Decorative::DarkMudDecal,
+    Decorative::LightMudDecal,
+    Decorative::NuclearGroundPatch,
+    Decorative::PubertyDecal,
+    Decorative::RedDesertDecal,
+    Decorative::SandDecal,
+    Decorative::SandDuneDecal,
+    Decorative::GreenPita,
+    Decorative::RedPita,
+    Decorative::GreenCroton,
+    Decorative::RedCroton,
+    Decorative::GreenPitaMini,
+    Decorative::BrownFluff,
+    Decorative::BrownFluffDry,
+    Decorative::GreenDesertBush,
+    Decorative::RedDesertBush,
+    Decorative::WhiteDesertBush,
+    Decorative::GarballoMiniDry,
+    Decorative::Garballo,
+    Decorative::GreenBushMini,
+    Decorative::LichenDecal,
+    Decorative::ShroomDecal,
+    Decorative::WormsDecal,
+    Decorative::RockMedium,
+    Decorative::RockSmall,
+    Decorative::RockTiny,
+    Decorative::BigShipWreckGrass,
+    Decorative::SandRockMedium,
+    Decorative::SandRockSmall,
+    Decorative::SmallShipWreckGrass,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Decorative> {
+    Decorative::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Decorative::BrownHairyGrass => "brown-hairy-grass",
@@ -98,52 +151,66 @@ impl Decorative {
       Decorative::SmallShipWreckGrass => "small-ship-wreck-grass",
     }
   }
-  pub fn from_name(name: &str) -> Decorative {
+  pub fn try_from_name(name: &str) -> Option<Decorative> {
     match name {
-      "brown-hairy-grass" => Decorative::BrownHairyGrass,
-      "green-hairy-grass" => Decorative::GreenHairyGrass,
-      "brown-carpet-grass" => Decorative::BrownCarpetGrass,
-      "green-carpet-grass" => Decorative::GreenCarpetGrass,
-      "green-small-grass" => Decorative::GreenSmallGrass,
-      "muddy-stump" => Decorative::MuddyStump,
-      "green-asterisk" => Decorative::GreenAsterisk,
-      "brown-asterisk-mini" => Decorative::BrownAsteriskMini,
-      "green-asterisk-mini" => Decorative::GreenAsteriskMini,
-      "brown-asterisk" => Decorative::BrownAsterisk,
-      "red-asterisk" => Decorative::RedAsterisk,
-      "enemy-decal" => Decorative::EnemyDecal,
-      "enemy-decal-transparent" => Decorative::EnemyDecalTransparent,
-      "dark-mud-decal" => Decorative::DarkMudDecal,
-      "light-mud-decal" => Decorative::LightMudDecal,
-      "nuclear-ground-patch" => Decorative::NuclearGroundPatch,
-      "puberty-decal" => Decorative::PubertyDecal,
-      "red-desert-decal" => Decorative::RedDesertDecal,
-      "sand-decal" => Decorative::SandDecal,
-      "sand-dune-decal" => Decorative::SandDuneDecal,
-      "green-pita" => Decorative::GreenPita,
-      "red-pita" => Decorative::RedPita,
-      "green-croton" => Decorative::GreenCroton,
-      "red-croton" => Decorative::RedCroton,
-      "green-pita-mini" => Decorative::GreenPitaMini,
-      "brown-fluff" => Decorative::BrownFluff,
-      "brown-fluff-dry" => Decorative::BrownFluffDry,
-      "green-desert-bush" => Decorative::GreenDesertBush,
-      "red-desert-bush" => Decorative::RedDesertBush,
-      "white-desert-bush" => Decorative::WhiteDesertBush,
-      "garballo-mini-dry" => Decorative::GarballoMiniDry,
-      "garballo" => Decorative::Garballo,
-      "green-bush-mini" => Decorative::GreenBushMini,
-      "lichen-decal" => Decorative::LichenDecal,
-      "shroom-decal" => Decorative::ShroomDecal,
-      "worms-decal" => Decorative::WormsDecal,
-      "rock-medium" => Decorative::RockMedium,
-      "rock-small" => Decorative::RockSmall,
-      "rock-tiny" => Decorative::RockTiny,
-      "big-ship-wreck-grass" => Decorative::BigShipWreckGrass,
-      "sand-rock-medium" => Decorative::SandRockMedium,
-      "sand-rock-small" => Decorative::SandRockSmall,
-      "small-ship-wreck-grass" => Decorative::SmallShipWreckGrass,
-      name => panic!("unknown Decorative \"{name}\""),
+      "brown-hairy-grass" => Some(Decorative::BrownHairyGrass),
+      "green-hairy-grass" => Some(Decorative::GreenHairyGrass),
+      "brown-carpet-grass" => Some(Decorative::BrownCarpetGrass),
+      "green-carpet-grass" => Some(Decorative::GreenCarpetGrass),
+      "green-small-grass" => Some(Decorative::GreenSmallGrass),
+      "muddy-stump" => Some(Decorative::MuddyStump),
+      "green-asterisk" => Some(Decorative::GreenAsterisk),
+      "brown-asterisk-mini" => Some(Decorative::BrownAsteriskMini),
+      "green-asterisk-mini" => Some(Decorative::GreenAsteriskMini),
+      "brown-asterisk" => Some(Decorative::BrownAsterisk),
+      "red-asterisk" => Some(Decorative::RedAsterisk),
+      "enemy-decal" => Some(Decorative::EnemyDecal),
+      "enemy-decal-transparent" => Some(Decorative::EnemyDecalTransparent),
+      "dark-mud-decal" => Some(Decorative::DarkMudDecal),
+      "light-mud-decal" => Some(Decorative::LightMudDecal),
+      "nuclear-ground-patch" => Some(Decorative::NuclearGroundPatch),
+      "puberty-decal" => Some(Decorative::PubertyDecal),
+      "red-desert-decal" => Some(Decorative::RedDesertDecal),
+      "sand-decal" => Some(Decorative::SandDecal),
+      "sand-dune-decal" => Some(Decorative::SandDuneDecal),
+      "green-pita" => Some(Decorative::GreenPita),
+      "red-pita" => Some(Decorative::RedPita),
+      "green-croton" => Some(Decorative::GreenCroton),
+      "red-croton" => Some(Decorative::RedCroton),
+      "green-pita-mini" => Some(Decorative::GreenPitaMini),
+      "brown-fluff" => Some(Decorative::BrownFluff),
+      "brown-fluff-dry" => Some(Decorative::BrownFluffDry),
+      "green-desert-bush" => Some(Decorative::GreenDesertBush),
+      "red-desert-bush" => Some(Decorative::RedDesertBush),
+      "white-desert-bush" => Some(Decorative::WhiteDesertBush),
+      "garballo-mini-dry" => Some(Decorative::GarballoMiniDry),
+      "garballo" => Some(Decorative::Garballo),
+      "green-bush-mini" => Some(Decorative::GreenBushMini),
+      "lichen-decal" => Some(Decorative::LichenDecal),
+      "shroom-decal" => Some(Decorative::ShroomDecal),
+      "worms-decal" => Some(Decorative::WormsDecal),
+      "rock-medium" => Some(Decorative::RockMedium),
+      "rock-small" => Some(Decorative::RockSmall),
+      "rock-tiny" => Some(Decorative::RockTiny),
+      "big-ship-wreck-grass" => Some(Decorative::BigShipWreckGrass),
+      "sand-rock-medium" => Some(Decorative::SandRockMedium),
+      "sand-rock-small" => Some(Decorative::SandRockSmall),
+      "small-ship-wreck-grass" => Some(Decorative::SmallShipWreckGrass),
+      _ => None,
     }
   }
-}
\ No newline at end of file
+  pub fn from_name(name: &str) -> Decorative {
+    Decorative::try_from_name(name).unwrap_or_else(|| panic!("unknown Decorative \"{name}\""))
+  }
+}
+impl std::fmt::Display for Decorative {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Decorative {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Decorative, UnknownPrototypeName> {
+    Decorative::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Decorative", name: name.to_owned() })
+  }
+}
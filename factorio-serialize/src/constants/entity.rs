@@ -1,12 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::{MapReadWriteEnumU16, ReplayReadWriteEnumU16};
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
 
-// Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, MapReadWriteEnumU16, ReplayReadWriteEnumU16)]
 
+// Version: 1.1.107
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(Entity)]
 pub enum Entity {
   Accumulator = 1,
   AcidSplashFireSpitterBehemoth = 2,
@@ -538,6 +540,540 @@ pub enum Entity {
   Compilatron = 528,
 }
 impl Entity {
+  pub const ALL: [Entity; 528] = [
+    Entity::Accumulator,
+    Entity::AcidSplashFireSpitterBehemoth,
+    Entity::AcidSplashFireSpitterBig,
+    Entity::AcidSplashFireSpitterMedium,
+    Entity::AcidSplashFireSpitterSmall,
+    Entity::AcidSplashFireWormBehemoth,
+    Entity::AcidSplashFireWormBig,
+    Entity::AcidSplashFireWormMedium,
+    Entity::AcidSplashFireWormSmall,
+    Entity::AcidStickerBehemoth,
+    Entity::AcidStickerBig,
+    Entity::AcidStickerMedium,
+    Entity::AcidStickerSmall,
+    Entity::AcidStreamSpitterBehemoth,
+    Entity::AcidStreamSpitterBig,
+    Entity::AcidStreamSpitterMedium,
+    Entity::AcidStreamSpitterSmall,
+    Entity::AcidStreamWormBehemoth,
+    Entity::AcidStreamWormBig,
+    Entity::AcidStreamWormMedium,
+    Entity::AcidStreamWormSmall,
+    Entity::ArithmeticCombinator,
+    Entity::ArtilleryCannonMuzzleFlash,
+    Entity::ArtilleryFlare,
+    Entity::ArtilleryProjectile,
+    Entity::ArtilleryTurret,
+    Entity::ArtilleryWagon,
+    Entity::AssemblingMachine1,
+    Entity::AssemblingMachine2,
+    Entity::AssemblingMachine3,
+    Entity::AtomicBombGroundZeroProjectile,
+    Entity::AtomicBombWave,
+    Entity::AtomicBombWaveSpawnsClusterNukeExplosion,
+    Entity::AtomicBombWaveSpawnsFireSmokeExplosion,
+    Entity::AtomicBombWaveSpawnsNuclearSmoke,
+    Entity::AtomicBombWaveSpawnsNukeShockwaveExplosion,
+    Entity::AtomicFireSmoke,
+    Entity::AtomicNukeShockwave,
+    Entity::AtomicRocket,
+    Entity::Beacon,
+    Entity::BehemothBiterDie,
+    Entity::BehemothSpitterDie,
+    Entity::BehemothWormDie,
+    Entity::BigArtilleryExplosion,
+    Entity::BigBiterDie,
+    Entity::BigElectricPole,
+    Entity::BigExplosion,
+    Entity::BigSpitterDie,
+    Entity::BigWormDie,
+    Entity::BiterSpawnerDie,
+    Entity::BloodExplosionBig,
+    Entity::BloodExplosionHuge,
+    Entity::BloodExplosionSmall,
+    Entity::BloodFountain,
+    Entity::BloodFountainBig,
+    Entity::BloodFountainHitSpray,
+    Entity::BlueLaser,
+    Entity::Boiler,
+    Entity::BurnerGenerator,
+    Entity::BurnerInserter,
+    Entity::BurnerMiningDrill,
+    Entity::CannonProjectile,
+    Entity::Car,
+    Entity::CargoWagon,
+    Entity::Centrifuge,
+    Entity::CharacterCorpse,
+    Entity::ChemicalPlant,
+    Entity::CliffExplosives,
+    Entity::ClusterGrenade,
+    Entity::ClusterNukeExplosion,
+    Entity::CompiSpeechBubble,
+    Entity::ConstantCombinator,
+    Entity::ConstructionRobot,
+    Entity::CrashSiteChest1,
+    Entity::CrashSiteChest2,
+    Entity::CrashSiteExplosionSmoke,
+    Entity::CrashSiteFireFlame,
+    Entity::CrashSiteFireSmoke,
+    Entity::CrashSiteSpaceship,
+    Entity::CrashSiteSpaceshipWreckBig1,
+    Entity::CrashSiteSpaceshipWreckBig2,
+    Entity::CrashSiteSpaceshipWreckMedium1,
+    Entity::CrashSiteSpaceshipWreckMedium2,
+    Entity::CrashSiteSpaceshipWreckMedium3,
+    Entity::CrashSiteSpaceshipWreckSmall1,
+    Entity::CrashSiteSpaceshipWreckSmall2,
+    Entity::CrashSiteSpaceshipWreckSmall3,
+    Entity::CrashSiteSpaceshipWreckSmall4,
+    Entity::CrashSiteSpaceshipWreckSmall5,
+    Entity::CrashSiteSpaceshipWreckSmall6,
+    Entity::CurvedRail,
+    Entity::DeciderCombinator,
+    Entity::DeconstructibleTileProxy,
+    Entity::DefenderCapsule,
+    Entity::DestroyerCapsule,
+    Entity::DistractorCapsule,
+    Entity::DummyFlameThrowerExplosion,
+    Entity::ElectricBeam,
+    Entity::ElectricBeamNoSound,
+    Entity::ElectricEnergyInterface,
+    Entity::ElectricFurnace,
+    Entity::ElectricMiningDrill,
+    Entity::EnemyDamagedExplosion,
+    Entity::EntityGhost,
+    Entity::EntityUnknown,
+    Entity::Explosion,
+    Entity::ExplosionGunshot,
+    Entity::ExplosionGunshotSmall,
+    Entity::ExplosionHit,
+    Entity::ExplosiveCannonProjectile,
+    Entity::ExplosiveRocket,
+    Entity::ExplosiveUraniumCannonProjectile,
+    Entity::ExpressLoader,
+    Entity::ExpressSplitter,
+    Entity::ExpressTransportBelt,
+    Entity::ExpressUndergroundBelt,
+    Entity::FactorioLogo11tiles,
+    Entity::FactorioLogo16tiles,
+    Entity::FactorioLogo22tiles,
+    Entity::FakeSelectionBox2x2,
+    Entity::FastInserter,
+    Entity::FastLoader,
+    Entity::FastSplitter,
+    Entity::FastTransportBelt,
+    Entity::FastUndergroundBelt,
+    Entity::FilterInserter,
+    Entity::FireFlame,
+    Entity::FireFlameOnTree,
+    Entity::FireSticker,
+    Entity::FlamethrowerFireStream,
+    Entity::FlamethrowerTurret,
+    Entity::FluidWagon,
+    Entity::FlyingRobotDamagedExplosion,
+    Entity::FlyingText,
+    Entity::Gate,
+    Entity::Grenade,
+    Entity::GrenadeExplosion,
+    Entity::GroundExplosion,
+    Entity::GunTurret,
+    Entity::HandheldFlamethrowerFireStream,
+    Entity::HeatExchanger,
+    Entity::HeatInterface,
+    Entity::HeatPipe,
+    Entity::HighlightBox,
+    Entity::InfinityChest,
+    Entity::InfinityPipe,
+    Entity::Inserter,
+    Entity::IronChest,
+    Entity::ItemOnGround,
+    Entity::ItemRequestProxy,
+    Entity::Lab,
+    Entity::LandMine,
+    Entity::Laser,
+    Entity::LaserBeam,
+    Entity::LaserBubble,
+    Entity::LaserTurret,
+    Entity::LeafParticleForMigration,
+    Entity::LinkedBelt,
+    Entity::LinkedChest,
+    Entity::Loader,
+    Entity::Loader1x1,
+    Entity::Locomotive,
+    Entity::LogisticChestActiveProvider,
+    Entity::LogisticChestBuffer,
+    Entity::LogisticChestPassiveProvider,
+    Entity::LogisticChestRequester,
+    Entity::LogisticChestStorage,
+    Entity::LogisticRobot,
+    Entity::LongHandedInserter,
+    Entity::MassiveExplosion,
+    Entity::MediumBiterDie,
+    Entity::MediumElectricPole,
+    Entity::MediumExplosion,
+    Entity::MediumSpitterDie,
+    Entity::MediumWormDie,
+    Entity::NuclearReactor,
+    Entity::NuclearSmoulderingSmokeSource,
+    Entity::NukeExplosion,
+    Entity::OffshorePump,
+    Entity::OilRefinery,
+    Entity::OrangeArrowWithCircle,
+    Entity::ParticleForMigration,
+    Entity::PiercingShotgunPellet,
+    Entity::Pipe,
+    Entity::PipeToGround,
+    Entity::PlayerPort,
+    Entity::PoisonCapsule,
+    Entity::PoisonCloud,
+    Entity::PoisonCloudVisualDummy,
+    Entity::PowerSwitch,
+    Entity::ProgrammableSpeaker,
+    Entity::Pump,
+    Entity::Pumpjack,
+    Entity::Radar,
+    Entity::RailChainSignal,
+    Entity::RailSignal,
+    Entity::Roboport,
+    Entity::RockDamagedExplosion,
+    Entity::Rocket,
+    Entity::RocketSilo,
+    Entity::RocketSiloRocket,
+    Entity::RocketSiloRocketShadow,
+    Entity::ShotgunPellet,
+    Entity::SlowdownCapsule,
+    Entity::SlowdownSticker,
+    Entity::SmallBiterDie,
+    Entity::SmallElectricPole,
+    Entity::SmallLamp,
+    Entity::SmallSpitterDie,
+    Entity::SmallWormDie,
+    Entity::SmokeForMigration,
+    Entity::SolarPanel,
+    Entity::SparkExplosion,
+    Entity::SparkExplosionHigher,
+    Entity::Spidertron,
+    Entity::SpidertronLeg1,
+    Entity::SpidertronLeg2,
+    Entity::SpidertronLeg3,
+    Entity::SpidertronLeg4,
+    Entity::SpidertronLeg5,
+    Entity::SpidertronLeg6,
+    Entity::SpidertronLeg7,
+    Entity::SpidertronLeg8,
+    Entity::SpidertronMilitaryTarget,
+    Entity::SpitterSpawnerDie,
+    Entity::Splitter,
+    Entity::StackFilterInserter,
+    Entity::StackInserter,
+    Entity::SteamEngine,
+    Entity::SteamTurbine,
+    Entity::SteelChest,
+    Entity::SteelFurnace,
+    Entity::StoneFurnace,
+    Entity::StoneWall,
+    Entity::StorageTank,
+    Entity::StraightRail,
+    Entity::StunSticker,
+    Entity::Substation,
+    Entity::Tank,
+    Entity::TankFlamethrowerFireStream,
+    Entity::TileGhost,
+    Entity::TileProxy,
+    Entity::TrainStop,
+    Entity::TransportBelt,
+    Entity::TreeDyingProxy,
+    Entity::TreeProxy,
+    Entity::TutorialFlyingText,
+    Entity::UndergroundBelt,
+    Entity::UraniumCannonExplosion,
+    Entity::UraniumCannonProjectile,
+    Entity::UraniumCannonShellExplosion,
+    Entity::WallDamagedExplosion,
+    Entity::WaterSplash,
+    Entity::WoodenChest,
+    Entity::Character,
+    Entity::AssemblingMachine1Remnants,
+    Entity::AssemblingMachine2Remnants,
+    Entity::AssemblingMachine3Remnants,
+    Entity::BoilerExplosion,
+    Entity::BoilerRemnants,
+    Entity::BurnerInserterRemnants,
+    Entity::BurnerMiningDrillRemnants,
+    Entity::ElectricFurnaceRemnants,
+    Entity::ElectricMiningDrillRemnants,
+    Entity::LampRemnants,
+    Entity::LandMineExplosion,
+    Entity::SlowdownCapsuleExplosion,
+    Entity::SmallElectricPoleRemnants,
+    Entity::SmallRemnants,
+    Entity::SmallScorchmark,
+    Entity::SteelFurnaceRemnants,
+    Entity::StoneFurnaceRemnants,
+    Entity::TransportBeltRemnants,
+    Entity::WallRemnants,
+    Entity::WoodenChestExplosion,
+    Entity::WoodenChestRemnants,
+    Entity::OneXTwoRemnants,
+    Entity::ArithmeticCombinatorRemnants,
+    Entity::CrudeOil,
+    Entity::FastTransportBeltRemnants,
+    Entity::GateRemnants,
+    Entity::InserterRemnants,
+    Entity::IronChestExplosion,
+    Entity::IronChestRemnants,
+    Entity::MediumElectricPoleRemnants,
+    Entity::SmallScorchmarkTintable,
+    Entity::SteamEngineExplosion,
+    Entity::SteamEngineRemnants,
+    Entity::Coal,
+    Entity::CopperOre,
+    Entity::IronOre,
+    Entity::Stone,
+    Entity::UraniumOre,
+    Entity::ActiveProviderChestRemnants,
+    Entity::BigElectricPoleRemnants,
+    Entity::BigRemnants,
+    Entity::DeciderCombinatorRemnants,
+    Entity::ExpressTransportBeltRemnants,
+    Entity::GunTurretRemnants,
+    Entity::LongHandedInserterRemnants,
+    Entity::MediumScorchmark,
+    Entity::PowerSwitchRemnants,
+    Entity::SolarPanelExplosion,
+    Entity::SolarPanelRemnants,
+    Entity::SteelChestExplosion,
+    Entity::SteelChestRemnants,
+    Entity::TrainStopRemnants,
+    Entity::AccumulatorRemnants,
+    Entity::BeaconRemnants,
+    Entity::ConstantCombinatorRemnants,
+    Entity::FastInserterRemnants,
+    Entity::LaserTurretRemnants,
+    Entity::MediumRemnants,
+    Entity::MediumScorchmarkTintable,
+    Entity::NuclearReactorExplosion,
+    Entity::OilRefineryRemnants,
+    Entity::PassiveProviderChestRemnants,
+    Entity::PipeRemnants,
+    Entity::PipeToGroundRemnants,
+    Entity::PumpRemnants,
+    Entity::PumpjackRemnants,
+    Entity::RailSignalRemnants,
+    Entity::StorageTankExplosion,
+    Entity::StorageTankRemnants,
+    Entity::SubstationRemnants,
+    Entity::UndergroundBeltRemnants,
+    Entity::OffshorePumpRemnants,
+    Entity::BigScorchmark,
+    Entity::ChemicalPlantRemnants,
+    Entity::FastUndergroundBeltRemnants,
+    Entity::FilterInserterRemnants,
+    Entity::FlamethrowerTurretRemnants,
+    Entity::HeatPipeExplosion,
+    Entity::MediumSmallRemnants,
+    Entity::NuclearReactorRemnants,
+    Entity::ProgrammableSpeakerRemnants,
+    Entity::RailChainSignalRemnants,
+    Entity::StorageChestRemnants,
+    Entity::ArtilleryTurretRemnants,
+    Entity::BigScorchmarkTintable,
+    Entity::BufferChestRemnants,
+    Entity::CentrifugeRemnants,
+    Entity::ExpressUndergroundBeltRemnants,
+    Entity::HeatExchangerExplosion,
+    Entity::HeatPipeRemnants,
+    Entity::LocomotiveRemnants,
+    Entity::StackInserterRemnants,
+    Entity::CargoWagonRemnants,
+    Entity::HeatExchangerRemnants,
+    Entity::HugeScorchmark,
+    Entity::LabRemnants,
+    Entity::RadarRemnants,
+    Entity::RequesterChestRemnants,
+    Entity::SplitterRemnants,
+    Entity::StackFilterInserterRemnants,
+    Entity::SteamTurbineExplosion,
+    Entity::FastSplitterRemnants,
+    Entity::FluidWagonRemnants,
+    Entity::HugeScorchmarkTintable,
+    Entity::RoboportRemnants,
+    Entity::RocketSiloRemnants,
+    Entity::SteamTurbineRemnants,
+    Entity::ArtilleryWagonRemnants,
+    Entity::ExpressSplitterRemnants,
+    Entity::LandMineRemnants,
+    Entity::CarRemnants,
+    Entity::TankRemnants,
+    Entity::SpidertronRemnants,
+    Entity::Tree01,
+    Entity::Tree02,
+    Entity::Tree03,
+    Entity::Tree04,
+    Entity::Tree05,
+    Entity::Tree09,
+    Entity::Tree02Red,
+    Entity::Tree07,
+    Entity::Tree06,
+    Entity::Tree06Brown,
+    Entity::Tree09Brown,
+    Entity::Tree09Red,
+    Entity::Tree08,
+    Entity::Tree08Brown,
+    Entity::Tree08Red,
+    Entity::DeadDryHairyTree,
+    Entity::DeadGreyTrunk,
+    Entity::DeadTreeDesert,
+    Entity::DryHairyTree,
+    Entity::DryTree,
+    Entity::Fish,
+    Entity::BurnerMiningDrillExplosion,
+    Entity::SmallBiter,
+    Entity::TransportBeltExplosion,
+    Entity::WallExplosion,
+    Entity::MediumBiter,
+    Entity::BigBiter,
+    Entity::BehemothBiter,
+    Entity::ElectricMiningDrillExplosion,
+    Entity::FastTransportBeltExplosion,
+    Entity::GateExplosion,
+    Entity::SmallSpitter,
+    Entity::MediumSpitter,
+    Entity::BigSpitter,
+    Entity::BehemothSpitter,
+    Entity::ExpressTransportBeltExplosion,
+    Entity::GunTurretExplosion,
+    Entity::OffshorePumpExplosion,
+    Entity::SmallWormTurret,
+    Entity::MediumWormTurret,
+    Entity::BigWormTurret,
+    Entity::BehemothWormTurret,
+    Entity::BiterSpawner,
+    Entity::LaserTurretExplosion,
+    Entity::PumpjackExplosion,
+    Entity::UndergroundBeltExplosion,
+    Entity::SpitterSpawner,
+    Entity::FastUndergroundBeltExplosion,
+    Entity::FlamethrowerTurretExplosion,
+    Entity::ArtilleryTurretExplosion,
+    Entity::ExpressUndergroundBeltExplosion,
+    Entity::RadarExplosion,
+    Entity::SplitterExplosion,
+    Entity::FastSplitterExplosion,
+    Entity::RocketSiloExplosion,
+    Entity::ExpressSplitterExplosion,
+    Entity::BlueChest,
+    Entity::SandRockBig,
+    Entity::RockHuge,
+    Entity::Cliff,
+    Entity::RockBig,
+    Entity::BurnerInserterExplosion,
+    Entity::DefenderRobotExplosion,
+    Entity::StoneFurnaceExplosion,
+    Entity::DistractorRobotExplosion,
+    Entity::InserterExplosion,
+    Entity::SteelFurnaceExplosion,
+    Entity::DestroyerRobotExplosion,
+    Entity::ElectricFurnaceExplosion,
+    Entity::LongHandedInserterExplosion,
+    Entity::FastInserterExplosion,
+    Entity::FilterInserterExplosion,
+    Entity::StackInserterExplosion,
+    Entity::StackFilterInserterExplosion,
+    Entity::SmallBiterCorpse,
+    Entity::MediumBiterCorpse,
+    Entity::BehemothBiterCorpse,
+    Entity::BigBiterCorpse,
+    Entity::BiterSpawnerCorpse,
+    Entity::BehemothSpitterCorpse,
+    Entity::BigSpitterCorpse,
+    Entity::MediumSpitterCorpse,
+    Entity::SmallSpitterCorpse,
+    Entity::SpitterSpawnerCorpse,
+    Entity::SmallWormCorpse,
+    Entity::MediumWormCorpse,
+    Entity::BigWormCorpse,
+    Entity::BehemothWormCorpse,
+    Entity::AssemblingMachine1Explosion,
+    Entity::Market,
+    Entity::SmallElectricPoleExplosion,
+    Entity::AssemblingMachine2Explosion,
+    Entity::MediumElectricPoleExplosion,
+    Entity::AssemblingMachine3Explosion,
+    Entity::BigElectricPoleExplosion,
+    Entity::OilRefineryExplosion,
+    Entity::SubstationExplosion,
+    Entity::ChemicalPlantExplosion,
+    Entity::CentrifugeExplosion,
+    Entity::PipeExplosion,
+    Entity::LabExplosion,
+    Entity::PipeToGroundExplosion,
+    Entity::PumpExplosion,
+    Entity::ConstructionRobotRemnants,
+    Entity::DefenderRemnants,
+    Entity::DestroyerRemnants,
+    Entity::DistractorRemnants,
+    Entity::LogisticRobotRemnants,
+    Entity::StraightRailRemnants,
+    Entity::CurvedRailRemnants,
+    Entity::RailEndingRemnants,
+    Entity::Tree01Stump,
+    Entity::Tree02Stump,
+    Entity::Tree03Stump,
+    Entity::Tree04Stump,
+    Entity::Tree05Stump,
+    Entity::Tree06Stump,
+    Entity::Tree07Stump,
+    Entity::Tree08Stump,
+    Entity::Tree09Stump,
+    Entity::BigShipWreck1,
+    Entity::BigShipWreck2,
+    Entity::BigShipWreck3,
+    Entity::MediumShipWreck,
+    Entity::SmallShipWreck,
+    Entity::BeaconExplosion,
+    Entity::Defender,
+    Entity::RailExplosion,
+    Entity::Distractor,
+    Entity::Destroyer,
+    Entity::TrainStopExplosion,
+    Entity::RailSignalExplosion,
+    Entity::RailChainSignalExplosion,
+    Entity::LocomotiveExplosion,
+    Entity::AccumulatorExplosion,
+    Entity::FluidWagonExplosion,
+    Entity::CargoWagonExplosion,
+    Entity::ArtilleryWagonExplosion,
+    Entity::CarExplosion,
+    Entity::TankExplosion,
+    Entity::SpidertronExplosion,
+    Entity::LogisticRobotExplosion,
+    Entity::ConstructionRobotExplosion,
+    Entity::ActiveProviderChestExplosion,
+    Entity::PassiveProviderChestExplosion,
+    Entity::BufferChestExplosion,
+    Entity::StorageChestExplosion,
+    Entity::RequesterChestExplosion,
+    Entity::RoboportExplosion,
+    Entity::LampExplosion,
+    Entity::ArithmeticCombinatorExplosion,
+    Entity::DeciderCombinatorExplosion,
+    Entity::ConstantCombinatorExplosion,
+    Entity::PowerSwitchExplosion,
+    Entity::ProgrammableSpeakerExplosion,
+    Entity::HiddenElectricEnergyInterface,
+    Entity::RedChest,
+    Entity::SimpleEntityWithForce,
+    Entity::SimpleEntityWithOwner,
+    Entity::Compilatron,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Entity> {
+    Entity::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Entity::Accumulator => "accumulator",
@@ -1070,537 +1606,551 @@ impl Entity {
       Entity::Compilatron => "compilatron",
     }
   }
-  pub fn from_name(name: &str) -> Entity {
+  pub fn try_from_name(name: &str) -> Option<Entity> {
     match name {
-      "accumulator" => Entity::Accumulator,
-      "acid-splash-fire-spitter-behemoth" => Entity::AcidSplashFireSpitterBehemoth,
-      "acid-splash-fire-spitter-big" => Entity::AcidSplashFireSpitterBig,
-      "acid-splash-fire-spitter-medium" => Entity::AcidSplashFireSpitterMedium,
-      "acid-splash-fire-spitter-small" => Entity::AcidSplashFireSpitterSmall,
-      "acid-splash-fire-worm-behemoth" => Entity::AcidSplashFireWormBehemoth,
-      "acid-splash-fire-worm-big" => Entity::AcidSplashFireWormBig,
-      "acid-splash-fire-worm-medium" => Entity::AcidSplashFireWormMedium,
-      "acid-splash-fire-worm-small" => Entity::AcidSplashFireWormSmall,
-      "acid-sticker-behemoth" => Entity::AcidStickerBehemoth,
-      "acid-sticker-big" => Entity::AcidStickerBig,
-      "acid-sticker-medium" => Entity::AcidStickerMedium,
-      "acid-sticker-small" => Entity::AcidStickerSmall,
-      "acid-stream-spitter-behemoth" => Entity::AcidStreamSpitterBehemoth,
-      "acid-stream-spitter-big" => Entity::AcidStreamSpitterBig,
-      "acid-stream-spitter-medium" => Entity::AcidStreamSpitterMedium,
-      "acid-stream-spitter-small" => Entity::AcidStreamSpitterSmall,
-      "acid-stream-worm-behemoth" => Entity::AcidStreamWormBehemoth,
-      "acid-stream-worm-big" => Entity::AcidStreamWormBig,
-      "acid-stream-worm-medium" => Entity::AcidStreamWormMedium,
-      "acid-stream-worm-small" => Entity::AcidStreamWormSmall,
-      "arithmetic-combinator" => Entity::ArithmeticCombinator,
-      "artillery-cannon-muzzle-flash" => Entity::ArtilleryCannonMuzzleFlash,
-      "artillery-flare" => Entity::ArtilleryFlare,
-      "artillery-projectile" => Entity::ArtilleryProjectile,
-      "artillery-turret" => Entity::ArtilleryTurret,
-      "artillery-wagon" => Entity::ArtilleryWagon,
-      "assembling-machine-1" => Entity::AssemblingMachine1,
-      "assembling-machine-2" => Entity::AssemblingMachine2,
-      "assembling-machine-3" => Entity::AssemblingMachine3,
-      "atomic-bomb-ground-zero-projectile" => Entity::AtomicBombGroundZeroProjectile,
-      "atomic-bomb-wave" => Entity::AtomicBombWave,
-      "atomic-bomb-wave-spawns-cluster-nuke-explosion" => Entity::AtomicBombWaveSpawnsClusterNukeExplosion,
-      "atomic-bomb-wave-spawns-fire-smoke-explosion" => Entity::AtomicBombWaveSpawnsFireSmokeExplosion,
-      "atomic-bomb-wave-spawns-nuclear-smoke" => Entity::AtomicBombWaveSpawnsNuclearSmoke,
-      "atomic-bomb-wave-spawns-nuke-shockwave-explosion" => Entity::AtomicBombWaveSpawnsNukeShockwaveExplosion,
-      "atomic-fire-smoke" => Entity::AtomicFireSmoke,
-      "atomic-nuke-shockwave" => Entity::AtomicNukeShockwave,
-      "atomic-rocket" => Entity::AtomicRocket,
-      "beacon" => Entity::Beacon,
-      "behemoth-biter-die" => Entity::BehemothBiterDie,
-      "behemoth-spitter-die" => Entity::BehemothSpitterDie,
-      "behemoth-worm-die" => Entity::BehemothWormDie,
-      "big-artillery-explosion" => Entity::BigArtilleryExplosion,
-      "big-biter-die" => Entity::BigBiterDie,
-      "big-electric-pole" => Entity::BigElectricPole,
-      "big-explosion" => Entity::BigExplosion,
-      "big-spitter-die" => Entity::BigSpitterDie,
-      "big-worm-die" => Entity::BigWormDie,
-      "biter-spawner-die" => Entity::BiterSpawnerDie,
-      "blood-explosion-big" => Entity::BloodExplosionBig,
-      "blood-explosion-huge" => Entity::BloodExplosionHuge,
-      "blood-explosion-small" => Entity::BloodExplosionSmall,
-      "blood-fountain" => Entity::BloodFountain,
-      "blood-fountain-big" => Entity::BloodFountainBig,
-      "blood-fountain-hit-spray" => Entity::BloodFountainHitSpray,
-      "blue-laser" => Entity::BlueLaser,
-      "boiler" => Entity::Boiler,
-      "burner-generator" => Entity::BurnerGenerator,
-      "burner-inserter" => Entity::BurnerInserter,
-      "burner-mining-drill" => Entity::BurnerMiningDrill,
-      "cannon-projectile" => Entity::CannonProjectile,
-      "car" => Entity::Car,
-      "cargo-wagon" => Entity::CargoWagon,
-      "centrifuge" => Entity::Centrifuge,
-      "character-corpse" => Entity::CharacterCorpse,
-      "chemical-plant" => Entity::ChemicalPlant,
-      "cliff-explosives" => Entity::CliffExplosives,
-      "cluster-grenade" => Entity::ClusterGrenade,
-      "cluster-nuke-explosion" => Entity::ClusterNukeExplosion,
-      "compi-speech-bubble" => Entity::CompiSpeechBubble,
-      "constant-combinator" => Entity::ConstantCombinator,
-      "construction-robot" => Entity::ConstructionRobot,
-      "crash-site-chest-1" => Entity::CrashSiteChest1,
-      "crash-site-chest-2" => Entity::CrashSiteChest2,
-      "crash-site-explosion-smoke" => Entity::CrashSiteExplosionSmoke,
-      "crash-site-fire-flame" => Entity::CrashSiteFireFlame,
-      "crash-site-fire-smoke" => Entity::CrashSiteFireSmoke,
-      "crash-site-spaceship" => Entity::CrashSiteSpaceship,
-      "crash-site-spaceship-wreck-big-1" => Entity::CrashSiteSpaceshipWreckBig1,
-      "crash-site-spaceship-wreck-big-2" => Entity::CrashSiteSpaceshipWreckBig2,
-      "crash-site-spaceship-wreck-medium-1" => Entity::CrashSiteSpaceshipWreckMedium1,
-      "crash-site-spaceship-wreck-medium-2" => Entity::CrashSiteSpaceshipWreckMedium2,
-      "crash-site-spaceship-wreck-medium-3" => Entity::CrashSiteSpaceshipWreckMedium3,
-      "crash-site-spaceship-wreck-small-1" => Entity::CrashSiteSpaceshipWreckSmall1,
-      "crash-site-spaceship-wreck-small-2" => Entity::CrashSiteSpaceshipWreckSmall2,
-      "crash-site-spaceship-wreck-small-3" => Entity::CrashSiteSpaceshipWreckSmall3,
-      "crash-site-spaceship-wreck-small-4" => Entity::CrashSiteSpaceshipWreckSmall4,
-      "crash-site-spaceship-wreck-small-5" => Entity::CrashSiteSpaceshipWreckSmall5,
-      "crash-site-spaceship-wreck-small-6" => Entity::CrashSiteSpaceshipWreckSmall6,
-      "curved-rail" => Entity::CurvedRail,
-      "decider-combinator" => Entity::DeciderCombinator,
-      "deconstructible-tile-proxy" => Entity::DeconstructibleTileProxy,
-      "defender-capsule" => Entity::DefenderCapsule,
-      "destroyer-capsule" => Entity::DestroyerCapsule,
-      "distractor-capsule" => Entity::DistractorCapsule,
-      "dummy-flame-thrower-explosion" => Entity::DummyFlameThrowerExplosion,
-      "electric-beam" => Entity::ElectricBeam,
-      "electric-beam-no-sound" => Entity::ElectricBeamNoSound,
-      "electric-energy-interface" => Entity::ElectricEnergyInterface,
-      "electric-furnace" => Entity::ElectricFurnace,
-      "electric-mining-drill" => Entity::ElectricMiningDrill,
-      "enemy-damaged-explosion" => Entity::EnemyDamagedExplosion,
-      "entity-ghost" => Entity::EntityGhost,
-      "entity-unknown" => Entity::EntityUnknown,
-      "explosion" => Entity::Explosion,
-      "explosion-gunshot" => Entity::ExplosionGunshot,
-      "explosion-gunshot-small" => Entity::ExplosionGunshotSmall,
-      "explosion-hit" => Entity::ExplosionHit,
-      "explosive-cannon-projectile" => Entity::ExplosiveCannonProjectile,
-      "explosive-rocket" => Entity::ExplosiveRocket,
-      "explosive-uranium-cannon-projectile" => Entity::ExplosiveUraniumCannonProjectile,
-      "express-loader" => Entity::ExpressLoader,
-      "express-splitter" => Entity::ExpressSplitter,
-      "express-transport-belt" => Entity::ExpressTransportBelt,
-      "express-underground-belt" => Entity::ExpressUndergroundBelt,
-      "factorio-logo-11tiles" => Entity::FactorioLogo11tiles,
-      "factorio-logo-16tiles" => Entity::FactorioLogo16tiles,
-      "factorio-logo-22tiles" => Entity::FactorioLogo22tiles,
-      "fake-selection-box-2x2" => Entity::FakeSelectionBox2x2,
-      "fast-inserter" => Entity::FastInserter,
-      "fast-loader" => Entity::FastLoader,
-      "fast-splitter" => Entity::FastSplitter,
-      "fast-transport-belt" => Entity::FastTransportBelt,
-      "fast-underground-belt" => Entity::FastUndergroundBelt,
-      "filter-inserter" => Entity::FilterInserter,
-      "fire-flame" => Entity::FireFlame,
-      "fire-flame-on-tree" => Entity::FireFlameOnTree,
-      "fire-sticker" => Entity::FireSticker,
-      "flamethrower-fire-stream" => Entity::FlamethrowerFireStream,
-      "flamethrower-turret" => Entity::FlamethrowerTurret,
-      "fluid-wagon" => Entity::FluidWagon,
-      "flying-robot-damaged-explosion" => Entity::FlyingRobotDamagedExplosion,
-      "flying-text" => Entity::FlyingText,
-      "gate" => Entity::Gate,
-      "grenade" => Entity::Grenade,
-      "grenade-explosion" => Entity::GrenadeExplosion,
-      "ground-explosion" => Entity::GroundExplosion,
-      "gun-turret" => Entity::GunTurret,
-      "handheld-flamethrower-fire-stream" => Entity::HandheldFlamethrowerFireStream,
-      "heat-exchanger" => Entity::HeatExchanger,
-      "heat-interface" => Entity::HeatInterface,
-      "heat-pipe" => Entity::HeatPipe,
-      "highlight-box" => Entity::HighlightBox,
-      "infinity-chest" => Entity::InfinityChest,
-      "infinity-pipe" => Entity::InfinityPipe,
-      "inserter" => Entity::Inserter,
-      "iron-chest" => Entity::IronChest,
-      "item-on-ground" => Entity::ItemOnGround,
-      "item-request-proxy" => Entity::ItemRequestProxy,
-      "lab" => Entity::Lab,
-      "land-mine" => Entity::LandMine,
-      "laser" => Entity::Laser,
-      "laser-beam" => Entity::LaserBeam,
-      "laser-bubble" => Entity::LaserBubble,
-      "laser-turret" => Entity::LaserTurret,
-      "leaf-particle-for-migration" => Entity::LeafParticleForMigration,
-      "linked-belt" => Entity::LinkedBelt,
-      "linked-chest" => Entity::LinkedChest,
-      "loader" => Entity::Loader,
-      "loader-1x1" => Entity::Loader1x1,
-      "locomotive" => Entity::Locomotive,
-      "logistic-chest-active-provider" => Entity::LogisticChestActiveProvider,
-      "logistic-chest-buffer" => Entity::LogisticChestBuffer,
-      "logistic-chest-passive-provider" => Entity::LogisticChestPassiveProvider,
-      "logistic-chest-requester" => Entity::LogisticChestRequester,
-      "logistic-chest-storage" => Entity::LogisticChestStorage,
-      "logistic-robot" => Entity::LogisticRobot,
-      "long-handed-inserter" => Entity::LongHandedInserter,
-      "massive-explosion" => Entity::MassiveExplosion,
-      "medium-biter-die" => Entity::MediumBiterDie,
-      "medium-electric-pole" => Entity::MediumElectricPole,
-      "medium-explosion" => Entity::MediumExplosion,
-      "medium-spitter-die" => Entity::MediumSpitterDie,
-      "medium-worm-die" => Entity::MediumWormDie,
-      "nuclear-reactor" => Entity::NuclearReactor,
-      "nuclear-smouldering-smoke-source" => Entity::NuclearSmoulderingSmokeSource,
-      "nuke-explosion" => Entity::NukeExplosion,
-      "offshore-pump" => Entity::OffshorePump,
-      "oil-refinery" => Entity::OilRefinery,
-      "orange-arrow-with-circle" => Entity::OrangeArrowWithCircle,
-      "particle-for-migration" => Entity::ParticleForMigration,
-      "piercing-shotgun-pellet" => Entity::PiercingShotgunPellet,
-      "pipe" => Entity::Pipe,
-      "pipe-to-ground" => Entity::PipeToGround,
-      "player-port" => Entity::PlayerPort,
-      "poison-capsule" => Entity::PoisonCapsule,
-      "poison-cloud" => Entity::PoisonCloud,
-      "poison-cloud-visual-dummy" => Entity::PoisonCloudVisualDummy,
-      "power-switch" => Entity::PowerSwitch,
-      "programmable-speaker" => Entity::ProgrammableSpeaker,
-      "pump" => Entity::Pump,
-      "pumpjack" => Entity::Pumpjack,
-      "radar" => Entity::Radar,
-      "rail-chain-signal" => Entity::RailChainSignal,
-      "rail-signal" => Entity::RailSignal,
-      "roboport" => Entity::Roboport,
-      "rock-damaged-explosion" => Entity::RockDamagedExplosion,
-      "rocket" => Entity::Rocket,
-      "rocket-silo" => Entity::RocketSilo,
-      "rocket-silo-rocket" => Entity::RocketSiloRocket,
-      "rocket-silo-rocket-shadow" => Entity::RocketSiloRocketShadow,
-      "shotgun-pellet" => Entity::ShotgunPellet,
-      "slowdown-capsule" => Entity::SlowdownCapsule,
-      "slowdown-sticker" => Entity::SlowdownSticker,
-      "small-biter-die" => Entity::SmallBiterDie,
-      "small-electric-pole" => Entity::SmallElectricPole,
-      "small-lamp" => Entity::SmallLamp,
-      "small-spitter-die" => Entity::SmallSpitterDie,
-      "small-worm-die" => Entity::SmallWormDie,
-      "smoke-for-migration" => Entity::SmokeForMigration,
-      "solar-panel" => Entity::SolarPanel,
-      "spark-explosion" => Entity::SparkExplosion,
-      "spark-explosion-higher" => Entity::SparkExplosionHigher,
-      "spidertron" => Entity::Spidertron,
-      "spidertron-leg-1" => Entity::SpidertronLeg1,
-      "spidertron-leg-2" => Entity::SpidertronLeg2,
-      "spidertron-leg-3" => Entity::SpidertronLeg3,
-      "spidertron-leg-4" => Entity::SpidertronLeg4,
-      "spidertron-leg-5" => Entity::SpidertronLeg5,
-      "spidertron-leg-6" => Entity::SpidertronLeg6,
-      "spidertron-leg-7" => Entity::SpidertronLeg7,
-      "spidertron-leg-8" => Entity::SpidertronLeg8,
-      "spidertron-military-target" => Entity::SpidertronMilitaryTarget,
-      "spitter-spawner-die" => Entity::SpitterSpawnerDie,
-      "splitter" => Entity::Splitter,
-      "stack-filter-inserter" => Entity::StackFilterInserter,
-      "stack-inserter" => Entity::StackInserter,
-      "steam-engine" => Entity::SteamEngine,
-      "steam-turbine" => Entity::SteamTurbine,
-      "steel-chest" => Entity::SteelChest,
-      "steel-furnace" => Entity::SteelFurnace,
-      "stone-furnace" => Entity::StoneFurnace,
-      "stone-wall" => Entity::StoneWall,
-      "storage-tank" => Entity::StorageTank,
-      "straight-rail" => Entity::StraightRail,
-      "stun-sticker" => Entity::StunSticker,
-      "substation" => Entity::Substation,
-      "tank" => Entity::Tank,
-      "tank-flamethrower-fire-stream" => Entity::TankFlamethrowerFireStream,
-      "tile-ghost" => Entity::TileGhost,
-      "tile-proxy" => Entity::TileProxy,
-      "train-stop" => Entity::TrainStop,
-      "transport-belt" => Entity::TransportBelt,
-      "tree-dying-proxy" => Entity::TreeDyingProxy,
-      "tree-proxy" => Entity::TreeProxy,
-      "tutorial-flying-text" => Entity::TutorialFlyingText,
-      "underground-belt" => Entity::UndergroundBelt,
-      "uranium-cannon-explosion" => Entity::UraniumCannonExplosion,
-      "uranium-cannon-projectile" => Entity::UraniumCannonProjectile,
-      "uranium-cannon-shell-explosion" => Entity::UraniumCannonShellExplosion,
-      "wall-damaged-explosion" => Entity::WallDamagedExplosion,
-      "water-splash" => Entity::WaterSplash,
-      "wooden-chest" => Entity::WoodenChest,
-      "character" => Entity::Character,
-      "assembling-machine-1-remnants" => Entity::AssemblingMachine1Remnants,
-      "assembling-machine-2-remnants" => Entity::AssemblingMachine2Remnants,
-      "assembling-machine-3-remnants" => Entity::AssemblingMachine3Remnants,
-      "boiler-explosion" => Entity::BoilerExplosion,
-      "boiler-remnants" => Entity::BoilerRemnants,
-      "burner-inserter-remnants" => Entity::BurnerInserterRemnants,
-      "burner-mining-drill-remnants" => Entity::BurnerMiningDrillRemnants,
-      "electric-furnace-remnants" => Entity::ElectricFurnaceRemnants,
-      "electric-mining-drill-remnants" => Entity::ElectricMiningDrillRemnants,
-      "lamp-remnants" => Entity::LampRemnants,
-      "land-mine-explosion" => Entity::LandMineExplosion,
-      "slowdown-capsule-explosion" => Entity::SlowdownCapsuleExplosion,
-      "small-electric-pole-remnants" => Entity::SmallElectricPoleRemnants,
-      "small-remnants" => Entity::SmallRemnants,
-      "small-scorchmark" => Entity::SmallScorchmark,
-      "steel-furnace-remnants" => Entity::SteelFurnaceRemnants,
-      "stone-furnace-remnants" => Entity::StoneFurnaceRemnants,
-      "transport-belt-remnants" => Entity::TransportBeltRemnants,
-      "wall-remnants" => Entity::WallRemnants,
-      "wooden-chest-explosion" => Entity::WoodenChestExplosion,
-      "wooden-chest-remnants" => Entity::WoodenChestRemnants,
-      "1x2-remnants" => Entity::OneXTwoRemnants,
-      "arithmetic-combinator-remnants" => Entity::ArithmeticCombinatorRemnants,
-      "crude-oil" => Entity::CrudeOil,
-      "fast-transport-belt-remnants" => Entity::FastTransportBeltRemnants,
-      "gate-remnants" => Entity::GateRemnants,
-      "inserter-remnants" => Entity::InserterRemnants,
-      "iron-chest-explosion" => Entity::IronChestExplosion,
-      "iron-chest-remnants" => Entity::IronChestRemnants,
-      "medium-electric-pole-remnants" => Entity::MediumElectricPoleRemnants,
-      "small-scorchmark-tintable" => Entity::SmallScorchmarkTintable,
-      "steam-engine-explosion" => Entity::SteamEngineExplosion,
-      "steam-engine-remnants" => Entity::SteamEngineRemnants,
-      "coal" => Entity::Coal,
-      "copper-ore" => Entity::CopperOre,
-      "iron-ore" => Entity::IronOre,
-      "stone" => Entity::Stone,
-      "uranium-ore" => Entity::UraniumOre,
-      "active-provider-chest-remnants" => Entity::ActiveProviderChestRemnants,
-      "big-electric-pole-remnants" => Entity::BigElectricPoleRemnants,
-      "big-remnants" => Entity::BigRemnants,
-      "decider-combinator-remnants" => Entity::DeciderCombinatorRemnants,
-      "express-transport-belt-remnants" => Entity::ExpressTransportBeltRemnants,
-      "gun-turret-remnants" => Entity::GunTurretRemnants,
-      "long-handed-inserter-remnants" => Entity::LongHandedInserterRemnants,
-      "medium-scorchmark" => Entity::MediumScorchmark,
-      "power-switch-remnants" => Entity::PowerSwitchRemnants,
-      "solar-panel-explosion" => Entity::SolarPanelExplosion,
-      "solar-panel-remnants" => Entity::SolarPanelRemnants,
-      "steel-chest-explosion" => Entity::SteelChestExplosion,
-      "steel-chest-remnants" => Entity::SteelChestRemnants,
-      "train-stop-remnants" => Entity::TrainStopRemnants,
-      "accumulator-remnants" => Entity::AccumulatorRemnants,
-      "beacon-remnants" => Entity::BeaconRemnants,
-      "constant-combinator-remnants" => Entity::ConstantCombinatorRemnants,
-      "fast-inserter-remnants" => Entity::FastInserterRemnants,
-      "laser-turret-remnants" => Entity::LaserTurretRemnants,
-      "medium-remnants" => Entity::MediumRemnants,
-      "medium-scorchmark-tintable" => Entity::MediumScorchmarkTintable,
-      "nuclear-reactor-explosion" => Entity::NuclearReactorExplosion,
-      "oil-refinery-remnants" => Entity::OilRefineryRemnants,
-      "passive-provider-chest-remnants" => Entity::PassiveProviderChestRemnants,
-      "pipe-remnants" => Entity::PipeRemnants,
-      "pipe-to-ground-remnants" => Entity::PipeToGroundRemnants,
-      "pump-remnants" => Entity::PumpRemnants,
-      "pumpjack-remnants" => Entity::PumpjackRemnants,
-      "rail-signal-remnants" => Entity::RailSignalRemnants,
-      "storage-tank-explosion" => Entity::StorageTankExplosion,
-      "storage-tank-remnants" => Entity::StorageTankRemnants,
-      "substation-remnants" => Entity::SubstationRemnants,
-      "underground-belt-remnants" => Entity::UndergroundBeltRemnants,
-      "offshore-pump-remnants" => Entity::OffshorePumpRemnants,
-      "big-scorchmark" => Entity::BigScorchmark,
-      "chemical-plant-remnants" => Entity::ChemicalPlantRemnants,
-      "fast-underground-belt-remnants" => Entity::FastUndergroundBeltRemnants,
-      "filter-inserter-remnants" => Entity::FilterInserterRemnants,
-      "flamethrower-turret-remnants" => Entity::FlamethrowerTurretRemnants,
-      "heat-pipe-explosion" => Entity::HeatPipeExplosion,
-      "medium-small-remnants" => Entity::MediumSmallRemnants,
-      "nuclear-reactor-remnants" => Entity::NuclearReactorRemnants,
-      "programmable-speaker-remnants" => Entity::ProgrammableSpeakerRemnants,
-      "rail-chain-signal-remnants" => Entity::RailChainSignalRemnants,
-      "storage-chest-remnants" => Entity::StorageChestRemnants,
-      "artillery-turret-remnants" => Entity::ArtilleryTurretRemnants,
-      "big-scorchmark-tintable" => Entity::BigScorchmarkTintable,
-      "buffer-chest-remnants" => Entity::BufferChestRemnants,
-      "centrifuge-remnants" => Entity::CentrifugeRemnants,
-      "express-underground-belt-remnants" => Entity::ExpressUndergroundBeltRemnants,
-      "heat-exchanger-explosion" => Entity::HeatExchangerExplosion,
-      "heat-pipe-remnants" => Entity::HeatPipeRemnants,
-      "locomotive-remnants" => Entity::LocomotiveRemnants,
-      "stack-inserter-remnants" => Entity::StackInserterRemnants,
-      "cargo-wagon-remnants" => Entity::CargoWagonRemnants,
-      "heat-exchanger-remnants" => Entity::HeatExchangerRemnants,
-      "huge-scorchmark" => Entity::HugeScorchmark,
-      "lab-remnants" => Entity::LabRemnants,
-      "radar-remnants" => Entity::RadarRemnants,
-      "requester-chest-remnants" => Entity::RequesterChestRemnants,
-      "splitter-remnants" => Entity::SplitterRemnants,
-      "stack-filter-inserter-remnants" => Entity::StackFilterInserterRemnants,
-      "steam-turbine-explosion" => Entity::SteamTurbineExplosion,
-      "fast-splitter-remnants" => Entity::FastSplitterRemnants,
-      "fluid-wagon-remnants" => Entity::FluidWagonRemnants,
-      "huge-scorchmark-tintable" => Entity::HugeScorchmarkTintable,
-      "roboport-remnants" => Entity::RoboportRemnants,
-      "rocket-silo-remnants" => Entity::RocketSiloRemnants,
-      "steam-turbine-remnants" => Entity::SteamTurbineRemnants,
-      "artillery-wagon-remnants" => Entity::ArtilleryWagonRemnants,
-      "express-splitter-remnants" => Entity::ExpressSplitterRemnants,
-      "land-mine-remnants" => Entity::LandMineRemnants,
-      "car-remnants" => Entity::CarRemnants,
-      "tank-remnants" => Entity::TankRemnants,
-      "spidertron-remnants" => Entity::SpidertronRemnants,
-      "tree-01" => Entity::Tree01,
-      "tree-02" => Entity::Tree02,
-      "tree-03" => Entity::Tree03,
-      "tree-04" => Entity::Tree04,
-      "tree-05" => Entity::Tree05,
-      "tree-09" => Entity::Tree09,
-      "tree-02-red" => Entity::Tree02Red,
-      "tree-07" => Entity::Tree07,
-      "tree-06" => Entity::Tree06,
-      "tree-06-brown" => Entity::Tree06Brown,
-      "tree-09-brown" => Entity::Tree09Brown,
-      "tree-09-red" => Entity::Tree09Red,
-      "tree-08" => Entity::Tree08,
-      "tree-08-brown" => Entity::Tree08Brown,
-      "tree-08-red" => Entity::Tree08Red,
-      "dead-dry-hairy-tree" => Entity::DeadDryHairyTree,
-      "dead-grey-trunk" => Entity::DeadGreyTrunk,
-      "dead-tree-desert" => Entity::DeadTreeDesert,
-      "dry-hairy-tree" => Entity::DryHairyTree,
-      "dry-tree" => Entity::DryTree,
-      "fish" => Entity::Fish,
-      "burner-mining-drill-explosion" => Entity::BurnerMiningDrillExplosion,
-      "small-biter" => Entity::SmallBiter,
-      "transport-belt-explosion" => Entity::TransportBeltExplosion,
-      "wall-explosion" => Entity::WallExplosion,
-      "medium-biter" => Entity::MediumBiter,
-      "big-biter" => Entity::BigBiter,
-      "behemoth-biter" => Entity::BehemothBiter,
-      "electric-mining-drill-explosion" => Entity::ElectricMiningDrillExplosion,
-      "fast-transport-belt-explosion" => Entity::FastTransportBeltExplosion,
-      "gate-explosion" => Entity::GateExplosion,
-      "small-spitter" => Entity::SmallSpitter,
-      "medium-spitter" => Entity::MediumSpitter,
-      "big-spitter" => Entity::BigSpitter,
-      "behemoth-spitter" => Entity::BehemothSpitter,
-      "express-transport-belt-explosion" => Entity::ExpressTransportBeltExplosion,
-      "gun-turret-explosion" => Entity::GunTurretExplosion,
-      "offshore-pump-explosion" => Entity::OffshorePumpExplosion,
-      "small-worm-turret" => Entity::SmallWormTurret,
-      "medium-worm-turret" => Entity::MediumWormTurret,
-      "big-worm-turret" => Entity::BigWormTurret,
-      "behemoth-worm-turret" => Entity::BehemothWormTurret,
-      "biter-spawner" => Entity::BiterSpawner,
-      "laser-turret-explosion" => Entity::LaserTurretExplosion,
-      "pumpjack-explosion" => Entity::PumpjackExplosion,
-      "underground-belt-explosion" => Entity::UndergroundBeltExplosion,
-      "spitter-spawner" => Entity::SpitterSpawner,
-      "fast-underground-belt-explosion" => Entity::FastUndergroundBeltExplosion,
-      "flamethrower-turret-explosion" => Entity::FlamethrowerTurretExplosion,
-      "artillery-turret-explosion" => Entity::ArtilleryTurretExplosion,
-      "express-underground-belt-explosion" => Entity::ExpressUndergroundBeltExplosion,
-      "radar-explosion" => Entity::RadarExplosion,
-      "splitter-explosion" => Entity::SplitterExplosion,
-      "fast-splitter-explosion" => Entity::FastSplitterExplosion,
-      "rocket-silo-explosion" => Entity::RocketSiloExplosion,
-      "express-splitter-explosion" => Entity::ExpressSplitterExplosion,
-      "blue-chest" => Entity::BlueChest,
-      "sand-rock-big" => Entity::SandRockBig,
-      "rock-huge" => Entity::RockHuge,
-      "cliff" => Entity::Cliff,
-      "rock-big" => Entity::RockBig,
-      "burner-inserter-explosion" => Entity::BurnerInserterExplosion,
-      "defender-robot-explosion" => Entity::DefenderRobotExplosion,
-      "stone-furnace-explosion" => Entity::StoneFurnaceExplosion,
-      "distractor-robot-explosion" => Entity::DistractorRobotExplosion,
-      "inserter-explosion" => Entity::InserterExplosion,
-      "steel-furnace-explosion" => Entity::SteelFurnaceExplosion,
-      "destroyer-robot-explosion" => Entity::DestroyerRobotExplosion,
-      "electric-furnace-explosion" => Entity::ElectricFurnaceExplosion,
-      "long-handed-inserter-explosion" => Entity::LongHandedInserterExplosion,
-      "fast-inserter-explosion" => Entity::FastInserterExplosion,
-      "filter-inserter-explosion" => Entity::FilterInserterExplosion,
-      "stack-inserter-explosion" => Entity::StackInserterExplosion,
-      "stack-filter-inserter-explosion" => Entity::StackFilterInserterExplosion,
-      "small-biter-corpse" => Entity::SmallBiterCorpse,
-      "medium-biter-corpse" => Entity::MediumBiterCorpse,
-      "behemoth-biter-corpse" => Entity::BehemothBiterCorpse,
-      "big-biter-corpse" => Entity::BigBiterCorpse,
-      "biter-spawner-corpse" => Entity::BiterSpawnerCorpse,
-      "behemoth-spitter-corpse" => Entity::BehemothSpitterCorpse,
-      "big-spitter-corpse" => Entity::BigSpitterCorpse,
-      "medium-spitter-corpse" => Entity::MediumSpitterCorpse,
-      "small-spitter-corpse" => Entity::SmallSpitterCorpse,
-      "spitter-spawner-corpse" => Entity::SpitterSpawnerCorpse,
-      "small-worm-corpse" => Entity::SmallWormCorpse,
-      "medium-worm-corpse" => Entity::MediumWormCorpse,
-      "big-worm-corpse" => Entity::BigWormCorpse,
-      "behemoth-worm-corpse" => Entity::BehemothWormCorpse,
-      "assembling-machine-1-explosion" => Entity::AssemblingMachine1Explosion,
-      "market" => Entity::Market,
-      "small-electric-pole-explosion" => Entity::SmallElectricPoleExplosion,
-      "assembling-machine-2-explosion" => Entity::AssemblingMachine2Explosion,
-      "medium-electric-pole-explosion" => Entity::MediumElectricPoleExplosion,
-      "assembling-machine-3-explosion" => Entity::AssemblingMachine3Explosion,
-      "big-electric-pole-explosion" => Entity::BigElectricPoleExplosion,
-      "oil-refinery-explosion" => Entity::OilRefineryExplosion,
-      "substation-explosion" => Entity::SubstationExplosion,
-      "chemical-plant-explosion" => Entity::ChemicalPlantExplosion,
-      "centrifuge-explosion" => Entity::CentrifugeExplosion,
-      "pipe-explosion" => Entity::PipeExplosion,
-      "lab-explosion" => Entity::LabExplosion,
-      "pipe-to-ground-explosion" => Entity::PipeToGroundExplosion,
-      "pump-explosion" => Entity::PumpExplosion,
-      "construction-robot-remnants" => Entity::ConstructionRobotRemnants,
-      "defender-remnants" => Entity::DefenderRemnants,
-      "destroyer-remnants" => Entity::DestroyerRemnants,
-      "distractor-remnants" => Entity::DistractorRemnants,
-      "logistic-robot-remnants" => Entity::LogisticRobotRemnants,
-      "straight-rail-remnants" => Entity::StraightRailRemnants,
-      "curved-rail-remnants" => Entity::CurvedRailRemnants,
-      "rail-ending-remnants" => Entity::RailEndingRemnants,
-      "tree-01-stump" => Entity::Tree01Stump,
-      "tree-02-stump" => Entity::Tree02Stump,
-      "tree-03-stump" => Entity::Tree03Stump,
-      "tree-04-stump" => Entity::Tree04Stump,
-      "tree-05-stump" => Entity::Tree05Stump,
-      "tree-06-stump" => Entity::Tree06Stump,
-      "tree-07-stump" => Entity::Tree07Stump,
-      "tree-08-stump" => Entity::Tree08Stump,
-      "tree-09-stump" => Entity::Tree09Stump,
-      "big-ship-wreck-1" => Entity::BigShipWreck1,
-      "big-ship-wreck-2" => Entity::BigShipWreck2,
-      "big-ship-wreck-3" => Entity::BigShipWreck3,
-      "medium-ship-wreck" => Entity::MediumShipWreck,
-      "small-ship-wreck" => Entity::SmallShipWreck,
-      "beacon-explosion" => Entity::BeaconExplosion,
-      "defender" => Entity::Defender,
-      "rail-explosion" => Entity::RailExplosion,
-      "distractor" => Entity::Distractor,
-      "destroyer" => Entity::Destroyer,
-      "train-stop-explosion" => Entity::TrainStopExplosion,
-      "rail-signal-explosion" => Entity::RailSignalExplosion,
-      "rail-chain-signal-explosion" => Entity::RailChainSignalExplosion,
-      "locomotive-explosion" => Entity::LocomotiveExplosion,
-      "accumulator-explosion" => Entity::AccumulatorExplosion,
-      "fluid-wagon-explosion" => Entity::FluidWagonExplosion,
-      "cargo-wagon-explosion" => Entity::CargoWagonExplosion,
-      "artillery-wagon-explosion" => Entity::ArtilleryWagonExplosion,
-      "car-explosion" => Entity::CarExplosion,
-      "tank-explosion" => Entity::TankExplosion,
-      "spidertron-explosion" => Entity::SpidertronExplosion,
-      "logistic-robot-explosion" => Entity::LogisticRobotExplosion,
-      "construction-robot-explosion" => Entity::ConstructionRobotExplosion,
-      "active-provider-chest-explosion" => Entity::ActiveProviderChestExplosion,
-      "passive-provider-chest-explosion" => Entity::PassiveProviderChestExplosion,
-      "buffer-chest-explosion" => Entity::BufferChestExplosion,
-      "storage-chest-explosion" => Entity::StorageChestExplosion,
-      "requester-chest-explosion" => Entity::RequesterChestExplosion,
-      "roboport-explosion" => Entity::RoboportExplosion,
-      "lamp-explosion" => Entity::LampExplosion,
-      "arithmetic-combinator-explosion" => Entity::ArithmeticCombinatorExplosion,
-      "decider-combinator-explosion" => Entity::DeciderCombinatorExplosion,
-      "constant-combinator-explosion" => Entity::ConstantCombinatorExplosion,
-      "power-switch-explosion" => Entity::PowerSwitchExplosion,
-      "programmable-speaker-explosion" => Entity::ProgrammableSpeakerExplosion,
-      "hidden-electric-energy-interface" => Entity::HiddenElectricEnergyInterface,
-      "red-chest" => Entity::RedChest,
-      "simple-entity-with-force" => Entity::SimpleEntityWithForce,
-      "simple-entity-with-owner" => Entity::SimpleEntityWithOwner,
-      "compilatron" => Entity::Compilatron,
-      name => panic!("unknown Entity \"{name}\""),
+      "accumulator" => Some(Entity::Accumulator),
+      "acid-splash-fire-spitter-behemoth" => Some(Entity::AcidSplashFireSpitterBehemoth),
+      "acid-splash-fire-spitter-big" => Some(Entity::AcidSplashFireSpitterBig),
+      "acid-splash-fire-spitter-medium" => Some(Entity::AcidSplashFireSpitterMedium),
+      "acid-splash-fire-spitter-small" => Some(Entity::AcidSplashFireSpitterSmall),
+      "acid-splash-fire-worm-behemoth" => Some(Entity::AcidSplashFireWormBehemoth),
+      "acid-splash-fire-worm-big" => Some(Entity::AcidSplashFireWormBig),
+      "acid-splash-fire-worm-medium" => Some(Entity::AcidSplashFireWormMedium),
+      "acid-splash-fire-worm-small" => Some(Entity::AcidSplashFireWormSmall),
+      "acid-sticker-behemoth" => Some(Entity::AcidStickerBehemoth),
+      "acid-sticker-big" => Some(Entity::AcidStickerBig),
+      "acid-sticker-medium" => Some(Entity::AcidStickerMedium),
+      "acid-sticker-small" => Some(Entity::AcidStickerSmall),
+      "acid-stream-spitter-behemoth" => Some(Entity::AcidStreamSpitterBehemoth),
+      "acid-stream-spitter-big" => Some(Entity::AcidStreamSpitterBig),
+      "acid-stream-spitter-medium" => Some(Entity::AcidStreamSpitterMedium),
+      "acid-stream-spitter-small" => Some(Entity::AcidStreamSpitterSmall),
+      "acid-stream-worm-behemoth" => Some(Entity::AcidStreamWormBehemoth),
+      "acid-stream-worm-big" => Some(Entity::AcidStreamWormBig),
+      "acid-stream-worm-medium" => Some(Entity::AcidStreamWormMedium),
+      "acid-stream-worm-small" => Some(Entity::AcidStreamWormSmall),
+      "arithmetic-combinator" => Some(Entity::ArithmeticCombinator),
+      "artillery-cannon-muzzle-flash" => Some(Entity::ArtilleryCannonMuzzleFlash),
+      "artillery-flare" => Some(Entity::ArtilleryFlare),
+      "artillery-projectile" => Some(Entity::ArtilleryProjectile),
+      "artillery-turret" => Some(Entity::ArtilleryTurret),
+      "artillery-wagon" => Some(Entity::ArtilleryWagon),
+      "assembling-machine-1" => Some(Entity::AssemblingMachine1),
+      "assembling-machine-2" => Some(Entity::AssemblingMachine2),
+      "assembling-machine-3" => Some(Entity::AssemblingMachine3),
+      "atomic-bomb-ground-zero-projectile" => Some(Entity::AtomicBombGroundZeroProjectile),
+      "atomic-bomb-wave" => Some(Entity::AtomicBombWave),
+      "atomic-bomb-wave-spawns-cluster-nuke-explosion" => Some(Entity::AtomicBombWaveSpawnsClusterNukeExplosion),
+      "atomic-bomb-wave-spawns-fire-smoke-explosion" => Some(Entity::AtomicBombWaveSpawnsFireSmokeExplosion),
+      "atomic-bomb-wave-spawns-nuclear-smoke" => Some(Entity::AtomicBombWaveSpawnsNuclearSmoke),
+      "atomic-bomb-wave-spawns-nuke-shockwave-explosion" => Some(Entity::AtomicBombWaveSpawnsNukeShockwaveExplosion),
+      "atomic-fire-smoke" => Some(Entity::AtomicFireSmoke),
+      "atomic-nuke-shockwave" => Some(Entity::AtomicNukeShockwave),
+      "atomic-rocket" => Some(Entity::AtomicRocket),
+      "beacon" => Some(Entity::Beacon),
+      "behemoth-biter-die" => Some(Entity::BehemothBiterDie),
+      "behemoth-spitter-die" => Some(Entity::BehemothSpitterDie),
+      "behemoth-worm-die" => Some(Entity::BehemothWormDie),
+      "big-artillery-explosion" => Some(Entity::BigArtilleryExplosion),
+      "big-biter-die" => Some(Entity::BigBiterDie),
+      "big-electric-pole" => Some(Entity::BigElectricPole),
+      "big-explosion" => Some(Entity::BigExplosion),
+      "big-spitter-die" => Some(Entity::BigSpitterDie),
+      "big-worm-die" => Some(Entity::BigWormDie),
+      "biter-spawner-die" => Some(Entity::BiterSpawnerDie),
+      "blood-explosion-big" => Some(Entity::BloodExplosionBig),
+      "blood-explosion-huge" => Some(Entity::BloodExplosionHuge),
+      "blood-explosion-small" => Some(Entity::BloodExplosionSmall),
+      "blood-fountain" => Some(Entity::BloodFountain),
+      "blood-fountain-big" => Some(Entity::BloodFountainBig),
+      "blood-fountain-hit-spray" => Some(Entity::BloodFountainHitSpray),
+      "blue-laser" => Some(Entity::BlueLaser),
+      "boiler" => Some(Entity::Boiler),
+      "burner-generator" => Some(Entity::BurnerGenerator),
+      "burner-inserter" => Some(Entity::BurnerInserter),
+      "burner-mining-drill" => Some(Entity::BurnerMiningDrill),
+      "cannon-projectile" => Some(Entity::CannonProjectile),
+      "car" => Some(Entity::Car),
+      "cargo-wagon" => Some(Entity::CargoWagon),
+      "centrifuge" => Some(Entity::Centrifuge),
+      "character-corpse" => Some(Entity::CharacterCorpse),
+      "chemical-plant" => Some(Entity::ChemicalPlant),
+      "cliff-explosives" => Some(Entity::CliffExplosives),
+      "cluster-grenade" => Some(Entity::ClusterGrenade),
+      "cluster-nuke-explosion" => Some(Entity::ClusterNukeExplosion),
+      "compi-speech-bubble" => Some(Entity::CompiSpeechBubble),
+      "constant-combinator" => Some(Entity::ConstantCombinator),
+      "construction-robot" => Some(Entity::ConstructionRobot),
+      "crash-site-chest-1" => Some(Entity::CrashSiteChest1),
+      "crash-site-chest-2" => Some(Entity::CrashSiteChest2),
+      "crash-site-explosion-smoke" => Some(Entity::CrashSiteExplosionSmoke),
+      "crash-site-fire-flame" => Some(Entity::CrashSiteFireFlame),
+      "crash-site-fire-smoke" => Some(Entity::CrashSiteFireSmoke),
+      "crash-site-spaceship" => Some(Entity::CrashSiteSpaceship),
+      "crash-site-spaceship-wreck-big-1" => Some(Entity::CrashSiteSpaceshipWreckBig1),
+      "crash-site-spaceship-wreck-big-2" => Some(Entity::CrashSiteSpaceshipWreckBig2),
+      "crash-site-spaceship-wreck-medium-1" => Some(Entity::CrashSiteSpaceshipWreckMedium1),
+      "crash-site-spaceship-wreck-medium-2" => Some(Entity::CrashSiteSpaceshipWreckMedium2),
+      "crash-site-spaceship-wreck-medium-3" => Some(Entity::CrashSiteSpaceshipWreckMedium3),
+      "crash-site-spaceship-wreck-small-1" => Some(Entity::CrashSiteSpaceshipWreckSmall1),
+      "crash-site-spaceship-wreck-small-2" => Some(Entity::CrashSiteSpaceshipWreckSmall2),
+      "crash-site-spaceship-wreck-small-3" => Some(Entity::CrashSiteSpaceshipWreckSmall3),
+      "crash-site-spaceship-wreck-small-4" => Some(Entity::CrashSiteSpaceshipWreckSmall4),
+      "crash-site-spaceship-wreck-small-5" => Some(Entity::CrashSiteSpaceshipWreckSmall5),
+      "crash-site-spaceship-wreck-small-6" => Some(Entity::CrashSiteSpaceshipWreckSmall6),
+      "curved-rail" => Some(Entity::CurvedRail),
+      "decider-combinator" => Some(Entity::DeciderCombinator),
+      "deconstructible-tile-proxy" => Some(Entity::DeconstructibleTileProxy),
+      "defender-capsule" => Some(Entity::DefenderCapsule),
+      "destroyer-capsule" => Some(Entity::DestroyerCapsule),
+      "distractor-capsule" => Some(Entity::DistractorCapsule),
+      "dummy-flame-thrower-explosion" => Some(Entity::DummyFlameThrowerExplosion),
+      "electric-beam" => Some(Entity::ElectricBeam),
+      "electric-beam-no-sound" => Some(Entity::ElectricBeamNoSound),
+      "electric-energy-interface" => Some(Entity::ElectricEnergyInterface),
+      "electric-furnace" => Some(Entity::ElectricFurnace),
+      "electric-mining-drill" => Some(Entity::ElectricMiningDrill),
+      "enemy-damaged-explosion" => Some(Entity::EnemyDamagedExplosion),
+      "entity-ghost" => Some(Entity::EntityGhost),
+      "entity-unknown" => Some(Entity::EntityUnknown),
+      "explosion" => Some(Entity::Explosion),
+      "explosion-gunshot" => Some(Entity::ExplosionGunshot),
+      "explosion-gunshot-small" => Some(Entity::ExplosionGunshotSmall),
+      "explosion-hit" => Some(Entity::ExplosionHit),
+      "explosive-cannon-projectile" => Some(Entity::ExplosiveCannonProjectile),
+      "explosive-rocket" => Some(Entity::ExplosiveRocket),
+      "explosive-uranium-cannon-projectile" => Some(Entity::ExplosiveUraniumCannonProjectile),
+      "express-loader" => Some(Entity::ExpressLoader),
+      "express-splitter" => Some(Entity::ExpressSplitter),
+      "express-transport-belt" => Some(Entity::ExpressTransportBelt),
+      "express-underground-belt" => Some(Entity::ExpressUndergroundBelt),
+      "factorio-logo-11tiles" => Some(Entity::FactorioLogo11tiles),
+      "factorio-logo-16tiles" => Some(Entity::FactorioLogo16tiles),
+      "factorio-logo-22tiles" => Some(Entity::FactorioLogo22tiles),
+      "fake-selection-box-2x2" => Some(Entity::FakeSelectionBox2x2),
+      "fast-inserter" => Some(Entity::FastInserter),
+      "fast-loader" => Some(Entity::FastLoader),
+      "fast-splitter" => Some(Entity::FastSplitter),
+      "fast-transport-belt" => Some(Entity::FastTransportBelt),
+      "fast-underground-belt" => Some(Entity::FastUndergroundBelt),
+      "filter-inserter" => Some(Entity::FilterInserter),
+      "fire-flame" => Some(Entity::FireFlame),
+      "fire-flame-on-tree" => Some(Entity::FireFlameOnTree),
+      "fire-sticker" => Some(Entity::FireSticker),
+      "flamethrower-fire-stream" => Some(Entity::FlamethrowerFireStream),
+      "flamethrower-turret" => Some(Entity::FlamethrowerTurret),
+      "fluid-wagon" => Some(Entity::FluidWagon),
+      "flying-robot-damaged-explosion" => Some(Entity::FlyingRobotDamagedExplosion),
+      "flying-text" => Some(Entity::FlyingText),
+      "gate" => Some(Entity::Gate),
+      "grenade" => Some(Entity::Grenade),
+      "grenade-explosion" => Some(Entity::GrenadeExplosion),
+      "ground-explosion" => Some(Entity::GroundExplosion),
+      "gun-turret" => Some(Entity::GunTurret),
+      "handheld-flamethrower-fire-stream" => Some(Entity::HandheldFlamethrowerFireStream),
+      "heat-exchanger" => Some(Entity::HeatExchanger),
+      "heat-interface" => Some(Entity::HeatInterface),
+      "heat-pipe" => Some(Entity::HeatPipe),
+      "highlight-box" => Some(Entity::HighlightBox),
+      "infinity-chest" => Some(Entity::InfinityChest),
+      "infinity-pipe" => Some(Entity::InfinityPipe),
+      "inserter" => Some(Entity::Inserter),
+      "iron-chest" => Some(Entity::IronChest),
+      "item-on-ground" => Some(Entity::ItemOnGround),
+      "item-request-proxy" => Some(Entity::ItemRequestProxy),
+      "lab" => Some(Entity::Lab),
+      "land-mine" => Some(Entity::LandMine),
+      "laser" => Some(Entity::Laser),
+      "laser-beam" => Some(Entity::LaserBeam),
+      "laser-bubble" => Some(Entity::LaserBubble),
+      "laser-turret" => Some(Entity::LaserTurret),
+      "leaf-particle-for-migration" => Some(Entity::LeafParticleForMigration),
+      "linked-belt" => Some(Entity::LinkedBelt),
+      "linked-chest" => Some(Entity::LinkedChest),
+      "loader" => Some(Entity::Loader),
+      "loader-1x1" => Some(Entity::Loader1x1),
+      "locomotive" => Some(Entity::Locomotive),
+      "logistic-chest-active-provider" => Some(Entity::LogisticChestActiveProvider),
+      "logistic-chest-buffer" => Some(Entity::LogisticChestBuffer),
+      "logistic-chest-passive-provider" => Some(Entity::LogisticChestPassiveProvider),
+      "logistic-chest-requester" => Some(Entity::LogisticChestRequester),
+      "logistic-chest-storage" => Some(Entity::LogisticChestStorage),
+      "logistic-robot" => Some(Entity::LogisticRobot),
+      "long-handed-inserter" => Some(Entity::LongHandedInserter),
+      "massive-explosion" => Some(Entity::MassiveExplosion),
+      "medium-biter-die" => Some(Entity::MediumBiterDie),
+      "medium-electric-pole" => Some(Entity::MediumElectricPole),
+      "medium-explosion" => Some(Entity::MediumExplosion),
+      "medium-spitter-die" => Some(Entity::MediumSpitterDie),
+      "medium-worm-die" => Some(Entity::MediumWormDie),
+      "nuclear-reactor" => Some(Entity::NuclearReactor),
+      "nuclear-smouldering-smoke-source" => Some(Entity::NuclearSmoulderingSmokeSource),
+      "nuke-explosion" => Some(Entity::NukeExplosion),
+      "offshore-pump" => Some(Entity::OffshorePump),
+      "oil-refinery" => Some(Entity::OilRefinery),
+      "orange-arrow-with-circle" => Some(Entity::OrangeArrowWithCircle),
+      "particle-for-migration" => Some(Entity::ParticleForMigration),
+      "piercing-shotgun-pellet" => Some(Entity::PiercingShotgunPellet),
+      "pipe" => Some(Entity::Pipe),
+      "pipe-to-ground" => Some(Entity::PipeToGround),
+      "player-port" => Some(Entity::PlayerPort),
+      "poison-capsule" => Some(Entity::PoisonCapsule),
+      "poison-cloud" => Some(Entity::PoisonCloud),
+      "poison-cloud-visual-dummy" => Some(Entity::PoisonCloudVisualDummy),
+      "power-switch" => Some(Entity::PowerSwitch),
+      "programmable-speaker" => Some(Entity::ProgrammableSpeaker),
+      "pump" => Some(Entity::Pump),
+      "pumpjack" => Some(Entity::Pumpjack),
+      "radar" => Some(Entity::Radar),
+      "rail-chain-signal" => Some(Entity::RailChainSignal),
+      "rail-signal" => Some(Entity::RailSignal),
+      "roboport" => Some(Entity::Roboport),
+      "rock-damaged-explosion" => Some(Entity::RockDamagedExplosion),
+      "rocket" => Some(Entity::Rocket),
+      "rocket-silo" => Some(Entity::RocketSilo),
+      "rocket-silo-rocket" => Some(Entity::RocketSiloRocket),
+      "rocket-silo-rocket-shadow" => Some(Entity::RocketSiloRocketShadow),
+      "shotgun-pellet" => Some(Entity::ShotgunPellet),
+      "slowdown-capsule" => Some(Entity::SlowdownCapsule),
+      "slowdown-sticker" => Some(Entity::SlowdownSticker),
+      "small-biter-die" => Some(Entity::SmallBiterDie),
+      "small-electric-pole" => Some(Entity::SmallElectricPole),
+      "small-lamp" => Some(Entity::SmallLamp),
+      "small-spitter-die" => Some(Entity::SmallSpitterDie),
+      "small-worm-die" => Some(Entity::SmallWormDie),
+      "smoke-for-migration" => Some(Entity::SmokeForMigration),
+      "solar-panel" => Some(Entity::SolarPanel),
+      "spark-explosion" => Some(Entity::SparkExplosion),
+      "spark-explosion-higher" => Some(Entity::SparkExplosionHigher),
+      "spidertron" => Some(Entity::Spidertron),
+      "spidertron-leg-1" => Some(Entity::SpidertronLeg1),
+      "spidertron-leg-2" => Some(Entity::SpidertronLeg2),
+      "spidertron-leg-3" => Some(Entity::SpidertronLeg3),
+      "spidertron-leg-4" => Some(Entity::SpidertronLeg4),
+      "spidertron-leg-5" => Some(Entity::SpidertronLeg5),
+      "spidertron-leg-6" => Some(Entity::SpidertronLeg6),
+      "spidertron-leg-7" => Some(Entity::SpidertronLeg7),
+      "spidertron-leg-8" => Some(Entity::SpidertronLeg8),
+      "spidertron-military-target" => Some(Entity::SpidertronMilitaryTarget),
+      "spitter-spawner-die" => Some(Entity::SpitterSpawnerDie),
+      "splitter" => Some(Entity::Splitter),
+      "stack-filter-inserter" => Some(Entity::StackFilterInserter),
+      "stack-inserter" => Some(Entity::StackInserter),
+      "steam-engine" => Some(Entity::SteamEngine),
+      "steam-turbine" => Some(Entity::SteamTurbine),
+      "steel-chest" => Some(Entity::SteelChest),
+      "steel-furnace" => Some(Entity::SteelFurnace),
+      "stone-furnace" => Some(Entity::StoneFurnace),
+      "stone-wall" => Some(Entity::StoneWall),
+      "storage-tank" => Some(Entity::StorageTank),
+      "straight-rail" => Some(Entity::StraightRail),
+      "stun-sticker" => Some(Entity::StunSticker),
+      "substation" => Some(Entity::Substation),
+      "tank" => Some(Entity::Tank),
+      "tank-flamethrower-fire-stream" => Some(Entity::TankFlamethrowerFireStream),
+      "tile-ghost" => Some(Entity::TileGhost),
+      "tile-proxy" => Some(Entity::TileProxy),
+      "train-stop" => Some(Entity::TrainStop),
+      "transport-belt" => Some(Entity::TransportBelt),
+      "tree-dying-proxy" => Some(Entity::TreeDyingProxy),
+      "tree-proxy" => Some(Entity::TreeProxy),
+      "tutorial-flying-text" => Some(Entity::TutorialFlyingText),
+      "underground-belt" => Some(Entity::UndergroundBelt),
+      "uranium-cannon-explosion" => Some(Entity::UraniumCannonExplosion),
+      "uranium-cannon-projectile" => Some(Entity::UraniumCannonProjectile),
+      "uranium-cannon-shell-explosion" => Some(Entity::UraniumCannonShellExplosion),
+      "wall-damaged-explosion" => Some(Entity::WallDamagedExplosion),
+      "water-splash" => Some(Entity::WaterSplash),
+      "wooden-chest" => Some(Entity::WoodenChest),
+      "character" => Some(Entity::Character),
+      "assembling-machine-1-remnants" => Some(Entity::AssemblingMachine1Remnants),
+      "assembling-machine-2-remnants" => Some(Entity::AssemblingMachine2Remnants),
+      "assembling-machine-3-remnants" => Some(Entity::AssemblingMachine3Remnants),
+      "boiler-explosion" => Some(Entity::BoilerExplosion),
+      "boiler-remnants" => Some(Entity::BoilerRemnants),
+      "burner-inserter-remnants" => Some(Entity::BurnerInserterRemnants),
+      "burner-mining-drill-remnants" => Some(Entity::BurnerMiningDrillRemnants),
+      "electric-furnace-remnants" => Some(Entity::ElectricFurnaceRemnants),
+      "electric-mining-drill-remnants" => Some(Entity::ElectricMiningDrillRemnants),
+      "lamp-remnants" => Some(Entity::LampRemnants),
+      "land-mine-explosion" => Some(Entity::LandMineExplosion),
+      "slowdown-capsule-explosion" => Some(Entity::SlowdownCapsuleExplosion),
+      "small-electric-pole-remnants" => Some(Entity::SmallElectricPoleRemnants),
+      "small-remnants" => Some(Entity::SmallRemnants),
+      "small-scorchmark" => Some(Entity::SmallScorchmark),
+      "steel-furnace-remnants" => Some(Entity::SteelFurnaceRemnants),
+      "stone-furnace-remnants" => Some(Entity::StoneFurnaceRemnants),
+      "transport-belt-remnants" => Some(Entity::TransportBeltRemnants),
+      "wall-remnants" => Some(Entity::WallRemnants),
+      "wooden-chest-explosion" => Some(Entity::WoodenChestExplosion),
+      "wooden-chest-remnants" => Some(Entity::WoodenChestRemnants),
+      "1x2-remnants" => Some(Entity::OneXTwoRemnants),
+      "arithmetic-combinator-remnants" => Some(Entity::ArithmeticCombinatorRemnants),
+      "crude-oil" => Some(Entity::CrudeOil),
+      "fast-transport-belt-remnants" => Some(Entity::FastTransportBeltRemnants),
+      "gate-remnants" => Some(Entity::GateRemnants),
+      "inserter-remnants" => Some(Entity::InserterRemnants),
+      "iron-chest-explosion" => Some(Entity::IronChestExplosion),
+      "iron-chest-remnants" => Some(Entity::IronChestRemnants),
+      "medium-electric-pole-remnants" => Some(Entity::MediumElectricPoleRemnants),
+      "small-scorchmark-tintable" => Some(Entity::SmallScorchmarkTintable),
+      "steam-engine-explosion" => Some(Entity::SteamEngineExplosion),
+      "steam-engine-remnants" => Some(Entity::SteamEngineRemnants),
+      "coal" => Some(Entity::Coal),
+      "copper-ore" => Some(Entity::CopperOre),
+      "iron-ore" => Some(Entity::IronOre),
+      "stone" => Some(Entity::Stone),
+      "uranium-ore" => Some(Entity::UraniumOre),
+      "active-provider-chest-remnants" => Some(Entity::ActiveProviderChestRemnants),
+      "big-electric-pole-remnants" => Some(Entity::BigElectricPoleRemnants),
+      "big-remnants" => Some(Entity::BigRemnants),
+      "decider-combinator-remnants" => Some(Entity::DeciderCombinatorRemnants),
+      "express-transport-belt-remnants" => Some(Entity::ExpressTransportBeltRemnants),
+      "gun-turret-remnants" => Some(Entity::GunTurretRemnants),
+      "long-handed-inserter-remnants" => Some(Entity::LongHandedInserterRemnants),
+      "medium-scorchmark" => Some(Entity::MediumScorchmark),
+      "power-switch-remnants" => Some(Entity::PowerSwitchRemnants),
+      "solar-panel-explosion" => Some(Entity::SolarPanelExplosion),
+      "solar-panel-remnants" => Some(Entity::SolarPanelRemnants),
+      "steel-chest-explosion" => Some(Entity::SteelChestExplosion),
+      "steel-chest-remnants" => Some(Entity::SteelChestRemnants),
+      "train-stop-remnants" => Some(Entity::TrainStopRemnants),
+      "accumulator-remnants" => Some(Entity::AccumulatorRemnants),
+      "beacon-remnants" => Some(Entity::BeaconRemnants),
+      "constant-combinator-remnants" => Some(Entity::ConstantCombinatorRemnants),
+      "fast-inserter-remnants" => Some(Entity::FastInserterRemnants),
+      "laser-turret-remnants" => Some(Entity::LaserTurretRemnants),
+      "medium-remnants" => Some(Entity::MediumRemnants),
+      "medium-scorchmark-tintable" => Some(Entity::MediumScorchmarkTintable),
+      "nuclear-reactor-explosion" => Some(Entity::NuclearReactorExplosion),
+      "oil-refinery-remnants" => Some(Entity::OilRefineryRemnants),
+      "passive-provider-chest-remnants" => Some(Entity::PassiveProviderChestRemnants),
+      "pipe-remnants" => Some(Entity::PipeRemnants),
+      "pipe-to-ground-remnants" => Some(Entity::PipeToGroundRemnants),
+      "pump-remnants" => Some(Entity::PumpRemnants),
+      "pumpjack-remnants" => Some(Entity::PumpjackRemnants),
+      "rail-signal-remnants" => Some(Entity::RailSignalRemnants),
+      "storage-tank-explosion" => Some(Entity::StorageTankExplosion),
+      "storage-tank-remnants" => Some(Entity::StorageTankRemnants),
+      "substation-remnants" => Some(Entity::SubstationRemnants),
+      "underground-belt-remnants" => Some(Entity::UndergroundBeltRemnants),
+      "offshore-pump-remnants" => Some(Entity::OffshorePumpRemnants),
+      "big-scorchmark" => Some(Entity::BigScorchmark),
+      "chemical-plant-remnants" => Some(Entity::ChemicalPlantRemnants),
+      "fast-underground-belt-remnants" => Some(Entity::FastUndergroundBeltRemnants),
+      "filter-inserter-remnants" => Some(Entity::FilterInserterRemnants),
+      "flamethrower-turret-remnants" => Some(Entity::FlamethrowerTurretRemnants),
+      "heat-pipe-explosion" => Some(Entity::HeatPipeExplosion),
+      "medium-small-remnants" => Some(Entity::MediumSmallRemnants),
+      "nuclear-reactor-remnants" => Some(Entity::NuclearReactorRemnants),
+      "programmable-speaker-remnants" => Some(Entity::ProgrammableSpeakerRemnants),
+      "rail-chain-signal-remnants" => Some(Entity::RailChainSignalRemnants),
+      "storage-chest-remnants" => Some(Entity::StorageChestRemnants),
+      "artillery-turret-remnants" => Some(Entity::ArtilleryTurretRemnants),
+      "big-scorchmark-tintable" => Some(Entity::BigScorchmarkTintable),
+      "buffer-chest-remnants" => Some(Entity::BufferChestRemnants),
+      "centrifuge-remnants" => Some(Entity::CentrifugeRemnants),
+      "express-underground-belt-remnants" => Some(Entity::ExpressUndergroundBeltRemnants),
+      "heat-exchanger-explosion" => Some(Entity::HeatExchangerExplosion),
+      "heat-pipe-remnants" => Some(Entity::HeatPipeRemnants),
+      "locomotive-remnants" => Some(Entity::LocomotiveRemnants),
+      "stack-inserter-remnants" => Some(Entity::StackInserterRemnants),
+      "cargo-wagon-remnants" => Some(Entity::CargoWagonRemnants),
+      "heat-exchanger-remnants" => Some(Entity::HeatExchangerRemnants),
+      "huge-scorchmark" => Some(Entity::HugeScorchmark),
+      "lab-remnants" => Some(Entity::LabRemnants),
+      "radar-remnants" => Some(Entity::RadarRemnants),
+      "requester-chest-remnants" => Some(Entity::RequesterChestRemnants),
+      "splitter-remnants" => Some(Entity::SplitterRemnants),
+      "stack-filter-inserter-remnants" => Some(Entity::StackFilterInserterRemnants),
+      "steam-turbine-explosion" => Some(Entity::SteamTurbineExplosion),
+      "fast-splitter-remnants" => Some(Entity::FastSplitterRemnants),
+      "fluid-wagon-remnants" => Some(Entity::FluidWagonRemnants),
+      "huge-scorchmark-tintable" => Some(Entity::HugeScorchmarkTintable),
+      "roboport-remnants" => Some(Entity::RoboportRemnants),
+      "rocket-silo-remnants" => Some(Entity::RocketSiloRemnants),
+      "steam-turbine-remnants" => Some(Entity::SteamTurbineRemnants),
+      "artillery-wagon-remnants" => Some(Entity::ArtilleryWagonRemnants),
+      "express-splitter-remnants" => Some(Entity::ExpressSplitterRemnants),
+      "land-mine-remnants" => Some(Entity::LandMineRemnants),
+      "car-remnants" => Some(Entity::CarRemnants),
+      "tank-remnants" => Some(Entity::TankRemnants),
+      "spidertron-remnants" => Some(Entity::SpidertronRemnants),
+      "tree-01" => Some(Entity::Tree01),
+      "tree-02" => Some(Entity::Tree02),
+      "tree-03" => Some(Entity::Tree03),
+      "tree-04" => Some(Entity::Tree04),
+      "tree-05" => Some(Entity::Tree05),
+      "tree-09" => Some(Entity::Tree09),
+      "tree-02-red" => Some(Entity::Tree02Red),
+      "tree-07" => Some(Entity::Tree07),
+      "tree-06" => Some(Entity::Tree06),
+      "tree-06-brown" => Some(Entity::Tree06Brown),
+      "tree-09-brown" => Some(Entity::Tree09Brown),
+      "tree-09-red" => Some(Entity::Tree09Red),
+      "tree-08" => Some(Entity::Tree08),
+      "tree-08-brown" => Some(Entity::Tree08Brown),
+      "tree-08-red" => Some(Entity::Tree08Red),
+      "dead-dry-hairy-tree" => Some(Entity::DeadDryHairyTree),
+      "dead-grey-trunk" => Some(Entity::DeadGreyTrunk),
+      "dead-tree-desert" => Some(Entity::DeadTreeDesert),
+      "dry-hairy-tree" => Some(Entity::DryHairyTree),
+      "dry-tree" => Some(Entity::DryTree),
+      "fish" => Some(Entity::Fish),
+      "burner-mining-drill-explosion" => Some(Entity::BurnerMiningDrillExplosion),
+      "small-biter" => Some(Entity::SmallBiter),
+      "transport-belt-explosion" => Some(Entity::TransportBeltExplosion),
+      "wall-explosion" => Some(Entity::WallExplosion),
+      "medium-biter" => Some(Entity::MediumBiter),
+      "big-biter" => Some(Entity::BigBiter),
+      "behemoth-biter" => Some(Entity::BehemothBiter),
+      "electric-mining-drill-explosion" => Some(Entity::ElectricMiningDrillExplosion),
+      "fast-transport-belt-explosion" => Some(Entity::FastTransportBeltExplosion),
+      "gate-explosion" => Some(Entity::GateExplosion),
+      "small-spitter" => Some(Entity::SmallSpitter),
+      "medium-spitter" => Some(Entity::MediumSpitter),
+      "big-spitter" => Some(Entity::BigSpitter),
+      "behemoth-spitter" => Some(Entity::BehemothSpitter),
+      "express-transport-belt-explosion" => Some(Entity::ExpressTransportBeltExplosion),
+      "gun-turret-explosion" => Some(Entity::GunTurretExplosion),
+      "offshore-pump-explosion" => Some(Entity::OffshorePumpExplosion),
+      "small-worm-turret" => Some(Entity::SmallWormTurret),
+      "medium-worm-turret" => Some(Entity::MediumWormTurret),
+      "big-worm-turret" => Some(Entity::BigWormTurret),
+      "behemoth-worm-turret" => Some(Entity::BehemothWormTurret),
+      "biter-spawner" => Some(Entity::BiterSpawner),
+      "laser-turret-explosion" => Some(Entity::LaserTurretExplosion),
+      "pumpjack-explosion" => Some(Entity::PumpjackExplosion),
+      "underground-belt-explosion" => Some(Entity::UndergroundBeltExplosion),
+      "spitter-spawner" => Some(Entity::SpitterSpawner),
+      "fast-underground-belt-explosion" => Some(Entity::FastUndergroundBeltExplosion),
+      "flamethrower-turret-explosion" => Some(Entity::FlamethrowerTurretExplosion),
+      "artillery-turret-explosion" => Some(Entity::ArtilleryTurretExplosion),
+      "express-underground-belt-explosion" => Some(Entity::ExpressUndergroundBeltExplosion),
+      "radar-explosion" => Some(Entity::RadarExplosion),
+      "splitter-explosion" => Some(Entity::SplitterExplosion),
+      "fast-splitter-explosion" => Some(Entity::FastSplitterExplosion),
+      "rocket-silo-explosion" => Some(Entity::RocketSiloExplosion),
+      "express-splitter-explosion" => Some(Entity::ExpressSplitterExplosion),
+      "blue-chest" => Some(Entity::BlueChest),
+      "sand-rock-big" => Some(Entity::SandRockBig),
+      "rock-huge" => Some(Entity::RockHuge),
+      "cliff" => Some(Entity::Cliff),
+      "rock-big" => Some(Entity::RockBig),
+      "burner-inserter-explosion" => Some(Entity::BurnerInserterExplosion),
+      "defender-robot-explosion" => Some(Entity::DefenderRobotExplosion),
+      "stone-furnace-explosion" => Some(Entity::StoneFurnaceExplosion),
+      "distractor-robot-explosion" => Some(Entity::DistractorRobotExplosion),
+      "inserter-explosion" => Some(Entity::InserterExplosion),
+      "steel-furnace-explosion" => Some(Entity::SteelFurnaceExplosion),
+      "destroyer-robot-explosion" => Some(Entity::DestroyerRobotExplosion),
+      "electric-furnace-explosion" => Some(Entity::ElectricFurnaceExplosion),
+      "long-handed-inserter-explosion" => Some(Entity::LongHandedInserterExplosion),
+      "fast-inserter-explosion" => Some(Entity::FastInserterExplosion),
+      "filter-inserter-explosion" => Some(Entity::FilterInserterExplosion),
+      "stack-inserter-explosion" => Some(Entity::StackInserterExplosion),
+      "stack-filter-inserter-explosion" => Some(Entity::StackFilterInserterExplosion),
+      "small-biter-corpse" => Some(Entity::SmallBiterCorpse),
+      "medium-biter-corpse" => Some(Entity::MediumBiterCorpse),
+      "behemoth-biter-corpse" => Some(Entity::BehemothBiterCorpse),
+      "big-biter-corpse" => Some(Entity::BigBiterCorpse),
+      "biter-spawner-corpse" => Some(Entity::BiterSpawnerCorpse),
+      "behemoth-spitter-corpse" => Some(Entity::BehemothSpitterCorpse),
+      "big-spitter-corpse" => Some(Entity::BigSpitterCorpse),
+      "medium-spitter-corpse" => Some(Entity::MediumSpitterCorpse),
+      "small-spitter-corpse" => Some(Entity::SmallSpitterCorpse),
+      "spitter-spawner-corpse" => Some(Entity::SpitterSpawnerCorpse),
+      "small-worm-corpse" => Some(Entity::SmallWormCorpse),
+      "medium-worm-corpse" => Some(Entity::MediumWormCorpse),
+      "big-worm-corpse" => Some(Entity::BigWormCorpse),
+      "behemoth-worm-corpse" => Some(Entity::BehemothWormCorpse),
+      "assembling-machine-1-explosion" => Some(Entity::AssemblingMachine1Explosion),
+      "market" => Some(Entity::Market),
+      "small-electric-pole-explosion" => Some(Entity::SmallElectricPoleExplosion),
+      "assembling-machine-2-explosion" => Some(Entity::AssemblingMachine2Explosion),
+      "medium-electric-pole-explosion" => Some(Entity::MediumElectricPoleExplosion),
+      "assembling-machine-3-explosion" => Some(Entity::AssemblingMachine3Explosion),
+      "big-electric-pole-explosion" => Some(Entity::BigElectricPoleExplosion),
+      "oil-refinery-explosion" => Some(Entity::OilRefineryExplosion),
+      "substation-explosion" => Some(Entity::SubstationExplosion),
+      "chemical-plant-explosion" => Some(Entity::ChemicalPlantExplosion),
+      "centrifuge-explosion" => Some(Entity::CentrifugeExplosion),
+      "pipe-explosion" => Some(Entity::PipeExplosion),
+      "lab-explosion" => Some(Entity::LabExplosion),
+      "pipe-to-ground-explosion" => Some(Entity::PipeToGroundExplosion),
+      "pump-explosion" => Some(Entity::PumpExplosion),
+      "construction-robot-remnants" => Some(Entity::ConstructionRobotRemnants),
+      "defender-remnants" => Some(Entity::DefenderRemnants),
+      "destroyer-remnants" => Some(Entity::DestroyerRemnants),
+      "distractor-remnants" => Some(Entity::DistractorRemnants),
+      "logistic-robot-remnants" => Some(Entity::LogisticRobotRemnants),
+      "straight-rail-remnants" => Some(Entity::StraightRailRemnants),
+      "curved-rail-remnants" => Some(Entity::CurvedRailRemnants),
+      "rail-ending-remnants" => Some(Entity::RailEndingRemnants),
+      "tree-01-stump" => Some(Entity::Tree01Stump),
+      "tree-02-stump" => Some(Entity::Tree02Stump),
+      "tree-03-stump" => Some(Entity::Tree03Stump),
+      "tree-04-stump" => Some(Entity::Tree04Stump),
+      "tree-05-stump" => Some(Entity::Tree05Stump),
+      "tree-06-stump" => Some(Entity::Tree06Stump),
+      "tree-07-stump" => Some(Entity::Tree07Stump),
+      "tree-08-stump" => Some(Entity::Tree08Stump),
+      "tree-09-stump" => Some(Entity::Tree09Stump),
+      "big-ship-wreck-1" => Some(Entity::BigShipWreck1),
+      "big-ship-wreck-2" => Some(Entity::BigShipWreck2),
+      "big-ship-wreck-3" => Some(Entity::BigShipWreck3),
+      "medium-ship-wreck" => Some(Entity::MediumShipWreck),
+      "small-ship-wreck" => Some(Entity::SmallShipWreck),
+      "beacon-explosion" => Some(Entity::BeaconExplosion),
+      "defender" => Some(Entity::Defender),
+      "rail-explosion" => Some(Entity::RailExplosion),
+      "distractor" => Some(Entity::Distractor),
+      "destroyer" => Some(Entity::Destroyer),
+      "train-stop-explosion" => Some(Entity::TrainStopExplosion),
+      "rail-signal-explosion" => Some(Entity::RailSignalExplosion),
+      "rail-chain-signal-explosion" => Some(Entity::RailChainSignalExplosion),
+      "locomotive-explosion" => Some(Entity::LocomotiveExplosion),
+      "accumulator-explosion" => Some(Entity::AccumulatorExplosion),
+      "fluid-wagon-explosion" => Some(Entity::FluidWagonExplosion),
+      "cargo-wagon-explosion" => Some(Entity::CargoWagonExplosion),
+      "artillery-wagon-explosion" => Some(Entity::ArtilleryWagonExplosion),
+      "car-explosion" => Some(Entity::CarExplosion),
+      "tank-explosion" => Some(Entity::TankExplosion),
+      "spidertron-explosion" => Some(Entity::SpidertronExplosion),
+      "logistic-robot-explosion" => Some(Entity::LogisticRobotExplosion),
+      "construction-robot-explosion" => Some(Entity::ConstructionRobotExplosion),
+      "active-provider-chest-explosion" => Some(Entity::ActiveProviderChestExplosion),
+      "passive-provider-chest-explosion" => Some(Entity::PassiveProviderChestExplosion),
+      "buffer-chest-explosion" => Some(Entity::BufferChestExplosion),
+      "storage-chest-explosion" => Some(Entity::StorageChestExplosion),
+      "requester-chest-explosion" => Some(Entity::RequesterChestExplosion),
+      "roboport-explosion" => Some(Entity::RoboportExplosion),
+      "lamp-explosion" => Some(Entity::LampExplosion),
+      "arithmetic-combinator-explosion" => Some(Entity::ArithmeticCombinatorExplosion),
+      "decider-combinator-explosion" => Some(Entity::DeciderCombinatorExplosion),
+      "constant-combinator-explosion" => Some(Entity::ConstantCombinatorExplosion),
+      "power-switch-explosion" => Some(Entity::PowerSwitchExplosion),
+      "programmable-speaker-explosion" => Some(Entity::ProgrammableSpeakerExplosion),
+      "hidden-electric-energy-interface" => Some(Entity::HiddenElectricEnergyInterface),
+      "red-chest" => Some(Entity::RedChest),
+      "simple-entity-with-force" => Some(Entity::SimpleEntityWithForce),
+      "simple-entity-with-owner" => Some(Entity::SimpleEntityWithOwner),
+      "compilatron" => Some(Entity::Compilatron),
+      _ => None,
     }
   }
+  pub fn from_name(name: &str) -> Entity {
+    Entity::try_from_name(name).unwrap_or_else(|| panic!("unknown Entity \"{name}\""))
+  }
+}
+impl std::fmt::Display for Entity {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Entity {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Entity, UnknownPrototypeName> {
+    Entity::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Entity", name: name.to_owned() })
+  }
 }
@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU16;
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU16)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(Equipment)]
 pub enum Equipment {
   BatteryEquipment = 1,
   BatteryMk2Equipment = 2,
@@ -22,6 +25,25 @@ pub enum Equipment {
   BeltImmunityEquipment = 13,
 }
 impl Equipment {
+  pub const ALL: [Equipment; 13] = [
+    Equipment::BatteryEquipment,
+    Equipment::BatteryMk2Equipment,
+    Equipment::DischargeDefenseEquipment,
+    Equipment::EnergyShieldEquipment,
+    Equipment::EnergyShieldMk2Equipment,
+    Equipment::ExoskeletonEquipment,
+    Equipment::FusionReactorEquipment,
+    Equipment::NightVisionEquipment,
+    Equipment::PersonalLaserDefenseEquipment,
+    Equipment::PersonalRoboportEquipment,
+    Equipment::PersonalRoboportMk2Equipment,
+    Equipment::SolarPanelEquipment,
+    Equipment::BeltImmunityEquipment,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Equipment> {
+    Equipment::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Equipment::BatteryEquipment => "battery-equipment",
@@ -39,22 +61,36 @@ impl Equipment {
       Equipment::BeltImmunityEquipment => "belt-immunity-equipment",
     }
   }
-  pub fn from_name(name: &str) -> Equipment {
+  pub fn try_from_name(name: &str) -> Option<Equipment> {
     match name {
-      "battery-equipment" => Equipment::BatteryEquipment,
-      "battery-mk2-equipment" => Equipment::BatteryMk2Equipment,
-      "discharge-defense-equipment" => Equipment::DischargeDefenseEquipment,
-      "energy-shield-equipment" => Equipment::EnergyShieldEquipment,
-      "energy-shield-mk2-equipment" => Equipment::EnergyShieldMk2Equipment,
-      "exoskeleton-equipment" => Equipment::ExoskeletonEquipment,
-      "fusion-reactor-equipment" => Equipment::FusionReactorEquipment,
-      "night-vision-equipment" => Equipment::NightVisionEquipment,
-      "personal-laser-defense-equipment" => Equipment::PersonalLaserDefenseEquipment,
-      "personal-roboport-equipment" => Equipment::PersonalRoboportEquipment,
-      "personal-roboport-mk2-equipment" => Equipment::PersonalRoboportMk2Equipment,
-      "solar-panel-equipment" => Equipment::SolarPanelEquipment,
-      "belt-immunity-equipment" => Equipment::BeltImmunityEquipment,
-      name => panic!("unknown Equipment \"{name}\""),
+      "battery-equipment" => Some(Equipment::BatteryEquipment),
+      "battery-mk2-equipment" => Some(Equipment::BatteryMk2Equipment),
+      "discharge-defense-equipment" => Some(Equipment::DischargeDefenseEquipment),
+      "energy-shield-equipment" => Some(Equipment::EnergyShieldEquipment),
+      "energy-shield-mk2-equipment" => Some(Equipment::EnergyShieldMk2Equipment),
+      "exoskeleton-equipment" => Some(Equipment::ExoskeletonEquipment),
+      "fusion-reactor-equipment" => Some(Equipment::FusionReactorEquipment),
+      "night-vision-equipment" => Some(Equipment::NightVisionEquipment),
+      "personal-laser-defense-equipment" => Some(Equipment::PersonalLaserDefenseEquipment),
+      "personal-roboport-equipment" => Some(Equipment::PersonalRoboportEquipment),
+      "personal-roboport-mk2-equipment" => Some(Equipment::PersonalRoboportMk2Equipment),
+      "solar-panel-equipment" => Some(Equipment::SolarPanelEquipment),
+      "belt-immunity-equipment" => Some(Equipment::BeltImmunityEquipment),
+      _ => None,
     }
   }
-}
\ No newline at end of file
+  pub fn from_name(name: &str) -> Equipment {
+    Equipment::try_from_name(name).unwrap_or_else(|| panic!("unknown Equipment \"{name}\""))
+  }
+}
+impl std::fmt::Display for Equipment {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Equipment {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Equipment, UnknownPrototypeName> {
+    Equipment::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Equipment", name: name.to_owned() })
+  }
+}
@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU16;
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU16)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(Fluid)]
 pub enum Fluid {
   FluidUnknown = 1,
   Water = 2,
@@ -18,6 +21,21 @@ pub enum Fluid {
   Lubricant = 9,
 }
 impl Fluid {
+  pub const ALL: [Fluid; 9] = [
+    Fluid::FluidUnknown,
+    Fluid::Water,
+    Fluid::CrudeOil,
+    Fluid::Steam,
+    Fluid::HeavyOil,
+    Fluid::LightOil,
+    Fluid::PetroleumGas,
+    Fluid::SulfuricAcid,
+    Fluid::Lubricant,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Fluid> {
+    Fluid::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Fluid::FluidUnknown => "fluid-unknown",
@@ -31,18 +49,32 @@ impl Fluid {
       Fluid::Lubricant => "lubricant",
     }
   }
-  pub fn from_name(name: &str) -> Fluid {
+  pub fn try_from_name(name: &str) -> Option<Fluid> {
     match name {
-      "fluid-unknown" => Fluid::FluidUnknown,
-      "water" => Fluid::Water,
-      "crude-oil" => Fluid::CrudeOil,
-      "steam" => Fluid::Steam,
-      "heavy-oil" => Fluid::HeavyOil,
-      "light-oil" => Fluid::LightOil,
-      "petroleum-gas" => Fluid::PetroleumGas,
-      "sulfuric-acid" => Fluid::SulfuricAcid,
-      "lubricant" => Fluid::Lubricant,
-      name => panic!("unknown Fluid \"{name}\""),
+      "fluid-unknown" => Some(Fluid::FluidUnknown),
+      "water" => Some(Fluid::Water),
+      "crude-oil" => Some(Fluid::CrudeOil),
+      "steam" => Some(Fluid::Steam),
+      "heavy-oil" => Some(Fluid::HeavyOil),
+      "light-oil" => Some(Fluid::LightOil),
+      "petroleum-gas" => Some(Fluid::PetroleumGas),
+      "sulfuric-acid" => Some(Fluid::SulfuricAcid),
+      "lubricant" => Some(Fluid::Lubricant),
+      _ => None,
     }
   }
+  pub fn from_name(name: &str) -> Fluid {
+    Fluid::try_from_name(name).unwrap_or_else(|| panic!("unknown Fluid \"{name}\""))
+  }
+}
+impl std::fmt::Display for Fluid {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Fluid {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Fluid, UnknownPrototypeName> {
+    Fluid::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Fluid", name: name.to_owned() })
+  }
 }
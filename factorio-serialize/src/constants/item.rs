@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU16;
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU16)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(Item)]
 pub enum Item {
   WoodenChest = 1,
   IronChest = 2,
@@ -244,6 +247,247 @@ pub enum Item {
   BurnerGenerator = 235,
 }
 impl Item {
+  pub const ALL: [Item; 235] = [
+    Item::WoodenChest,
+    Item::IronChest,
+    Item::SteelChest,
+    Item::StorageTank,
+    Item::TransportBelt,
+    Item::FastTransportBelt,
+    Item::ExpressTransportBelt,
+    Item::UndergroundBelt,
+    Item::FastUndergroundBelt,
+    Item::ExpressUndergroundBelt,
+    Item::Splitter,
+    Item::FastSplitter,
+    Item::ExpressSplitter,
+    Item::Loader,
+    Item::FastLoader,
+    Item::ExpressLoader,
+    Item::BurnerInserter,
+    Item::Inserter,
+    Item::LongHandedInserter,
+    Item::FastInserter,
+    Item::FilterInserter,
+    Item::StackInserter,
+    Item::StackFilterInserter,
+    Item::SmallElectricPole,
+    Item::MediumElectricPole,
+    Item::BigElectricPole,
+    Item::Substation,
+    Item::Pipe,
+    Item::PipeToGround,
+    Item::Pump,
+    Item::Rail,
+    Item::TrainStop,
+    Item::RailSignal,
+    Item::RailChainSignal,
+    Item::Locomotive,
+    Item::CargoWagon,
+    Item::FluidWagon,
+    Item::ArtilleryWagon,
+    Item::Car,
+    Item::Tank,
+    Item::Spidertron,
+    Item::SpidertronRemote,
+    Item::LogisticRobot,
+    Item::ConstructionRobot,
+    Item::LogisticChestActiveProvider,
+    Item::LogisticChestPassiveProvider,
+    Item::LogisticChestStorage,
+    Item::LogisticChestBuffer,
+    Item::LogisticChestRequester,
+    Item::Roboport,
+    Item::SmallLamp,
+    Item::RedWire,
+    Item::GreenWire,
+    Item::ArithmeticCombinator,
+    Item::DeciderCombinator,
+    Item::ConstantCombinator,
+    Item::PowerSwitch,
+    Item::ProgrammableSpeaker,
+    Item::StoneBrick,
+    Item::Concrete,
+    Item::HazardConcrete,
+    Item::RefinedConcrete,
+    Item::RefinedHazardConcrete,
+    Item::Landfill,
+    Item::CliffExplosives,
+    Item::DummySteelAxe,
+    Item::RepairPack,
+    Item::Blueprint,
+    Item::DeconstructionPlanner,
+    Item::UpgradePlanner,
+    Item::BlueprintBook,
+    Item::CopyPasteTool,
+    Item::CutPasteTool,
+    Item::Boiler,
+    Item::SteamEngine,
+    Item::SolarPanel,
+    Item::Accumulator,
+    Item::NuclearReactor,
+    Item::HeatPipe,
+    Item::HeatExchanger,
+    Item::SteamTurbine,
+    Item::BurnerMiningDrill,
+    Item::ElectricMiningDrill,
+    Item::OffshorePump,
+    Item::Pumpjack,
+    Item::StoneFurnace,
+    Item::SteelFurnace,
+    Item::ElectricFurnace,
+    Item::AssemblingMachine1,
+    Item::AssemblingMachine2,
+    Item::AssemblingMachine3,
+    Item::OilRefinery,
+    Item::ChemicalPlant,
+    Item::Centrifuge,
+    Item::Lab,
+    Item::Beacon,
+    Item::SpeedModule,
+    Item::SpeedModule2,
+    Item::SpeedModule3,
+    Item::EffectivityModule,
+    Item::EffectivityModule2,
+    Item::EffectivityModule3,
+    Item::ProductivityModule,
+    Item::ProductivityModule2,
+    Item::ProductivityModule3,
+    Item::RocketSilo,
+    Item::Satellite,
+    Item::Wood,
+    Item::Coal,
+    Item::Stone,
+    Item::IronOre,
+    Item::CopperOre,
+    Item::UraniumOre,
+    Item::RawFish,
+    Item::IronPlate,
+    Item::CopperPlate,
+    Item::SolidFuel,
+    Item::SteelPlate,
+    Item::PlasticBar,
+    Item::Sulfur,
+    Item::Battery,
+    Item::Explosives,
+    Item::CrudeOilBarrel,
+    Item::HeavyOilBarrel,
+    Item::LightOilBarrel,
+    Item::LubricantBarrel,
+    Item::PetroleumGasBarrel,
+    Item::SulfuricAcidBarrel,
+    Item::WaterBarrel,
+    Item::CopperCable,
+    Item::IronStick,
+    Item::IronGearWheel,
+    Item::EmptyBarrel,
+    Item::ElectronicCircuit,
+    Item::AdvancedCircuit,
+    Item::ProcessingUnit,
+    Item::EngineUnit,
+    Item::ElectricEngineUnit,
+    Item::FlyingRobotFrame,
+    Item::RocketControlUnit,
+    Item::LowDensityStructure,
+    Item::RocketFuel,
+    Item::RocketPart,
+    Item::NuclearFuel,
+    Item::Uranium235,
+    Item::Uranium238,
+    Item::UraniumFuelCell,
+    Item::UsedUpUraniumFuelCell,
+    Item::AutomationSciencePack,
+    Item::LogisticSciencePack,
+    Item::MilitarySciencePack,
+    Item::ChemicalSciencePack,
+    Item::ProductionSciencePack,
+    Item::UtilitySciencePack,
+    Item::SpaceSciencePack,
+    Item::Coin,
+    Item::Pistol,
+    Item::SubmachineGun,
+    Item::TankMachineGun,
+    Item::VehicleMachineGun,
+    Item::TankFlamethrower,
+    Item::Shotgun,
+    Item::CombatShotgun,
+    Item::RocketLauncher,
+    Item::Flamethrower,
+    Item::LandMine,
+    Item::ArtilleryWagonCannon,
+    Item::SpidertronRocketLauncher1,
+    Item::SpidertronRocketLauncher2,
+    Item::SpidertronRocketLauncher3,
+    Item::SpidertronRocketLauncher4,
+    Item::TankCannon,
+    Item::FirearmMagazine,
+    Item::PiercingRoundsMagazine,
+    Item::UraniumRoundsMagazine,
+    Item::ShotgunShell,
+    Item::PiercingShotgunShell,
+    Item::CannonShell,
+    Item::ExplosiveCannonShell,
+    Item::UraniumCannonShell,
+    Item::ExplosiveUraniumCannonShell,
+    Item::ArtilleryShell,
+    Item::Rocket,
+    Item::ExplosiveRocket,
+    Item::AtomicBomb,
+    Item::FlamethrowerAmmo,
+    Item::Grenade,
+    Item::ClusterGrenade,
+    Item::PoisonCapsule,
+    Item::SlowdownCapsule,
+    Item::DefenderCapsule,
+    Item::DistractorCapsule,
+    Item::DestroyerCapsule,
+    Item::LightArmor,
+    Item::HeavyArmor,
+    Item::ModularArmor,
+    Item::PowerArmor,
+    Item::PowerArmorMk2,
+    Item::SolarPanelEquipment,
+    Item::FusionReactorEquipment,
+    Item::BatteryEquipment,
+    Item::BatteryMk2Equipment,
+    Item::BeltImmunityEquipment,
+    Item::ExoskeletonEquipment,
+    Item::PersonalRoboportEquipment,
+    Item::PersonalRoboportMk2Equipment,
+    Item::NightVisionEquipment,
+    Item::EnergyShieldEquipment,
+    Item::EnergyShieldMk2Equipment,
+    Item::PersonalLaserDefenseEquipment,
+    Item::DischargeDefenseEquipment,
+    Item::DischargeDefenseRemote,
+    Item::StoneWall,
+    Item::Gate,
+    Item::GunTurret,
+    Item::LaserTurret,
+    Item::FlamethrowerTurret,
+    Item::ArtilleryTurret,
+    Item::ArtilleryTargetingRemote,
+    Item::Radar,
+    Item::PlayerPort,
+    Item::ItemUnknown,
+    Item::ElectricEnergyInterface,
+    Item::LinkedChest,
+    Item::HeatInterface,
+    Item::LinkedBelt,
+    Item::InfinityChest,
+    Item::InfinityPipe,
+    Item::SelectionTool,
+    Item::ItemWithInventory,
+    Item::ItemWithLabel,
+    Item::ItemWithTags,
+    Item::SimpleEntityWithForce,
+    Item::SimpleEntityWithOwner,
+    Item::BurnerGenerator,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Item> {
+    Item::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Item::WoodenChest => "wooden-chest",
@@ -483,244 +727,258 @@ impl Item {
       Item::BurnerGenerator => "burner-generator",
     }
   }
-  pub fn from_name(name: &str) -> Item {
+  pub fn try_from_name(name: &str) -> Option<Item> {
     match name {
-      "wooden-chest" => Item::WoodenChest,
-      "iron-chest" => Item::IronChest,
-      "steel-chest" => Item::SteelChest,
-      "storage-tank" => Item::StorageTank,
-      "transport-belt" => Item::TransportBelt,
-      "fast-transport-belt" => Item::FastTransportBelt,
-      "express-transport-belt" => Item::ExpressTransportBelt,
-      "underground-belt" => Item::UndergroundBelt,
-      "fast-underground-belt" => Item::FastUndergroundBelt,
-      "express-underground-belt" => Item::ExpressUndergroundBelt,
-      "splitter" => Item::Splitter,
-      "fast-splitter" => Item::FastSplitter,
-      "express-splitter" => Item::ExpressSplitter,
-      "loader" => Item::Loader,
-      "fast-loader" => Item::FastLoader,
-      "express-loader" => Item::ExpressLoader,
-      "burner-inserter" => Item::BurnerInserter,
-      "inserter" => Item::Inserter,
-      "long-handed-inserter" => Item::LongHandedInserter,
-      "fast-inserter" => Item::FastInserter,
-      "filter-inserter" => Item::FilterInserter,
-      "stack-inserter" => Item::StackInserter,
-      "stack-filter-inserter" => Item::StackFilterInserter,
-      "small-electric-pole" => Item::SmallElectricPole,
-      "medium-electric-pole" => Item::MediumElectricPole,
-      "big-electric-pole" => Item::BigElectricPole,
-      "substation" => Item::Substation,
-      "pipe" => Item::Pipe,
-      "pipe-to-ground" => Item::PipeToGround,
-      "pump" => Item::Pump,
-      "rail" => Item::Rail,
-      "train-stop" => Item::TrainStop,
-      "rail-signal" => Item::RailSignal,
-      "rail-chain-signal" => Item::RailChainSignal,
-      "locomotive" => Item::Locomotive,
-      "cargo-wagon" => Item::CargoWagon,
-      "fluid-wagon" => Item::FluidWagon,
-      "artillery-wagon" => Item::ArtilleryWagon,
-      "car" => Item::Car,
-      "tank" => Item::Tank,
-      "spidertron" => Item::Spidertron,
-      "spidertron-remote" => Item::SpidertronRemote,
-      "logistic-robot" => Item::LogisticRobot,
-      "construction-robot" => Item::ConstructionRobot,
-      "logistic-chest-active-provider" => Item::LogisticChestActiveProvider,
-      "logistic-chest-passive-provider" => Item::LogisticChestPassiveProvider,
-      "logistic-chest-storage" => Item::LogisticChestStorage,
-      "logistic-chest-buffer" => Item::LogisticChestBuffer,
-      "logistic-chest-requester" => Item::LogisticChestRequester,
-      "roboport" => Item::Roboport,
-      "small-lamp" => Item::SmallLamp,
-      "red-wire" => Item::RedWire,
-      "green-wire" => Item::GreenWire,
-      "arithmetic-combinator" => Item::ArithmeticCombinator,
-      "decider-combinator" => Item::DeciderCombinator,
-      "constant-combinator" => Item::ConstantCombinator,
-      "power-switch" => Item::PowerSwitch,
-      "programmable-speaker" => Item::ProgrammableSpeaker,
-      "stone-brick" => Item::StoneBrick,
-      "concrete" => Item::Concrete,
-      "hazard-concrete" => Item::HazardConcrete,
-      "refined-concrete" => Item::RefinedConcrete,
-      "refined-hazard-concrete" => Item::RefinedHazardConcrete,
-      "landfill" => Item::Landfill,
-      "cliff-explosives" => Item::CliffExplosives,
-      "dummy-steel-axe" => Item::DummySteelAxe,
-      "repair-pack" => Item::RepairPack,
-      "blueprint" => Item::Blueprint,
-      "deconstruction-planner" => Item::DeconstructionPlanner,
-      "upgrade-planner" => Item::UpgradePlanner,
-      "blueprint-book" => Item::BlueprintBook,
-      "copy-paste-tool" => Item::CopyPasteTool,
-      "cut-paste-tool" => Item::CutPasteTool,
-      "boiler" => Item::Boiler,
-      "steam-engine" => Item::SteamEngine,
-      "solar-panel" => Item::SolarPanel,
-      "accumulator" => Item::Accumulator,
-      "nuclear-reactor" => Item::NuclearReactor,
-      "heat-pipe" => Item::HeatPipe,
-      "heat-exchanger" => Item::HeatExchanger,
-      "steam-turbine" => Item::SteamTurbine,
-      "burner-mining-drill" => Item::BurnerMiningDrill,
-      "electric-mining-drill" => Item::ElectricMiningDrill,
-      "offshore-pump" => Item::OffshorePump,
-      "pumpjack" => Item::Pumpjack,
-      "stone-furnace" => Item::StoneFurnace,
-      "steel-furnace" => Item::SteelFurnace,
-      "electric-furnace" => Item::ElectricFurnace,
-      "assembling-machine-1" => Item::AssemblingMachine1,
-      "assembling-machine-2" => Item::AssemblingMachine2,
-      "assembling-machine-3" => Item::AssemblingMachine3,
-      "oil-refinery" => Item::OilRefinery,
-      "chemical-plant" => Item::ChemicalPlant,
-      "centrifuge" => Item::Centrifuge,
-      "lab" => Item::Lab,
-      "beacon" => Item::Beacon,
-      "speed-module" => Item::SpeedModule,
-      "speed-module-2" => Item::SpeedModule2,
-      "speed-module-3" => Item::SpeedModule3,
-      "effectivity-module" => Item::EffectivityModule,
-      "effectivity-module-2" => Item::EffectivityModule2,
-      "effectivity-module-3" => Item::EffectivityModule3,
-      "productivity-module" => Item::ProductivityModule,
-      "productivity-module-2" => Item::ProductivityModule2,
-      "productivity-module-3" => Item::ProductivityModule3,
-      "rocket-silo" => Item::RocketSilo,
-      "satellite" => Item::Satellite,
-      "wood" => Item::Wood,
-      "coal" => Item::Coal,
-      "stone" => Item::Stone,
-      "iron-ore" => Item::IronOre,
-      "copper-ore" => Item::CopperOre,
-      "uranium-ore" => Item::UraniumOre,
-      "raw-fish" => Item::RawFish,
-      "iron-plate" => Item::IronPlate,
-      "copper-plate" => Item::CopperPlate,
-      "solid-fuel" => Item::SolidFuel,
-      "steel-plate" => Item::SteelPlate,
-      "plastic-bar" => Item::PlasticBar,
-      "sulfur" => Item::Sulfur,
-      "battery" => Item::Battery,
-      "explosives" => Item::Explosives,
-      "crude-oil-barrel" => Item::CrudeOilBarrel,
-      "heavy-oil-barrel" => Item::HeavyOilBarrel,
-      "light-oil-barrel" => Item::LightOilBarrel,
-      "lubricant-barrel" => Item::LubricantBarrel,
-      "petroleum-gas-barrel" => Item::PetroleumGasBarrel,
-      "sulfuric-acid-barrel" => Item::SulfuricAcidBarrel,
-      "water-barrel" => Item::WaterBarrel,
-      "copper-cable" => Item::CopperCable,
-      "iron-stick" => Item::IronStick,
-      "iron-gear-wheel" => Item::IronGearWheel,
-      "empty-barrel" => Item::EmptyBarrel,
-      "electronic-circuit" => Item::ElectronicCircuit,
-      "advanced-circuit" => Item::AdvancedCircuit,
-      "processing-unit" => Item::ProcessingUnit,
-      "engine-unit" => Item::EngineUnit,
-      "electric-engine-unit" => Item::ElectricEngineUnit,
-      "flying-robot-frame" => Item::FlyingRobotFrame,
-      "rocket-control-unit" => Item::RocketControlUnit,
-      "low-density-structure" => Item::LowDensityStructure,
-      "rocket-fuel" => Item::RocketFuel,
-      "rocket-part" => Item::RocketPart,
-      "nuclear-fuel" => Item::NuclearFuel,
-      "uranium-235" => Item::Uranium235,
-      "uranium-238" => Item::Uranium238,
-      "uranium-fuel-cell" => Item::UraniumFuelCell,
-      "used-up-uranium-fuel-cell" => Item::UsedUpUraniumFuelCell,
-      "automation-science-pack" => Item::AutomationSciencePack,
-      "logistic-science-pack" => Item::LogisticSciencePack,
-      "military-science-pack" => Item::MilitarySciencePack,
-      "chemical-science-pack" => Item::ChemicalSciencePack,
-      "production-science-pack" => Item::ProductionSciencePack,
-      "utility-science-pack" => Item::UtilitySciencePack,
-      "space-science-pack" => Item::SpaceSciencePack,
-      "coin" => Item::Coin,
-      "pistol" => Item::Pistol,
-      "submachine-gun" => Item::SubmachineGun,
-      "tank-machine-gun" => Item::TankMachineGun,
-      "vehicle-machine-gun" => Item::VehicleMachineGun,
-      "tank-flamethrower" => Item::TankFlamethrower,
-      "shotgun" => Item::Shotgun,
-      "combat-shotgun" => Item::CombatShotgun,
-      "rocket-launcher" => Item::RocketLauncher,
-      "flamethrower" => Item::Flamethrower,
-      "land-mine" => Item::LandMine,
-      "artillery-wagon-cannon" => Item::ArtilleryWagonCannon,
-      "spidertron-rocket-launcher-1" => Item::SpidertronRocketLauncher1,
-      "spidertron-rocket-launcher-2" => Item::SpidertronRocketLauncher2,
-      "spidertron-rocket-launcher-3" => Item::SpidertronRocketLauncher3,
-      "spidertron-rocket-launcher-4" => Item::SpidertronRocketLauncher4,
-      "tank-cannon" => Item::TankCannon,
-      "firearm-magazine" => Item::FirearmMagazine,
-      "piercing-rounds-magazine" => Item::PiercingRoundsMagazine,
-      "uranium-rounds-magazine" => Item::UraniumRoundsMagazine,
-      "shotgun-shell" => Item::ShotgunShell,
-      "piercing-shotgun-shell" => Item::PiercingShotgunShell,
-      "cannon-shell" => Item::CannonShell,
-      "explosive-cannon-shell" => Item::ExplosiveCannonShell,
-      "uranium-cannon-shell" => Item::UraniumCannonShell,
-      "explosive-uranium-cannon-shell" => Item::ExplosiveUraniumCannonShell,
-      "artillery-shell" => Item::ArtilleryShell,
-      "rocket" => Item::Rocket,
-      "explosive-rocket" => Item::ExplosiveRocket,
-      "atomic-bomb" => Item::AtomicBomb,
-      "flamethrower-ammo" => Item::FlamethrowerAmmo,
-      "grenade" => Item::Grenade,
-      "cluster-grenade" => Item::ClusterGrenade,
-      "poison-capsule" => Item::PoisonCapsule,
-      "slowdown-capsule" => Item::SlowdownCapsule,
-      "defender-capsule" => Item::DefenderCapsule,
-      "distractor-capsule" => Item::DistractorCapsule,
-      "destroyer-capsule" => Item::DestroyerCapsule,
-      "light-armor" => Item::LightArmor,
-      "heavy-armor" => Item::HeavyArmor,
-      "modular-armor" => Item::ModularArmor,
-      "power-armor" => Item::PowerArmor,
-      "power-armor-mk2" => Item::PowerArmorMk2,
-      "solar-panel-equipment" => Item::SolarPanelEquipment,
-      "fusion-reactor-equipment" => Item::FusionReactorEquipment,
-      "battery-equipment" => Item::BatteryEquipment,
-      "battery-mk2-equipment" => Item::BatteryMk2Equipment,
-      "belt-immunity-equipment" => Item::BeltImmunityEquipment,
-      "exoskeleton-equipment" => Item::ExoskeletonEquipment,
-      "personal-roboport-equipment" => Item::PersonalRoboportEquipment,
-      "personal-roboport-mk2-equipment" => Item::PersonalRoboportMk2Equipment,
-      "night-vision-equipment" => Item::NightVisionEquipment,
-      "energy-shield-equipment" => Item::EnergyShieldEquipment,
-      "energy-shield-mk2-equipment" => Item::EnergyShieldMk2Equipment,
-      "personal-laser-defense-equipment" => Item::PersonalLaserDefenseEquipment,
-      "discharge-defense-equipment" => Item::DischargeDefenseEquipment,
-      "discharge-defense-remote" => Item::DischargeDefenseRemote,
-      "stone-wall" => Item::StoneWall,
-      "gate" => Item::Gate,
-      "gun-turret" => Item::GunTurret,
-      "laser-turret" => Item::LaserTurret,
-      "flamethrower-turret" => Item::FlamethrowerTurret,
-      "artillery-turret" => Item::ArtilleryTurret,
-      "artillery-targeting-remote" => Item::ArtilleryTargetingRemote,
-      "radar" => Item::Radar,
-      "player-port" => Item::PlayerPort,
-      "item-unknown" => Item::ItemUnknown,
-      "electric-energy-interface" => Item::ElectricEnergyInterface,
-      "linked-chest" => Item::LinkedChest,
-      "heat-interface" => Item::HeatInterface,
-      "linked-belt" => Item::LinkedBelt,
-      "infinity-chest" => Item::InfinityChest,
-      "infinity-pipe" => Item::InfinityPipe,
-      "selection-tool" => Item::SelectionTool,
-      "item-with-inventory" => Item::ItemWithInventory,
-      "item-with-label" => Item::ItemWithLabel,
-      "item-with-tags" => Item::ItemWithTags,
-      "simple-entity-with-force" => Item::SimpleEntityWithForce,
-      "simple-entity-with-owner" => Item::SimpleEntityWithOwner,
-      "burner-generator" => Item::BurnerGenerator,
-      name => panic!("unknown Item name {name}"),
+      "wooden-chest" => Some(Item::WoodenChest),
+      "iron-chest" => Some(Item::IronChest),
+      "steel-chest" => Some(Item::SteelChest),
+      "storage-tank" => Some(Item::StorageTank),
+      "transport-belt" => Some(Item::TransportBelt),
+      "fast-transport-belt" => Some(Item::FastTransportBelt),
+      "express-transport-belt" => Some(Item::ExpressTransportBelt),
+      "underground-belt" => Some(Item::UndergroundBelt),
+      "fast-underground-belt" => Some(Item::FastUndergroundBelt),
+      "express-underground-belt" => Some(Item::ExpressUndergroundBelt),
+      "splitter" => Some(Item::Splitter),
+      "fast-splitter" => Some(Item::FastSplitter),
+      "express-splitter" => Some(Item::ExpressSplitter),
+      "loader" => Some(Item::Loader),
+      "fast-loader" => Some(Item::FastLoader),
+      "express-loader" => Some(Item::ExpressLoader),
+      "burner-inserter" => Some(Item::BurnerInserter),
+      "inserter" => Some(Item::Inserter),
+      "long-handed-inserter" => Some(Item::LongHandedInserter),
+      "fast-inserter" => Some(Item::FastInserter),
+      "filter-inserter" => Some(Item::FilterInserter),
+      "stack-inserter" => Some(Item::StackInserter),
+      "stack-filter-inserter" => Some(Item::StackFilterInserter),
+      "small-electric-pole" => Some(Item::SmallElectricPole),
+      "medium-electric-pole" => Some(Item::MediumElectricPole),
+      "big-electric-pole" => Some(Item::BigElectricPole),
+      "substation" => Some(Item::Substation),
+      "pipe" => Some(Item::Pipe),
+      "pipe-to-ground" => Some(Item::PipeToGround),
+      "pump" => Some(Item::Pump),
+      "rail" => Some(Item::Rail),
+      "train-stop" => Some(Item::TrainStop),
+      "rail-signal" => Some(Item::RailSignal),
+      "rail-chain-signal" => Some(Item::RailChainSignal),
+      "locomotive" => Some(Item::Locomotive),
+      "cargo-wagon" => Some(Item::CargoWagon),
+      "fluid-wagon" => Some(Item::FluidWagon),
+      "artillery-wagon" => Some(Item::ArtilleryWagon),
+      "car" => Some(Item::Car),
+      "tank" => Some(Item::Tank),
+      "spidertron" => Some(Item::Spidertron),
+      "spidertron-remote" => Some(Item::SpidertronRemote),
+      "logistic-robot" => Some(Item::LogisticRobot),
+      "construction-robot" => Some(Item::ConstructionRobot),
+      "logistic-chest-active-provider" => Some(Item::LogisticChestActiveProvider),
+      "logistic-chest-passive-provider" => Some(Item::LogisticChestPassiveProvider),
+      "logistic-chest-storage" => Some(Item::LogisticChestStorage),
+      "logistic-chest-buffer" => Some(Item::LogisticChestBuffer),
+      "logistic-chest-requester" => Some(Item::LogisticChestRequester),
+      "roboport" => Some(Item::Roboport),
+      "small-lamp" => Some(Item::SmallLamp),
+      "red-wire" => Some(Item::RedWire),
+      "green-wire" => Some(Item::GreenWire),
+      "arithmetic-combinator" => Some(Item::ArithmeticCombinator),
+      "decider-combinator" => Some(Item::DeciderCombinator),
+      "constant-combinator" => Some(Item::ConstantCombinator),
+      "power-switch" => Some(Item::PowerSwitch),
+      "programmable-speaker" => Some(Item::ProgrammableSpeaker),
+      "stone-brick" => Some(Item::StoneBrick),
+      "concrete" => Some(Item::Concrete),
+      "hazard-concrete" => Some(Item::HazardConcrete),
+      "refined-concrete" => Some(Item::RefinedConcrete),
+      "refined-hazard-concrete" => Some(Item::RefinedHazardConcrete),
+      "landfill" => Some(Item::Landfill),
+      "cliff-explosives" => Some(Item::CliffExplosives),
+      "dummy-steel-axe" => Some(Item::DummySteelAxe),
+      "repair-pack" => Some(Item::RepairPack),
+      "blueprint" => Some(Item::Blueprint),
+      "deconstruction-planner" => Some(Item::DeconstructionPlanner),
+      "upgrade-planner" => Some(Item::UpgradePlanner),
+      "blueprint-book" => Some(Item::BlueprintBook),
+      "copy-paste-tool" => Some(Item::CopyPasteTool),
+      "cut-paste-tool" => Some(Item::CutPasteTool),
+      "boiler" => Some(Item::Boiler),
+      "steam-engine" => Some(Item::SteamEngine),
+      "solar-panel" => Some(Item::SolarPanel),
+      "accumulator" => Some(Item::Accumulator),
+      "nuclear-reactor" => Some(Item::NuclearReactor),
+      "heat-pipe" => Some(Item::HeatPipe),
+      "heat-exchanger" => Some(Item::HeatExchanger),
+      "steam-turbine" => Some(Item::SteamTurbine),
+      "burner-mining-drill" => Some(Item::BurnerMiningDrill),
+      "electric-mining-drill" => Some(Item::ElectricMiningDrill),
+      "offshore-pump" => Some(Item::OffshorePump),
+      "pumpjack" => Some(Item::Pumpjack),
+      "stone-furnace" => Some(Item::StoneFurnace),
+      "steel-furnace" => Some(Item::SteelFurnace),
+      "electric-furnace" => Some(Item::ElectricFurnace),
+      "assembling-machine-1" => Some(Item::AssemblingMachine1),
+      "assembling-machine-2" => Some(Item::AssemblingMachine2),
+      "assembling-machine-3" => Some(Item::AssemblingMachine3),
+      "oil-refinery" => Some(Item::OilRefinery),
+      "chemical-plant" => Some(Item::ChemicalPlant),
+      "centrifuge" => Some(Item::Centrifuge),
+      "lab" => Some(Item::Lab),
+      "beacon" => Some(Item::Beacon),
+      "speed-module" => Some(Item::SpeedModule),
+      "speed-module-2" => Some(Item::SpeedModule2),
+      "speed-module-3" => Some(Item::SpeedModule3),
+      "effectivity-module" => Some(Item::EffectivityModule),
+      "effectivity-module-2" => Some(Item::EffectivityModule2),
+      "effectivity-module-3" => Some(Item::EffectivityModule3),
+      "productivity-module" => Some(Item::ProductivityModule),
+      "productivity-module-2" => Some(Item::ProductivityModule2),
+      "productivity-module-3" => Some(Item::ProductivityModule3),
+      "rocket-silo" => Some(Item::RocketSilo),
+      "satellite" => Some(Item::Satellite),
+      "wood" => Some(Item::Wood),
+      "coal" => Some(Item::Coal),
+      "stone" => Some(Item::Stone),
+      "iron-ore" => Some(Item::IronOre),
+      "copper-ore" => Some(Item::CopperOre),
+      "uranium-ore" => Some(Item::UraniumOre),
+      "raw-fish" => Some(Item::RawFish),
+      "iron-plate" => Some(Item::IronPlate),
+      "copper-plate" => Some(Item::CopperPlate),
+      "solid-fuel" => Some(Item::SolidFuel),
+      "steel-plate" => Some(Item::SteelPlate),
+      "plastic-bar" => Some(Item::PlasticBar),
+      "sulfur" => Some(Item::Sulfur),
+      "battery" => Some(Item::Battery),
+      "explosives" => Some(Item::Explosives),
+      "crude-oil-barrel" => Some(Item::CrudeOilBarrel),
+      "heavy-oil-barrel" => Some(Item::HeavyOilBarrel),
+      "light-oil-barrel" => Some(Item::LightOilBarrel),
+      "lubricant-barrel" => Some(Item::LubricantBarrel),
+      "petroleum-gas-barrel" => Some(Item::PetroleumGasBarrel),
+      "sulfuric-acid-barrel" => Some(Item::SulfuricAcidBarrel),
+      "water-barrel" => Some(Item::WaterBarrel),
+      "copper-cable" => Some(Item::CopperCable),
+      "iron-stick" => Some(Item::IronStick),
+      "iron-gear-wheel" => Some(Item::IronGearWheel),
+      "empty-barrel" => Some(Item::EmptyBarrel),
+      "electronic-circuit" => Some(Item::ElectronicCircuit),
+      "advanced-circuit" => Some(Item::AdvancedCircuit),
+      "processing-unit" => Some(Item::ProcessingUnit),
+      "engine-unit" => Some(Item::EngineUnit),
+      "electric-engine-unit" => Some(Item::ElectricEngineUnit),
+      "flying-robot-frame" => Some(Item::FlyingRobotFrame),
+      "rocket-control-unit" => Some(Item::RocketControlUnit),
+      "low-density-structure" => Some(Item::LowDensityStructure),
+      "rocket-fuel" => Some(Item::RocketFuel),
+      "rocket-part" => Some(Item::RocketPart),
+      "nuclear-fuel" => Some(Item::NuclearFuel),
+      "uranium-235" => Some(Item::Uranium235),
+      "uranium-238" => Some(Item::Uranium238),
+      "uranium-fuel-cell" => Some(Item::UraniumFuelCell),
+      "used-up-uranium-fuel-cell" => Some(Item::UsedUpUraniumFuelCell),
+      "automation-science-pack" => Some(Item::AutomationSciencePack),
+      "logistic-science-pack" => Some(Item::LogisticSciencePack),
+      "military-science-pack" => Some(Item::MilitarySciencePack),
+      "chemical-science-pack" => Some(Item::ChemicalSciencePack),
+      "production-science-pack" => Some(Item::ProductionSciencePack),
+      "utility-science-pack" => Some(Item::UtilitySciencePack),
+      "space-science-pack" => Some(Item::SpaceSciencePack),
+      "coin" => Some(Item::Coin),
+      "pistol" => Some(Item::Pistol),
+      "submachine-gun" => Some(Item::SubmachineGun),
+      "tank-machine-gun" => Some(Item::TankMachineGun),
+      "vehicle-machine-gun" => Some(Item::VehicleMachineGun),
+      "tank-flamethrower" => Some(Item::TankFlamethrower),
+      "shotgun" => Some(Item::Shotgun),
+      "combat-shotgun" => Some(Item::CombatShotgun),
+      "rocket-launcher" => Some(Item::RocketLauncher),
+      "flamethrower" => Some(Item::Flamethrower),
+      "land-mine" => Some(Item::LandMine),
+      "artillery-wagon-cannon" => Some(Item::ArtilleryWagonCannon),
+      "spidertron-rocket-launcher-1" => Some(Item::SpidertronRocketLauncher1),
+      "spidertron-rocket-launcher-2" => Some(Item::SpidertronRocketLauncher2),
+      "spidertron-rocket-launcher-3" => Some(Item::SpidertronRocketLauncher3),
+      "spidertron-rocket-launcher-4" => Some(Item::SpidertronRocketLauncher4),
+      "tank-cannon" => Some(Item::TankCannon),
+      "firearm-magazine" => Some(Item::FirearmMagazine),
+      "piercing-rounds-magazine" => Some(Item::PiercingRoundsMagazine),
+      "uranium-rounds-magazine" => Some(Item::UraniumRoundsMagazine),
+      "shotgun-shell" => Some(Item::ShotgunShell),
+      "piercing-shotgun-shell" => Some(Item::PiercingShotgunShell),
+      "cannon-shell" => Some(Item::CannonShell),
+      "explosive-cannon-shell" => Some(Item::ExplosiveCannonShell),
+      "uranium-cannon-shell" => Some(Item::UraniumCannonShell),
+      "explosive-uranium-cannon-shell" => Some(Item::ExplosiveUraniumCannonShell),
+      "artillery-shell" => Some(Item::ArtilleryShell),
+      "rocket" => Some(Item::Rocket),
+      "explosive-rocket" => Some(Item::ExplosiveRocket),
+      "atomic-bomb" => Some(Item::AtomicBomb),
+      "flamethrower-ammo" => Some(Item::FlamethrowerAmmo),
+      "grenade" => Some(Item::Grenade),
+      "cluster-grenade" => Some(Item::ClusterGrenade),
+      "poison-capsule" => Some(Item::PoisonCapsule),
+      "slowdown-capsule" => Some(Item::SlowdownCapsule),
+      "defender-capsule" => Some(Item::DefenderCapsule),
+      "distractor-capsule" => Some(Item::DistractorCapsule),
+      "destroyer-capsule" => Some(Item::DestroyerCapsule),
+      "light-armor" => Some(Item::LightArmor),
+      "heavy-armor" => Some(Item::HeavyArmor),
+      "modular-armor" => Some(Item::ModularArmor),
+      "power-armor" => Some(Item::PowerArmor),
+      "power-armor-mk2" => Some(Item::PowerArmorMk2),
+      "solar-panel-equipment" => Some(Item::SolarPanelEquipment),
+      "fusion-reactor-equipment" => Some(Item::FusionReactorEquipment),
+      "battery-equipment" => Some(Item::BatteryEquipment),
+      "battery-mk2-equipment" => Some(Item::BatteryMk2Equipment),
+      "belt-immunity-equipment" => Some(Item::BeltImmunityEquipment),
+      "exoskeleton-equipment" => Some(Item::ExoskeletonEquipment),
+      "personal-roboport-equipment" => Some(Item::PersonalRoboportEquipment),
+      "personal-roboport-mk2-equipment" => Some(Item::PersonalRoboportMk2Equipment),
+      "night-vision-equipment" => Some(Item::NightVisionEquipment),
+      "energy-shield-equipment" => Some(Item::EnergyShieldEquipment),
+      "energy-shield-mk2-equipment" => Some(Item::EnergyShieldMk2Equipment),
+      "personal-laser-defense-equipment" => Some(Item::PersonalLaserDefenseEquipment),
+      "discharge-defense-equipment" => Some(Item::DischargeDefenseEquipment),
+      "discharge-defense-remote" => Some(Item::DischargeDefenseRemote),
+      "stone-wall" => Some(Item::StoneWall),
+      "gate" => Some(Item::Gate),
+      "gun-turret" => Some(Item::GunTurret),
+      "laser-turret" => Some(Item::LaserTurret),
+      "flamethrower-turret" => Some(Item::FlamethrowerTurret),
+      "artillery-turret" => Some(Item::ArtilleryTurret),
+      "artillery-targeting-remote" => Some(Item::ArtilleryTargetingRemote),
+      "radar" => Some(Item::Radar),
+      "player-port" => Some(Item::PlayerPort),
+      "item-unknown" => Some(Item::ItemUnknown),
+      "electric-energy-interface" => Some(Item::ElectricEnergyInterface),
+      "linked-chest" => Some(Item::LinkedChest),
+      "heat-interface" => Some(Item::HeatInterface),
+      "linked-belt" => Some(Item::LinkedBelt),
+      "infinity-chest" => Some(Item::InfinityChest),
+      "infinity-pipe" => Some(Item::InfinityPipe),
+      "selection-tool" => Some(Item::SelectionTool),
+      "item-with-inventory" => Some(Item::ItemWithInventory),
+      "item-with-label" => Some(Item::ItemWithLabel),
+      "item-with-tags" => Some(Item::ItemWithTags),
+      "simple-entity-with-force" => Some(Item::SimpleEntityWithForce),
+      "simple-entity-with-owner" => Some(Item::SimpleEntityWithOwner),
+      "burner-generator" => Some(Item::BurnerGenerator),
+      _ => None,
     }
   }
-}
\ No newline at end of file
+  pub fn from_name(name: &str) -> Item {
+    Item::try_from_name(name).unwrap_or_else(|| panic!("unknown Item \"{name}\""))
+  }
+}
+impl std::fmt::Display for Item {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Item {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Item, UnknownPrototypeName> {
+    Item::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Item", name: name.to_owned() })
+  }
+}
@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU8;
+use factorio_serialize_derive::PrototypeIdU8;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU8)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU8)]
+#[prototype_type(ItemGroup)]
 pub enum ItemGroup {
   Logistics = 1,
   Production = 2,
@@ -19,6 +22,22 @@ pub enum ItemGroup {
   Other = 10,
 }
 impl ItemGroup {
+  pub const ALL: [ItemGroup; 10] = [
+    ItemGroup::Logistics,
+    ItemGroup::Production,
+    ItemGroup::IntermediateProducts,
+    ItemGroup::Combat,
+    ItemGroup::Fluids,
+    ItemGroup::Signals,
+    ItemGroup::Enemies,
+    ItemGroup::Environment,
+    ItemGroup::Effects,
+    ItemGroup::Other,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = ItemGroup> {
+    ItemGroup::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       ItemGroup::Logistics => "logistics",
@@ -33,19 +52,33 @@ impl ItemGroup {
       ItemGroup::Other => "other",
     }
   }
-  pub fn from_name(name: &str) -> ItemGroup {
+  pub fn try_from_name(name: &str) -> Option<ItemGroup> {
     match name {
-      "logistics" => ItemGroup::Logistics,
-      "production" => ItemGroup::Production,
-      "intermediate-products" => ItemGroup::IntermediateProducts,
-      "combat" => ItemGroup::Combat,
-      "fluids" => ItemGroup::Fluids,
-      "signals" => ItemGroup::Signals,
-      "enemies" => ItemGroup::Enemies,
-      "environment" => ItemGroup::Environment,
-      "effects" => ItemGroup::Effects,
-      "other" => ItemGroup::Other,
-      name => panic!("unknown ItemGroup \"{name}\""),
+      "logistics" => Some(ItemGroup::Logistics),
+      "production" => Some(ItemGroup::Production),
+      "intermediate-products" => Some(ItemGroup::IntermediateProducts),
+      "combat" => Some(ItemGroup::Combat),
+      "fluids" => Some(ItemGroup::Fluids),
+      "signals" => Some(ItemGroup::Signals),
+      "enemies" => Some(ItemGroup::Enemies),
+      "environment" => Some(ItemGroup::Environment),
+      "effects" => Some(ItemGroup::Effects),
+      "other" => Some(ItemGroup::Other),
+      _ => None,
     }
   }
+  pub fn from_name(name: &str) -> ItemGroup {
+    ItemGroup::try_from_name(name).unwrap_or_else(|| panic!("unknown ItemGroup \"{name}\""))
+  }
+}
+impl std::fmt::Display for ItemGroup {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for ItemGroup {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<ItemGroup, UnknownPrototypeName> {
+    ItemGroup::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "ItemGroup", name: name.to_owned() })
+  }
 }
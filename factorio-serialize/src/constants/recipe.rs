@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU16;
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU16)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(Recipe)]
 pub enum Recipe {
   Accumulator = 1,
   AdvancedCircuit = 2,
@@ -221,6 +224,224 @@ pub enum Recipe {
   KovarexEnrichmentProcess = 212,
 }
 impl Recipe {
+  pub const ALL: [Recipe; 212] = [
+    Recipe::Accumulator,
+    Recipe::AdvancedCircuit,
+    Recipe::ArithmeticCombinator,
+    Recipe::ArtilleryShell,
+    Recipe::ArtilleryTargetingRemote,
+    Recipe::ArtilleryTurret,
+    Recipe::ArtilleryWagon,
+    Recipe::AssemblingMachine1,
+    Recipe::AssemblingMachine2,
+    Recipe::AssemblingMachine3,
+    Recipe::AtomicBomb,
+    Recipe::AutomationSciencePack,
+    Recipe::Battery,
+    Recipe::BatteryEquipment,
+    Recipe::BatteryMk2Equipment,
+    Recipe::Beacon,
+    Recipe::BeltImmunityEquipment,
+    Recipe::BigElectricPole,
+    Recipe::Boiler,
+    Recipe::BurnerInserter,
+    Recipe::BurnerMiningDrill,
+    Recipe::CannonShell,
+    Recipe::Car,
+    Recipe::CargoWagon,
+    Recipe::Centrifuge,
+    Recipe::ChemicalPlant,
+    Recipe::ChemicalSciencePack,
+    Recipe::CliffExplosives,
+    Recipe::ClusterGrenade,
+    Recipe::CombatShotgun,
+    Recipe::Concrete,
+    Recipe::ConstantCombinator,
+    Recipe::ConstructionRobot,
+    Recipe::CopperCable,
+    Recipe::CopperPlate,
+    Recipe::DeciderCombinator,
+    Recipe::DefenderCapsule,
+    Recipe::DestroyerCapsule,
+    Recipe::DischargeDefenseEquipment,
+    Recipe::DischargeDefenseRemote,
+    Recipe::DistractorCapsule,
+    Recipe::EffectivityModule,
+    Recipe::EffectivityModule2,
+    Recipe::EffectivityModule3,
+    Recipe::ElectricEnergyInterface,
+    Recipe::ElectricEngineUnit,
+    Recipe::ElectricFurnace,
+    Recipe::ElectricMiningDrill,
+    Recipe::ElectronicCircuit,
+    Recipe::EmptyBarrel,
+    Recipe::EnergyShieldEquipment,
+    Recipe::EnergyShieldMk2Equipment,
+    Recipe::EngineUnit,
+    Recipe::ExoskeletonEquipment,
+    Recipe::ExplosiveCannonShell,
+    Recipe::ExplosiveRocket,
+    Recipe::ExplosiveUraniumCannonShell,
+    Recipe::Explosives,
+    Recipe::ExpressLoader,
+    Recipe::ExpressSplitter,
+    Recipe::ExpressTransportBelt,
+    Recipe::ExpressUndergroundBelt,
+    Recipe::FastInserter,
+    Recipe::FastLoader,
+    Recipe::FastSplitter,
+    Recipe::FastTransportBelt,
+    Recipe::FastUndergroundBelt,
+    Recipe::FilterInserter,
+    Recipe::FirearmMagazine,
+    Recipe::Flamethrower,
+    Recipe::FlamethrowerAmmo,
+    Recipe::FlamethrowerTurret,
+    Recipe::FluidWagon,
+    Recipe::FlyingRobotFrame,
+    Recipe::FusionReactorEquipment,
+    Recipe::Gate,
+    Recipe::GreenWire,
+    Recipe::Grenade,
+    Recipe::GunTurret,
+    Recipe::HazardConcrete,
+    Recipe::HeatExchanger,
+    Recipe::HeatPipe,
+    Recipe::HeavyArmor,
+    Recipe::Inserter,
+    Recipe::IronChest,
+    Recipe::IronGearWheel,
+    Recipe::IronPlate,
+    Recipe::IronStick,
+    Recipe::Lab,
+    Recipe::LandMine,
+    Recipe::Landfill,
+    Recipe::LaserTurret,
+    Recipe::LightArmor,
+    Recipe::Loader,
+    Recipe::Locomotive,
+    Recipe::LogisticChestActiveProvider,
+    Recipe::LogisticChestBuffer,
+    Recipe::LogisticChestPassiveProvider,
+    Recipe::LogisticChestRequester,
+    Recipe::LogisticChestStorage,
+    Recipe::LogisticRobot,
+    Recipe::LogisticSciencePack,
+    Recipe::LongHandedInserter,
+    Recipe::LowDensityStructure,
+    Recipe::Lubricant,
+    Recipe::MediumElectricPole,
+    Recipe::MilitarySciencePack,
+    Recipe::ModularArmor,
+    Recipe::NightVisionEquipment,
+    Recipe::NuclearFuel,
+    Recipe::NuclearReactor,
+    Recipe::OffshorePump,
+    Recipe::OilRefinery,
+    Recipe::PersonalLaserDefenseEquipment,
+    Recipe::PersonalRoboportEquipment,
+    Recipe::PersonalRoboportMk2Equipment,
+    Recipe::PiercingRoundsMagazine,
+    Recipe::PiercingShotgunShell,
+    Recipe::Pipe,
+    Recipe::PipeToGround,
+    Recipe::Pistol,
+    Recipe::PlasticBar,
+    Recipe::PoisonCapsule,
+    Recipe::PowerArmor,
+    Recipe::PowerArmorMk2,
+    Recipe::PowerSwitch,
+    Recipe::ProcessingUnit,
+    Recipe::ProductionSciencePack,
+    Recipe::ProductivityModule,
+    Recipe::ProductivityModule2,
+    Recipe::ProductivityModule3,
+    Recipe::ProgrammableSpeaker,
+    Recipe::Pump,
+    Recipe::Pumpjack,
+    Recipe::Radar,
+    Recipe::Rail,
+    Recipe::RailChainSignal,
+    Recipe::RailSignal,
+    Recipe::RedWire,
+    Recipe::RefinedConcrete,
+    Recipe::RefinedHazardConcrete,
+    Recipe::RepairPack,
+    Recipe::Roboport,
+    Recipe::Rocket,
+    Recipe::RocketControlUnit,
+    Recipe::RocketFuel,
+    Recipe::RocketLauncher,
+    Recipe::RocketPart,
+    Recipe::RocketSilo,
+    Recipe::Satellite,
+    Recipe::Shotgun,
+    Recipe::ShotgunShell,
+    Recipe::SlowdownCapsule,
+    Recipe::SmallElectricPole,
+    Recipe::SmallLamp,
+    Recipe::SolarPanel,
+    Recipe::SolarPanelEquipment,
+    Recipe::SpeedModule,
+    Recipe::SpeedModule2,
+    Recipe::SpeedModule3,
+    Recipe::Spidertron,
+    Recipe::SpidertronRemote,
+    Recipe::Splitter,
+    Recipe::StackFilterInserter,
+    Recipe::StackInserter,
+    Recipe::SteamEngine,
+    Recipe::SteamTurbine,
+    Recipe::SteelChest,
+    Recipe::SteelFurnace,
+    Recipe::SteelPlate,
+    Recipe::StoneBrick,
+    Recipe::StoneFurnace,
+    Recipe::StoneWall,
+    Recipe::StorageTank,
+    Recipe::SubmachineGun,
+    Recipe::Substation,
+    Recipe::Sulfur,
+    Recipe::SulfuricAcid,
+    Recipe::Tank,
+    Recipe::TrainStop,
+    Recipe::TransportBelt,
+    Recipe::UndergroundBelt,
+    Recipe::UraniumCannonShell,
+    Recipe::UraniumFuelCell,
+    Recipe::UraniumRoundsMagazine,
+    Recipe::UtilitySciencePack,
+    Recipe::WoodenChest,
+    Recipe::BasicOilProcessing,
+    Recipe::AdvancedOilProcessing,
+    Recipe::CoalLiquefaction,
+    Recipe::FillCrudeOilBarrel,
+    Recipe::FillHeavyOilBarrel,
+    Recipe::FillLightOilBarrel,
+    Recipe::FillLubricantBarrel,
+    Recipe::FillPetroleumGasBarrel,
+    Recipe::FillSulfuricAcidBarrel,
+    Recipe::FillWaterBarrel,
+    Recipe::HeavyOilCracking,
+    Recipe::LightOilCracking,
+    Recipe::SolidFuelFromLightOil,
+    Recipe::SolidFuelFromPetroleumGas,
+    Recipe::SolidFuelFromHeavyOil,
+    Recipe::EmptyCrudeOilBarrel,
+    Recipe::EmptyHeavyOilBarrel,
+    Recipe::EmptyLightOilBarrel,
+    Recipe::EmptyLubricantBarrel,
+    Recipe::EmptyPetroleumGasBarrel,
+    Recipe::EmptySulfuricAcidBarrel,
+    Recipe::EmptyWaterBarrel,
+    Recipe::UraniumProcessing,
+    Recipe::NuclearFuelReprocessing,
+    Recipe::KovarexEnrichmentProcess,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Recipe> {
+    Recipe::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Recipe::Accumulator => "accumulator",
@@ -437,221 +658,235 @@ impl Recipe {
       Recipe::KovarexEnrichmentProcess => "kovarex-enrichment-process",
     }
   }
-  pub fn from_name(name: &str) -> Recipe {
+  pub fn try_from_name(name: &str) -> Option<Recipe> {
     match name {
-      "accumulator" => Recipe::Accumulator,
-      "advanced-circuit" => Recipe::AdvancedCircuit,
-      "arithmetic-combinator" => Recipe::ArithmeticCombinator,
-      "artillery-shell" => Recipe::ArtilleryShell,
-      "artillery-targeting-remote" => Recipe::ArtilleryTargetingRemote,
-      "artillery-turret" => Recipe::ArtilleryTurret,
-      "artillery-wagon" => Recipe::ArtilleryWagon,
-      "assembling-machine-1" => Recipe::AssemblingMachine1,
-      "assembling-machine-2" => Recipe::AssemblingMachine2,
-      "assembling-machine-3" => Recipe::AssemblingMachine3,
-      "atomic-bomb" => Recipe::AtomicBomb,
-      "automation-science-pack" => Recipe::AutomationSciencePack,
-      "battery" => Recipe::Battery,
-      "battery-equipment" => Recipe::BatteryEquipment,
-      "battery-mk2-equipment" => Recipe::BatteryMk2Equipment,
-      "beacon" => Recipe::Beacon,
-      "belt-immunity-equipment" => Recipe::BeltImmunityEquipment,
-      "big-electric-pole" => Recipe::BigElectricPole,
-      "boiler" => Recipe::Boiler,
-      "burner-inserter" => Recipe::BurnerInserter,
-      "burner-mining-drill" => Recipe::BurnerMiningDrill,
-      "cannon-shell" => Recipe::CannonShell,
-      "car" => Recipe::Car,
-      "cargo-wagon" => Recipe::CargoWagon,
-      "centrifuge" => Recipe::Centrifuge,
-      "chemical-plant" => Recipe::ChemicalPlant,
-      "chemical-science-pack" => Recipe::ChemicalSciencePack,
-      "cliff-explosives" => Recipe::CliffExplosives,
-      "cluster-grenade" => Recipe::ClusterGrenade,
-      "combat-shotgun" => Recipe::CombatShotgun,
-      "concrete" => Recipe::Concrete,
-      "constant-combinator" => Recipe::ConstantCombinator,
-      "construction-robot" => Recipe::ConstructionRobot,
-      "copper-cable" => Recipe::CopperCable,
-      "copper-plate" => Recipe::CopperPlate,
-      "decider-combinator" => Recipe::DeciderCombinator,
-      "defender-capsule" => Recipe::DefenderCapsule,
-      "destroyer-capsule" => Recipe::DestroyerCapsule,
-      "discharge-defense-equipment" => Recipe::DischargeDefenseEquipment,
-      "discharge-defense-remote" => Recipe::DischargeDefenseRemote,
-      "distractor-capsule" => Recipe::DistractorCapsule,
-      "effectivity-module" => Recipe::EffectivityModule,
-      "effectivity-module-2" => Recipe::EffectivityModule2,
-      "effectivity-module-3" => Recipe::EffectivityModule3,
-      "electric-energy-interface" => Recipe::ElectricEnergyInterface,
-      "electric-engine-unit" => Recipe::ElectricEngineUnit,
-      "electric-furnace" => Recipe::ElectricFurnace,
-      "electric-mining-drill" => Recipe::ElectricMiningDrill,
-      "electronic-circuit" => Recipe::ElectronicCircuit,
-      "empty-barrel" => Recipe::EmptyBarrel,
-      "energy-shield-equipment" => Recipe::EnergyShieldEquipment,
-      "energy-shield-mk2-equipment" => Recipe::EnergyShieldMk2Equipment,
-      "engine-unit" => Recipe::EngineUnit,
-      "exoskeleton-equipment" => Recipe::ExoskeletonEquipment,
-      "explosive-cannon-shell" => Recipe::ExplosiveCannonShell,
-      "explosive-rocket" => Recipe::ExplosiveRocket,
-      "explosive-uranium-cannon-shell" => Recipe::ExplosiveUraniumCannonShell,
-      "explosives" => Recipe::Explosives,
-      "express-loader" => Recipe::ExpressLoader,
-      "express-splitter" => Recipe::ExpressSplitter,
-      "express-transport-belt" => Recipe::ExpressTransportBelt,
-      "express-underground-belt" => Recipe::ExpressUndergroundBelt,
-      "fast-inserter" => Recipe::FastInserter,
-      "fast-loader" => Recipe::FastLoader,
-      "fast-splitter" => Recipe::FastSplitter,
-      "fast-transport-belt" => Recipe::FastTransportBelt,
-      "fast-underground-belt" => Recipe::FastUndergroundBelt,
-      "filter-inserter" => Recipe::FilterInserter,
-      "firearm-magazine" => Recipe::FirearmMagazine,
-      "flamethrower" => Recipe::Flamethrower,
-      "flamethrower-ammo" => Recipe::FlamethrowerAmmo,
-      "flamethrower-turret" => Recipe::FlamethrowerTurret,
-      "fluid-wagon" => Recipe::FluidWagon,
-      "flying-robot-frame" => Recipe::FlyingRobotFrame,
-      "fusion-reactor-equipment" => Recipe::FusionReactorEquipment,
-      "gate" => Recipe::Gate,
-      "green-wire" => Recipe::GreenWire,
-      "grenade" => Recipe::Grenade,
-      "gun-turret" => Recipe::GunTurret,
-      "hazard-concrete" => Recipe::HazardConcrete,
-      "heat-exchanger" => Recipe::HeatExchanger,
-      "heat-pipe" => Recipe::HeatPipe,
-      "heavy-armor" => Recipe::HeavyArmor,
-      "inserter" => Recipe::Inserter,
-      "iron-chest" => Recipe::IronChest,
-      "iron-gear-wheel" => Recipe::IronGearWheel,
-      "iron-plate" => Recipe::IronPlate,
-      "iron-stick" => Recipe::IronStick,
-      "lab" => Recipe::Lab,
-      "land-mine" => Recipe::LandMine,
-      "landfill" => Recipe::Landfill,
-      "laser-turret" => Recipe::LaserTurret,
-      "light-armor" => Recipe::LightArmor,
-      "loader" => Recipe::Loader,
-      "locomotive" => Recipe::Locomotive,
-      "logistic-chest-active-provider" => Recipe::LogisticChestActiveProvider,
-      "logistic-chest-buffer" => Recipe::LogisticChestBuffer,
-      "logistic-chest-passive-provider" => Recipe::LogisticChestPassiveProvider,
-      "logistic-chest-requester" => Recipe::LogisticChestRequester,
-      "logistic-chest-storage" => Recipe::LogisticChestStorage,
-      "logistic-robot" => Recipe::LogisticRobot,
-      "logistic-science-pack" => Recipe::LogisticSciencePack,
-      "long-handed-inserter" => Recipe::LongHandedInserter,
-      "low-density-structure" => Recipe::LowDensityStructure,
-      "lubricant" => Recipe::Lubricant,
-      "medium-electric-pole" => Recipe::MediumElectricPole,
-      "military-science-pack" => Recipe::MilitarySciencePack,
-      "modular-armor" => Recipe::ModularArmor,
-      "night-vision-equipment" => Recipe::NightVisionEquipment,
-      "nuclear-fuel" => Recipe::NuclearFuel,
-      "nuclear-reactor" => Recipe::NuclearReactor,
-      "offshore-pump" => Recipe::OffshorePump,
-      "oil-refinery" => Recipe::OilRefinery,
-      "personal-laser-defense-equipment" => Recipe::PersonalLaserDefenseEquipment,
-      "personal-roboport-equipment" => Recipe::PersonalRoboportEquipment,
-      "personal-roboport-mk2-equipment" => Recipe::PersonalRoboportMk2Equipment,
-      "piercing-rounds-magazine" => Recipe::PiercingRoundsMagazine,
-      "piercing-shotgun-shell" => Recipe::PiercingShotgunShell,
-      "pipe" => Recipe::Pipe,
-      "pipe-to-ground" => Recipe::PipeToGround,
-      "pistol" => Recipe::Pistol,
-      "plastic-bar" => Recipe::PlasticBar,
-      "poison-capsule" => Recipe::PoisonCapsule,
-      "power-armor" => Recipe::PowerArmor,
-      "power-armor-mk2" => Recipe::PowerArmorMk2,
-      "power-switch" => Recipe::PowerSwitch,
-      "processing-unit" => Recipe::ProcessingUnit,
-      "production-science-pack" => Recipe::ProductionSciencePack,
-      "productivity-module" => Recipe::ProductivityModule,
-      "productivity-module-2" => Recipe::ProductivityModule2,
-      "productivity-module-3" => Recipe::ProductivityModule3,
-      "programmable-speaker" => Recipe::ProgrammableSpeaker,
-      "pump" => Recipe::Pump,
-      "pumpjack" => Recipe::Pumpjack,
-      "radar" => Recipe::Radar,
-      "rail" => Recipe::Rail,
-      "rail-chain-signal" => Recipe::RailChainSignal,
-      "rail-signal" => Recipe::RailSignal,
-      "red-wire" => Recipe::RedWire,
-      "refined-concrete" => Recipe::RefinedConcrete,
-      "refined-hazard-concrete" => Recipe::RefinedHazardConcrete,
-      "repair-pack" => Recipe::RepairPack,
-      "roboport" => Recipe::Roboport,
-      "rocket" => Recipe::Rocket,
-      "rocket-control-unit" => Recipe::RocketControlUnit,
-      "rocket-fuel" => Recipe::RocketFuel,
-      "rocket-launcher" => Recipe::RocketLauncher,
-      "rocket-part" => Recipe::RocketPart,
-      "rocket-silo" => Recipe::RocketSilo,
-      "satellite" => Recipe::Satellite,
-      "shotgun" => Recipe::Shotgun,
-      "shotgun-shell" => Recipe::ShotgunShell,
-      "slowdown-capsule" => Recipe::SlowdownCapsule,
-      "small-electric-pole" => Recipe::SmallElectricPole,
-      "small-lamp" => Recipe::SmallLamp,
-      "solar-panel" => Recipe::SolarPanel,
-      "solar-panel-equipment" => Recipe::SolarPanelEquipment,
-      "speed-module" => Recipe::SpeedModule,
-      "speed-module-2" => Recipe::SpeedModule2,
-      "speed-module-3" => Recipe::SpeedModule3,
-      "spidertron" => Recipe::Spidertron,
-      "spidertron-remote" => Recipe::SpidertronRemote,
-      "splitter" => Recipe::Splitter,
-      "stack-filter-inserter" => Recipe::StackFilterInserter,
-      "stack-inserter" => Recipe::StackInserter,
-      "steam-engine" => Recipe::SteamEngine,
-      "steam-turbine" => Recipe::SteamTurbine,
-      "steel-chest" => Recipe::SteelChest,
-      "steel-furnace" => Recipe::SteelFurnace,
-      "steel-plate" => Recipe::SteelPlate,
-      "stone-brick" => Recipe::StoneBrick,
-      "stone-furnace" => Recipe::StoneFurnace,
-      "stone-wall" => Recipe::StoneWall,
-      "storage-tank" => Recipe::StorageTank,
-      "submachine-gun" => Recipe::SubmachineGun,
-      "substation" => Recipe::Substation,
-      "sulfur" => Recipe::Sulfur,
-      "sulfuric-acid" => Recipe::SulfuricAcid,
-      "tank" => Recipe::Tank,
-      "train-stop" => Recipe::TrainStop,
-      "transport-belt" => Recipe::TransportBelt,
-      "underground-belt" => Recipe::UndergroundBelt,
-      "uranium-cannon-shell" => Recipe::UraniumCannonShell,
-      "uranium-fuel-cell" => Recipe::UraniumFuelCell,
-      "uranium-rounds-magazine" => Recipe::UraniumRoundsMagazine,
-      "utility-science-pack" => Recipe::UtilitySciencePack,
-      "wooden-chest" => Recipe::WoodenChest,
-      "basic-oil-processing" => Recipe::BasicOilProcessing,
-      "advanced-oil-processing" => Recipe::AdvancedOilProcessing,
-      "coal-liquefaction" => Recipe::CoalLiquefaction,
-      "fill-crude-oil-barrel" => Recipe::FillCrudeOilBarrel,
-      "fill-heavy-oil-barrel" => Recipe::FillHeavyOilBarrel,
-      "fill-light-oil-barrel" => Recipe::FillLightOilBarrel,
-      "fill-lubricant-barrel" => Recipe::FillLubricantBarrel,
-      "fill-petroleum-gas-barrel" => Recipe::FillPetroleumGasBarrel,
-      "fill-sulfuric-acid-barrel" => Recipe::FillSulfuricAcidBarrel,
-      "fill-water-barrel" => Recipe::FillWaterBarrel,
-      "heavy-oil-cracking" => Recipe::HeavyOilCracking,
-      "light-oil-cracking" => Recipe::LightOilCracking,
-      "solid-fuel-from-light-oil" => Recipe::SolidFuelFromLightOil,
-      "solid-fuel-from-petroleum-gas" => Recipe::SolidFuelFromPetroleumGas,
-      "solid-fuel-from-heavy-oil" => Recipe::SolidFuelFromHeavyOil,
-      "empty-crude-oil-barrel" => Recipe::EmptyCrudeOilBarrel,
-      "empty-heavy-oil-barrel" => Recipe::EmptyHeavyOilBarrel,
-      "empty-light-oil-barrel" => Recipe::EmptyLightOilBarrel,
-      "empty-lubricant-barrel" => Recipe::EmptyLubricantBarrel,
-      "empty-petroleum-gas-barrel" => Recipe::EmptyPetroleumGasBarrel,
-      "empty-sulfuric-acid-barrel" => Recipe::EmptySulfuricAcidBarrel,
-      "empty-water-barrel" => Recipe::EmptyWaterBarrel,
-      "uranium-processing" => Recipe::UraniumProcessing,
-      "nuclear-fuel-reprocessing" => Recipe::NuclearFuelReprocessing,
-      "kovarex-enrichment-process" => Recipe::KovarexEnrichmentProcess,
-      name => panic!("unknown Recipe \"{name}\""),
+      "accumulator" => Some(Recipe::Accumulator),
+      "advanced-circuit" => Some(Recipe::AdvancedCircuit),
+      "arithmetic-combinator" => Some(Recipe::ArithmeticCombinator),
+      "artillery-shell" => Some(Recipe::ArtilleryShell),
+      "artillery-targeting-remote" => Some(Recipe::ArtilleryTargetingRemote),
+      "artillery-turret" => Some(Recipe::ArtilleryTurret),
+      "artillery-wagon" => Some(Recipe::ArtilleryWagon),
+      "assembling-machine-1" => Some(Recipe::AssemblingMachine1),
+      "assembling-machine-2" => Some(Recipe::AssemblingMachine2),
+      "assembling-machine-3" => Some(Recipe::AssemblingMachine3),
+      "atomic-bomb" => Some(Recipe::AtomicBomb),
+      "automation-science-pack" => Some(Recipe::AutomationSciencePack),
+      "battery" => Some(Recipe::Battery),
+      "battery-equipment" => Some(Recipe::BatteryEquipment),
+      "battery-mk2-equipment" => Some(Recipe::BatteryMk2Equipment),
+      "beacon" => Some(Recipe::Beacon),
+      "belt-immunity-equipment" => Some(Recipe::BeltImmunityEquipment),
+      "big-electric-pole" => Some(Recipe::BigElectricPole),
+      "boiler" => Some(Recipe::Boiler),
+      "burner-inserter" => Some(Recipe::BurnerInserter),
+      "burner-mining-drill" => Some(Recipe::BurnerMiningDrill),
+      "cannon-shell" => Some(Recipe::CannonShell),
+      "car" => Some(Recipe::Car),
+      "cargo-wagon" => Some(Recipe::CargoWagon),
+      "centrifuge" => Some(Recipe::Centrifuge),
+      "chemical-plant" => Some(Recipe::ChemicalPlant),
+      "chemical-science-pack" => Some(Recipe::ChemicalSciencePack),
+      "cliff-explosives" => Some(Recipe::CliffExplosives),
+      "cluster-grenade" => Some(Recipe::ClusterGrenade),
+      "combat-shotgun" => Some(Recipe::CombatShotgun),
+      "concrete" => Some(Recipe::Concrete),
+      "constant-combinator" => Some(Recipe::ConstantCombinator),
+      "construction-robot" => Some(Recipe::ConstructionRobot),
+      "copper-cable" => Some(Recipe::CopperCable),
+      "copper-plate" => Some(Recipe::CopperPlate),
+      "decider-combinator" => Some(Recipe::DeciderCombinator),
+      "defender-capsule" => Some(Recipe::DefenderCapsule),
+      "destroyer-capsule" => Some(Recipe::DestroyerCapsule),
+      "discharge-defense-equipment" => Some(Recipe::DischargeDefenseEquipment),
+      "discharge-defense-remote" => Some(Recipe::DischargeDefenseRemote),
+      "distractor-capsule" => Some(Recipe::DistractorCapsule),
+      "effectivity-module" => Some(Recipe::EffectivityModule),
+      "effectivity-module-2" => Some(Recipe::EffectivityModule2),
+      "effectivity-module-3" => Some(Recipe::EffectivityModule3),
+      "electric-energy-interface" => Some(Recipe::ElectricEnergyInterface),
+      "electric-engine-unit" => Some(Recipe::ElectricEngineUnit),
+      "electric-furnace" => Some(Recipe::ElectricFurnace),
+      "electric-mining-drill" => Some(Recipe::ElectricMiningDrill),
+      "electronic-circuit" => Some(Recipe::ElectronicCircuit),
+      "empty-barrel" => Some(Recipe::EmptyBarrel),
+      "energy-shield-equipment" => Some(Recipe::EnergyShieldEquipment),
+      "energy-shield-mk2-equipment" => Some(Recipe::EnergyShieldMk2Equipment),
+      "engine-unit" => Some(Recipe::EngineUnit),
+      "exoskeleton-equipment" => Some(Recipe::ExoskeletonEquipment),
+      "explosive-cannon-shell" => Some(Recipe::ExplosiveCannonShell),
+      "explosive-rocket" => Some(Recipe::ExplosiveRocket),
+      "explosive-uranium-cannon-shell" => Some(Recipe::ExplosiveUraniumCannonShell),
+      "explosives" => Some(Recipe::Explosives),
+      "express-loader" => Some(Recipe::ExpressLoader),
+      "express-splitter" => Some(Recipe::ExpressSplitter),
+      "express-transport-belt" => Some(Recipe::ExpressTransportBelt),
+      "express-underground-belt" => Some(Recipe::ExpressUndergroundBelt),
+      "fast-inserter" => Some(Recipe::FastInserter),
+      "fast-loader" => Some(Recipe::FastLoader),
+      "fast-splitter" => Some(Recipe::FastSplitter),
+      "fast-transport-belt" => Some(Recipe::FastTransportBelt),
+      "fast-underground-belt" => Some(Recipe::FastUndergroundBelt),
+      "filter-inserter" => Some(Recipe::FilterInserter),
+      "firearm-magazine" => Some(Recipe::FirearmMagazine),
+      "flamethrower" => Some(Recipe::Flamethrower),
+      "flamethrower-ammo" => Some(Recipe::FlamethrowerAmmo),
+      "flamethrower-turret" => Some(Recipe::FlamethrowerTurret),
+      "fluid-wagon" => Some(Recipe::FluidWagon),
+      "flying-robot-frame" => Some(Recipe::FlyingRobotFrame),
+      "fusion-reactor-equipment" => Some(Recipe::FusionReactorEquipment),
+      "gate" => Some(Recipe::Gate),
+      "green-wire" => Some(Recipe::GreenWire),
+      "grenade" => Some(Recipe::Grenade),
+      "gun-turret" => Some(Recipe::GunTurret),
+      "hazard-concrete" => Some(Recipe::HazardConcrete),
+      "heat-exchanger" => Some(Recipe::HeatExchanger),
+      "heat-pipe" => Some(Recipe::HeatPipe),
+      "heavy-armor" => Some(Recipe::HeavyArmor),
+      "inserter" => Some(Recipe::Inserter),
+      "iron-chest" => Some(Recipe::IronChest),
+      "iron-gear-wheel" => Some(Recipe::IronGearWheel),
+      "iron-plate" => Some(Recipe::IronPlate),
+      "iron-stick" => Some(Recipe::IronStick),
+      "lab" => Some(Recipe::Lab),
+      "land-mine" => Some(Recipe::LandMine),
+      "landfill" => Some(Recipe::Landfill),
+      "laser-turret" => Some(Recipe::LaserTurret),
+      "light-armor" => Some(Recipe::LightArmor),
+      "loader" => Some(Recipe::Loader),
+      "locomotive" => Some(Recipe::Locomotive),
+      "logistic-chest-active-provider" => Some(Recipe::LogisticChestActiveProvider),
+      "logistic-chest-buffer" => Some(Recipe::LogisticChestBuffer),
+      "logistic-chest-passive-provider" => Some(Recipe::LogisticChestPassiveProvider),
+      "logistic-chest-requester" => Some(Recipe::LogisticChestRequester),
+      "logistic-chest-storage" => Some(Recipe::LogisticChestStorage),
+      "logistic-robot" => Some(Recipe::LogisticRobot),
+      "logistic-science-pack" => Some(Recipe::LogisticSciencePack),
+      "long-handed-inserter" => Some(Recipe::LongHandedInserter),
+      "low-density-structure" => Some(Recipe::LowDensityStructure),
+      "lubricant" => Some(Recipe::Lubricant),
+      "medium-electric-pole" => Some(Recipe::MediumElectricPole),
+      "military-science-pack" => Some(Recipe::MilitarySciencePack),
+      "modular-armor" => Some(Recipe::ModularArmor),
+      "night-vision-equipment" => Some(Recipe::NightVisionEquipment),
+      "nuclear-fuel" => Some(Recipe::NuclearFuel),
+      "nuclear-reactor" => Some(Recipe::NuclearReactor),
+      "offshore-pump" => Some(Recipe::OffshorePump),
+      "oil-refinery" => Some(Recipe::OilRefinery),
+      "personal-laser-defense-equipment" => Some(Recipe::PersonalLaserDefenseEquipment),
+      "personal-roboport-equipment" => Some(Recipe::PersonalRoboportEquipment),
+      "personal-roboport-mk2-equipment" => Some(Recipe::PersonalRoboportMk2Equipment),
+      "piercing-rounds-magazine" => Some(Recipe::PiercingRoundsMagazine),
+      "piercing-shotgun-shell" => Some(Recipe::PiercingShotgunShell),
+      "pipe" => Some(Recipe::Pipe),
+      "pipe-to-ground" => Some(Recipe::PipeToGround),
+      "pistol" => Some(Recipe::Pistol),
+      "plastic-bar" => Some(Recipe::PlasticBar),
+      "poison-capsule" => Some(Recipe::PoisonCapsule),
+      "power-armor" => Some(Recipe::PowerArmor),
+      "power-armor-mk2" => Some(Recipe::PowerArmorMk2),
+      "power-switch" => Some(Recipe::PowerSwitch),
+      "processing-unit" => Some(Recipe::ProcessingUnit),
+      "production-science-pack" => Some(Recipe::ProductionSciencePack),
+      "productivity-module" => Some(Recipe::ProductivityModule),
+      "productivity-module-2" => Some(Recipe::ProductivityModule2),
+      "productivity-module-3" => Some(Recipe::ProductivityModule3),
+      "programmable-speaker" => Some(Recipe::ProgrammableSpeaker),
+      "pump" => Some(Recipe::Pump),
+      "pumpjack" => Some(Recipe::Pumpjack),
+      "radar" => Some(Recipe::Radar),
+      "rail" => Some(Recipe::Rail),
+      "rail-chain-signal" => Some(Recipe::RailChainSignal),
+      "rail-signal" => Some(Recipe::RailSignal),
+      "red-wire" => Some(Recipe::RedWire),
+      "refined-concrete" => Some(Recipe::RefinedConcrete),
+      "refined-hazard-concrete" => Some(Recipe::RefinedHazardConcrete),
+      "repair-pack" => Some(Recipe::RepairPack),
+      "roboport" => Some(Recipe::Roboport),
+      "rocket" => Some(Recipe::Rocket),
+      "rocket-control-unit" => Some(Recipe::RocketControlUnit),
+      "rocket-fuel" => Some(Recipe::RocketFuel),
+      "rocket-launcher" => Some(Recipe::RocketLauncher),
+      "rocket-part" => Some(Recipe::RocketPart),
+      "rocket-silo" => Some(Recipe::RocketSilo),
+      "satellite" => Some(Recipe::Satellite),
+      "shotgun" => Some(Recipe::Shotgun),
+      "shotgun-shell" => Some(Recipe::ShotgunShell),
+      "slowdown-capsule" => Some(Recipe::SlowdownCapsule),
+      "small-electric-pole" => Some(Recipe::SmallElectricPole),
+      "small-lamp" => Some(Recipe::SmallLamp),
+      "solar-panel" => Some(Recipe::SolarPanel),
+      "solar-panel-equipment" => Some(Recipe::SolarPanelEquipment),
+      "speed-module" => Some(Recipe::SpeedModule),
+      "speed-module-2" => Some(Recipe::SpeedModule2),
+      "speed-module-3" => Some(Recipe::SpeedModule3),
+      "spidertron" => Some(Recipe::Spidertron),
+      "spidertron-remote" => Some(Recipe::SpidertronRemote),
+      "splitter" => Some(Recipe::Splitter),
+      "stack-filter-inserter" => Some(Recipe::StackFilterInserter),
+      "stack-inserter" => Some(Recipe::StackInserter),
+      "steam-engine" => Some(Recipe::SteamEngine),
+      "steam-turbine" => Some(Recipe::SteamTurbine),
+      "steel-chest" => Some(Recipe::SteelChest),
+      "steel-furnace" => Some(Recipe::SteelFurnace),
+      "steel-plate" => Some(Recipe::SteelPlate),
+      "stone-brick" => Some(Recipe::StoneBrick),
+      "stone-furnace" => Some(Recipe::StoneFurnace),
+      "stone-wall" => Some(Recipe::StoneWall),
+      "storage-tank" => Some(Recipe::StorageTank),
+      "submachine-gun" => Some(Recipe::SubmachineGun),
+      "substation" => Some(Recipe::Substation),
+      "sulfur" => Some(Recipe::Sulfur),
+      "sulfuric-acid" => Some(Recipe::SulfuricAcid),
+      "tank" => Some(Recipe::Tank),
+      "train-stop" => Some(Recipe::TrainStop),
+      "transport-belt" => Some(Recipe::TransportBelt),
+      "underground-belt" => Some(Recipe::UndergroundBelt),
+      "uranium-cannon-shell" => Some(Recipe::UraniumCannonShell),
+      "uranium-fuel-cell" => Some(Recipe::UraniumFuelCell),
+      "uranium-rounds-magazine" => Some(Recipe::UraniumRoundsMagazine),
+      "utility-science-pack" => Some(Recipe::UtilitySciencePack),
+      "wooden-chest" => Some(Recipe::WoodenChest),
+      "basic-oil-processing" => Some(Recipe::BasicOilProcessing),
+      "advanced-oil-processing" => Some(Recipe::AdvancedOilProcessing),
+      "coal-liquefaction" => Some(Recipe::CoalLiquefaction),
+      "fill-crude-oil-barrel" => Some(Recipe::FillCrudeOilBarrel),
+      "fill-heavy-oil-barrel" => Some(Recipe::FillHeavyOilBarrel),
+      "fill-light-oil-barrel" => Some(Recipe::FillLightOilBarrel),
+      "fill-lubricant-barrel" => Some(Recipe::FillLubricantBarrel),
+      "fill-petroleum-gas-barrel" => Some(Recipe::FillPetroleumGasBarrel),
+      "fill-sulfuric-acid-barrel" => Some(Recipe::FillSulfuricAcidBarrel),
+      "fill-water-barrel" => Some(Recipe::FillWaterBarrel),
+      "heavy-oil-cracking" => Some(Recipe::HeavyOilCracking),
+      "light-oil-cracking" => Some(Recipe::LightOilCracking),
+      "solid-fuel-from-light-oil" => Some(Recipe::SolidFuelFromLightOil),
+      "solid-fuel-from-petroleum-gas" => Some(Recipe::SolidFuelFromPetroleumGas),
+      "solid-fuel-from-heavy-oil" => Some(Recipe::SolidFuelFromHeavyOil),
+      "empty-crude-oil-barrel" => Some(Recipe::EmptyCrudeOilBarrel),
+      "empty-heavy-oil-barrel" => Some(Recipe::EmptyHeavyOilBarrel),
+      "empty-light-oil-barrel" => Some(Recipe::EmptyLightOilBarrel),
+      "empty-lubricant-barrel" => Some(Recipe::EmptyLubricantBarrel),
+      "empty-petroleum-gas-barrel" => Some(Recipe::EmptyPetroleumGasBarrel),
+      "empty-sulfuric-acid-barrel" => Some(Recipe::EmptySulfuricAcidBarrel),
+      "empty-water-barrel" => Some(Recipe::EmptyWaterBarrel),
+      "uranium-processing" => Some(Recipe::UraniumProcessing),
+      "nuclear-fuel-reprocessing" => Some(Recipe::NuclearFuelReprocessing),
+      "kovarex-enrichment-process" => Some(Recipe::KovarexEnrichmentProcess),
+      _ => None,
     }
   }
+  pub fn from_name(name: &str) -> Recipe {
+    Recipe::try_from_name(name).unwrap_or_else(|| panic!("unknown Recipe \"{name}\""))
+  }
+}
+impl std::fmt::Display for Recipe {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Recipe {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Recipe, UnknownPrototypeName> {
+    Recipe::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Recipe", name: name.to_owned() })
+  }
 }
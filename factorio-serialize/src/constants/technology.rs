@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU16;
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU16)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(Technology)]
 pub enum Technology {
   Automation = 1,
   Automation2 = 2,
@@ -201,6 +204,204 @@ pub enum Technology {
   CliffExplosives = 192,
 }
 impl Technology {
+  pub const ALL: [Technology; 192] = [
+    Technology::Automation,
+    Technology::Automation2,
+    Technology::Automation3,
+    Technology::Electronics,
+    Technology::FastInserter,
+    Technology::AdvancedElectronics,
+    Technology::AdvancedElectronics2,
+    Technology::CircuitNetwork,
+    Technology::Explosives,
+    Technology::Logistics,
+    Technology::Logistics2,
+    Technology::Logistics3,
+    Technology::Optics,
+    Technology::Laser,
+    Technology::SolarEnergy,
+    Technology::GunTurret,
+    Technology::LaserTurret,
+    Technology::StoneWall,
+    Technology::Gate,
+    Technology::Engine,
+    Technology::ElectricEngine,
+    Technology::Lubricant,
+    Technology::Battery,
+    Technology::Landfill,
+    Technology::BrakingForce1,
+    Technology::BrakingForce2,
+    Technology::BrakingForce3,
+    Technology::BrakingForce4,
+    Technology::BrakingForce5,
+    Technology::BrakingForce6,
+    Technology::BrakingForce7,
+    Technology::ChemicalSciencePack,
+    Technology::LogisticSciencePack,
+    Technology::MilitarySciencePack,
+    Technology::ProductionSciencePack,
+    Technology::SpaceSciencePack,
+    Technology::SteelProcessing,
+    Technology::UtilitySciencePack,
+    Technology::AdvancedMaterialProcessing,
+    Technology::SteelAxe,
+    Technology::AdvancedMaterialProcessing2,
+    Technology::Concrete,
+    Technology::ElectricEnergyAccumulators,
+    Technology::ElectricEnergyDistribution1,
+    Technology::ElectricEnergyDistribution2,
+    Technology::Railway,
+    Technology::FluidWagon,
+    Technology::AutomatedRailTransportation,
+    Technology::RailSignals,
+    Technology::Robotics,
+    Technology::ConstructionRobotics,
+    Technology::LogisticRobotics,
+    Technology::LogisticSystem,
+    Technology::PersonalRoboportEquipment,
+    Technology::PersonalRoboportMk2Equipment,
+    Technology::WorkerRobotsSpeed1,
+    Technology::WorkerRobotsSpeed2,
+    Technology::WorkerRobotsSpeed3,
+    Technology::WorkerRobotsSpeed4,
+    Technology::MiningProductivity1,
+    Technology::MiningProductivity2,
+    Technology::MiningProductivity3,
+    Technology::MiningProductivity4,
+    Technology::WorkerRobotsSpeed5,
+    Technology::WorkerRobotsSpeed6,
+    Technology::WorkerRobotsStorage1,
+    Technology::WorkerRobotsStorage2,
+    Technology::WorkerRobotsStorage3,
+    Technology::Toolbelt,
+    Technology::ResearchSpeed1,
+    Technology::ResearchSpeed2,
+    Technology::ResearchSpeed3,
+    Technology::ResearchSpeed4,
+    Technology::ResearchSpeed5,
+    Technology::ResearchSpeed6,
+    Technology::StackInserter,
+    Technology::InserterCapacityBonus1,
+    Technology::InserterCapacityBonus2,
+    Technology::InserterCapacityBonus3,
+    Technology::InserterCapacityBonus4,
+    Technology::InserterCapacityBonus5,
+    Technology::InserterCapacityBonus6,
+    Technology::InserterCapacityBonus7,
+    Technology::OilProcessing,
+    Technology::FluidHandling,
+    Technology::AdvancedOilProcessing,
+    Technology::CoalLiquefaction,
+    Technology::SulfurProcessing,
+    Technology::Plastics,
+    Technology::Artillery,
+    Technology::Spidertron,
+    Technology::Military,
+    Technology::AtomicBomb,
+    Technology::Military2,
+    Technology::UraniumAmmo,
+    Technology::Military3,
+    Technology::Military4,
+    Technology::Automobilism,
+    Technology::Flammables,
+    Technology::Flamethrower,
+    Technology::Tank,
+    Technology::LandMine,
+    Technology::Rocketry,
+    Technology::ExplosiveRocketry,
+    Technology::EnergyWeaponsDamage1,
+    Technology::RefinedFlammables1,
+    Technology::StrongerExplosives1,
+    Technology::WeaponShootingSpeed1,
+    Technology::ArtilleryShellRange1,
+    Technology::ArtilleryShellSpeed1,
+    Technology::PhysicalProjectileDamage1,
+    Technology::EnergyWeaponsDamage2,
+    Technology::PhysicalProjectileDamage2,
+    Technology::RefinedFlammables2,
+    Technology::StrongerExplosives2,
+    Technology::WeaponShootingSpeed2,
+    Technology::EnergyWeaponsDamage3,
+    Technology::PhysicalProjectileDamage3,
+    Technology::RefinedFlammables3,
+    Technology::StrongerExplosives3,
+    Technology::WeaponShootingSpeed3,
+    Technology::EnergyWeaponsDamage4,
+    Technology::PhysicalProjectileDamage4,
+    Technology::RefinedFlammables4,
+    Technology::StrongerExplosives4,
+    Technology::WeaponShootingSpeed4,
+    Technology::EnergyWeaponsDamage5,
+    Technology::PhysicalProjectileDamage5,
+    Technology::RefinedFlammables5,
+    Technology::StrongerExplosives5,
+    Technology::WeaponShootingSpeed5,
+    Technology::EnergyWeaponsDamage6,
+    Technology::EnergyWeaponsDamage7,
+    Technology::PhysicalProjectileDamage6,
+    Technology::PhysicalProjectileDamage7,
+    Technology::RefinedFlammables6,
+    Technology::RefinedFlammables7,
+    Technology::StrongerExplosives6,
+    Technology::StrongerExplosives7,
+    Technology::WeaponShootingSpeed6,
+    Technology::LaserShootingSpeed1,
+    Technology::LaserShootingSpeed2,
+    Technology::LaserShootingSpeed3,
+    Technology::LaserShootingSpeed4,
+    Technology::LaserShootingSpeed5,
+    Technology::LaserShootingSpeed6,
+    Technology::LaserShootingSpeed7,
+    Technology::Defender,
+    Technology::Distractor,
+    Technology::Destroyer,
+    Technology::FollowerRobotCount1,
+    Technology::FollowerRobotCount2,
+    Technology::FollowerRobotCount3,
+    Technology::FollowerRobotCount4,
+    Technology::FollowerRobotCount5,
+    Technology::FollowerRobotCount6,
+    Technology::FollowerRobotCount7,
+    Technology::KovarexEnrichmentProcess,
+    Technology::NuclearFuelReprocessing,
+    Technology::NuclearPower,
+    Technology::UraniumProcessing,
+    Technology::HeavyArmor,
+    Technology::ModularArmor,
+    Technology::PowerArmor,
+    Technology::PowerArmorMk2,
+    Technology::EnergyShieldEquipment,
+    Technology::EnergyShieldMk2Equipment,
+    Technology::NightVisionEquipment,
+    Technology::BeltImmunityEquipment,
+    Technology::ExoskeletonEquipment,
+    Technology::BatteryEquipment,
+    Technology::BatteryMk2Equipment,
+    Technology::SolarPanelEquipment,
+    Technology::FusionReactorEquipment,
+    Technology::PersonalLaserDefenseEquipment,
+    Technology::DischargeDefenseEquipment,
+    Technology::Modules,
+    Technology::SpeedModule,
+    Technology::SpeedModule2,
+    Technology::SpeedModule3,
+    Technology::ProductivityModule,
+    Technology::ProductivityModule2,
+    Technology::ProductivityModule3,
+    Technology::EffectivityModule,
+    Technology::EffectivityModule2,
+    Technology::EffectivityModule3,
+    Technology::EffectTransmission,
+    Technology::LowDensityStructure,
+    Technology::RocketControlUnit,
+    Technology::RocketFuel,
+    Technology::RocketSilo,
+    Technology::CliffExplosives,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Technology> {
+    Technology::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Technology::Automation => "automation",
@@ -397,201 +598,215 @@ impl Technology {
       Technology::CliffExplosives => "cliff-explosives",
     }
   }
-  pub fn from_name(name: &str) -> Technology {
+  pub fn try_from_name(name: &str) -> Option<Technology> {
     match name {
-      "automation" => Technology::Automation,
-      "automation-2" => Technology::Automation2,
-      "automation-3" => Technology::Automation3,
-      "electronics" => Technology::Electronics,
-      "fast-inserter" => Technology::FastInserter,
-      "advanced-electronics" => Technology::AdvancedElectronics,
-      "advanced-electronics-2" => Technology::AdvancedElectronics2,
-      "circuit-network" => Technology::CircuitNetwork,
-      "explosives" => Technology::Explosives,
-      "logistics" => Technology::Logistics,
-      "logistics-2" => Technology::Logistics2,
-      "logistics-3" => Technology::Logistics3,
-      "optics" => Technology::Optics,
-      "laser" => Technology::Laser,
-      "solar-energy" => Technology::SolarEnergy,
-      "gun-turret" => Technology::GunTurret,
-      "laser-turret" => Technology::LaserTurret,
-      "stone-wall" => Technology::StoneWall,
-      "gate" => Technology::Gate,
-      "engine" => Technology::Engine,
-      "electric-engine" => Technology::ElectricEngine,
-      "lubricant" => Technology::Lubricant,
-      "battery" => Technology::Battery,
-      "landfill" => Technology::Landfill,
-      "braking-force-1" => Technology::BrakingForce1,
-      "braking-force-2" => Technology::BrakingForce2,
-      "braking-force-3" => Technology::BrakingForce3,
-      "braking-force-4" => Technology::BrakingForce4,
-      "braking-force-5" => Technology::BrakingForce5,
-      "braking-force-6" => Technology::BrakingForce6,
-      "braking-force-7" => Technology::BrakingForce7,
-      "chemical-science-pack" => Technology::ChemicalSciencePack,
-      "logistic-science-pack" => Technology::LogisticSciencePack,
-      "military-science-pack" => Technology::MilitarySciencePack,
-      "production-science-pack" => Technology::ProductionSciencePack,
-      "space-science-pack" => Technology::SpaceSciencePack,
-      "steel-processing" => Technology::SteelProcessing,
-      "utility-science-pack" => Technology::UtilitySciencePack,
-      "advanced-material-processing" => Technology::AdvancedMaterialProcessing,
-      "steel-axe" => Technology::SteelAxe,
-      "advanced-material-processing-2" => Technology::AdvancedMaterialProcessing2,
-      "concrete" => Technology::Concrete,
-      "electric-energy-accumulators" => Technology::ElectricEnergyAccumulators,
-      "electric-energy-distribution-1" => Technology::ElectricEnergyDistribution1,
-      "electric-energy-distribution-2" => Technology::ElectricEnergyDistribution2,
-      "railway" => Technology::Railway,
-      "fluid-wagon" => Technology::FluidWagon,
-      "automated-rail-transportation" => Technology::AutomatedRailTransportation,
-      "rail-signals" => Technology::RailSignals,
-      "robotics" => Technology::Robotics,
-      "construction-robotics" => Technology::ConstructionRobotics,
-      "logistic-robotics" => Technology::LogisticRobotics,
-      "logistic-system" => Technology::LogisticSystem,
-      "personal-roboport-equipment" => Technology::PersonalRoboportEquipment,
-      "personal-roboport-mk2-equipment" => Technology::PersonalRoboportMk2Equipment,
-      "worker-robots-speed-1" => Technology::WorkerRobotsSpeed1,
-      "worker-robots-speed-2" => Technology::WorkerRobotsSpeed2,
-      "worker-robots-speed-3" => Technology::WorkerRobotsSpeed3,
-      "worker-robots-speed-4" => Technology::WorkerRobotsSpeed4,
-      "mining-productivity-1" => Technology::MiningProductivity1,
-      "mining-productivity-2" => Technology::MiningProductivity2,
-      "mining-productivity-3" => Technology::MiningProductivity3,
-      "mining-productivity-4" => Technology::MiningProductivity4,
-      "worker-robots-speed-5" => Technology::WorkerRobotsSpeed5,
-      "worker-robots-speed-6" => Technology::WorkerRobotsSpeed6,
-      "worker-robots-storage-1" => Technology::WorkerRobotsStorage1,
-      "worker-robots-storage-2" => Technology::WorkerRobotsStorage2,
-      "worker-robots-storage-3" => Technology::WorkerRobotsStorage3,
-      "toolbelt" => Technology::Toolbelt,
-      "research-speed-1" => Technology::ResearchSpeed1,
-      "research-speed-2" => Technology::ResearchSpeed2,
-      "research-speed-3" => Technology::ResearchSpeed3,
-      "research-speed-4" => Technology::ResearchSpeed4,
-      "research-speed-5" => Technology::ResearchSpeed5,
-      "research-speed-6" => Technology::ResearchSpeed6,
-      "stack-inserter" => Technology::StackInserter,
-      "inserter-capacity-bonus-1" => Technology::InserterCapacityBonus1,
-      "inserter-capacity-bonus-2" => Technology::InserterCapacityBonus2,
-      "inserter-capacity-bonus-3" => Technology::InserterCapacityBonus3,
-      "inserter-capacity-bonus-4" => Technology::InserterCapacityBonus4,
-      "inserter-capacity-bonus-5" => Technology::InserterCapacityBonus5,
-      "inserter-capacity-bonus-6" => Technology::InserterCapacityBonus6,
-      "inserter-capacity-bonus-7" => Technology::InserterCapacityBonus7,
-      "oil-processing" => Technology::OilProcessing,
-      "fluid-handling" => Technology::FluidHandling,
-      "advanced-oil-processing" => Technology::AdvancedOilProcessing,
-      "coal-liquefaction" => Technology::CoalLiquefaction,
-      "sulfur-processing" => Technology::SulfurProcessing,
-      "plastics" => Technology::Plastics,
-      "artillery" => Technology::Artillery,
-      "spidertron" => Technology::Spidertron,
-      "military" => Technology::Military,
-      "atomic-bomb" => Technology::AtomicBomb,
-      "military-2" => Technology::Military2,
-      "uranium-ammo" => Technology::UraniumAmmo,
-      "military-3" => Technology::Military3,
-      "military-4" => Technology::Military4,
-      "automobilism" => Technology::Automobilism,
-      "flammables" => Technology::Flammables,
-      "flamethrower" => Technology::Flamethrower,
-      "tank" => Technology::Tank,
-      "land-mine" => Technology::LandMine,
-      "rocketry" => Technology::Rocketry,
-      "explosive-rocketry" => Technology::ExplosiveRocketry,
-      "energy-weapons-damage-1" => Technology::EnergyWeaponsDamage1,
-      "refined-flammables-1" => Technology::RefinedFlammables1,
-      "stronger-explosives-1" => Technology::StrongerExplosives1,
-      "weapon-shooting-speed-1" => Technology::WeaponShootingSpeed1,
-      "artillery-shell-range-1" => Technology::ArtilleryShellRange1,
-      "artillery-shell-speed-1" => Technology::ArtilleryShellSpeed1,
-      "physical-projectile-damage-1" => Technology::PhysicalProjectileDamage1,
-      "energy-weapons-damage-2" => Technology::EnergyWeaponsDamage2,
-      "physical-projectile-damage-2" => Technology::PhysicalProjectileDamage2,
-      "refined-flammables-2" => Technology::RefinedFlammables2,
-      "stronger-explosives-2" => Technology::StrongerExplosives2,
-      "weapon-shooting-speed-2" => Technology::WeaponShootingSpeed2,
-      "energy-weapons-damage-3" => Technology::EnergyWeaponsDamage3,
-      "physical-projectile-damage-3" => Technology::PhysicalProjectileDamage3,
-      "refined-flammables-3" => Technology::RefinedFlammables3,
-      "stronger-explosives-3" => Technology::StrongerExplosives3,
-      "weapon-shooting-speed-3" => Technology::WeaponShootingSpeed3,
-      "energy-weapons-damage-4" => Technology::EnergyWeaponsDamage4,
-      "physical-projectile-damage-4" => Technology::PhysicalProjectileDamage4,
-      "refined-flammables-4" => Technology::RefinedFlammables4,
-      "stronger-explosives-4" => Technology::StrongerExplosives4,
-      "weapon-shooting-speed-4" => Technology::WeaponShootingSpeed4,
-      "energy-weapons-damage-5" => Technology::EnergyWeaponsDamage5,
-      "physical-projectile-damage-5" => Technology::PhysicalProjectileDamage5,
-      "refined-flammables-5" => Technology::RefinedFlammables5,
-      "stronger-explosives-5" => Technology::StrongerExplosives5,
-      "weapon-shooting-speed-5" => Technology::WeaponShootingSpeed5,
-      "energy-weapons-damage-6" => Technology::EnergyWeaponsDamage6,
-      "energy-weapons-damage-7" => Technology::EnergyWeaponsDamage7,
-      "physical-projectile-damage-6" => Technology::PhysicalProjectileDamage6,
-      "physical-projectile-damage-7" => Technology::PhysicalProjectileDamage7,
-      "refined-flammables-6" => Technology::RefinedFlammables6,
-      "refined-flammables-7" => Technology::RefinedFlammables7,
-      "stronger-explosives-6" => Technology::StrongerExplosives6,
-      "stronger-explosives-7" => Technology::StrongerExplosives7,
-      "weapon-shooting-speed-6" => Technology::WeaponShootingSpeed6,
-      "laser-shooting-speed-1" => Technology::LaserShootingSpeed1,
-      "laser-shooting-speed-2" => Technology::LaserShootingSpeed2,
-      "laser-shooting-speed-3" => Technology::LaserShootingSpeed3,
-      "laser-shooting-speed-4" => Technology::LaserShootingSpeed4,
-      "laser-shooting-speed-5" => Technology::LaserShootingSpeed5,
-      "laser-shooting-speed-6" => Technology::LaserShootingSpeed6,
-      "laser-shooting-speed-7" => Technology::LaserShootingSpeed7,
-      "defender" => Technology::Defender,
-      "distractor" => Technology::Distractor,
-      "destroyer" => Technology::Destroyer,
-      "follower-robot-count-1" => Technology::FollowerRobotCount1,
-      "follower-robot-count-2" => Technology::FollowerRobotCount2,
-      "follower-robot-count-3" => Technology::FollowerRobotCount3,
-      "follower-robot-count-4" => Technology::FollowerRobotCount4,
-      "follower-robot-count-5" => Technology::FollowerRobotCount5,
-      "follower-robot-count-6" => Technology::FollowerRobotCount6,
-      "follower-robot-count-7" => Technology::FollowerRobotCount7,
-      "kovarex-enrichment-process" => Technology::KovarexEnrichmentProcess,
-      "nuclear-fuel-reprocessing" => Technology::NuclearFuelReprocessing,
-      "nuclear-power" => Technology::NuclearPower,
-      "uranium-processing" => Technology::UraniumProcessing,
-      "heavy-armor" => Technology::HeavyArmor,
-      "modular-armor" => Technology::ModularArmor,
-      "power-armor" => Technology::PowerArmor,
-      "power-armor-mk2" => Technology::PowerArmorMk2,
-      "energy-shield-equipment" => Technology::EnergyShieldEquipment,
-      "energy-shield-mk2-equipment" => Technology::EnergyShieldMk2Equipment,
-      "night-vision-equipment" => Technology::NightVisionEquipment,
-      "belt-immunity-equipment" => Technology::BeltImmunityEquipment,
-      "exoskeleton-equipment" => Technology::ExoskeletonEquipment,
-      "battery-equipment" => Technology::BatteryEquipment,
-      "battery-mk2-equipment" => Technology::BatteryMk2Equipment,
-      "solar-panel-equipment" => Technology::SolarPanelEquipment,
-      "fusion-reactor-equipment" => Technology::FusionReactorEquipment,
-      "personal-laser-defense-equipment" => Technology::PersonalLaserDefenseEquipment,
-      "discharge-defense-equipment" => Technology::DischargeDefenseEquipment,
-      "modules" => Technology::Modules,
-      "speed-module" => Technology::SpeedModule,
-      "speed-module-2" => Technology::SpeedModule2,
-      "speed-module-3" => Technology::SpeedModule3,
-      "productivity-module" => Technology::ProductivityModule,
-      "productivity-module-2" => Technology::ProductivityModule2,
-      "productivity-module-3" => Technology::ProductivityModule3,
-      "effectivity-module" => Technology::EffectivityModule,
-      "effectivity-module-2" => Technology::EffectivityModule2,
-      "effectivity-module-3" => Technology::EffectivityModule3,
-      "effect-transmission" => Technology::EffectTransmission,
-      "low-density-structure" => Technology::LowDensityStructure,
-      "rocket-control-unit" => Technology::RocketControlUnit,
-      "rocket-fuel" => Technology::RocketFuel,
-      "rocket-silo" => Technology::RocketSilo,
-      "cliff-explosives" => Technology::CliffExplosives,
-      name => panic!("unknown Technology \"{name}\""),
+      "automation" => Some(Technology::Automation),
+      "automation-2" => Some(Technology::Automation2),
+      "automation-3" => Some(Technology::Automation3),
+      "electronics" => Some(Technology::Electronics),
+      "fast-inserter" => Some(Technology::FastInserter),
+      "advanced-electronics" => Some(Technology::AdvancedElectronics),
+      "advanced-electronics-2" => Some(Technology::AdvancedElectronics2),
+      "circuit-network" => Some(Technology::CircuitNetwork),
+      "explosives" => Some(Technology::Explosives),
+      "logistics" => Some(Technology::Logistics),
+      "logistics-2" => Some(Technology::Logistics2),
+      "logistics-3" => Some(Technology::Logistics3),
+      "optics" => Some(Technology::Optics),
+      "laser" => Some(Technology::Laser),
+      "solar-energy" => Some(Technology::SolarEnergy),
+      "gun-turret" => Some(Technology::GunTurret),
+      "laser-turret" => Some(Technology::LaserTurret),
+      "stone-wall" => Some(Technology::StoneWall),
+      "gate" => Some(Technology::Gate),
+      "engine" => Some(Technology::Engine),
+      "electric-engine" => Some(Technology::ElectricEngine),
+      "lubricant" => Some(Technology::Lubricant),
+      "battery" => Some(Technology::Battery),
+      "landfill" => Some(Technology::Landfill),
+      "braking-force-1" => Some(Technology::BrakingForce1),
+      "braking-force-2" => Some(Technology::BrakingForce2),
+      "braking-force-3" => Some(Technology::BrakingForce3),
+      "braking-force-4" => Some(Technology::BrakingForce4),
+      "braking-force-5" => Some(Technology::BrakingForce5),
+      "braking-force-6" => Some(Technology::BrakingForce6),
+      "braking-force-7" => Some(Technology::BrakingForce7),
+      "chemical-science-pack" => Some(Technology::ChemicalSciencePack),
+      "logistic-science-pack" => Some(Technology::LogisticSciencePack),
+      "military-science-pack" => Some(Technology::MilitarySciencePack),
+      "production-science-pack" => Some(Technology::ProductionSciencePack),
+      "space-science-pack" => Some(Technology::SpaceSciencePack),
+      "steel-processing" => Some(Technology::SteelProcessing),
+      "utility-science-pack" => Some(Technology::UtilitySciencePack),
+      "advanced-material-processing" => Some(Technology::AdvancedMaterialProcessing),
+      "steel-axe" => Some(Technology::SteelAxe),
+      "advanced-material-processing-2" => Some(Technology::AdvancedMaterialProcessing2),
+      "concrete" => Some(Technology::Concrete),
+      "electric-energy-accumulators" => Some(Technology::ElectricEnergyAccumulators),
+      "electric-energy-distribution-1" => Some(Technology::ElectricEnergyDistribution1),
+      "electric-energy-distribution-2" => Some(Technology::ElectricEnergyDistribution2),
+      "railway" => Some(Technology::Railway),
+      "fluid-wagon" => Some(Technology::FluidWagon),
+      "automated-rail-transportation" => Some(Technology::AutomatedRailTransportation),
+      "rail-signals" => Some(Technology::RailSignals),
+      "robotics" => Some(Technology::Robotics),
+      "construction-robotics" => Some(Technology::ConstructionRobotics),
+      "logistic-robotics" => Some(Technology::LogisticRobotics),
+      "logistic-system" => Some(Technology::LogisticSystem),
+      "personal-roboport-equipment" => Some(Technology::PersonalRoboportEquipment),
+      "personal-roboport-mk2-equipment" => Some(Technology::PersonalRoboportMk2Equipment),
+      "worker-robots-speed-1" => Some(Technology::WorkerRobotsSpeed1),
+      "worker-robots-speed-2" => Some(Technology::WorkerRobotsSpeed2),
+      "worker-robots-speed-3" => Some(Technology::WorkerRobotsSpeed3),
+      "worker-robots-speed-4" => Some(Technology::WorkerRobotsSpeed4),
+      "mining-productivity-1" => Some(Technology::MiningProductivity1),
+      "mining-productivity-2" => Some(Technology::MiningProductivity2),
+      "mining-productivity-3" => Some(Technology::MiningProductivity3),
+      "mining-productivity-4" => Some(Technology::MiningProductivity4),
+      "worker-robots-speed-5" => Some(Technology::WorkerRobotsSpeed5),
+      "worker-robots-speed-6" => Some(Technology::WorkerRobotsSpeed6),
+      "worker-robots-storage-1" => Some(Technology::WorkerRobotsStorage1),
+      "worker-robots-storage-2" => Some(Technology::WorkerRobotsStorage2),
+      "worker-robots-storage-3" => Some(Technology::WorkerRobotsStorage3),
+      "toolbelt" => Some(Technology::Toolbelt),
+      "research-speed-1" => Some(Technology::ResearchSpeed1),
+      "research-speed-2" => Some(Technology::ResearchSpeed2),
+      "research-speed-3" => Some(Technology::ResearchSpeed3),
+      "research-speed-4" => Some(Technology::ResearchSpeed4),
+      "research-speed-5" => Some(Technology::ResearchSpeed5),
+      "research-speed-6" => Some(Technology::ResearchSpeed6),
+      "stack-inserter" => Some(Technology::StackInserter),
+      "inserter-capacity-bonus-1" => Some(Technology::InserterCapacityBonus1),
+      "inserter-capacity-bonus-2" => Some(Technology::InserterCapacityBonus2),
+      "inserter-capacity-bonus-3" => Some(Technology::InserterCapacityBonus3),
+      "inserter-capacity-bonus-4" => Some(Technology::InserterCapacityBonus4),
+      "inserter-capacity-bonus-5" => Some(Technology::InserterCapacityBonus5),
+      "inserter-capacity-bonus-6" => Some(Technology::InserterCapacityBonus6),
+      "inserter-capacity-bonus-7" => Some(Technology::InserterCapacityBonus7),
+      "oil-processing" => Some(Technology::OilProcessing),
+      "fluid-handling" => Some(Technology::FluidHandling),
+      "advanced-oil-processing" => Some(Technology::AdvancedOilProcessing),
+      "coal-liquefaction" => Some(Technology::CoalLiquefaction),
+      "sulfur-processing" => Some(Technology::SulfurProcessing),
+      "plastics" => Some(Technology::Plastics),
+      "artillery" => Some(Technology::Artillery),
+      "spidertron" => Some(Technology::Spidertron),
+      "military" => Some(Technology::Military),
+      "atomic-bomb" => Some(Technology::AtomicBomb),
+      "military-2" => Some(Technology::Military2),
+      "uranium-ammo" => Some(Technology::UraniumAmmo),
+      "military-3" => Some(Technology::Military3),
+      "military-4" => Some(Technology::Military4),
+      "automobilism" => Some(Technology::Automobilism),
+      "flammables" => Some(Technology::Flammables),
+      "flamethrower" => Some(Technology::Flamethrower),
+      "tank" => Some(Technology::Tank),
+      "land-mine" => Some(Technology::LandMine),
+      "rocketry" => Some(Technology::Rocketry),
+      "explosive-rocketry" => Some(Technology::ExplosiveRocketry),
+      "energy-weapons-damage-1" => Some(Technology::EnergyWeaponsDamage1),
+      "refined-flammables-1" => Some(Technology::RefinedFlammables1),
+      "stronger-explosives-1" => Some(Technology::StrongerExplosives1),
+      "weapon-shooting-speed-1" => Some(Technology::WeaponShootingSpeed1),
+      "artillery-shell-range-1" => Some(Technology::ArtilleryShellRange1),
+      "artillery-shell-speed-1" => Some(Technology::ArtilleryShellSpeed1),
+      "physical-projectile-damage-1" => Some(Technology::PhysicalProjectileDamage1),
+      "energy-weapons-damage-2" => Some(Technology::EnergyWeaponsDamage2),
+      "physical-projectile-damage-2" => Some(Technology::PhysicalProjectileDamage2),
+      "refined-flammables-2" => Some(Technology::RefinedFlammables2),
+      "stronger-explosives-2" => Some(Technology::StrongerExplosives2),
+      "weapon-shooting-speed-2" => Some(Technology::WeaponShootingSpeed2),
+      "energy-weapons-damage-3" => Some(Technology::EnergyWeaponsDamage3),
+      "physical-projectile-damage-3" => Some(Technology::PhysicalProjectileDamage3),
+      "refined-flammables-3" => Some(Technology::RefinedFlammables3),
+      "stronger-explosives-3" => Some(Technology::StrongerExplosives3),
+      "weapon-shooting-speed-3" => Some(Technology::WeaponShootingSpeed3),
+      "energy-weapons-damage-4" => Some(Technology::EnergyWeaponsDamage4),
+      "physical-projectile-damage-4" => Some(Technology::PhysicalProjectileDamage4),
+      "refined-flammables-4" => Some(Technology::RefinedFlammables4),
+      "stronger-explosives-4" => Some(Technology::StrongerExplosives4),
+      "weapon-shooting-speed-4" => Some(Technology::WeaponShootingSpeed4),
+      "energy-weapons-damage-5" => Some(Technology::EnergyWeaponsDamage5),
+      "physical-projectile-damage-5" => Some(Technology::PhysicalProjectileDamage5),
+      "refined-flammables-5" => Some(Technology::RefinedFlammables5),
+      "stronger-explosives-5" => Some(Technology::StrongerExplosives5),
+      "weapon-shooting-speed-5" => Some(Technology::WeaponShootingSpeed5),
+      "energy-weapons-damage-6" => Some(Technology::EnergyWeaponsDamage6),
+      "energy-weapons-damage-7" => Some(Technology::EnergyWeaponsDamage7),
+      "physical-projectile-damage-6" => Some(Technology::PhysicalProjectileDamage6),
+      "physical-projectile-damage-7" => Some(Technology::PhysicalProjectileDamage7),
+      "refined-flammables-6" => Some(Technology::RefinedFlammables6),
+      "refined-flammables-7" => Some(Technology::RefinedFlammables7),
+      "stronger-explosives-6" => Some(Technology::StrongerExplosives6),
+      "stronger-explosives-7" => Some(Technology::StrongerExplosives7),
+      "weapon-shooting-speed-6" => Some(Technology::WeaponShootingSpeed6),
+      "laser-shooting-speed-1" => Some(Technology::LaserShootingSpeed1),
+      "laser-shooting-speed-2" => Some(Technology::LaserShootingSpeed2),
+      "laser-shooting-speed-3" => Some(Technology::LaserShootingSpeed3),
+      "laser-shooting-speed-4" => Some(Technology::LaserShootingSpeed4),
+      "laser-shooting-speed-5" => Some(Technology::LaserShootingSpeed5),
+      "laser-shooting-speed-6" => Some(Technology::LaserShootingSpeed6),
+      "laser-shooting-speed-7" => Some(Technology::LaserShootingSpeed7),
+      "defender" => Some(Technology::Defender),
+      "distractor" => Some(Technology::Distractor),
+      "destroyer" => Some(Technology::Destroyer),
+      "follower-robot-count-1" => Some(Technology::FollowerRobotCount1),
+      "follower-robot-count-2" => Some(Technology::FollowerRobotCount2),
+      "follower-robot-count-3" => Some(Technology::FollowerRobotCount3),
+      "follower-robot-count-4" => Some(Technology::FollowerRobotCount4),
+      "follower-robot-count-5" => Some(Technology::FollowerRobotCount5),
+      "follower-robot-count-6" => Some(Technology::FollowerRobotCount6),
+      "follower-robot-count-7" => Some(Technology::FollowerRobotCount7),
+      "kovarex-enrichment-process" => Some(Technology::KovarexEnrichmentProcess),
+      "nuclear-fuel-reprocessing" => Some(Technology::NuclearFuelReprocessing),
+      "nuclear-power" => Some(Technology::NuclearPower),
+      "uranium-processing" => Some(Technology::UraniumProcessing),
+      "heavy-armor" => Some(Technology::HeavyArmor),
+      "modular-armor" => Some(Technology::ModularArmor),
+      "power-armor" => Some(Technology::PowerArmor),
+      "power-armor-mk2" => Some(Technology::PowerArmorMk2),
+      "energy-shield-equipment" => Some(Technology::EnergyShieldEquipment),
+      "energy-shield-mk2-equipment" => Some(Technology::EnergyShieldMk2Equipment),
+      "night-vision-equipment" => Some(Technology::NightVisionEquipment),
+      "belt-immunity-equipment" => Some(Technology::BeltImmunityEquipment),
+      "exoskeleton-equipment" => Some(Technology::ExoskeletonEquipment),
+      "battery-equipment" => Some(Technology::BatteryEquipment),
+      "battery-mk2-equipment" => Some(Technology::BatteryMk2Equipment),
+      "solar-panel-equipment" => Some(Technology::SolarPanelEquipment),
+      "fusion-reactor-equipment" => Some(Technology::FusionReactorEquipment),
+      "personal-laser-defense-equipment" => Some(Technology::PersonalLaserDefenseEquipment),
+      "discharge-defense-equipment" => Some(Technology::DischargeDefenseEquipment),
+      "modules" => Some(Technology::Modules),
+      "speed-module" => Some(Technology::SpeedModule),
+      "speed-module-2" => Some(Technology::SpeedModule2),
+      "speed-module-3" => Some(Technology::SpeedModule3),
+      "productivity-module" => Some(Technology::ProductivityModule),
+      "productivity-module-2" => Some(Technology::ProductivityModule2),
+      "productivity-module-3" => Some(Technology::ProductivityModule3),
+      "effectivity-module" => Some(Technology::EffectivityModule),
+      "effectivity-module-2" => Some(Technology::EffectivityModule2),
+      "effectivity-module-3" => Some(Technology::EffectivityModule3),
+      "effect-transmission" => Some(Technology::EffectTransmission),
+      "low-density-structure" => Some(Technology::LowDensityStructure),
+      "rocket-control-unit" => Some(Technology::RocketControlUnit),
+      "rocket-fuel" => Some(Technology::RocketFuel),
+      "rocket-silo" => Some(Technology::RocketSilo),
+      "cliff-explosives" => Some(Technology::CliffExplosives),
+      _ => None,
     }
   }
+  pub fn from_name(name: &str) -> Technology {
+    Technology::try_from_name(name).unwrap_or_else(|| panic!("unknown Technology \"{name}\""))
+  }
+}
+impl std::fmt::Display for Technology {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Technology {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Technology, UnknownPrototypeName> {
+    Technology::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Technology", name: name.to_owned() })
+  }
 }
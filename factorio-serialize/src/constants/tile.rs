@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::{MapReadWriteEnumU8, ReplayReadWriteEnumU8};
+use factorio_serialize_derive::PrototypeIdU8;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU8, MapReadWriteEnumU8)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU8)]
+#[prototype_type(Tile)]
 pub enum Tile {
   StonePath = 1,
   Concrete = 2,
@@ -61,6 +64,64 @@ pub enum Tile {
   TutorialGrid = 52,
 }
 impl Tile {
+  pub const ALL: [Tile; 52] = [
+    Tile::StonePath,
+    Tile::Concrete,
+    Tile::HazardConcreteLeft,
+    Tile::HazardConcreteRight,
+    Tile::RefinedConcrete,
+    Tile::RefinedHazardConcreteLeft,
+    Tile::RefinedHazardConcreteRight,
+    Tile::Landfill,
+    Tile::AcidRefinedConcrete,
+    Tile::BlackRefinedConcrete,
+    Tile::BlueRefinedConcrete,
+    Tile::BrownRefinedConcrete,
+    Tile::CyanRefinedConcrete,
+    Tile::GreenRefinedConcrete,
+    Tile::OrangeRefinedConcrete,
+    Tile::PinkRefinedConcrete,
+    Tile::PurpleRefinedConcrete,
+    Tile::RedRefinedConcrete,
+    Tile::YellowRefinedConcrete,
+    Tile::Grass1,
+    Tile::Grass2,
+    Tile::Grass3,
+    Tile::Grass4,
+    Tile::DryDirt,
+    Tile::Dirt1,
+    Tile::Dirt2,
+    Tile::Dirt3,
+    Tile::Dirt4,
+    Tile::Dirt5,
+    Tile::Dirt6,
+    Tile::Dirt7,
+    Tile::Sand1,
+    Tile::Sand2,
+    Tile::Sand3,
+    Tile::RedDesert0,
+    Tile::RedDesert1,
+    Tile::RedDesert2,
+    Tile::RedDesert3,
+    Tile::Water,
+    Tile::Deepwater,
+    Tile::WaterGreen,
+    Tile::DeepwaterGreen,
+    Tile::WaterShallow,
+    Tile::WaterMud,
+    Tile::NuclearGround,
+    Tile::WaterWube,
+    Tile::TileUnknown,
+    Tile::OutOfMap,
+    Tile::LabDark1,
+    Tile::LabDark2,
+    Tile::LabWhite,
+    Tile::TutorialGrid,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = Tile> {
+    Tile::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       Tile::StonePath => "stone-path",
@@ -117,61 +178,75 @@ impl Tile {
       Tile::TutorialGrid => "tutorial-grid",
     }
   }
-  pub fn from_name(name: &str) -> Tile {
+  pub fn try_from_name(name: &str) -> Option<Tile> {
     match name {
-      "stone-path" => Tile::StonePath,
-      "concrete" => Tile::Concrete,
-      "hazard-concrete-left" => Tile::HazardConcreteLeft,
-      "hazard-concrete-right" => Tile::HazardConcreteRight,
-      "refined-concrete" => Tile::RefinedConcrete,
-      "refined-hazard-concrete-left" => Tile::RefinedHazardConcreteLeft,
-      "refined-hazard-concrete-right" => Tile::RefinedHazardConcreteRight,
-      "landfill" => Tile::Landfill,
-      "acid-refined-concrete" => Tile::AcidRefinedConcrete,
-      "black-refined-concrete" => Tile::BlackRefinedConcrete,
-      "blue-refined-concrete" => Tile::BlueRefinedConcrete,
-      "brown-refined-concrete" => Tile::BrownRefinedConcrete,
-      "cyan-refined-concrete" => Tile::CyanRefinedConcrete,
-      "green-refined-concrete" => Tile::GreenRefinedConcrete,
-      "orange-refined-concrete" => Tile::OrangeRefinedConcrete,
-      "pink-refined-concrete" => Tile::PinkRefinedConcrete,
-      "purple-refined-concrete" => Tile::PurpleRefinedConcrete,
-      "red-refined-concrete" => Tile::RedRefinedConcrete,
-      "yellow-refined-concrete" => Tile::YellowRefinedConcrete,
-      "grass-1" => Tile::Grass1,
-      "grass-2" => Tile::Grass2,
-      "grass-3" => Tile::Grass3,
-      "grass-4" => Tile::Grass4,
-      "dry-dirt" => Tile::DryDirt,
-      "dirt-1" => Tile::Dirt1,
-      "dirt-2" => Tile::Dirt2,
-      "dirt-3" => Tile::Dirt3,
-      "dirt-4" => Tile::Dirt4,
-      "dirt-5" => Tile::Dirt5,
-      "dirt-6" => Tile::Dirt6,
-      "dirt-7" => Tile::Dirt7,
-      "sand-1" => Tile::Sand1,
-      "sand-2" => Tile::Sand2,
-      "sand-3" => Tile::Sand3,
-      "red-desert-0" => Tile::RedDesert0,
-      "red-desert-1" => Tile::RedDesert1,
-      "red-desert-2" => Tile::RedDesert2,
-      "red-desert-3" => Tile::RedDesert3,
-      "water" => Tile::Water,
-      "deepwater" => Tile::Deepwater,
-      "water-green" => Tile::WaterGreen,
-      "deepwater-green" => Tile::DeepwaterGreen,
-      "water-shallow" => Tile::WaterShallow,
-      "water-mud" => Tile::WaterMud,
-      "nuclear-ground" => Tile::NuclearGround,
-      "water-wube" => Tile::WaterWube,
-      "tile-unknown" => Tile::TileUnknown,
-      "out-of-map" => Tile::OutOfMap,
-      "lab-dark-1" => Tile::LabDark1,
-      "lab-dark-2" => Tile::LabDark2,
-      "lab-white" => Tile::LabWhite,
-      "tutorial-grid" => Tile::TutorialGrid,
-      name => panic!("unknown Tile \"{name}\""),
+      "stone-path" => Some(Tile::StonePath),
+      "concrete" => Some(Tile::Concrete),
+      "hazard-concrete-left" => Some(Tile::HazardConcreteLeft),
+      "hazard-concrete-right" => Some(Tile::HazardConcreteRight),
+      "refined-concrete" => Some(Tile::RefinedConcrete),
+      "refined-hazard-concrete-left" => Some(Tile::RefinedHazardConcreteLeft),
+      "refined-hazard-concrete-right" => Some(Tile::RefinedHazardConcreteRight),
+      "landfill" => Some(Tile::Landfill),
+      "acid-refined-concrete" => Some(Tile::AcidRefinedConcrete),
+      "black-refined-concrete" => Some(Tile::BlackRefinedConcrete),
+      "blue-refined-concrete" => Some(Tile::BlueRefinedConcrete),
+      "brown-refined-concrete" => Some(Tile::BrownRefinedConcrete),
+      "cyan-refined-concrete" => Some(Tile::CyanRefinedConcrete),
+      "green-refined-concrete" => Some(Tile::GreenRefinedConcrete),
+      "orange-refined-concrete" => Some(Tile::OrangeRefinedConcrete),
+      "pink-refined-concrete" => Some(Tile::PinkRefinedConcrete),
+      "purple-refined-concrete" => Some(Tile::PurpleRefinedConcrete),
+      "red-refined-concrete" => Some(Tile::RedRefinedConcrete),
+      "yellow-refined-concrete" => Some(Tile::YellowRefinedConcrete),
+      "grass-1" => Some(Tile::Grass1),
+      "grass-2" => Some(Tile::Grass2),
+      "grass-3" => Some(Tile::Grass3),
+      "grass-4" => Some(Tile::Grass4),
+      "dry-dirt" => Some(Tile::DryDirt),
+      "dirt-1" => Some(Tile::Dirt1),
+      "dirt-2" => Some(Tile::Dirt2),
+      "dirt-3" => Some(Tile::Dirt3),
+      "dirt-4" => Some(Tile::Dirt4),
+      "dirt-5" => Some(Tile::Dirt5),
+      "dirt-6" => Some(Tile::Dirt6),
+      "dirt-7" => Some(Tile::Dirt7),
+      "sand-1" => Some(Tile::Sand1),
+      "sand-2" => Some(Tile::Sand2),
+      "sand-3" => Some(Tile::Sand3),
+      "red-desert-0" => Some(Tile::RedDesert0),
+      "red-desert-1" => Some(Tile::RedDesert1),
+      "red-desert-2" => Some(Tile::RedDesert2),
+      "red-desert-3" => Some(Tile::RedDesert3),
+      "water" => Some(Tile::Water),
+      "deepwater" => Some(Tile::Deepwater),
+      "water-green" => Some(Tile::WaterGreen),
+      "deepwater-green" => Some(Tile::DeepwaterGreen),
+      "water-shallow" => Some(Tile::WaterShallow),
+      "water-mud" => Some(Tile::WaterMud),
+      "nuclear-ground" => Some(Tile::NuclearGround),
+      "water-wube" => Some(Tile::WaterWube),
+      "tile-unknown" => Some(Tile::TileUnknown),
+      "out-of-map" => Some(Tile::OutOfMap),
+      "lab-dark-1" => Some(Tile::LabDark1),
+      "lab-dark-2" => Some(Tile::LabDark2),
+      "lab-white" => Some(Tile::LabWhite),
+      "tutorial-grid" => Some(Tile::TutorialGrid),
+      _ => None,
     }
   }
+  pub fn from_name(name: &str) -> Tile {
+    Tile::try_from_name(name).unwrap_or_else(|| panic!("unknown Tile \"{name}\""))
+  }
+}
+impl std::fmt::Display for Tile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for Tile {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<Tile, UnknownPrototypeName> {
+    Tile::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "Tile", name: name.to_owned() })
+  }
 }
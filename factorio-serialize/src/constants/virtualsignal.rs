@@ -1,11 +1,14 @@
 use enum_primitive_derive::Primitive;
-use factorio_serialize_derive::ReplayReadWriteEnumU16;
+use factorio_serialize_derive::PrototypeIdU16;
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use super::UnknownPrototypeName;
+
 
 // Version: 1.1.107
-// Extraction method: util::export_prototypes
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU16)]
+// Extraction method: constantsgenerator::regenerate_constants
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeIdU16)]
+#[prototype_type(VirtualSignal)]
 pub enum VirtualSignal {
   SignalEverything = 1,
   SignalAnything = 2,
@@ -61,6 +64,64 @@ pub enum VirtualSignal {
   SignalUnknown = 52,
 }
 impl VirtualSignal {
+  pub const ALL: [VirtualSignal; 52] = [
+    VirtualSignal::SignalEverything,
+    VirtualSignal::SignalAnything,
+    VirtualSignal::SignalEach,
+    VirtualSignal::Signal0,
+    VirtualSignal::Signal1,
+    VirtualSignal::Signal2,
+    VirtualSignal::Signal3,
+    VirtualSignal::Signal4,
+    VirtualSignal::Signal5,
+    VirtualSignal::Signal6,
+    VirtualSignal::Signal7,
+    VirtualSignal::Signal8,
+    VirtualSignal::Signal9,
+    VirtualSignal::SignalA,
+    VirtualSignal::SignalB,
+    VirtualSignal::SignalC,
+    VirtualSignal::SignalD,
+    VirtualSignal::SignalE,
+    VirtualSignal::SignalF,
+    VirtualSignal::SignalG,
+    VirtualSignal::SignalH,
+    VirtualSignal::SignalI,
+    VirtualSignal::SignalJ,
+    VirtualSignal::SignalK,
+    VirtualSignal::SignalL,
+    VirtualSignal::SignalM,
+    VirtualSignal::SignalN,
+    VirtualSignal::SignalO,
+    VirtualSignal::SignalP,
+    VirtualSignal::SignalQ,
+    VirtualSignal::SignalR,
+    VirtualSignal::SignalS,
+    VirtualSignal::SignalT,
+    VirtualSignal::SignalU,
+    VirtualSignal::SignalV,
+    VirtualSignal::SignalW,
+    VirtualSignal::SignalX,
+    VirtualSignal::SignalY,
+    VirtualSignal::SignalZ,
+    VirtualSignal::SignalRed,
+    VirtualSignal::SignalGreen,
+    VirtualSignal::SignalBlue,
+    VirtualSignal::SignalYellow,
+    VirtualSignal::SignalPink,
+    VirtualSignal::SignalCyan,
+    VirtualSignal::SignalWhite,
+    VirtualSignal::SignalGrey,
+    VirtualSignal::SignalBlack,
+    VirtualSignal::SignalCheck,
+    VirtualSignal::SignalInfo,
+    VirtualSignal::SignalDot,
+    VirtualSignal::SignalUnknown,
+  ];
+
+  pub fn iter() -> impl Iterator<Item = VirtualSignal> {
+    VirtualSignal::ALL.into_iter()
+  }
   pub fn name(self) -> &'static str {
     match self {
       VirtualSignal::SignalEverything => "signal-everything",
@@ -117,61 +178,75 @@ impl VirtualSignal {
       VirtualSignal::SignalUnknown => "signal-unknown",
     }
   }
-  pub fn from_name(name: &str) -> VirtualSignal {
+  pub fn try_from_name(name: &str) -> Option<VirtualSignal> {
     match name {
-      "signal-everything" => VirtualSignal::SignalEverything,
-      "signal-anything" => VirtualSignal::SignalAnything,
-      "signal-each" => VirtualSignal::SignalEach,
-      "signal-0" => VirtualSignal::Signal0,
-      "signal-1" => VirtualSignal::Signal1,
-      "signal-2" => VirtualSignal::Signal2,
-      "signal-3" => VirtualSignal::Signal3,
-      "signal-4" => VirtualSignal::Signal4,
-      "signal-5" => VirtualSignal::Signal5,
-      "signal-6" => VirtualSignal::Signal6,
-      "signal-7" => VirtualSignal::Signal7,
-      "signal-8" => VirtualSignal::Signal8,
-      "signal-9" => VirtualSignal::Signal9,
-      "signal-A" => VirtualSignal::SignalA,
-      "signal-B" => VirtualSignal::SignalB,
-      "signal-C" => VirtualSignal::SignalC,
-      "signal-D" => VirtualSignal::SignalD,
-      "signal-E" => VirtualSignal::SignalE,
-      "signal-F" => VirtualSignal::SignalF,
-      "signal-G" => VirtualSignal::SignalG,
-      "signal-H" => VirtualSignal::SignalH,
-      "signal-I" => VirtualSignal::SignalI,
-      "signal-J" => VirtualSignal::SignalJ,
-      "signal-K" => VirtualSignal::SignalK,
-      "signal-L" => VirtualSignal::SignalL,
-      "signal-M" => VirtualSignal::SignalM,
-      "signal-N" => VirtualSignal::SignalN,
-      "signal-O" => VirtualSignal::SignalO,
-      "signal-P" => VirtualSignal::SignalP,
-      "signal-Q" => VirtualSignal::SignalQ,
-      "signal-R" => VirtualSignal::SignalR,
-      "signal-S" => VirtualSignal::SignalS,
-      "signal-T" => VirtualSignal::SignalT,
-      "signal-U" => VirtualSignal::SignalU,
-      "signal-V" => VirtualSignal::SignalV,
-      "signal-W" => VirtualSignal::SignalW,
-      "signal-X" => VirtualSignal::SignalX,
-      "signal-Y" => VirtualSignal::SignalY,
-      "signal-Z" => VirtualSignal::SignalZ,
-      "signal-red" => VirtualSignal::SignalRed,
-      "signal-green" => VirtualSignal::SignalGreen,
-      "signal-blue" => VirtualSignal::SignalBlue,
-      "signal-yellow" => VirtualSignal::SignalYellow,
-      "signal-pink" => VirtualSignal::SignalPink,
-      "signal-cyan" => VirtualSignal::SignalCyan,
-      "signal-white" => VirtualSignal::SignalWhite,
-      "signal-grey" => VirtualSignal::SignalGrey,
-      "signal-black" => VirtualSignal::SignalBlack,
-      "signal-check" => VirtualSignal::SignalCheck,
-      "signal-info" => VirtualSignal::SignalInfo,
-      "signal-dot" => VirtualSignal::SignalDot,
-      "signal-unknown" => VirtualSignal::SignalUnknown,
-      name => panic!("unknown VirtualSignal \"{name}\""),
+      "signal-everything" => Some(VirtualSignal::SignalEverything),
+      "signal-anything" => Some(VirtualSignal::SignalAnything),
+      "signal-each" => Some(VirtualSignal::SignalEach),
+      "signal-0" => Some(VirtualSignal::Signal0),
+      "signal-1" => Some(VirtualSignal::Signal1),
+      "signal-2" => Some(VirtualSignal::Signal2),
+      "signal-3" => Some(VirtualSignal::Signal3),
+      "signal-4" => Some(VirtualSignal::Signal4),
+      "signal-5" => Some(VirtualSignal::Signal5),
+      "signal-6" => Some(VirtualSignal::Signal6),
+      "signal-7" => Some(VirtualSignal::Signal7),
+      "signal-8" => Some(VirtualSignal::Signal8),
+      "signal-9" => Some(VirtualSignal::Signal9),
+      "signal-A" => Some(VirtualSignal::SignalA),
+      "signal-B" => Some(VirtualSignal::SignalB),
+      "signal-C" => Some(VirtualSignal::SignalC),
+      "signal-D" => Some(VirtualSignal::SignalD),
+      "signal-E" => Some(VirtualSignal::SignalE),
+      "signal-F" => Some(VirtualSignal::SignalF),
+      "signal-G" => Some(VirtualSignal::SignalG),
+      "signal-H" => Some(VirtualSignal::SignalH),
+      "signal-I" => Some(VirtualSignal::SignalI),
+      "signal-J" => Some(VirtualSignal::SignalJ),
+      "signal-K" => Some(VirtualSignal::SignalK),
+      "signal-L" => Some(VirtualSignal::SignalL),
+      "signal-M" => Some(VirtualSignal::SignalM),
+      "signal-N" => Some(VirtualSignal::SignalN),
+      "signal-O" => Some(VirtualSignal::SignalO),
+      "signal-P" => Some(VirtualSignal::SignalP),
+      "signal-Q" => Some(VirtualSignal::SignalQ),
+      "signal-R" => Some(VirtualSignal::SignalR),
+      "signal-S" => Some(VirtualSignal::SignalS),
+      "signal-T" => Some(VirtualSignal::SignalT),
+      "signal-U" => Some(VirtualSignal::SignalU),
+      "signal-V" => Some(VirtualSignal::SignalV),
+      "signal-W" => Some(VirtualSignal::SignalW),
+      "signal-X" => Some(VirtualSignal::SignalX),
+      "signal-Y" => Some(VirtualSignal::SignalY),
+      "signal-Z" => Some(VirtualSignal::SignalZ),
+      "signal-red" => Some(VirtualSignal::SignalRed),
+      "signal-green" => Some(VirtualSignal::SignalGreen),
+      "signal-blue" => Some(VirtualSignal::SignalBlue),
+      "signal-yellow" => Some(VirtualSignal::SignalYellow),
+      "signal-pink" => Some(VirtualSignal::SignalPink),
+      "signal-cyan" => Some(VirtualSignal::SignalCyan),
+      "signal-white" => Some(VirtualSignal::SignalWhite),
+      "signal-grey" => Some(VirtualSignal::SignalGrey),
+      "signal-black" => Some(VirtualSignal::SignalBlack),
+      "signal-check" => Some(VirtualSignal::SignalCheck),
+      "signal-info" => Some(VirtualSignal::SignalInfo),
+      "signal-dot" => Some(VirtualSignal::SignalDot),
+      "signal-unknown" => Some(VirtualSignal::SignalUnknown),
+      _ => None,
     }
   }
+  pub fn from_name(name: &str) -> VirtualSignal {
+    VirtualSignal::try_from_name(name).unwrap_or_else(|| panic!("unknown VirtualSignal \"{name}\""))
+  }
+}
+impl std::fmt::Display for VirtualSignal {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+impl std::str::FromStr for VirtualSignal {
+  type Err = UnknownPrototypeName;
+  fn from_str(name: &str) -> Result<VirtualSignal, UnknownPrototypeName> {
+    VirtualSignal::try_from_name(name).ok_or_else(|| UnknownPrototypeName { prototype_type: "VirtualSignal", name: name.to_owned() })
+  }
 }
@@ -2,16 +2,19 @@ pub mod constants;
 mod error;
 pub mod map;
 mod reader;
+pub mod registry;
 pub mod replay;
 mod writer;
 pub mod save;
 pub mod script;
+mod serpent;
 mod structs;
 
 pub use structs::BoundingBox;
 pub use structs::ChunkPosition;
 pub use structs::MapPosition;
 pub use structs::RandomGenerator;
+pub use structs::RandomGeneratorComponent;
 pub use structs::TilePosition;
 pub use structs::FixedPoint32_8;
 pub use structs::Vector;
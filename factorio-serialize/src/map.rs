@@ -3,9 +3,12 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::io::BufRead;
 use std::io::Cursor;
+use std::io::Read;
 use std::io::Seek;
+use std::io::Write;
 use std::num::TryFromIntError;
 
+use base64::Engine;
 use enum_primitive_derive::Primitive;
 use factorio_serialize_derive::MapReadWriteEnumU8;
 use factorio_serialize_derive::MapReadWriteStruct;
@@ -21,9 +24,15 @@ use crate::RandomGenerator;
 use crate::Reader;
 use crate::Result;
 use crate::Writer;
+use crate::constants;
 use crate::constants::Achievement;
 use crate::constants::Entity;
 use crate::constants::Tile;
+use crate::registry::Prototype;
+use crate::registry::PrototypeId;
+use crate::registry::PrototypeRegistry;
+use crate::registry::PrototypeType;
+use crate::replay;
 
 
 pub struct MapData {
@@ -46,6 +55,29 @@ impl MapData {
     Ok(MapData { map_version, scenario_execution_context, map, remaining_data })
   }
 
+  /// Reads one of the RNGs of map data without parsing the rest of the map, which may hold entities this crate can't parse.
+  pub fn read_random_generator(map_data: &[u8], role: RandomGeneratorRole) -> Result<RandomGenerator> {
+    let mut map_deserialiser = MapDeserialiser::new(Cursor::new(map_data))?;
+    seek_random_generator(&mut map_deserialiser, role)?;
+    RandomGenerator::map_read(&mut map_deserialiser)
+  }
+  /// Replaces one of the RNGs of map data in place, leaving all other bytes as they are.
+  pub fn patch_random_generator(map_data: &[u8], role: RandomGeneratorRole, random_generator: &RandomGenerator) -> Result<Vec<u8>> {
+    let mut map_deserialiser = MapDeserialiser::new(Cursor::new(map_data))?;
+    seek_random_generator(&mut map_deserialiser, role)?;
+    let start = map_deserialiser.stream.position() as usize;
+    RandomGenerator::map_read(&mut map_deserialiser)?;
+    let end = map_deserialiser.stream.position() as usize;
+
+    let mut map_serialiser = MapSerialiser::new(map_deserialiser.map_version)?;
+    let header_len = map_serialiser.stream.position() as usize;
+    random_generator.map_write(&mut map_serialiser)?;
+    let bytes = map_serialiser.stream.into_inner().into_inner();
+    let mut patched = map_data.to_vec();
+    patched.splice(start..end, bytes[header_len..].iter().copied());
+    Ok(patched)
+  }
+
   pub fn write_map_data(&self) -> Result<Vec<u8>> {
     let mut map_serialiser = MapSerialiser::new(self.map_version.clone())?;
 
@@ -56,6 +88,173 @@ impl MapData {
 
     Ok(map_serialiser.stream.into_inner().into_inner())
   }
+
+  pub fn prototype_registry(&self) -> PrototypeRegistry {
+    PrototypeRegistry::from_migrations(&self.map.prototype_migrations)
+  }
+
+  pub fn map_exchange_data(&self) -> MapExchangeData {
+    MapExchangeData { map_version: self.map_version.clone(), map_gen_settings: self.map.map_gen_settings.clone(), map_settings: self.map.map_settings.clone() }
+  }
+  /// Replaces the map generation and map settings, including the settings compiled into existing surfaces.
+  pub fn set_map_exchange_data(&mut self, map_exchange_data: &MapExchangeData) {
+    self.map.map_gen_settings = map_exchange_data.map_gen_settings.clone();
+    self.map.map_settings = map_exchange_data.map_settings.clone();
+    for surface in &mut self.map.surfaces {
+      surface.compiled_map_gen_settings.settings = map_exchange_data.map_gen_settings.clone();
+    }
+  }
+  pub fn export_map_exchange_string(&self) -> Result<String> {
+    self.map_exchange_data().write_map_exchange_string()
+  }
+  /// Imports the settings of a map exchange string as with set_map_exchange_data.
+  pub fn import_map_exchange_string(&mut self, map_exchange_string: &str) -> Result<()> {
+    self.set_map_exchange_data(&MapExchangeData::parse_map_exchange_string(map_exchange_string)?);
+    Ok(())
+  }
+
+  pub fn random_generator(&self, role: RandomGeneratorRole) -> &RandomGenerator {
+    match role {
+      RandomGeneratorRole::General => &self.map.general_random_generator,
+      RandomGeneratorRole::Ai => &self.map.ai_random_generator,
+      RandomGeneratorRole::Entities => &self.map.entities_random_generator,
+      RandomGeneratorRole::Map => &self.map.map_random_generator,
+      RandomGeneratorRole::Triggers => &self.map.triggers_random_generator,
+    }
+  }
+  pub fn random_generator_mut(&mut self, role: RandomGeneratorRole) -> &mut RandomGenerator {
+    match role {
+      RandomGeneratorRole::General => &mut self.map.general_random_generator,
+      RandomGeneratorRole::Ai => &mut self.map.ai_random_generator,
+      RandomGeneratorRole::Entities => &mut self.map.entities_random_generator,
+      RandomGeneratorRole::Map => &mut self.map.map_random_generator,
+      RandomGeneratorRole::Triggers => &mut self.map.triggers_random_generator,
+    }
+  }
+  pub fn set_random_generator(&mut self, role: RandomGeneratorRole, random_generator: RandomGenerator) {
+    *self.random_generator_mut(role) = random_generator;
+  }
+
+  pub fn force_data(&self, force: replay::ForceId) -> Result<&ForceData> {
+    self.map.force_manager.force_data(force)
+  }
+  pub fn force_data_mut(&mut self, force: replay::ForceId) -> Result<&mut ForceData> {
+    self.map.force_manager.force_data_list.iter_mut().find(|force_data| force_data.id == force as ForceId)
+      .ok_or_else(|| crate::Error::custom(format!("force {force:?} not found"), 0))
+  }
+
+  fn technology_save_id(&self, technology: constants::Technology) -> Result<u16> {
+    let id = self.prototype_registry().to_save_id(technology).ok_or_else(|| crate::Error::custom(format!("technology {technology} does not exist in this map"), 0))?;
+    u16::try_from(id).map_err(|e| crate::Error::custom(format!("technology id {id} out of range: {e}"), 0))
+  }
+  fn technology_from_save_id(&self, id: u16) -> Result<constants::Technology> {
+    self.prototype_registry().to_static(u32::from(id)).ok_or_else(|| crate::Error::custom(format!("unknown technology id {id}"), 0))
+  }
+
+  pub fn is_technology_researched(&self, force: replay::ForceId, technology: constants::Technology) -> Result<bool> {
+    self.force_data(force)?.is_technology_researched(self.technology_save_id(technology)?)
+  }
+  /// Marks a technology as researched or not for a force, applying or reverting its recipe unlocks and modifiers.
+  pub fn set_technology_researched(&mut self, force: replay::ForceId, technology: constants::Technology, researched: bool) -> Result<()> {
+    let technology_id = self.technology_save_id(technology)?;
+    self.force_data_mut(force)?.set_technology_researched(technology_id, researched)
+  }
+  pub fn current_research(&self, force: replay::ForceId) -> Result<Option<(constants::Technology, f64)>> {
+    match self.force_data(force)?.current_research() {
+      Some((technology_id, progress)) => Ok(Some((self.technology_from_save_id(technology_id)?, progress))),
+      None => Ok(None),
+    }
+  }
+  pub fn set_current_research(&mut self, force: replay::ForceId, research: Option<(constants::Technology, f64)>) -> Result<()> {
+    let research = match research {
+      Some((technology, progress)) => Some((self.technology_save_id(technology)?, progress)),
+      None => None,
+    };
+    self.force_data_mut(force)?.set_current_research(research)
+  }
+  pub fn research_queue(&self, force: replay::ForceId) -> Result<Vec<constants::Technology>> {
+    self.force_data(force)?.research_queue().iter().map(|&technology_id| self.technology_from_save_id(technology_id)).collect()
+  }
+  pub fn set_research_queue(&mut self, force: replay::ForceId, research_queue: &[constants::Technology]) -> Result<()> {
+    let research_queue = research_queue.iter().map(|&technology| self.technology_save_id(technology)).collect::<Result<_>>()?;
+    self.force_data_mut(force)?.set_research_queue(research_queue)
+  }
+
+  /// Exports the samples of one of a force's production statistics as CSV, see FlowStatistics::to_csv.
+  pub fn flow_statistics_csv(&self, force: replay::ForceId, statistics_type: FlowStatisticsType, precision_index: usize) -> Result<String> {
+    flow_statistics_csv(self.force_data(force)?, &self.prototype_registry(), self.map.map_header.update_tick, statistics_type, precision_index)
+  }
+}
+fn flow_statistics_csv(force_data: &ForceData, registry: &PrototypeRegistry, tick: u32, statistics_type: FlowStatisticsType, precision_index: usize) -> Result<String> {
+  if precision_index >= 8 { return Err(crate::Error::custom(format!("invalid precision index {precision_index}"), 0)); }
+  let missing = || crate::Error::custom(format!("force {} has no {statistics_type:?} statistics", force_data.name), 0);
+  Ok(match statistics_type {
+    FlowStatisticsType::ItemProduction => force_data.item_production_statistics.as_ref().ok_or_else(missing)?.to_csv(precision_index, tick, |id| prototype_name::<constants::Item>(registry, id)),
+    FlowStatisticsType::FluidProduction => force_data.fluid_production_statistics.as_ref().ok_or_else(missing)?.to_csv(precision_index, tick, |id| prototype_name::<constants::Fluid>(registry, id)),
+    FlowStatisticsType::KillCount => force_data.kill_count_statistics.as_ref().ok_or_else(missing)?.to_csv(precision_index, tick, |id| prototype_name::<Entity>(registry, id)),
+    FlowStatisticsType::BuildCount => force_data.build_count_statistics.as_ref().ok_or_else(missing)?.to_csv(precision_index, tick, |id| prototype_name::<Entity>(registry, id)),
+  })
+}
+
+/// The forces of map data, read without parsing the surfaces and entities after them, which this crate may not be able to parse.
+#[derive(Debug)]
+pub struct MapForces {
+  pub map_header: MapHeader,
+  pub prototype_migrations: PrototypeMigrationList,
+  pub force_manager: ForceManager,
+}
+impl MapForces {
+  pub fn read_map_forces(map_data: &[u8]) -> Result<MapForces> {
+    let mut r = MapDeserialiser::new(Cursor::new(map_data))?;
+    // the fields of Map up to its force manager
+    ScenarioExecutionContext::map_read(&mut r)?;
+    let map_header = MapHeader::map_read(&mut r)?;
+    MapGenSettings::map_read(&mut r)?;
+    MapSettings::map_read(&mut r)?;
+    for _ in RandomGeneratorRole::ALL { RandomGenerator::map_read(&mut r)?; }
+    EntityUpdatePausedState::map_read(&mut r)?;
+    let prototype_migrations = PrototypeMigrationList::map_read(&mut r)?;
+    Vec::<PrototypeMigrationListDefinitionMigration>::map_read(&mut r)?;
+    for _ in 0..6 { u32::map_read(&mut r)?; }
+    u64::map_read(&mut r)?;
+    MapModSettings::map_read(&mut r)?;
+    TrainManager::map_read(&mut r)?;
+    let force_manager = ForceManager::map_read(&mut r)?;
+    Ok(MapForces { map_header, prototype_migrations, force_manager })
+  }
+
+  pub fn force_data(&self, force: replay::ForceId) -> Result<&ForceData> {
+    self.force_manager.force_data(force)
+  }
+  /// Exports the samples of one of a force's production statistics as CSV, see FlowStatistics::to_csv.
+  pub fn flow_statistics_csv(&self, force: replay::ForceId, statistics_type: FlowStatisticsType, precision_index: usize) -> Result<String> {
+    flow_statistics_csv(self.force_data(force)?, &PrototypeRegistry::from_migrations(&self.prototype_migrations), self.map_header.update_tick, statistics_type, precision_index)
+  }
+}
+// falls back to the static names for saves without migration information, and to the raw id for unknown prototypes
+fn prototype_name<T: PrototypeId>(registry: &PrototypeRegistry, id: u16) -> String {
+  registry.name(T::PROTOTYPE_TYPE, u32::from(id)).map(str::to_owned)
+    .or_else(|| registry.to_static::<T>(u32::from(id)).map(|prototype| prototype.prototype_name().to_owned()))
+    .unwrap_or_else(|| id.to_string())
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FlowStatisticsType {
+  ItemProduction,
+  FluidProduction,
+  KillCount,
+  BuildCount,
+}
+impl FlowStatisticsType {
+  pub fn try_from_name(name: &str) -> Option<FlowStatisticsType> {
+    match name {
+      "item" => Some(FlowStatisticsType::ItemProduction),
+      "fluid" => Some(FlowStatisticsType::FluidProduction),
+      "kill" => Some(FlowStatisticsType::KillCount),
+      "build" => Some(FlowStatisticsType::BuildCount),
+      _ => None,
+    }
+  }
 }
 impl std::fmt::Debug for MapData {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -69,6 +268,7 @@ impl std::fmt::Debug for MapData {
 pub struct MapDeserialiser<R: BufRead + Seek> {
   pub stream: Reader<R>,
   pub map_version: MapVersion,
+  pub prototype_registry: PrototypeRegistry,
 
   pub last_loaded_position: MapPosition,
 }
@@ -80,6 +280,7 @@ impl<R: BufRead + Seek> MapDeserialiser<R> {
     Ok(MapDeserialiser {
       stream,
       map_version,
+      prototype_registry: PrototypeRegistry::default(),
 
       last_loaded_position: MapPosition::default(),
     })
@@ -88,6 +289,7 @@ impl<R: BufRead + Seek> MapDeserialiser<R> {
 pub struct MapSerialiser {
   pub stream: Writer<Cursor<Vec<u8>>>,
   pub map_version: MapVersion,
+  pub prototype_registry: PrototypeRegistry,
 
   pub last_saved_position: MapPosition,
 }
@@ -99,6 +301,7 @@ impl MapSerialiser{
     Ok(MapSerialiser {
       stream,
       map_version,
+      prototype_registry: PrototypeRegistry::default(),
 
       last_saved_position: MapPosition::default(),
     })
@@ -278,7 +481,7 @@ pub struct ScenarioExecutionContext {
   allowed_commands: AllowedCommands,
   active_mods: Vec<ModId>,
   startup_settings_crc: u32,
-  startup_mod_settings: PropertyTree,
+  pub startup_mod_settings: PropertyTree,
 }
 
 #[derive(Debug, MapReadWriteStruct)]
@@ -334,7 +537,7 @@ pub enum PropertyTree {
   Number { any_type_flag: bool, value: f64, },
   String { any_type_flag: bool, value: Option<String>, },
   List { any_type_flag: bool, value: Vec<PropertyTree>, },
-  Dictionary { any_type_flag: bool, value: HashMap<String, PropertyTree>, },
+  Dictionary { any_type_flag: bool, value: Vec<(String, PropertyTree)>, },  // keeps the stored key order
 }
 impl MapReadWrite for PropertyTree {
   fn map_read<R: BufRead + Seek>(r: &mut MapDeserialiser<R>) -> Result<Self> {
@@ -357,11 +560,11 @@ impl MapReadWrite for PropertyTree {
       },
       5 => {
         let len = r.stream.read_u32()?;
-        let mut value = HashMap::new();
+        let mut value = Vec::new();
         for _ in 0..len {
           let name = r.stream.read_immutable_string()?;
           if name.is_none() { return Err(r.stream.error_at(format!("Unknown PropertyTree Dict contains null name"), 1)) }
-          value.insert(name.unwrap(), PropertyTree::map_read(r)?);
+          value.push((name.unwrap(), PropertyTree::map_read(r)?));
         }
         Ok(PropertyTree::Dictionary { any_type_flag, value, })
       },
@@ -413,12 +616,178 @@ impl MapReadWrite for PropertyTree {
   }
 }
 
+// JSON representation: values map to their natural JSON counterparts. Anything plain JSON can't express (any_type_flag set,
+// null strings, non-finite numbers, dictionaries containing the tag key) is wrapped as {"$property_tree": type, "any_type_flag": flag, "value": value}.
+const PROPERTY_TREE_JSON_TAG: &str = "$property_tree";
+impl PropertyTree {
+  pub fn bool(value: bool) -> Self { PropertyTree::Bool { any_type_flag: false, value } }
+  pub fn number(value: f64) -> Self { PropertyTree::Number { any_type_flag: false, value } }
+  pub fn string(value: &str) -> Self { PropertyTree::String { any_type_flag: false, value: Some(value.to_owned()) } }
+  pub fn list(value: Vec<PropertyTree>) -> Self { PropertyTree::List { any_type_flag: false, value } }
+  pub fn dictionary(value: Vec<(String, PropertyTree)>) -> Self { PropertyTree::Dictionary { any_type_flag: false, value } }
+
+  pub fn as_bool(&self) -> Option<bool> {
+    if let PropertyTree::Bool { value, .. } = self { Some(*value) } else { None }
+  }
+  pub fn as_number(&self) -> Option<f64> {
+    if let PropertyTree::Number { value, .. } = self { Some(*value) } else { None }
+  }
+  pub fn as_str(&self) -> Option<&str> {
+    if let PropertyTree::String { value, .. } = self { value.as_deref() } else { None }
+  }
+  pub fn as_list(&self) -> Option<&[PropertyTree]> {
+    if let PropertyTree::List { value, .. } = self { Some(value) } else { None }
+  }
+  pub fn as_dictionary(&self) -> Option<&[(String, PropertyTree)]> {
+    if let PropertyTree::Dictionary { value, .. } = self { Some(value) } else { None }
+  }
+
+  /// Looks up a key of a dictionary.
+  pub fn get(&self, key: &str) -> Option<&PropertyTree> {
+    self.as_dictionary()?.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+  }
+  pub fn get_mut(&mut self, key: &str) -> Option<&mut PropertyTree> {
+    if let PropertyTree::Dictionary { value, .. } = self { value.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v) } else { None }
+  }
+  /// Looks up a value through nested dictionaries.
+  pub fn get_path(&self, path: &[&str]) -> Option<&PropertyTree> {
+    path.iter().try_fold(self, |tree, key| tree.get(key))
+  }
+  pub fn get_path_mut(&mut self, path: &[&str]) -> Option<&mut PropertyTree> {
+    path.iter().try_fold(self, |tree, key| tree.get_mut(key))
+  }
+  /// Sets a key of a dictionary, replacing an existing entry in place or appending a new one. Turns non-dictionaries into an empty dictionary first.
+  pub fn insert(&mut self, key: &str, new_value: PropertyTree) -> Option<PropertyTree> {
+    if !matches!(self, PropertyTree::Dictionary { .. }) { *self = PropertyTree::dictionary(vec![]); }
+    let PropertyTree::Dictionary { value, .. } = self else { unreachable!() };
+    if let Some((_, v)) = value.iter_mut().find(|(k, _)| k == key) {
+      Some(std::mem::replace(v, new_value))
+    } else {
+      value.push((key.to_owned(), new_value));
+      None
+    }
+  }
+  pub fn remove(&mut self, key: &str) -> Option<PropertyTree> {
+    let PropertyTree::Dictionary { value, .. } = self else { return None };
+    let index = value.iter().position(|(k, _)| k == key)?;
+    Some(value.remove(index).1)
+  }
+
+  // mod settings are stored as {setting_name: {value: ...}}
+  pub fn get_mod_setting(&self, name: &str) -> Option<&PropertyTree> {
+    self.get_path(&[name, "value"])
+  }
+  pub fn set_mod_setting(&mut self, name: &str, value: PropertyTree) -> Option<PropertyTree> {
+    if self.get(name).is_none() { self.insert(name, PropertyTree::dictionary(vec![])); }
+    self.get_mut(name).unwrap().insert("value", value)
+  }
+
+  pub fn to_json(&self) -> serde_json::Value {
+    use serde_json::Value;
+    // (type name, any_type_flag, representable as plain JSON, JSON value)
+    let (type_name, any_type_flag, is_plain, value) = match self {
+      PropertyTree::Nothing { any_type_flag } => ("nothing", *any_type_flag, true, Value::Null),
+      PropertyTree::Bool { any_type_flag, value } => ("bool", *any_type_flag, true, Value::Bool(*value)),
+      PropertyTree::Number { any_type_flag, value } => match serde_json::Number::from_f64(*value) {
+        Some(n) => ("number", *any_type_flag, true, Value::Number(n)),
+        None => ("number", *any_type_flag, false, Value::String(value.to_string())),
+      },
+      PropertyTree::String { any_type_flag, value } => ("string", *any_type_flag, value.is_some(), value.as_ref().map_or(Value::Null, |v| Value::String(v.clone()))),
+      PropertyTree::List { any_type_flag, value } => ("list", *any_type_flag, true, Value::Array(value.iter().map(PropertyTree::to_json).collect())),
+      PropertyTree::Dictionary { any_type_flag, value } => ("dictionary", *any_type_flag, value.iter().all(|(k, _)| k != PROPERTY_TREE_JSON_TAG), Value::Object(value.iter().map(|(k, v)| (k.clone(), v.to_json())).collect())),
+    };
+    if is_plain && !any_type_flag { return value; }
+    let mut tagged = serde_json::Map::new();
+    tagged.insert(PROPERTY_TREE_JSON_TAG.to_owned(), Value::String(type_name.to_owned()));
+    tagged.insert("any_type_flag".to_owned(), Value::Bool(any_type_flag));
+    tagged.insert("value".to_owned(), value);
+    Value::Object(tagged)
+  }
+
+  pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+    use serde_json::Value;
+    Ok(match json {
+      Value::Null => PropertyTree::Nothing { any_type_flag: false },
+      Value::Bool(value) => PropertyTree::bool(*value),
+      Value::Number(value) => PropertyTree::number(value.as_f64().ok_or_else(|| crate::Error::custom(format!("PropertyTree number {value} is not representable as f64"), 0))?),
+      Value::String(value) => PropertyTree::string(value),
+      Value::Array(value) => PropertyTree::list(value.iter().map(PropertyTree::from_json).collect::<Result<_>>()?),
+      Value::Object(value) => match value.get(PROPERTY_TREE_JSON_TAG) {
+        None => PropertyTree::dictionary(value.iter().map(|(k, v)| Ok((k.clone(), PropertyTree::from_json(v)?))).collect::<Result<_>>()?),
+        Some(type_name) => {
+          let any_type_flag = value.get("any_type_flag").and_then(Value::as_bool).unwrap_or(false);
+          let inner = value.get("value").unwrap_or(&Value::Null);
+          let invalid = || crate::Error::custom(format!("invalid tagged PropertyTree {json}"), 0);
+          match type_name.as_str().ok_or_else(invalid)? {
+            "nothing" => PropertyTree::Nothing { any_type_flag },
+            "bool" => PropertyTree::Bool { any_type_flag, value: inner.as_bool().ok_or_else(invalid)? },
+            "number" => PropertyTree::Number { any_type_flag, value: match inner {
+              Value::String(s) => s.parse().map_err(|_| invalid())?,
+              _ => inner.as_f64().ok_or_else(invalid)?,
+            } },
+            "string" => PropertyTree::String { any_type_flag, value: match inner {
+              Value::Null => None,
+              _ => Some(inner.as_str().ok_or_else(invalid)?.to_owned()),
+            } },
+            "list" => PropertyTree::List { any_type_flag, value: inner.as_array().ok_or_else(invalid)?.iter().map(PropertyTree::from_json).collect::<Result<_>>()? },
+            "dictionary" => PropertyTree::Dictionary { any_type_flag, value: inner.as_object().ok_or_else(invalid)?.iter().map(|(k, v)| Ok((k.clone(), PropertyTree::from_json(v)?))).collect::<Result<_>>()? },
+            _ => return Err(invalid()),
+          }
+        },
+      },
+    })
+  }
+}
+
+
+/// The random generators stored in Map, by what the game uses them for.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RandomGeneratorRole {
+  General,
+  Ai,
+  Entities,  // e.g. determines the contents of mined Huge Rocks
+  Map,
+  Triggers,
+}
+impl RandomGeneratorRole {
+  pub const ALL: [RandomGeneratorRole; 5] = [RandomGeneratorRole::General, RandomGeneratorRole::Ai, RandomGeneratorRole::Entities, RandomGeneratorRole::Map, RandomGeneratorRole::Triggers];
+
+  pub fn name(self) -> &'static str {
+    match self {
+      RandomGeneratorRole::General => "general",
+      RandomGeneratorRole::Ai => "ai",
+      RandomGeneratorRole::Entities => "entities",
+      RandomGeneratorRole::Map => "map",
+      RandomGeneratorRole::Triggers => "triggers",
+    }
+  }
+  pub fn try_from_name(name: &str) -> Option<RandomGeneratorRole> {
+    RandomGeneratorRole::ALL.into_iter().find(|role| role.name() == name)
+  }
+}
+impl std::fmt::Display for RandomGeneratorRole {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+// skips the start of the map up to the RNG, the RNGs come right after the map settings and before the prototype migrations and entities
+fn seek_random_generator<R: BufRead + Seek>(map_deserialiser: &mut MapDeserialiser<R>, role: RandomGeneratorRole) -> Result<()> {
+  ScenarioExecutionContext::map_read(map_deserialiser)?;
+  MapHeader::map_read(map_deserialiser)?;
+  MapGenSettings::map_read(map_deserialiser)?;
+  MapSettings::map_read(map_deserialiser)?;
+  for _ in RandomGeneratorRole::ALL.into_iter().take_while(|&preceding_role| preceding_role != role) {
+    RandomGenerator::map_read(map_deserialiser)?;
+  }
+  Ok(())
+}
 
 #[derive(Debug, MapReadWriteStruct)]
 pub struct Map {
   pub map_header: MapHeader,
   pub map_gen_settings: MapGenSettings,
-  map_settings: MapSettings,
+  pub map_settings: MapSettings,
   pub general_random_generator: RandomGenerator,
   pub ai_random_generator: RandomGenerator,
   pub entities_random_generator: RandomGenerator,
@@ -640,9 +1009,52 @@ pub struct MapHeader {
   pub ticks_played: u32,
 }
 
+/// The settings contained in a map exchange string, as produced by the game's map generator GUI.
+#[derive(Clone, Debug)]
+pub struct MapExchangeData {
+  pub map_version: MapVersion,
+  pub map_gen_settings: MapGenSettings,
+  pub map_settings: MapSettings,
+}
+impl MapExchangeData {
+  // format: ">>>" base64(zlib(map version, MapGenSettings, MapSettings, crc32 of the preceding data)) "<<<", whitespace is ignored
+  pub fn parse_map_exchange_string(map_exchange_string: &str) -> Result<MapExchangeData> {
+    let encoded: String = map_exchange_string.chars().filter(|c| !c.is_whitespace()).collect();
+    let encoded = encoded.strip_prefix(">>>").and_then(|e| e.strip_suffix("<<<")).ok_or_else(|| crate::Error::custom("map exchange string must be enclosed in >>> <<<".to_owned(), 0))?;
+    let compressed = base64::engine::general_purpose::STANDARD.decode(encoded).map_err(|e| crate::Error::custom(format!("invalid base64 in map exchange string: {e}"), 0))?;
+    let mut data = Vec::new();
+    flate2::read::ZlibDecoder::new(&compressed[..]).read_to_end(&mut data).map_err(|e| crate::Error::from_io(e, 0))?;
+
+    if data.len() < 4 { return Err(crate::Error::custom("map exchange string data too short".to_owned(), 0)) }
+    let (data, crc) = data.split_at(data.len() - 4);
+    let crc = u32::from_le_bytes(crc.try_into().unwrap());
+    if crc != crc32fast::hash(data) { return Err(crate::Error::custom(format!("map exchange string checksum mismatch: {crc:#x} != {:#x}", crc32fast::hash(data)), data.len() as u64)) }
+
+    let mut map_deserialiser = MapDeserialiser::new(Cursor::new(data))?;
+    let map_gen_settings = MapGenSettings::map_read(&mut map_deserialiser)?;
+    let map_settings = MapSettings::map_read(&mut map_deserialiser)?;
+    if !map_deserialiser.stream.is_at_eof()? { return Err(map_deserialiser.stream.error_at("unexpected data after map settings".to_owned(), 0)) }
+
+    Ok(MapExchangeData { map_version: map_deserialiser.map_version, map_gen_settings, map_settings })
+  }
+
+  pub fn write_map_exchange_string(&self) -> Result<String> {
+    let mut map_serialiser = MapSerialiser::new(self.map_version.clone())?;
+    self.map_gen_settings.map_write(&mut map_serialiser)?;
+    self.map_settings.map_write(&mut map_serialiser)?;
+    let mut data = map_serialiser.stream.into_inner().into_inner();
+    data.extend_from_slice(&crc32fast::hash(&data).to_le_bytes());
+
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(&data).map_err(|e| crate::Error::from_io(e, 0))?;
+    let compressed = encoder.finish().map_err(|e| crate::Error::from_io(e, 0))?;
+    Ok(format!(">>>{}<<<", base64::engine::general_purpose::STANDARD.encode(compressed)))
+  }
+}
+
 type MapGenSize = f32;
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct MapGenSettings {
   pub segmentation: MapGenSize,
   pub water_size: MapGenSize,
@@ -660,154 +1072,154 @@ pub struct MapGenSettings {
   pub cliff_placement_settings: CliffPlacementSettings,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct FrequencySizeRichness {
-  frequency: MapGenSize,
-  size: MapGenSize,
-  richness: MapGenSize,
+  pub frequency: MapGenSize,
+  pub size: MapGenSize,
+  pub richness: MapGenSize,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct AutoplaceSettings {
-  treat_missing_as_default: bool,
-  settings: Vec<(String, FrequencySizeRichness)>,
+  pub treat_missing_as_default: bool,
+  pub settings: Vec<(String, FrequencySizeRichness)>,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct CliffPlacementSettings {
-  cliff_name: String,
-  cliff_elevation0: f32,
-  cliff_elevation_interval: f32,
-  richness: f32,
+  pub cliff_name: String,
+  pub cliff_elevation0: f32,
+  pub cliff_elevation_interval: f32,
+  pub richness: f32,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct MapSettings {
-  pollution_settings: PollutionSettings,
-  steering_settings: SteeringSettings,
-  enemy_evolution_settings: EnemyEvolutionSettings,
-  enemy_expansion_settings: EnemyExpansionSettings,
-  unit_group_settings: UnitGroupSettings,
-  path_finder_settings: PathFinderSettings,
-  max_failed_behavior_count: u32,
-  difficulty_settings: DifficultySettings,
+  pub pollution_settings: PollutionSettings,
+  pub steering_settings: SteeringSettings,
+  pub enemy_evolution_settings: EnemyEvolutionSettings,
+  pub enemy_expansion_settings: EnemyExpansionSettings,
+  pub unit_group_settings: UnitGroupSettings,
+  pub path_finder_settings: PathFinderSettings,
+  pub max_failed_behavior_count: u32,
+  pub difficulty_settings: DifficultySettings,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct PollutionSettings {  // falls back to global settings if empty
-  enabled: Option<bool>,
-  diffusion_ratio: Option<f64>,
-  min_to_diffuse: Option<f64>,
-  ageing: Option<f64>,
-  expected_max_per_chunk: Option<f64>,
-  min_to_show_per_chunk: Option<f64>,
-  min_pollution_to_damage_trees: Option<f64>,
-  pollution_with_max_forest_damage: Option<f64>,
-  pollution_per_tree_damage: Option<f64>,
-  pollution_restored_per_tree_damage: Option<f64>,
-  max_pollution_to_restore_trees: Option<f64>,
-  enemy_attack_pollution_consumption_modifier: Option<f64>,
+  pub enabled: Option<bool>,
+  pub diffusion_ratio: Option<f64>,
+  pub min_to_diffuse: Option<f64>,
+  pub ageing: Option<f64>,
+  pub expected_max_per_chunk: Option<f64>,
+  pub min_to_show_per_chunk: Option<f64>,
+  pub min_pollution_to_damage_trees: Option<f64>,
+  pub pollution_with_max_forest_damage: Option<f64>,
+  pub pollution_per_tree_damage: Option<f64>,
+  pub pollution_restored_per_tree_damage: Option<f64>,
+  pub max_pollution_to_restore_trees: Option<f64>,
+  pub enemy_attack_pollution_consumption_modifier: Option<f64>,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct SteeringSettings {
-  default_settings: StateSteeringSettings,
-  moving_settings: StateSteeringSettings,
+  pub default_settings: StateSteeringSettings,
+  pub moving_settings: StateSteeringSettings,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct StateSteeringSettings {  // falls back to global settings if empty
-  radius: Option<f64>,
-  separation_factor: Option<f64>,
-  separation_force: Option<f64>,
-  force_unit_fuzzy_goto_behavior: Option<bool>,
+  pub radius: Option<f64>,
+  pub separation_factor: Option<f64>,
+  pub separation_force: Option<f64>,
+  pub force_unit_fuzzy_goto_behavior: Option<bool>,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct EnemyEvolutionSettings {
-  enabled: Option<bool>,
-  time_factor: Option<f64>,
-  destroy_factor: Option<f64>,
-  pollution_factor: Option<f64>,
+  pub enabled: Option<bool>,
+  pub time_factor: Option<f64>,
+  pub destroy_factor: Option<f64>,
+  pub pollution_factor: Option<f64>,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct EnemyExpansionSettings {
-  enabled: Option<bool>,
-  max_expansion_distance: Option<u32>,
-  friendly_base_influence_radius: Option<u32>,
-  enemy_building_influence_radius: Option<u32>,
-  building_coefficient: Option<f64>,
-  other_base_coefficient: Option<f64>,
-  neighbouring_chunk_coefficient: Option<f64>,
-  neighbouring_base_chunk_coefficient: Option<f64>,
-  max_colliding_tiles_coefficient: Option<f64>,
-  settler_group_min_size: Option<u32>,
-  settler_group_max_size: Option<u32>,
-  min_expansion_cooldown: Option<u32>,
-  max_expansion_cooldown: Option<u32>,
+  pub enabled: Option<bool>,
+  pub max_expansion_distance: Option<u32>,
+  pub friendly_base_influence_radius: Option<u32>,
+  pub enemy_building_influence_radius: Option<u32>,
+  pub building_coefficient: Option<f64>,
+  pub other_base_coefficient: Option<f64>,
+  pub neighbouring_chunk_coefficient: Option<f64>,
+  pub neighbouring_base_chunk_coefficient: Option<f64>,
+  pub max_colliding_tiles_coefficient: Option<f64>,
+  pub settler_group_min_size: Option<u32>,
+  pub settler_group_max_size: Option<u32>,
+  pub min_expansion_cooldown: Option<u32>,
+  pub max_expansion_cooldown: Option<u32>,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct UnitGroupSettings {
-  min_group_gathering_time: Option<u32>,
-  max_group_gathering_time: Option<u32>,
-  max_wait_time_for_late_members: Option<u32>,
-  max_group_radius: Option<f64>,
-  min_group_radius: Option<f64>,
-  max_member_speedup_when_behind: Option<f64>,
-  max_member_slowdown_when_ahead: Option<f64>,
-  max_group_slowdown_factor: Option<f64>,
-  max_group_member_fallback_factor: Option<f64>,
-  member_disown_distance: Option<f64>,
-  tick_tolerance_when_member_arrives: Option<u32>,
-  max_gathering_unit_groups: Option<u32>,
-  max_unit_group_size: Option<u32>,
+  pub min_group_gathering_time: Option<u32>,
+  pub max_group_gathering_time: Option<u32>,
+  pub max_wait_time_for_late_members: Option<u32>,
+  pub max_group_radius: Option<f64>,
+  pub min_group_radius: Option<f64>,
+  pub max_member_speedup_when_behind: Option<f64>,
+  pub max_member_slowdown_when_ahead: Option<f64>,
+  pub max_group_slowdown_factor: Option<f64>,
+  pub max_group_member_fallback_factor: Option<f64>,
+  pub member_disown_distance: Option<f64>,
+  pub tick_tolerance_when_member_arrives: Option<u32>,
+  pub max_gathering_unit_groups: Option<u32>,
+  pub max_unit_group_size: Option<u32>,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct PathFinderSettings {
-  fwd_2_bwd_ratio: Option<u32>,
-  goal_pressure_ratio: Option<f64>,
-  use_path_cache: Option<bool>,
-  max_steps_worked_per_tick: Option<f64>,
-  max_work_done_per_tick: Option<u32>,
-  short_cache_size: Option<u32>,
-  long_cache_size: Option<u32>,
-  short_cache_min_cacheable_distance: Option<f64>,
-  short_cache_min_algo_steps_to_cache: Option<u32>,
-  long_cache_min_cacheable_distance: Option<f64>,
-  cache_max_connect_to_cache_steps_multiplier: Option<u32>,
-  cache_accept_path_start_distance_ratio: Option<f64>,
-  cache_accept_path_end_distance_ratio: Option<f64>,
-  negative_cache_accept_path_start_distance_ratio: Option<f64>,
-  negative_cache_accept_path_end_distance_ratio: Option<f64>,
-  cache_path_start_distance_rating_multiplier: Option<f64>,
-  cache_path_end_distance_rating_multiplier: Option<f64>,
-  stale_enemy_with_same_destination_collision_penalty: Option<f64>,
-  ignore_moving_enemy_collision_distance: Option<f64>,
-  enemy_with_different_destination_collision_penalty: Option<f64>,
-  general_entity_collision_penalty: Option<f64>,
-  general_entity_subsequent_collision_penalty: Option<f64>,
-  extended_collision_penalty: Option<f64>,
-  max_clients_to_accept_any_new_request: Option<u32>,
-  max_clients_to_accept_short_new_request: Option<u32>,
-  direct_distance_to_consider_short_request: Option<u32>,
-  short_request_max_steps: Option<u32>,
-  short_request_ratio: Option<f64>,
-  min_steps_to_check_path_find_termination: Option<u32>,
-  start_to_goal_cost_multiplier_to_terminate_path_find: Option<f64>,
-  overload_levels: Option<Vec<u32>>,
-  overload_multipliers: Option<Vec<f64>>,
-  negative_path_cache_delay_interval: Option<u32>,
+  pub fwd_2_bwd_ratio: Option<u32>,
+  pub goal_pressure_ratio: Option<f64>,
+  pub use_path_cache: Option<bool>,
+  pub max_steps_worked_per_tick: Option<f64>,
+  pub max_work_done_per_tick: Option<u32>,
+  pub short_cache_size: Option<u32>,
+  pub long_cache_size: Option<u32>,
+  pub short_cache_min_cacheable_distance: Option<f64>,
+  pub short_cache_min_algo_steps_to_cache: Option<u32>,
+  pub long_cache_min_cacheable_distance: Option<f64>,
+  pub cache_max_connect_to_cache_steps_multiplier: Option<u32>,
+  pub cache_accept_path_start_distance_ratio: Option<f64>,
+  pub cache_accept_path_end_distance_ratio: Option<f64>,
+  pub negative_cache_accept_path_start_distance_ratio: Option<f64>,
+  pub negative_cache_accept_path_end_distance_ratio: Option<f64>,
+  pub cache_path_start_distance_rating_multiplier: Option<f64>,
+  pub cache_path_end_distance_rating_multiplier: Option<f64>,
+  pub stale_enemy_with_same_destination_collision_penalty: Option<f64>,
+  pub ignore_moving_enemy_collision_distance: Option<f64>,
+  pub enemy_with_different_destination_collision_penalty: Option<f64>,
+  pub general_entity_collision_penalty: Option<f64>,
+  pub general_entity_subsequent_collision_penalty: Option<f64>,
+  pub extended_collision_penalty: Option<f64>,
+  pub max_clients_to_accept_any_new_request: Option<u32>,
+  pub max_clients_to_accept_short_new_request: Option<u32>,
+  pub direct_distance_to_consider_short_request: Option<u32>,
+  pub short_request_max_steps: Option<u32>,
+  pub short_request_ratio: Option<f64>,
+  pub min_steps_to_check_path_find_termination: Option<u32>,
+  pub start_to_goal_cost_multiplier_to_terminate_path_find: Option<f64>,
+  pub overload_levels: Option<Vec<u32>>,
+  pub overload_multipliers: Option<Vec<f64>>,
+  pub negative_path_cache_delay_interval: Option<u32>,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct DifficultySettings {
-  recipe_difficulty: DifficultySettingsValue,
-  technology_difficulty: DifficultySettingsValue,
-  technology_price_multiplier: f64,
-  research_queue_setting: ResearchQueueSetting,
+  pub recipe_difficulty: DifficultySettingsValue,
+  pub technology_difficulty: DifficultySettingsValue,
+  pub technology_price_multiplier: f64,
+  pub research_queue_setting: ResearchQueueSetting,
 }
 
 // Source: disassembly DifficultySettings::Value
@@ -831,7 +1243,7 @@ pub struct EntityUpdatePausedState {
   ticks_to_run: u32,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Debug)]
 pub struct PrototypeMigrationList {
   pub custom_input_id_migrations: ActiveMigrations<u16>,
   pub equipment_grid_id_migrations: ActiveMigrations<u8>,
@@ -862,6 +1274,106 @@ pub struct PrototypeMigrationList {
   pub trivial_smoke_id_migrations: ActiveMigrations<u8>,
   pub shortcut_id_migrations: ActiveMigrations<u16>,
 }
+impl MapReadWrite for PrototypeMigrationList {
+  fn map_read<R: BufRead + Seek>(r: &mut MapDeserialiser<R>) -> Result<Self> {
+    let custom_input_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let equipment_grid_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let item_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let tile_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let decorative_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let technology_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let entity_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let particle_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let recipe_category_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let item_sub_group_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let item_group_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let fluid_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let virtual_signal_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let ammo_category_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let fuel_category_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let resource_category_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let equipment_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let noise_layer_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let named_noise_expression_id_migrations = ActiveMigrations::<u32>::map_read(r)?;
+    let autoplace_control_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let damage_type_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let recipe_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let achievement_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let module_category_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let equipment_category_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let mod_settings_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+    let trivial_smoke_id_migrations = ActiveMigrations::<u8>::map_read(r)?;
+    let shortcut_id_migrations = ActiveMigrations::<u16>::map_read(r)?;
+
+    let prototype_migrations = PrototypeMigrationList {
+      custom_input_id_migrations,
+      equipment_grid_id_migrations,
+      item_id_migrations,
+      tile_id_migrations,
+      decorative_id_migrations,
+      technology_id_migrations,
+      entity_id_migrations,
+      particle_id_migrations,
+      recipe_category_id_migrations,
+      item_sub_group_id_migrations,
+      item_group_id_migrations,
+      fluid_id_migrations,
+      virtual_signal_id_migrations,
+      ammo_category_id_migrations,
+      fuel_category_id_migrations,
+      resource_category_id_migrations,
+      equipment_id_migrations,
+      noise_layer_id_migrations,
+      named_noise_expression_id_migrations,
+      autoplace_control_id_migrations,
+      damage_type_id_migrations,
+      recipe_id_migrations,
+      achievement_id_migrations,
+      module_category_id_migrations,
+      equipment_category_id_migrations,
+      mod_settings_id_migrations,
+      trivial_smoke_id_migrations,
+      shortcut_id_migrations,
+    };
+    // all prototype ids read from here on are mapped through the ids of this save
+    r.prototype_registry = PrototypeRegistry::from_migrations(&prototype_migrations);
+    Ok(prototype_migrations)
+  }
+  fn map_write(&self, w: &mut MapSerialiser) -> Result<()> {
+    self.custom_input_id_migrations.map_write(w)?;
+    self.equipment_grid_id_migrations.map_write(w)?;
+    self.item_id_migrations.map_write(w)?;
+    self.tile_id_migrations.map_write(w)?;
+    self.decorative_id_migrations.map_write(w)?;
+    self.technology_id_migrations.map_write(w)?;
+    self.entity_id_migrations.map_write(w)?;
+    self.particle_id_migrations.map_write(w)?;
+    self.recipe_category_id_migrations.map_write(w)?;
+    self.item_sub_group_id_migrations.map_write(w)?;
+    self.item_group_id_migrations.map_write(w)?;
+    self.fluid_id_migrations.map_write(w)?;
+    self.virtual_signal_id_migrations.map_write(w)?;
+    self.ammo_category_id_migrations.map_write(w)?;
+    self.fuel_category_id_migrations.map_write(w)?;
+    self.resource_category_id_migrations.map_write(w)?;
+    self.equipment_id_migrations.map_write(w)?;
+    self.noise_layer_id_migrations.map_write(w)?;
+    self.named_noise_expression_id_migrations.map_write(w)?;
+    self.autoplace_control_id_migrations.map_write(w)?;
+    self.damage_type_id_migrations.map_write(w)?;
+    self.recipe_id_migrations.map_write(w)?;
+    self.achievement_id_migrations.map_write(w)?;
+    self.module_category_id_migrations.map_write(w)?;
+    self.equipment_category_id_migrations.map_write(w)?;
+    self.mod_settings_id_migrations.map_write(w)?;
+    self.trivial_smoke_id_migrations.map_write(w)?;
+    self.shortcut_id_migrations.map_write(w)?;
+
+    // all prototype ids written from here on are mapped through the ids of this save
+    w.prototype_registry = PrototypeRegistry::from_migrations(self);
+    Ok(())
+  }
+}
 
 #[derive(Clone, Debug)]
 pub struct ActiveMigrations<V> {
@@ -934,6 +1446,12 @@ pub struct ForceManager {
   pub force_data_list: [ForceData; 3],
   #[assert_eq(0)] forces_to_delete: u32,  // Vec<(ForceID, ForceID)>
 }
+impl ForceManager {
+  pub fn force_data(&self, force: replay::ForceId) -> Result<&ForceData> {
+    self.force_data_list.iter().find(|force_data| force_data.id == force as ForceId)
+      .ok_or_else(|| crate::Error::custom(format!("force {force:?} not found"), 0))
+  }
+}
 
 type ForceId = u8;
 type ForceSet = u64;
@@ -994,14 +1512,264 @@ pub struct ForceData {
   friends: ForceSet,
   #[vec_u32] pub charts: Vec<(SurfaceIndex, Chart)>,
   #[assert_eq(0)] spawn_positions: u32,  // Vec<(SurfaceIndex, MapPosition)>
-  #[assert_eq(0)] item_production_statistics: u8,  // Option<FlowStatistics>
-  #[assert_eq(0)] fluid_production_statistics: u8,  // Option<FlowStatistics>
-  #[assert_eq(0)] kill_count_statistics: u8,  // Option<FlowStatistics>
+  pub item_production_statistics: Option<FlowStatistics<u64>>,
+  pub fluid_production_statistics: Option<FlowStatistics<f64>>,
+  pub kill_count_statistics: Option<FlowStatistics<u64>>,
   pub build_count_statistics: Option<BuildCountStatistics>,
   custom_color: Color,
   rockets_launched: u32,
   #[assert_eq(0)] items_launched: u8,  // Vec<(u16, u32)>
 }
+impl ForceData {
+  fn technology(&self, technology_id: u16) -> Result<&Technology> {
+    self.custom_prototypes.technologies.iter().filter_map(|t| t.option.as_ref()).find(|(id, _)| *id == technology_id).map(|(_, technology)| technology)
+      .ok_or_else(|| crate::Error::custom(format!("technology {technology_id} not found in force {}", self.name), 0))
+  }
+  fn technology_mut(&mut self, technology_id: u16) -> Result<&mut Technology> {
+    let force_name = &self.name;
+    self.custom_prototypes.technologies.iter_mut().filter_map(|t| t.option.as_mut()).find(|(id, _)| *id == technology_id).map(|(_, technology)| technology)
+      .ok_or_else(|| crate::Error::custom(format!("technology {technology_id} not found in force {force_name}"), 0))
+  }
+  fn recipe_mut(&mut self, recipe_id: u16) -> Result<&mut Recipe> {
+    let force_name = &self.name;
+    self.custom_prototypes.recipes.iter_mut().filter_map(|r| r.option.as_mut()).find(|(id, _)| *id == recipe_id).map(|(_, recipe)| recipe)
+      .ok_or_else(|| crate::Error::custom(format!("recipe {recipe_id} not found in force {force_name}"), 0))
+  }
+
+  pub fn is_technology_researched(&self, technology_id: u16) -> Result<bool> {
+    Ok(self.technology(technology_id)?.research_count > 0)
+  }
+  /// Marks a technology as researched or not, applying or reverting its effects if the state changes.
+  ///
+  /// A technology that becomes researched is removed from the research queue and stops being researched, the next queued one taking its place.
+  pub fn set_technology_researched(&mut self, technology_id: u16, researched: bool) -> Result<()> {
+    let technology = self.technology_mut(technology_id)?;
+    if (technology.research_count > 0) == researched { return Ok(()) }
+    technology.research_count = u32::from(researched);
+    let effects = technology.effects.clone();
+    for effect in &effects {
+      self.apply_modifier(effect, !researched)?;
+    }
+    if researched {
+      let research_manager = &mut self.research_manager;
+      research_manager.research_queue.retain(|&id| id != technology_id);
+      research_manager.switched_research_progress.retain(|&(id, _)| id != technology_id);
+      if research_manager.technology_in_research == technology_id {
+        // from ResearchManager::researchFinished: the next queued research resumes any progress made on it before
+        research_manager.previous_technology_in_research = technology_id;
+        research_manager.technology_in_research = research_manager.research_queue.first().copied().filter(|_| research_manager.research_queue_enabled).unwrap_or(0);
+        let next_technology_id = research_manager.technology_in_research;
+        research_manager.research_progress = research_manager.switched_research_progress.iter().find(|&&(id, _)| id == next_technology_id).map_or(0.0, |&(_, progress)| progress);
+        research_manager.switched_research_progress.retain(|&(id, _)| id != next_technology_id);
+        research_manager.research_state = if next_technology_id == 0 { ResearchState::NotResearching } else { ResearchState::Researching };
+      }
+    }
+    Ok(())
+  }
+
+  /// Technology currently being researched and its progress in [0, 1].
+  pub fn current_research(&self) -> Option<(u16, f64)> {
+    let research_manager = &self.research_manager;
+    if research_manager.technology_in_research == 0 { return None }
+    Some((research_manager.technology_in_research, research_manager.research_progress))
+  }
+  /// Switches the current research, keeping the progress of the previous one for when it is resumed, as the game does.
+  pub fn set_current_research(&mut self, research: Option<(u16, f64)>) -> Result<()> {
+    if let Some((technology_id, _)) = research {
+      if self.is_technology_researched(technology_id)? { return Err(crate::Error::custom(format!("technology {technology_id} is already researched"), 0)) }
+    }
+    let research_manager = &mut self.research_manager;
+    let previous_technology_id = research_manager.technology_in_research;
+    if previous_technology_id != 0 && research_manager.research_progress > 0.0 && research.map(|(id, _)| id) != Some(previous_technology_id) {
+      research_manager.switched_research_progress.retain(|&(id, _)| id != previous_technology_id);
+      research_manager.switched_research_progress.push((previous_technology_id, research_manager.research_progress));
+    }
+    match research {
+      Some((technology_id, progress)) => {
+        research_manager.switched_research_progress.retain(|&(id, _)| id != technology_id);
+        research_manager.technology_in_research = technology_id;
+        research_manager.research_progress = progress;
+        research_manager.research_state = ResearchState::Researching;
+        if research_manager.research_queue_enabled {
+          // the front of the queue is the current research
+          research_manager.research_queue.retain(|&id| id != technology_id);
+          research_manager.research_queue.insert(0, technology_id);
+        }
+      },
+      None => {
+        research_manager.technology_in_research = 0;
+        research_manager.research_progress = 0.0;
+        research_manager.research_state = ResearchState::NotResearching;
+        if research_manager.research_queue_enabled {
+          research_manager.research_queue.retain(|&id| id != previous_technology_id);
+        }
+      },
+    }
+    Ok(())
+  }
+
+  pub fn research_queue(&self) -> &[u16] {
+    &self.research_manager.research_queue
+  }
+  /// Replaces the research queue. With the queue enabled, its first technology becomes the current research, resuming any progress made on it before.
+  pub fn set_research_queue(&mut self, research_queue: Vec<u16>) -> Result<()> {
+    for &technology_id in &research_queue {
+      if self.is_technology_researched(technology_id)? { return Err(crate::Error::custom(format!("technology {technology_id} is already researched"), 0)) }
+    }
+    if self.research_manager.research_queue_enabled {
+      let current_research = self.current_research();
+      match research_queue.first() {
+        Some(&technology_id) if current_research.map(|(id, _)| id) == Some(technology_id) => {},
+        Some(&technology_id) => {
+          let progress = self.research_manager.switched_research_progress.iter().find(|&&(id, _)| id == technology_id).map_or(0.0, |&(_, progress)| progress);
+          self.set_current_research(Some((technology_id, progress)))?;
+        },
+        None => self.set_current_research(None)?,
+      }
+    }
+    self.research_manager.research_queue = research_queue;
+    Ok(())
+  }
+
+  fn apply_modifier(&mut self, modifier: &Modifier, revert: bool) -> Result<()> {
+    if let Modifier::UnlockRecipe(m) = modifier {
+      self.recipe_mut(m.recipe_id)?.enabled = !revert;
+    } else {
+      let mut modifiers = self.modifiers();
+      if revert { modifiers.revert_modifier(modifier) } else { modifiers.apply_modifier(modifier) }
+      self.set_modifiers(&modifiers);
+    }
+    Ok(())
+  }
+}
+
+// Declares ForceModifiers with the given ForceData fields, and the conversions from and to ForceData and JSON.
+macro_rules! force_modifiers {
+  (lists: [$($list:ident),* $(,)?], numbers: [$($number:ident),* $(,)?], flags: [$($flag:ident),* $(,)?]) => {
+    /// All modifiers of a force, as changed by technology effects or scripts.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ForceModifiers {
+      $(pub $list: Vec<f64>,)*
+      $(pub $number: f64,)*
+      $(pub $flag: bool,)*
+    }
+    impl ForceData {
+      pub fn modifiers(&self) -> ForceModifiers {
+        ForceModifiers { $($list: self.$list.clone(),)* $($number: self.$number,)* $($flag: self.$flag,)* }
+      }
+      pub fn set_modifiers(&mut self, modifiers: &ForceModifiers) {
+        $(self.$list = modifiers.$list.clone();)*
+        $(self.$number = modifiers.$number;)*
+        $(self.$flag = modifiers.$flag;)*
+      }
+    }
+    impl ForceModifiers {
+      pub fn to_json(&self) -> serde_json::Value {
+        let mut json = serde_json::Map::new();
+        $(json.insert(stringify!($list).to_owned(), self.$list.iter().map(|&v| modifier_to_json(v)).collect());)*
+        $(json.insert(stringify!($number).to_owned(), modifier_to_json(self.$number));)*
+        $(json.insert(stringify!($flag).to_owned(), serde_json::Value::Bool(self.$flag));)*
+        serde_json::Value::Object(json)
+      }
+      pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        let field = |name: &str| json.get(name).ok_or_else(|| crate::Error::custom(format!("missing force modifier {name}"), 0));
+        let invalid = |name: &str| crate::Error::custom(format!("invalid force modifier {name}"), 0);
+        Ok(ForceModifiers {
+          $($list: field(stringify!($list))?.as_array().ok_or_else(|| invalid(stringify!($list)))?.iter().map(|v| modifier_from_json(v).ok_or_else(|| invalid(stringify!($list)))).collect::<Result<_>>()?,)*
+          $($number: modifier_from_json(field(stringify!($number))?).ok_or_else(|| invalid(stringify!($number)))?,)*
+          $($flag: field(stringify!($flag))?.as_bool().ok_or_else(|| invalid(stringify!($flag)))?,)*
+        })
+      }
+    }
+  };
+}
+force_modifiers! {
+  lists: [ammo_damage_modifiers, gun_speed_modifiers, turret_attack_modifiers],
+  numbers: [
+    worker_robots_speed_modifier, worker_robots_battery_modifier, worker_robots_storage_bonus, laboratory_speed_modifier, laboratory_productivity_bonus,
+    following_robots_lifetime_modifier, manual_crafting_speed_modifier, manual_mining_speed_modifier, running_speed_modifier, artillery_range_modifier,
+    build_distance_bonus, item_drop_distance_bonus, reach_distance_bonus, resource_reach_distance_bonus, item_pickup_distance_bonus, loot_pickup_distance_bonus,
+    character_inventory_slot_count_bonus, character_health_bonus, mining_drill_productivity_bonus, train_braking_force_bonus, inserter_stack_size_bonus,
+    stack_inserter_capacity_bonus, character_logistic_trash_slot_count, maximum_following_robots_count, ghost_time_to_live, deconstruction_time_to_live,
+    max_successful_attempts_per_tick_per_construction_queue, max_failed_attempts_per_tick_per_construction_queue,
+  ],
+  flags: [
+    zoom_to_world_enabled, zoom_to_world_ghost_building_enabled, zoom_to_world_blueprint_enabled, zoom_to_world_deconstruction_planner_enabled,
+    zoom_to_world_upgrade_planner_enabled, zoom_to_world_selection_tool_enabled, character_logistic_requests,
+  ]
+}
+impl ForceModifiers {
+  // from Force::applyModifier, UnlockRecipe doesn't change any modifiers
+  pub fn apply_modifier(&mut self, modifier: &Modifier) {
+    self.add_modifier(modifier, 1.0);
+  }
+  /// Undoes apply_modifier, numeric modifiers are decreased by the same amount, flags are set to the opposite value.
+  pub fn revert_modifier(&mut self, modifier: &Modifier) {
+    self.add_modifier(modifier, -1.0);
+  }
+
+  fn add_modifier(&mut self, modifier: &Modifier, sign: f64) {
+    let flag = |m: &BoolModifier| m.value == (sign > 0.0);
+    match modifier {
+      Modifier::InserterStackSizeBonus(m) => self.inserter_stack_size_bonus += sign * m.value,
+      Modifier::LaboratorySpeed(m) => self.laboratory_speed_modifier += sign * m.value,
+      Modifier::CharacterLogisticTrashSlots(m) => self.character_logistic_trash_slot_count += sign * m.value,
+      Modifier::MaximumFollowingRobotsCount(m) => self.maximum_following_robots_count += sign * m.value,
+      Modifier::WorkerRobotsSpeed(m) => self.worker_robots_speed_modifier += sign * m.value,
+      Modifier::WorkerRobotsStorage(m) => self.worker_robots_storage_bonus += sign * m.value,
+      Modifier::GhostTimeToLive(m) => self.ghost_time_to_live += sign * m.value,
+      Modifier::TurretAttack(m) => *indexed_modifier(&mut self.turret_attack_modifiers, usize::from(m.entity_id)) += sign * m.amount,
+      Modifier::AmmoDamage(m) => *indexed_modifier(&mut self.ammo_damage_modifiers, usize::from(m.id)) += sign * m.amount,
+      Modifier::GunSpeed(m) => *indexed_modifier(&mut self.gun_speed_modifiers, usize::from(m.id)) += sign * m.amount,
+      Modifier::UnlockRecipe(_) => {},
+      Modifier::CharacterCraftingSpeed(m) => self.manual_crafting_speed_modifier += sign * m.value,
+      Modifier::CharacterMiningSpeed(m) => self.manual_mining_speed_modifier += sign * m.value,
+      Modifier::CharacterRunningSpeed(m) => self.running_speed_modifier += sign * m.value,
+      Modifier::CharacterBuildDistance(m) => self.build_distance_bonus += sign * m.value,
+      Modifier::CharacterItemDropDistance(m) => self.item_drop_distance_bonus += sign * m.value,
+      Modifier::CharacterReachDistance(m) => self.reach_distance_bonus += sign * m.value,
+      Modifier::CharacterResourceReachDistance(m) => self.resource_reach_distance_bonus += sign * m.value,
+      Modifier::CharacterItemPickupDistance(m) => self.item_pickup_distance_bonus += sign * m.value,
+      Modifier::CharacterLootPickupDistance(m) => self.loot_pickup_distance_bonus += sign * m.value,
+      Modifier::CharacterInventorySlotsBonus(m) => self.character_inventory_slot_count_bonus += sign * m.value,
+      Modifier::DeconstructionTimeToLive(m) => self.deconstruction_time_to_live += sign * m.value,
+      Modifier::CharacterHealthBonus(m) => self.character_health_bonus += sign * m.value,
+      Modifier::StackInserterCapacityBonus(m) => self.stack_inserter_capacity_bonus += sign * m.value,
+      Modifier::MiningDrillProductivityBonus(m) => self.mining_drill_productivity_bonus += sign * m.value,
+      Modifier::TrainBrakingForceBonus(m) => self.train_braking_force_bonus += sign * m.value,
+      Modifier::ZoomToWorldEnabled(m) => self.zoom_to_world_enabled = flag(m),
+      Modifier::ZoomToWorldGhostBuildingEnabled(m) => self.zoom_to_world_ghost_building_enabled = flag(m),
+      Modifier::ZoomToWorldBlueprintEnabled(m) => self.zoom_to_world_blueprint_enabled = flag(m),
+      Modifier::ZoomToWorldDeconstructionPlannerEnabled(m) => self.zoom_to_world_deconstruction_planner_enabled = flag(m),
+      Modifier::ZoomToWorldSelectionToolEnabled(m) => self.zoom_to_world_selection_tool_enabled = flag(m),
+      Modifier::WorkerRobotsBattery(m) => self.worker_robots_battery_modifier += sign * m.value,
+      Modifier::LaboratoryProductivity(m) => self.laboratory_productivity_bonus += sign * m.value,
+      Modifier::FollowingRobotsLifetime(m) => self.following_robots_lifetime_modifier += sign * m.value,
+      Modifier::MaxSuccessfulAttempsPerTickPerConstructionQueue(m) => self.max_successful_attempts_per_tick_per_construction_queue += sign * m.value,
+      Modifier::MaxFailedAttemptsPerTickPerConstructionQueue(m) => self.max_failed_attempts_per_tick_per_construction_queue += sign * m.value,
+      Modifier::ArtilleryRange(m) => self.artillery_range_modifier += sign * m.value,
+      Modifier::ZoomToWorldUpgradePlannerEnabled(m) => self.zoom_to_world_upgrade_planner_enabled = flag(m),
+      Modifier::CharacterLogisticRequests(m) => self.character_logistic_requests = flag(m),
+    }
+  }
+}
+// modifiers per ammo category, gun or turret are stored densely by id
+fn indexed_modifier(modifiers: &mut Vec<f64>, index: usize) -> &mut f64 {
+  if modifiers.len() <= index {
+    modifiers.resize(index + 1, 0.0);
+  }
+  &mut modifiers[index]
+}
+// non-finite values (e.g. infinite ghost time to live) are stored as strings
+fn modifier_to_json(value: f64) -> serde_json::Value {
+  serde_json::Number::from_f64(value).map_or_else(|| serde_json::Value::String(value.to_string()), serde_json::Value::Number)
+}
+fn modifier_from_json(json: &serde_json::Value) -> Option<f64> {
+  match json {
+    serde_json::Value::String(s) => s.parse().ok(),
+    _ => json.as_f64(),
+  }
+}
 
 #[derive(Debug, MapReadWriteStruct)]
 pub struct EvolutionFactorData {
@@ -1142,11 +1910,11 @@ pub struct Technology {
   #[space_optimized] research_count: u32,
   #[vec_u32] prerequisite_ids: Vec<u16>,
   #[vec_u32] research_unit_ingredients: Vec<(u8, ItemIngredient)>,
-  #[vec_u32] effects: Vec<Modifier>,
+  #[vec_u32] pub effects: Vec<Modifier>,
   num_research_units_count_formula: String,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Modifier {
   // InserterStackSizeBonus	0	
   // LaboratorySpeed	1	
@@ -1204,12 +1972,33 @@ pub enum Modifier {
   AmmoDamage(GunModifier),
   GunSpeed(GunModifier),
   UnlockRecipe(UnlockRecipeModifier),
+  CharacterCraftingSpeed(SimpleModifier),
   CharacterMiningSpeed(SimpleModifier),
+  CharacterRunningSpeed(SimpleModifier),
+  CharacterBuildDistance(SimpleModifier),
+  CharacterItemDropDistance(SimpleModifier),
+  CharacterReachDistance(SimpleModifier),
+  CharacterResourceReachDistance(SimpleModifier),
+  CharacterItemPickupDistance(SimpleModifier),
+  CharacterLootPickupDistance(SimpleModifier),
   CharacterInventorySlotsBonus(SimpleModifier),
+  DeconstructionTimeToLive(SimpleModifier),
+  CharacterHealthBonus(SimpleModifier),
   StackInserterCapacityBonus(SimpleModifier),
   MiningDrillProductivityBonus(SimpleModifier),
   TrainBrakingForceBonus(SimpleModifier),
+  ZoomToWorldEnabled(BoolModifier),
+  ZoomToWorldGhostBuildingEnabled(BoolModifier),
+  ZoomToWorldBlueprintEnabled(BoolModifier),
+  ZoomToWorldDeconstructionPlannerEnabled(BoolModifier),
+  ZoomToWorldSelectionToolEnabled(BoolModifier),
+  WorkerRobotsBattery(SimpleModifier),
+  LaboratoryProductivity(SimpleModifier),
+  FollowingRobotsLifetime(SimpleModifier),
+  MaxSuccessfulAttempsPerTickPerConstructionQueue(SimpleModifier),
+  MaxFailedAttemptsPerTickPerConstructionQueue(SimpleModifier),
   ArtilleryRange(SimpleModifier),
+  ZoomToWorldUpgradePlannerEnabled(BoolModifier),
   CharacterLogisticRequests(BoolModifier),
 }
 impl MapReadWrite for Modifier {
@@ -1228,12 +2017,33 @@ impl MapReadWrite for Modifier {
       0x0a => Ok(Modifier::AmmoDamage(GunModifier::map_read(input)?)),
       0x0c => Ok(Modifier::GunSpeed(GunModifier::map_read(input)?)),
       0x0d => Ok(Modifier::UnlockRecipe(UnlockRecipeModifier::map_read(input)?)),
+      0x0e => Ok(Modifier::CharacterCraftingSpeed(SimpleModifier::map_read(input)?)),
       0x0f => Ok(Modifier::CharacterMiningSpeed(SimpleModifier::map_read(input)?)),
+      0x10 => Ok(Modifier::CharacterRunningSpeed(SimpleModifier::map_read(input)?)),
+      0x11 => Ok(Modifier::CharacterBuildDistance(SimpleModifier::map_read(input)?)),
+      0x12 => Ok(Modifier::CharacterItemDropDistance(SimpleModifier::map_read(input)?)),
+      0x13 => Ok(Modifier::CharacterReachDistance(SimpleModifier::map_read(input)?)),
+      0x14 => Ok(Modifier::CharacterResourceReachDistance(SimpleModifier::map_read(input)?)),
+      0x15 => Ok(Modifier::CharacterItemPickupDistance(SimpleModifier::map_read(input)?)),
+      0x16 => Ok(Modifier::CharacterLootPickupDistance(SimpleModifier::map_read(input)?)),
       0x17 => Ok(Modifier::CharacterInventorySlotsBonus(SimpleModifier::map_read(input)?)),
+      0x18 => Ok(Modifier::DeconstructionTimeToLive(SimpleModifier::map_read(input)?)),
+      0x19 => Ok(Modifier::CharacterHealthBonus(SimpleModifier::map_read(input)?)),
       0x1a => Ok(Modifier::StackInserterCapacityBonus(SimpleModifier::map_read(input)?)),
       0x1c => Ok(Modifier::MiningDrillProductivityBonus(SimpleModifier::map_read(input)?)),
       0x1d => Ok(Modifier::TrainBrakingForceBonus(SimpleModifier::map_read(input)?)),
+      0x1e => Ok(Modifier::ZoomToWorldEnabled(BoolModifier::map_read(input)?)),
+      0x1f => Ok(Modifier::ZoomToWorldGhostBuildingEnabled(BoolModifier::map_read(input)?)),
+      0x20 => Ok(Modifier::ZoomToWorldBlueprintEnabled(BoolModifier::map_read(input)?)),
+      0x21 => Ok(Modifier::ZoomToWorldDeconstructionPlannerEnabled(BoolModifier::map_read(input)?)),
+      0x22 => Ok(Modifier::ZoomToWorldSelectionToolEnabled(BoolModifier::map_read(input)?)),
+      0x24 => Ok(Modifier::WorkerRobotsBattery(SimpleModifier::map_read(input)?)),
+      0x25 => Ok(Modifier::LaboratoryProductivity(SimpleModifier::map_read(input)?)),
+      0x26 => Ok(Modifier::FollowingRobotsLifetime(SimpleModifier::map_read(input)?)),
+      0x27 => Ok(Modifier::MaxSuccessfulAttempsPerTickPerConstructionQueue(SimpleModifier::map_read(input)?)),
+      0x28 => Ok(Modifier::MaxFailedAttemptsPerTickPerConstructionQueue(SimpleModifier::map_read(input)?)),
       0x29 => Ok(Modifier::ArtilleryRange(SimpleModifier::map_read(input)?)),
+      0x2a => Ok(Modifier::ZoomToWorldUpgradePlannerEnabled(BoolModifier::map_read(input)?)),
       0x2c => Ok(Modifier::CharacterLogisticRequests(BoolModifier::map_read(input)?)),
       x => Err(input.stream.error_at(format!("unknown Modifier type {}", x), 1)),
     }
@@ -1251,42 +2061,63 @@ impl MapReadWrite for Modifier {
       Modifier::AmmoDamage(i) => { 0x0a_u8.map_write(input)?; i.map_write(input) }
       Modifier::GunSpeed(i) => { 0x0c_u8.map_write(input)?; i.map_write(input) }
       Modifier::UnlockRecipe(i) => { 0x0d_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterCraftingSpeed(i) => { 0x0e_u8.map_write(input)?; i.map_write(input) }
       Modifier::CharacterMiningSpeed(i) => { 0x0f_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterRunningSpeed(i) => { 0x10_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterBuildDistance(i) => { 0x11_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterItemDropDistance(i) => { 0x12_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterReachDistance(i) => { 0x13_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterResourceReachDistance(i) => { 0x14_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterItemPickupDistance(i) => { 0x15_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterLootPickupDistance(i) => { 0x16_u8.map_write(input)?; i.map_write(input) }
       Modifier::CharacterInventorySlotsBonus(i) => { 0x17_u8.map_write(input)?; i.map_write(input) }
+      Modifier::DeconstructionTimeToLive(i) => { 0x18_u8.map_write(input)?; i.map_write(input) }
+      Modifier::CharacterHealthBonus(i) => { 0x19_u8.map_write(input)?; i.map_write(input) }
       Modifier::StackInserterCapacityBonus(i) => { 0x1a_u8.map_write(input)?; i.map_write(input) }
       Modifier::MiningDrillProductivityBonus(i) => { 0x1c_u8.map_write(input)?; i.map_write(input) }
       Modifier::TrainBrakingForceBonus(i) => { 0x1d_u8.map_write(input)?; i.map_write(input) }
+      Modifier::ZoomToWorldEnabled(i) => { 0x1e_u8.map_write(input)?; i.map_write(input) }
+      Modifier::ZoomToWorldGhostBuildingEnabled(i) => { 0x1f_u8.map_write(input)?; i.map_write(input) }
+      Modifier::ZoomToWorldBlueprintEnabled(i) => { 0x20_u8.map_write(input)?; i.map_write(input) }
+      Modifier::ZoomToWorldDeconstructionPlannerEnabled(i) => { 0x21_u8.map_write(input)?; i.map_write(input) }
+      Modifier::ZoomToWorldSelectionToolEnabled(i) => { 0x22_u8.map_write(input)?; i.map_write(input) }
+      Modifier::WorkerRobotsBattery(i) => { 0x24_u8.map_write(input)?; i.map_write(input) }
+      Modifier::LaboratoryProductivity(i) => { 0x25_u8.map_write(input)?; i.map_write(input) }
+      Modifier::FollowingRobotsLifetime(i) => { 0x26_u8.map_write(input)?; i.map_write(input) }
+      Modifier::MaxSuccessfulAttempsPerTickPerConstructionQueue(i) => { 0x27_u8.map_write(input)?; i.map_write(input) }
+      Modifier::MaxFailedAttemptsPerTickPerConstructionQueue(i) => { 0x28_u8.map_write(input)?; i.map_write(input) }
       Modifier::ArtilleryRange(i) => { 0x29_u8.map_write(input)?; i.map_write(input) }
+      Modifier::ZoomToWorldUpgradePlannerEnabled(i) => { 0x2a_u8.map_write(input)?; i.map_write(input) }
       Modifier::CharacterLogisticRequests(i) => { 0x2c_u8.map_write(input)?; i.map_write(input) }
     }
   }
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct UnlockRecipeModifier {
-  recipe_id: u16,
+  pub recipe_id: u16,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct SimpleModifier {
-  value: f64,
+  pub value: f64,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct BoolModifier {
-  value: bool,
+  pub value: bool,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct GunModifier {
-  id: u8,
-  amount: f64,
+  pub id: u8,
+  pub amount: f64,
 }
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct TurretAttackModifier {
-  entity_id: u16,
-  amount: f64,
+  pub entity_id: u16,
+  pub amount: f64,
 }
 
 #[derive(Debug, MapReadWriteStruct)]
@@ -1430,30 +2261,57 @@ pub enum SubChartPixelCommand {
   ExistingPaletteColor { index: u8, len: u8 },
 }
 
-#[derive(Debug, MapReadWriteStruct)]
-pub struct BuildCountStatistics {
-  precision: [BuildCountStatisticsPrecision; 8],
-  input_running_counts: Vec<(u16, u64)>,
-  output_running_counts: Vec<(u16, u64)>,
-}
-
-#[derive(Debug, MapReadWriteStruct)]
-pub struct BuildCountStatisticsPrecision {
-  #[vec_u32] pub input_elements: Vec<BuildCountStatisticsPrecisionElements>,
-  #[vec_u32] pub output_elements: Vec<BuildCountStatisticsPrecisionElements>,
+pub type BuildCountStatistics = FlowStatistics<u64>;
+pub type PollutionStatistics = FlowStatistics<f64>;
+
+/// Production graph data, per prototype id, of item, fluid, kill, build count and pollution statistics.
+#[derive(Debug, MapReadWriteStruct)]
+pub struct FlowStatistics<T: MapReadWrite + Debug> {
+  pub precision: [FlowStatisticsPrecision; 8],
+  pub input_running_counts: Vec<(u16, T)>,
+  pub output_running_counts: Vec<(u16, T)>,
+}
+impl<T: MapReadWrite + Debug> FlowStatistics<T> {
+  // ticks covered by one sample of each precision index, i.e. 5s, 1m, 10m, 1h, 10h, 50h, 250h and 1000h over 300 samples
+  pub const TICKS_PER_SAMPLE: [u32; 8] = [1, 12, 120, 720, 7200, 36000, 180000, 720000];
+
+  /// Samples of the given precision as of the given tick as CSV, one row per prototype, direction and sample.
+  ///
+  /// Input is production (or kills), output is consumption (or losses). Prototypes without any non-zero sample are left out.
+  /// Samples are ordered from oldest to newest, tick_offset is the (negative) start of each sample relative to the sample the tick is in.
+  pub fn to_csv(&self, precision_index: usize, tick: u32, name: impl Fn(u16) -> String) -> String {
+    use std::fmt::Write;
+    let mut csv = "name,direction,sample,tick_offset,value\n".to_owned();
+    let ticks_per_sample = Self::TICKS_PER_SAMPLE[precision_index];
+    let precision = &self.precision[precision_index];
+    for (direction, elements) in [("input", &precision.input_elements), ("output", &precision.output_elements)] {
+      for (id, element) in elements.iter().enumerate() {
+        if element.elements.iter().all(|&value| value == 0.0) { continue; }
+        let name = name(id as u16);
+        // the samples are a ring buffer, the sample the tick is in is accumulated in f and overwrites the oldest one once complete
+        let len = element.elements.len();
+        let write_position = (tick / ticks_per_sample) as usize % len;
+        for sample in 0..len {
+          let value = element.elements[(write_position + sample) % len];
+          let tick_offset = -((len - sample) as i64 * i64::from(ticks_per_sample));
+          writeln!(csv, "{name},{direction},{sample},{tick_offset},{value}").unwrap();
+        }
+      }
+    }
+    csv
+  }
 }
 
 #[derive(Debug, MapReadWriteStruct)]
-pub struct BuildCountStatisticsPrecisionElements {
-  #[vec_u16] elements: Vec<f32>,
-  f: f64,
+pub struct FlowStatisticsPrecision {
+  #[vec_u32] pub input_elements: Vec<FlowStatisticsPrecisionElements>,  // indexed by prototype id
+  #[vec_u32] pub output_elements: Vec<FlowStatisticsPrecisionElements>,
 }
 
 #[derive(Debug, MapReadWriteStruct)]
-pub struct PollutionStatistics {
-  pub precision: [BuildCountStatisticsPrecision; 8],
-  pub input_running_counts: Vec<(u16, f64)>,
-  pub output_running_counts: Vec<(u16, f64)>,
+pub struct FlowStatisticsPrecisionElements {
+  #[vec_u16] pub elements: Vec<f32>,  // one value per sample
+  pub f: f64,  // accumulated into the current sample
 }
 
 #[derive(Debug, MapReadWriteStruct)]
@@ -1595,7 +2453,7 @@ impl MapReadWrite for Surface {
     self.active_entities_serialisation_helper.map_write(input)?;
     let chunks_len = self.chunks.len() as u32;
     chunks_len.map_write(input)?;
-    self.chunks.iter().map(|c| c.initial_write(input)).collect::<Result<_>>()?;
+    self.chunks.iter().try_for_each(|c| c.initial_write(input))?;
     self.name.map_write(input)?;
     self.compiled_map_gen_settings.map_write(input)?;
     self.path_finders.map_write(input)?;
@@ -1655,8 +2513,8 @@ pub struct Chunk {
   pub planned_update_counts_to_be_loaded: Vec<u32>,
   pub active_when_enemy_is_around: u32,
 
-  pub tiles: [[(Tile, u8); 32]; 32],  // [x][y], u8=TileVariation bits: SSSsVVVV V=variation, s=small, S=size
-  pub entities_to_be_inserted_before_setup: Vec<(Entity, EntityData)>,
+  pub tiles: [[(Prototype<Tile>, u8); 32]; 32],  // [x][y], u8=TileVariation bits: SSSsVVVV V=variation, s=small, S=size
+  pub entities_to_be_inserted_before_setup: Vec<(Prototype<Entity>, EntityData)>,
   pub tick_of_optional_activation: u32,
   pub tick_of_last_change_that_could_affect_charting: u32,
   pub pollution: f64,
@@ -1671,7 +2529,7 @@ impl Chunk {
     let active_when_enemy_is_around = u32::map_read(input)?; assert_eq!(active_when_enemy_is_around, 0);
 
     Ok(Chunk { position, generated_status, military_targets_len, active_entities_serialisation_helper, planned_update_counts_to_be_loaded,
-      active_when_enemy_is_around, tiles: [[(Tile::LabWhite, 0x10); 32]; 32], entities_to_be_inserted_before_setup: vec![], tick_of_optional_activation: 0, tick_of_last_change_that_could_affect_charting: 0, pollution: 0.0 })
+      active_when_enemy_is_around, tiles: [[(Prototype::Known(Tile::LabWhite), 0x10); 32]; 32], entities_to_be_inserted_before_setup: vec![], tick_of_optional_activation: 0, tick_of_last_change_that_could_affect_charting: 0, pollution: 0.0 })
   }
   fn initial_write(&self, input: &mut MapSerialiser) -> Result<()> {
     self.position.map_write(input)?;
@@ -1694,8 +2552,9 @@ impl Chunk {
       let action_type_pos = input.stream.position();
       let next_entity = u16::map_read(input)?;
       if next_entity == 0 { break; }
-      let entity = Entity::from_u16(next_entity).unwrap();
-      self.entities_to_be_inserted_before_setup.push((entity, EntityData::map_read(entity, action_type_pos, input)?));
+      let entity = input.prototype_registry.resolve(u32::from(next_entity));
+      let type_name = input.prototype_registry.table(PrototypeType::Entity).and_then(|table| table.type_name(u32::from(next_entity))).map(str::to_owned);
+      self.entities_to_be_inserted_before_setup.push((entity, EntityData::map_read(entity, type_name.as_deref(), action_type_pos, input)?));
       // println!("Read entity {:?}", self.entities_to_be_inserted_before_setup.last());
     }
     self.tick_of_optional_activation = u32::map_read(input)?;
@@ -1731,8 +2590,9 @@ impl Chunk {
   }
 }
 
-#[derive(Clone, Debug, MapReadWriteTaggedUnion)]
-#[tag_type(Entity)]
+/// Entities without a variant of their own, including ones unknown to `Entity` (e.g. from mods), are read by the type of their
+/// prototype, if the save records it.
+#[derive(Clone, Debug)]
 pub enum EntityData {
   // Nothing,
   Coal(ResourceEntity),
@@ -1742,6 +2602,33 @@ pub enum EntityData {
   CrudeOil(ResourceEntity),
   RockHuge(SimpleEntity),
   DryTree(Tree),
+  Resource(ResourceEntity),
+  SimpleEntity(SimpleEntity),
+  Tree(Tree),
+}
+impl EntityData {
+  fn map_read<R: BufRead + Seek>(entity: Prototype<Entity>, type_name: Option<&str>, entity_pos: u64, r: &mut MapDeserialiser<R>) -> Result<Self> {
+    match (entity, type_name) {
+      (Prototype::Known(Entity::Coal), _) => Ok(EntityData::Coal(ResourceEntity::map_read(r)?)),
+      (Prototype::Known(Entity::CopperOre), _) => Ok(EntityData::CopperOre(ResourceEntity::map_read(r)?)),
+      (Prototype::Known(Entity::IronOre), _) => Ok(EntityData::IronOre(ResourceEntity::map_read(r)?)),
+      (Prototype::Known(Entity::Stone), _) => Ok(EntityData::Stone(ResourceEntity::map_read(r)?)),
+      (Prototype::Known(Entity::CrudeOil), _) => Ok(EntityData::CrudeOil(ResourceEntity::map_read(r)?)),
+      (Prototype::Known(Entity::RockHuge), _) => Ok(EntityData::RockHuge(SimpleEntity::map_read(r)?)),
+      (Prototype::Known(Entity::DryTree), _) => Ok(EntityData::DryTree(Tree::map_read(r)?)),
+      (_, Some("resource")) => Ok(EntityData::Resource(ResourceEntity::map_read(r)?)),
+      (_, Some("simple-entity")) => Ok(EntityData::SimpleEntity(SimpleEntity::map_read(r)?)),
+      (_, Some("tree")) => Ok(EntityData::Tree(Tree::map_read(r)?)),
+      _ => Err(crate::Error::custom(format!("Unsupported entity {:?} of type {:?}", entity, type_name), entity_pos)),
+    }
+  }
+  fn map_write(&self, w: &mut MapSerialiser) -> Result<()> {
+    match self {
+      EntityData::Coal(data) | EntityData::CopperOre(data) | EntityData::IronOre(data) | EntityData::Stone(data) | EntityData::CrudeOil(data) | EntityData::Resource(data) => data.map_write(w),
+      EntityData::RockHuge(data) | EntityData::SimpleEntity(data) => data.map_write(w),
+      EntityData::DryTree(data) | EntityData::Tree(data) => data.map_write(w),
+    }
+  }
 }
 
 #[derive(Clone, Debug, MapReadWriteStruct)]
@@ -1799,7 +2686,7 @@ pub struct EntityCommon {
 }
 
 
-#[derive(Debug, MapReadWriteStruct)]
+#[derive(Clone, Debug, MapReadWriteStruct)]
 pub struct CompiledMapGenSettings {
   pub settings: MapGenSettings,
   pub serialized_data: Vec<u8>,
@@ -1833,3 +2720,329 @@ pub struct MapGenerationManager {
   active: bool,
   force_all_to_lab_grid: bool,
 }
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_property_tree_json_round_trip() {
+    let mut tree = PropertyTree::dictionary(vec![
+      ("zeta".to_owned(), PropertyTree::number(1.5)),
+      ("alpha".to_owned(), PropertyTree::list(vec![PropertyTree::string("a"), PropertyTree::bool(true), PropertyTree::Nothing { any_type_flag: false }])),
+      ("null-string".to_owned(), PropertyTree::String { any_type_flag: false, value: None }),
+      ("any-type".to_owned(), PropertyTree::Number { any_type_flag: true, value: f64::INFINITY }),
+      ("nested".to_owned(), PropertyTree::dictionary(vec![(PROPERTY_TREE_JSON_TAG.to_owned(), PropertyTree::dictionary(vec![]))])),
+    ]);
+    tree.set_mod_setting("my-setting", PropertyTree::number(3.0));
+    assert_eq!(tree.get_mod_setting("my-setting").and_then(PropertyTree::as_number), Some(3.0));
+
+    let json = tree.to_json();
+    assert_eq!(json.as_object().unwrap().keys().collect::<Vec<_>>(), ["zeta", "alpha", "null-string", "any-type", "nested", "my-setting"]);
+    let from_json = PropertyTree::from_json(&serde_json::from_str(&json.to_string()).unwrap()).unwrap();
+
+    let mut w = MapSerialiser::new(MapVersion { version: 0x0001_0001_006b_0000, quality_version: false }).unwrap();
+    tree.map_write(&mut w).unwrap();
+    let expected = w.stream.into_inner().into_inner();
+    let mut w = MapSerialiser::new(MapVersion { version: 0x0001_0001_006b_0000, quality_version: false }).unwrap();
+    from_json.map_write(&mut w).unwrap();
+    let actual = w.stream.into_inner().into_inner();
+    assert_eq!(actual, expected);
+
+    let mut r = MapDeserialiser::new(Cursor::new(&expected[..])).unwrap();
+    assert_eq!(PropertyTree::map_read(&mut r).unwrap().to_json(), json);
+  }
+
+  #[test]
+  fn test_map_exchange_string_round_trip() {
+    // settings read from zeroes have all optional map settings unset, as in a game started with default settings
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let mut bytes = MapSerialiser::new(map_version.clone()).unwrap().stream.into_inner().into_inner();
+    bytes.resize(bytes.len() + 1024, 0);
+    let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+    let mut map_gen_settings = MapGenSettings::map_read(&mut r).unwrap();
+    let mut map_settings = MapSettings::map_read(&mut r).unwrap();
+    map_gen_settings.random_seed = 123456789;
+    map_gen_settings.autoplace_controls.push(("iron-ore".to_owned(), FrequencySizeRichness { frequency: 1.0, size: 2.0, richness: 0.5 }));
+    map_gen_settings.cliff_placement_settings.cliff_name = "cliff".to_owned();
+    map_settings.pollution_settings.enabled = Some(false);
+    map_settings.path_finder_settings.overload_levels = Some(vec![0, 100000, 200000]);
+    map_settings.difficulty_settings.research_queue_setting = ResearchQueueSetting::Never;
+
+    let map_exchange_data = MapExchangeData { map_version, map_gen_settings, map_settings };
+    let map_exchange_string = map_exchange_data.write_map_exchange_string().unwrap();
+    assert!(map_exchange_string.starts_with(">>>") && map_exchange_string.ends_with("<<<"));
+
+    // line breaks as inserted when copying the string out of the game are ignored
+    let (start, end) = map_exchange_string.split_at(20);
+    let read = MapExchangeData::parse_map_exchange_string(&format!("{start}\n{end}")).unwrap();
+    assert_eq!(read.map_version, map_exchange_data.map_version);
+    assert_eq!(read.map_gen_settings.random_seed, 123456789);
+    assert_eq!(read.map_gen_settings.autoplace_controls[0].0, "iron-ore");
+    assert_eq!(read.map_gen_settings.autoplace_controls[0].1.size, 2.0);
+    assert_eq!(read.map_settings.pollution_settings.enabled, Some(false));
+    assert_eq!(read.map_settings.path_finder_settings.overload_levels, Some(vec![0, 100000, 200000]));
+    assert_eq!(read.map_settings.difficulty_settings.research_queue_setting, ResearchQueueSetting::Never);
+    assert_eq!(read.write_map_exchange_string().unwrap(), map_exchange_string);
+
+    let mut reseeded = MapExchangeData::parse_map_exchange_string(&map_exchange_string).unwrap();
+    reseeded.map_gen_settings.random_seed += 1;
+    assert_ne!(reseeded.write_map_exchange_string().unwrap(), map_exchange_string);
+    assert!(MapExchangeData::parse_map_exchange_string(&map_exchange_string[3..]).is_err());
+  }
+
+  #[test]
+  fn test_patch_random_generator() {
+    // the start of a map as read from zeroes, followed by its RNGs and data which doesn't parse
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let mut bytes = MapSerialiser::new(map_version.clone()).unwrap().stream.into_inner().into_inner();
+    let allowed_commands_offset = bytes.len() + 16;  // after the empty scenario location, the flags and the version
+    bytes.resize(bytes.len() + 1024, 0);
+    bytes[allowed_commands_offset] = AllowedCommands::True as u8;
+    let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+    let mut w = MapSerialiser::new(map_version).unwrap();
+    ScenarioExecutionContext::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    MapHeader::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    MapGenSettings::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    MapSettings::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    for i in 0..5 {
+      RandomGenerator::new(i, i + 10, i + 20).map_write(&mut w).unwrap();
+    }
+    let mut map_data = w.stream.into_inner().into_inner();
+    map_data.extend_from_slice(&[0xff; 16]);
+    assert!(MapData::parse_map_data(&map_data).is_err());
+
+    assert_eq!(MapData::read_random_generator(&map_data, RandomGeneratorRole::Entities).unwrap(), RandomGenerator::new(2, 12, 22));
+    let patched = MapData::patch_random_generator(&map_data, RandomGeneratorRole::Entities, &RandomGenerator::new(7, 8, 9)).unwrap();
+    assert_eq!(patched.len(), map_data.len());
+    assert_eq!(MapData::read_random_generator(&patched, RandomGeneratorRole::Entities).unwrap(), RandomGenerator::new(7, 8, 9));
+    for role in [RandomGeneratorRole::Ai, RandomGeneratorRole::Map] {
+      assert_eq!(MapData::read_random_generator(&patched, role).unwrap(), MapData::read_random_generator(&map_data, role).unwrap());
+    }
+    let differing_bytes = map_data.iter().zip(&patched).filter(|(a, b)| a != b).count();
+    assert_eq!(differing_bytes, 3);
+  }
+
+  #[test]
+  fn test_read_map_forces() {
+    // the start of a map as read from zeroes, followed by its forces and data which doesn't parse
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let mut bytes = MapSerialiser::new(map_version.clone()).unwrap().stream.into_inner().into_inner();
+    let allowed_commands_offset = bytes.len() + 16;
+    bytes.resize(bytes.len() + 4096, 0);
+    bytes[allowed_commands_offset] = AllowedCommands::True as u8;
+    let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+    let mut w = MapSerialiser::new(map_version).unwrap();
+    ScenarioExecutionContext::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    MapHeader { update_tick: 12 * 9 + 5, entity_tick: 0, ticks_played: 0 }.map_write(&mut w).unwrap();
+    MapHeader::map_read(&mut r).unwrap();
+    MapGenSettings::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    MapSettings::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    for _ in RandomGeneratorRole::ALL { RandomGenerator::map_read(&mut r).unwrap().map_write(&mut w).unwrap(); }
+    EntityUpdatePausedState::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    PrototypeMigrationList::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    Vec::<PrototypeMigrationListDefinitionMigration>::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    for _ in 0..6 { u32::map_read(&mut r).unwrap().map_write(&mut w).unwrap(); }
+    u64::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    MapModSettings::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    TrainManager::map_read(&mut r).unwrap().map_write(&mut w).unwrap();
+    let force_data_list = std::array::from_fn(|i| {
+      let mut force_data = test_force_data();
+      force_data.id = i as u8 + 1;
+      force_data
+    });
+    let mut force_manager = ForceManager { force_data_list_len: 3, force_data_list, forces_to_delete: 0 };
+    force_manager.force_data_list[0].build_count_statistics = Some(BuildCountStatistics {
+      precision: std::array::from_fn(|_| FlowStatisticsPrecision {
+        input_elements: vec![FlowStatisticsPrecisionElements { elements: vec![10.0, 20.0, 30.0, 40.0], f: 0.0 }],
+        output_elements: vec![],
+      }),
+      input_running_counts: vec![],
+      output_running_counts: vec![],
+    });
+    force_manager.map_write(&mut w).unwrap();
+    let mut map_data = w.stream.into_inner().into_inner();
+    map_data.extend_from_slice(&[0xff; 16]);
+
+    let map_forces = MapForces::read_map_forces(&map_data).unwrap();
+    assert_eq!(map_forces.map_header.update_tick, 12 * 9 + 5);
+    assert_eq!(map_forces.force_data(replay::ForceId::Neutral).unwrap().id, 3);
+    let csv = map_forces.flow_statistics_csv(replay::ForceId::Player, FlowStatisticsType::BuildCount, 1).unwrap();
+    assert_eq!(csv.lines().nth(1), Some("0,input,0,-48,20"));
+    assert!(map_forces.flow_statistics_csv(replay::ForceId::Enemy, FlowStatisticsType::BuildCount, 1).is_err());
+  }
+
+  #[test]
+  fn test_flow_statistics_round_trip() {
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let statistics = BuildCountStatistics {
+      precision: std::array::from_fn(|i| FlowStatisticsPrecision {
+        input_elements: vec![
+          FlowStatisticsPrecisionElements { elements: vec![0.0; 3], f: 0.0 },
+          FlowStatisticsPrecisionElements { elements: vec![1.0, 0.0, i as f32], f: 0.5 },
+        ],
+        output_elements: vec![],
+      }),
+      input_running_counts: vec![(1, 7)],
+      output_running_counts: vec![],
+    };
+    let mut w = MapSerialiser::new(map_version.clone()).unwrap();
+    statistics.map_write(&mut w).unwrap();
+    let bytes = w.stream.into_inner().into_inner();
+    let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+    let read = BuildCountStatistics::map_read(&mut r).unwrap();
+    assert_eq!(read.input_running_counts, [(1, 7)]);
+    assert_eq!(read.precision[2].input_elements[1].elements, [1.0, 0.0, 2.0]);
+    assert_eq!(read.precision[2].input_elements[1].f, 0.5);
+
+    let mut w = MapSerialiser::new(map_version).unwrap();
+    read.map_write(&mut w).unwrap();
+    assert_eq!(w.stream.into_inner().into_inner(), bytes);
+
+    let csv = read.to_csv(1, 0, |id| format!("proto-{id}"));
+    assert_eq!(csv, "name,direction,sample,tick_offset,value\nproto-1,input,0,-36,1\nproto-1,input,1,-24,0\nproto-1,input,2,-12,1\n");
+  }
+
+  #[test]
+  fn test_flow_statistics_csv_wrapped_write_position() {
+    let statistics = BuildCountStatistics {
+      precision: std::array::from_fn(|_| FlowStatisticsPrecision {
+        input_elements: vec![FlowStatisticsPrecisionElements { elements: vec![10.0, 20.0, 30.0, 40.0], f: 0.0 }],
+        output_elements: vec![],
+      }),
+      input_running_counts: vec![],
+      output_running_counts: vec![],
+    };
+    // tick 113 is in sample 9 of precision index 1, which wraps around to position 1, holding the oldest sample
+    let csv = statistics.to_csv(1, 12 * 9 + 5, |id| format!("proto-{id}"));
+    assert_eq!(csv, "name,direction,sample,tick_offset,value\nproto-0,input,0,-48,20\nproto-0,input,1,-36,30\nproto-0,input,2,-24,40\nproto-0,input,3,-12,10\n");
+    // a tick in the same sample gives the same rows
+    assert_eq!(statistics.to_csv(1, 12 * 9 + 11, |id| format!("proto-{id}")), csv);
+    assert_ne!(statistics.to_csv(1, 12 * 10, |id| format!("proto-{id}")), csv);
+  }
+
+  // a force read from zeroes, with unresearched technologies 1 to 3 and the research queue enabled
+  fn test_force_data() -> ForceData {
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let mut bytes = MapSerialiser::new(map_version).unwrap().stream.into_inner().into_inner();
+    let header_len = bytes.len();
+    bytes.resize(header_len + 4096, 0);
+    let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+    let mut force_data = ForceData::map_read(&mut r).unwrap();
+    for technology_id in 1..=3 {
+      let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+      force_data.custom_prototypes.technologies.push(CustomPrototypesOption { option: Some((technology_id, Technology::map_read(&mut r).unwrap())) });
+    }
+    force_data.research_manager.research_queue_enabled = true;
+    force_data
+  }
+
+  #[test]
+  fn test_research_finished_resumes_switched_progress() {
+    let mut force_data = test_force_data();
+    force_data.set_current_research(Some((2, 0.25))).unwrap();
+    force_data.set_current_research(Some((1, 0.5))).unwrap();
+    assert_eq!(force_data.research_manager.switched_research_progress, [(2, 0.25)]);
+    force_data.set_research_queue(vec![1, 2, 3]).unwrap();
+    assert_eq!(force_data.current_research(), Some((1, 0.5)));
+
+    force_data.set_technology_researched(1, true).unwrap();
+    assert!(force_data.is_technology_researched(1).unwrap());
+    assert_eq!(force_data.current_research(), Some((2, 0.25)));
+    assert_eq!(force_data.research_manager.previous_technology_in_research, 1);
+    assert_eq!(force_data.research_queue(), [2, 3]);
+    assert!(force_data.research_manager.switched_research_progress.is_empty());
+
+    force_data.set_technology_researched(2, true).unwrap();
+    assert_eq!(force_data.current_research(), Some((3, 0.0)));
+    assert_eq!(force_data.research_manager.previous_technology_in_research, 2);
+
+    force_data.set_technology_researched(3, true).unwrap();
+    assert_eq!(force_data.current_research(), None);
+    assert_eq!(force_data.research_manager.research_state, ResearchState::NotResearching);
+    assert_eq!(force_data.research_manager.previous_technology_in_research, 3);
+    assert!(force_data.set_current_research(Some((3, 0.0))).is_err());
+  }
+
+  #[test]
+  fn test_research_finished_without_queue() {
+    let mut force_data = test_force_data();
+    force_data.research_manager.research_queue_enabled = false;
+    force_data.set_current_research(Some((1, 0.75))).unwrap();
+    force_data.set_technology_researched(2, true).unwrap();
+    assert_eq!(force_data.current_research(), Some((1, 0.75)));
+    assert_eq!(force_data.research_manager.previous_technology_in_research, 0);
+
+    force_data.set_technology_researched(1, true).unwrap();
+    assert_eq!(force_data.current_research(), None);
+    assert_eq!(force_data.research_manager.previous_technology_in_research, 1);
+
+    force_data.set_technology_researched(1, false).unwrap();
+    assert!(!force_data.is_technology_researched(1).unwrap());
+  }
+
+  #[test]
+  fn test_modifiers_apply_revert_round_trip() {
+    let mut force_data = test_force_data();
+    force_data.ammo_damage_modifiers = vec![0.0; 4];
+    force_data.gun_speed_modifiers = vec![0.0; 4];
+    force_data.turret_attack_modifiers = vec![0.0; 4];
+    force_data.ghost_time_to_live = f64::INFINITY;
+    let original = force_data.modifiers();
+    let effects = vec![
+      Modifier::InserterStackSizeBonus(SimpleModifier { value: 1.0 }),
+      Modifier::CharacterCraftingSpeed(SimpleModifier { value: 0.25 }),
+      Modifier::GhostTimeToLive(SimpleModifier { value: 60.0 }),
+      Modifier::AmmoDamage(GunModifier { id: 2, amount: 0.5 }),
+      Modifier::GunSpeed(GunModifier { id: 1, amount: 0.125 }),
+      Modifier::TurretAttack(TurretAttackModifier { entity_id: 3, amount: 0.75 }),
+      Modifier::ZoomToWorldEnabled(BoolModifier { value: true }),
+    ];
+
+    let mut modifiers = original.clone();
+    for effect in &effects { modifiers.apply_modifier(effect); }
+    assert_eq!(modifiers.inserter_stack_size_bonus, 1.0);
+    assert_eq!(modifiers.manual_crafting_speed_modifier, 0.25);
+    assert_eq!(modifiers.ghost_time_to_live, f64::INFINITY);
+    assert_eq!(modifiers.ammo_damage_modifiers, [0.0, 0.0, 0.5, 0.0]);
+    assert_eq!(modifiers.gun_speed_modifiers, [0.0, 0.125, 0.0, 0.0]);
+    assert_eq!(modifiers.turret_attack_modifiers, [0.0, 0.0, 0.0, 0.75]);
+    assert!(modifiers.zoom_to_world_enabled);
+    assert_eq!(ForceModifiers::from_json(&modifiers.to_json()).unwrap(), modifiers);
+    for effect in effects.iter().rev() { modifiers.revert_modifier(effect); }
+    assert_eq!(modifiers, original);
+
+    // indexed modifiers grow to cover ids beyond the stored ones
+    modifiers.apply_modifier(&Modifier::AmmoDamage(GunModifier { id: 5, amount: 0.5 }));
+    assert_eq!(modifiers.ammo_damage_modifiers, [0.0, 0.0, 0.0, 0.0, 0.0, 0.5]);
+  }
+
+  #[test]
+  fn test_technology_effects_apply_revert_round_trip() {
+    let mut force_data = test_force_data();
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let mut bytes = MapSerialiser::new(map_version).unwrap().stream.into_inner().into_inner();
+    bytes.resize(bytes.len() + 64, 0);
+    let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+    force_data.custom_prototypes.recipes.push(CustomPrototypesOption { option: Some((7, Recipe::map_read(&mut r).unwrap())) });
+    force_data.technology_mut(1).unwrap().effects = vec![
+      Modifier::UnlockRecipe(UnlockRecipeModifier { recipe_id: 7 }),
+      Modifier::CharacterMiningSpeed(SimpleModifier { value: 0.5 }),
+      Modifier::CharacterLogisticRequests(BoolModifier { value: true }),
+    ];
+    let original = force_data.modifiers();
+
+    force_data.set_technology_researched(1, true).unwrap();
+    assert!(force_data.recipe_mut(7).unwrap().enabled);
+    assert_eq!(force_data.modifiers().manual_mining_speed_modifier, 0.5);
+    assert!(force_data.modifiers().character_logistic_requests);
+    // setting the same state again doesn't apply the effects twice
+    force_data.set_technology_researched(1, true).unwrap();
+    assert_eq!(force_data.modifiers().manual_mining_speed_modifier, 0.5);
+
+    force_data.set_technology_researched(1, false).unwrap();
+    assert!(!force_data.recipe_mut(7).unwrap().enabled);
+    assert_eq!(force_data.modifiers(), original);
+  }
+}
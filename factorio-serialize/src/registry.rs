@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::OnceLock;
+
+use crate::constants::{Achievement, Decorative, Entity, Equipment, Fluid, Item, ItemGroup, Recipe, Technology, Tile, VirtualSignal};
+use crate::map::{ActiveMigrations, PrototypeMigrationList};
+
+
+/// The kinds of prototypes which are assigned numeric ids, one per list in PrototypeMigrationList.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PrototypeType {
+  CustomInput,
+  EquipmentGrid,
+  Item,
+  Tile,
+  Decorative,
+  Technology,
+  Entity,
+  Particle,
+  RecipeCategory,
+  ItemSubGroup,
+  ItemGroup,
+  Fluid,
+  VirtualSignal,
+  AmmoCategory,
+  FuelCategory,
+  ResourceCategory,
+  Equipment,
+  NoiseLayer,
+  NamedNoiseExpression,
+  AutoplaceControl,
+  DamageType,
+  Recipe,
+  Achievement,
+  ModuleCategory,
+  EquipmentCategory,
+  ModSettings,
+  TrivialSmoke,
+  Shortcut,
+}
+
+/// A statically known prototype enum from `constants`, whose discriminants are the ids of the version it was exported from.
+pub trait PrototypeId: Copy + Debug + 'static {
+  const PROTOTYPE_TYPE: PrototypeType;
+  fn all() -> &'static [Self];
+  fn from_static_id(id: u32) -> Option<Self>;
+  fn static_id(self) -> u32;
+  fn prototype_name(self) -> &'static str;
+}
+
+/// A prototype referenced by its id in a save. Prototypes unknown to the static enum (e.g. from mods or other versions) keep the id
+/// of the save, their name can be looked up in the registry.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Prototype<T> {
+  Known(T),
+  Unknown(u32),
+}
+impl<T: PrototypeId> Prototype<T> {
+  pub fn known(self) -> Option<T> {
+    match self {
+      Prototype::Known(value) => Some(value),
+      Prototype::Unknown(_) => None,
+    }
+  }
+  pub fn name(self, registry: &PrototypeRegistry) -> Option<&str> {
+    match self {
+      Prototype::Known(value) => Some(value.prototype_name()),
+      Prototype::Unknown(id) => registry.name(T::PROTOTYPE_TYPE, id),
+    }
+  }
+}
+impl<T> From<T> for Prototype<T> {
+  fn from(value: T) -> Self {
+    Prototype::Known(value)
+  }
+}
+
+/// Id assignments of a single prototype type, as found in a save.
+#[derive(Clone, Debug, Default)]
+pub struct PrototypeIdTable {
+  by_id: HashMap<u32, (String, String)>,  // id -> (prototype type name, prototype name)
+  by_name: HashMap<String, u32>,
+  save_to_static: HashMap<u32, u32>,
+  static_to_save: HashMap<u32, u32>,
+}
+impl PrototypeIdTable {
+  fn from_migrations<V: Copy>(migrations: &ActiveMigrations<V>, static_ids: Option<&HashMap<&'static str, u32>>) -> Self where u32: From<V> {
+    let mut table = PrototypeIdTable::default();
+    for (type_name, mapping) in &migrations.mappings {
+      for (name, id) in mapping {
+        let id = u32::from(*id);
+        table.by_id.insert(id, (type_name.clone(), name.clone()));
+        table.by_name.insert(name.clone(), id);
+        if let Some(&static_id) = static_ids.and_then(|static_ids| static_ids.get(name.as_str())) {
+          table.save_to_static.insert(id, static_id);
+          table.static_to_save.insert(static_id, id);
+        }
+      }
+    }
+    table
+  }
+
+  pub fn id(&self, name: &str) -> Option<u32> {
+    self.by_name.get(name).copied()
+  }
+  pub fn name(&self, id: u32) -> Option<&str> {
+    self.by_id.get(&id).map(|(_, name)| name.as_str())
+  }
+  pub fn type_name(&self, id: u32) -> Option<&str> {
+    self.by_id.get(&id).map(|(type_name, _)| type_name.as_str())
+  }
+  pub fn len(&self) -> usize {
+    self.by_id.len()
+  }
+  pub fn is_empty(&self) -> bool {
+    self.by_id.is_empty()
+  }
+  /// All (id, prototype type name, prototype name) entries, sorted by id.
+  pub fn entries(&self) -> Vec<(u32, &str, &str)> {
+    let mut entries: Vec<_> = self.by_id.iter().map(|(&id, (type_name, name))| (id, type_name.as_str(), name.as_str())).collect();
+    entries.sort();
+    entries
+  }
+}
+
+/// Maps numeric prototype ids of a specific save to prototype names and to the static enums in `constants`.
+///
+/// Prototype types without a table (all of them in the default registry) pass ids through unchanged, i.e. assume the save uses the ids of the static enums.
+#[derive(Clone, Debug, Default)]
+pub struct PrototypeRegistry {
+  tables: HashMap<PrototypeType, PrototypeIdTable>,
+}
+impl PrototypeRegistry {
+  pub fn from_migrations(migrations: &PrototypeMigrationList) -> Self {
+    let mut tables = HashMap::new();
+    insert_table(&mut tables, PrototypeType::CustomInput, &migrations.custom_input_id_migrations);
+    insert_table(&mut tables, PrototypeType::EquipmentGrid, &migrations.equipment_grid_id_migrations);
+    insert_table(&mut tables, PrototypeType::Item, &migrations.item_id_migrations);
+    insert_table(&mut tables, PrototypeType::Tile, &migrations.tile_id_migrations);
+    insert_table(&mut tables, PrototypeType::Decorative, &migrations.decorative_id_migrations);
+    insert_table(&mut tables, PrototypeType::Technology, &migrations.technology_id_migrations);
+    insert_table(&mut tables, PrototypeType::Entity, &migrations.entity_id_migrations);
+    insert_table(&mut tables, PrototypeType::Particle, &migrations.particle_id_migrations);
+    insert_table(&mut tables, PrototypeType::RecipeCategory, &migrations.recipe_category_id_migrations);
+    insert_table(&mut tables, PrototypeType::ItemSubGroup, &migrations.item_sub_group_id_migrations);
+    insert_table(&mut tables, PrototypeType::ItemGroup, &migrations.item_group_id_migrations);
+    insert_table(&mut tables, PrototypeType::Fluid, &migrations.fluid_id_migrations);
+    insert_table(&mut tables, PrototypeType::VirtualSignal, &migrations.virtual_signal_id_migrations);
+    insert_table(&mut tables, PrototypeType::AmmoCategory, &migrations.ammo_category_id_migrations);
+    insert_table(&mut tables, PrototypeType::FuelCategory, &migrations.fuel_category_id_migrations);
+    insert_table(&mut tables, PrototypeType::ResourceCategory, &migrations.resource_category_id_migrations);
+    insert_table(&mut tables, PrototypeType::Equipment, &migrations.equipment_id_migrations);
+    insert_table(&mut tables, PrototypeType::NoiseLayer, &migrations.noise_layer_id_migrations);
+    insert_table(&mut tables, PrototypeType::NamedNoiseExpression, &migrations.named_noise_expression_id_migrations);
+    insert_table(&mut tables, PrototypeType::AutoplaceControl, &migrations.autoplace_control_id_migrations);
+    insert_table(&mut tables, PrototypeType::DamageType, &migrations.damage_type_id_migrations);
+    insert_table(&mut tables, PrototypeType::Recipe, &migrations.recipe_id_migrations);
+    insert_table(&mut tables, PrototypeType::Achievement, &migrations.achievement_id_migrations);
+    insert_table(&mut tables, PrototypeType::ModuleCategory, &migrations.module_category_id_migrations);
+    insert_table(&mut tables, PrototypeType::EquipmentCategory, &migrations.equipment_category_id_migrations);
+    insert_table(&mut tables, PrototypeType::ModSettings, &migrations.mod_settings_id_migrations);
+    insert_table(&mut tables, PrototypeType::TrivialSmoke, &migrations.trivial_smoke_id_migrations);
+    insert_table(&mut tables, PrototypeType::Shortcut, &migrations.shortcut_id_migrations);
+    PrototypeRegistry { tables }
+  }
+
+  pub fn table(&self, prototype_type: PrototypeType) -> Option<&PrototypeIdTable> {
+    self.tables.get(&prototype_type)
+  }
+  pub fn id(&self, prototype_type: PrototypeType, name: &str) -> Option<u32> {
+    self.table(prototype_type)?.id(name)
+  }
+  pub fn name(&self, prototype_type: PrototypeType, id: u32) -> Option<&str> {
+    self.table(prototype_type)?.name(id)
+  }
+
+  /// Converts an id used in the save into the corresponding static enum value, if the prototype is known to it.
+  pub fn to_static<T: PrototypeId>(&self, id: u32) -> Option<T> {
+    match self.table(T::PROTOTYPE_TYPE) {
+      Some(table) => T::from_static_id(*table.save_to_static.get(&id)?),
+      None => T::from_static_id(id),
+    }
+  }
+  /// Resolves an id used in the save, keeping the raw id for prototypes unknown to the static enum.
+  pub fn resolve<T: PrototypeId>(&self, id: u32) -> Prototype<T> {
+    self.to_static(id).map_or(Prototype::Unknown(id), Prototype::Known)
+  }
+  /// Converts a static enum value into the id used in the save, if the prototype exists in it.
+  pub fn to_save_id<T: PrototypeId>(&self, value: T) -> Option<u32> {
+    match self.table(T::PROTOTYPE_TYPE) {
+      Some(table) => table.static_to_save.get(&value.static_id()).copied(),
+      None => Some(value.static_id()),
+    }
+  }
+  pub fn save_id<T: PrototypeId>(&self, prototype: Prototype<T>) -> Option<u32> {
+    match prototype {
+      Prototype::Known(value) => self.to_save_id(value),
+      Prototype::Unknown(id) => Some(id),
+    }
+  }
+}
+
+// saves without migration information (e.g. cleaned up template saves) use the static ids
+fn insert_table<V: Copy>(tables: &mut HashMap<PrototypeType, PrototypeIdTable>, prototype_type: PrototypeType, migrations: &ActiveMigrations<V>) where u32: From<V> {
+  if !migrations.mappings.is_empty() {
+    tables.insert(prototype_type, PrototypeIdTable::from_migrations(migrations, static_ids(prototype_type)));
+  }
+}
+// names and ids of the static enums, by prototype type
+fn static_ids(prototype_type: PrototypeType) -> Option<&'static HashMap<&'static str, u32>> {
+  static STATIC_IDS: OnceLock<HashMap<PrototypeType, HashMap<&'static str, u32>>> = OnceLock::new();
+  STATIC_IDS.get_or_init(|| HashMap::from([
+    static_ids_of::<Achievement>(),
+    static_ids_of::<Decorative>(),
+    static_ids_of::<Entity>(),
+    static_ids_of::<Equipment>(),
+    static_ids_of::<Fluid>(),
+    static_ids_of::<Item>(),
+    static_ids_of::<ItemGroup>(),
+    static_ids_of::<Recipe>(),
+    static_ids_of::<Technology>(),
+    static_ids_of::<Tile>(),
+    static_ids_of::<VirtualSignal>(),
+  ])).get(&prototype_type)
+}
+fn static_ids_of<T: PrototypeId>() -> (PrototypeType, HashMap<&'static str, u32>) {
+  (T::PROTOTYPE_TYPE, T::all().iter().map(|p| (p.prototype_name(), p.static_id())).collect())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_renumbered_ids() {
+    let mut migrations = ActiveMigrations::<u16> { mappings: vec![] };
+    migrations.mappings.push(("simple-entity".to_owned(), vec![("rock-huge".to_owned(), 7)]));
+    migrations.mappings.push(("tree".to_owned(), vec![("dry-tree".to_owned(), 3), ("modded-tree".to_owned(), 4)]));
+    let mut registry = PrototypeRegistry::default();
+    registry.tables.insert(PrototypeType::Entity, PrototypeIdTable::from_migrations(&migrations, static_ids(PrototypeType::Entity)));
+
+    assert_eq!(registry.to_static::<Entity>(7), Some(Entity::RockHuge));
+    assert_eq!(registry.to_static::<Entity>(3), Some(Entity::DryTree));
+    assert_eq!(registry.to_static::<Entity>(4), None);
+    assert_eq!(registry.to_save_id(Entity::RockHuge), Some(7));
+    assert_eq!(registry.to_save_id(Entity::IronOre), None);
+    assert_eq!(registry.name(PrototypeType::Entity, 4), Some("modded-tree"));
+    assert_eq!(registry.id(PrototypeType::Entity, "dry-tree"), Some(3));
+
+    // unknown prototypes keep their save id
+    let modded_tree = registry.resolve::<Entity>(4);
+    assert_eq!(modded_tree, Prototype::Unknown(4));
+    assert_eq!(modded_tree.name(&registry), Some("modded-tree"));
+    assert_eq!(registry.save_id(modded_tree), Some(4));
+    assert_eq!(registry.resolve::<Entity>(7), Prototype::Known(Entity::RockHuge));
+
+    // types without a table keep using the static ids
+    assert_eq!(registry.to_save_id(Tile::Water), Some(Tile::Water as u32));
+  }
+}
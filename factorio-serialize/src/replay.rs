@@ -13,6 +13,7 @@ use num_traits::ToPrimitive;
 
 use crate::structs::BoundingBox;
 use crate::structs::Vector;
+use crate::FixedPoint32_8;
 use crate::MapPosition;
 use crate::Reader;
 use crate::Result;
@@ -29,6 +30,7 @@ use crate::constants::Recipe;
 use crate::constants::Technology;
 use crate::constants::Tile;
 use crate::constants::VirtualSignal;
+use crate::registry::PrototypeRegistry;
 
 
 
@@ -40,7 +42,11 @@ impl ReplayData {
     Self { actions }
   }
   pub fn parse_replay_data(replay_data: &[u8]) -> Result<ReplayData> {
+    Self::parse_replay_data_with_registry(replay_data, PrototypeRegistry::default())
+  }
+  pub fn parse_replay_data_with_registry(replay_data: &[u8], prototype_registry: PrototypeRegistry) -> Result<ReplayData> {
     let mut replay_deserialiser = ReplayDeserialiser::new(Cursor::new(replay_data))?;
+    replay_deserialiser.prototype_registry = prototype_registry;
 
     let mut actions = vec![];
     while !replay_deserialiser.stream.is_at_eof().unwrap() {
@@ -52,7 +58,11 @@ impl ReplayData {
   }
 
   pub fn write_replay_data(&self) -> Result<Vec<u8>> {
+    self.write_replay_data_with_registry(PrototypeRegistry::default())
+  }
+  pub fn write_replay_data_with_registry(&self, prototype_registry: PrototypeRegistry) -> Result<Vec<u8>> {
     let mut replay_serialiser = ReplaySerialiser::new()?;
+    replay_serialiser.prototype_registry = prototype_registry;
 
     for input_action in &self.actions {
       input_action.replay_write(&mut replay_serialiser).unwrap();
@@ -71,6 +81,7 @@ impl std::fmt::Debug for ReplayData {
 
 pub struct ReplayDeserialiser<R: BufRead + Seek> {
   pub stream: Reader<R>,
+  pub prototype_registry: PrototypeRegistry,
 }
 impl<R: BufRead + Seek> ReplayDeserialiser<R> {
   pub fn new(replay_data: R) -> Result<ReplayDeserialiser<R>> {
@@ -78,11 +89,13 @@ impl<R: BufRead + Seek> ReplayDeserialiser<R> {
 
     Ok(ReplayDeserialiser {
       stream,
+      prototype_registry: PrototypeRegistry::default(),
     })
   }
 }
 pub struct ReplaySerialiser {
   pub stream: Writer<Cursor<Vec<u8>>>,
+  pub prototype_registry: PrototypeRegistry,
 }
 impl ReplaySerialiser{
   fn new() -> Result<ReplaySerialiser> {
@@ -90,6 +103,7 @@ impl ReplaySerialiser{
 
     Ok(ReplaySerialiser {
       stream,
+      prototype_registry: PrototypeRegistry::default(),
     })
   }
 }
@@ -215,7 +229,7 @@ impl <A: ReplayReadWrite, B: ReplayReadWrite> ReplayReadWrite for (A, B) {
 
 
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct InputAction {
   update_tick: u32,
   player_index: u16,
@@ -225,6 +239,15 @@ impl InputAction {
   pub fn new(update_tick: u32, player_index: u16, action: InputActionData) -> Self {
     Self { update_tick, player_index, action }
   }
+  pub fn update_tick(&self) -> u32 {
+    self.update_tick
+  }
+  pub fn player_index(&self) -> u16 {
+    self.player_index
+  }
+  pub fn action(&self) -> &InputActionData {
+    &self.action
+  }
 }
 impl ReplayReadWrite for InputAction {
   fn replay_read<R: BufRead + Seek>(input: &mut ReplayDeserialiser<R>) -> Result<Self> {
@@ -247,7 +270,7 @@ impl ReplayReadWrite for InputAction {
 }
 
 
-#[derive(Debug, ReplayReadWriteTaggedUnion)]
+#[derive(Clone, Debug, ReplayReadWriteTaggedUnion)]
 #[tag_type(InputActionType)]
 pub enum InputActionData {
   Nothing,  // 0,
@@ -501,7 +524,7 @@ pub enum InputActionData {
   GuiLeave(u32),  // 249
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct PingCoordinates {
   position: MapPosition,
   surface_name: String,
@@ -513,7 +536,7 @@ pub enum PlayerInputMethod {
   GameController = 1,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ByteColor {
   r: u8,
   g: u8,
@@ -521,14 +544,14 @@ pub struct ByteColor {
   a: u8,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct TranslationResultDataEntry {
   localised_string: LocalisedString,
   result: String,
   translated: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct LocalisedString {
   key: String,
   mode: LocalisedStringMode,
@@ -545,13 +568,13 @@ pub enum LocalisedStringMode {
   FallbackGroup = 4,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct LuaShortcutData {
   player: u16,
   prototype_name: String,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct AdminActionData {
   player_index: u16,
   username: String,
@@ -578,7 +601,7 @@ pub enum AdminActionDataType {
   Delete = 13,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct MultiplayerConfigSettings {
   name: String,
   description: String,
@@ -599,7 +622,7 @@ pub struct MultiplayerConfigSettings {
   tags: Option<Vec<String>>,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ServerGameVisibility {
   public_game: bool,
   steam_game: bool,
@@ -631,33 +654,33 @@ pub enum DisconnectReason {
   SwitchingServers = 11,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct QuickBarSetSelectedPageParameters {
   main_window_row: u8, // top or bottom
   new_selected_page: u8, // 0-9
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SelectMapperSlotParameters {
   id: UpgradeId,
   index: u16,
   is_to: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct DragWaitConditionListBoxData {
   from: u32,
   to: u32,
   schedule_index: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct DragListBoxData {
   from: u32,
   to: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ChooseElemId {
   item: Item,
   entity: Entity,
@@ -672,13 +695,13 @@ pub struct ChooseElemId {
   technology: Technology,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ScriptDataTooLarge {
   size: u32,
   max_size: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ImportBlueprintStringData {
   string_data: String,
   import_as_clipboard: bool,
@@ -686,7 +709,7 @@ pub struct ImportBlueprintStringData {
   not_from_chat: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct EditPermissionGroupParameters {
   #[space_optimized] group_id: u32,
   player_index: u16,
@@ -708,7 +731,7 @@ pub enum EditPermissionGroupType {
   EditGroupName = 7,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct CustomChartTagData {
   tag_number: u32,
   name: String,
@@ -716,7 +739,7 @@ pub struct CustomChartTagData {
   position: MapPosition,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct EntityEnergyPropertyChangedData {
   typ: EnergyPropertyType,
   value: f64,
@@ -730,12 +753,12 @@ pub enum EnergyPropertyType {
   PowerUsage = 2,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ModSettingsChangedData {
   #[vec_u32] settings: Vec<ModSetting>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum ModSetting {
   BoolSetting(String, bool),
   DoubleSetting(String, f64),
@@ -806,7 +829,7 @@ impl ReplayReadWrite for ModSetting {
   }
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct InfinityPipeFilterData {
   fluid: Fluid,
   mode: InfinityFilterMode,
@@ -814,7 +837,7 @@ pub struct InfinityPipeFilterData {
   temperature: f64,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct InfinityContainerFilterItemData {
   item: Item,
   mode: InfinityFilterMode,
@@ -832,21 +855,21 @@ pub enum InfinityFilterMode {
   Remove = 4,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ServerCommandData {
   command: String,
   id: u32,
   connection_id: u64,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct TrainWaitConditionData {
   schedule_index: u32,
   condition_index: u32,
   condition: WaitCondition,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct WaitCondition {
   typ: WaitConditionType,
   compare_type: WaitConditionComparisonType,
@@ -861,20 +884,20 @@ pub enum WaitConditionComparisonType {
   Or = 1,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct VehicleAutomaticTargetingParameters {
   auto_target_without_gunner: bool,
   auto_target_with_gunner: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ProgrammableSpeakerCircuitParameters {
   signal_value_is_pitch: bool,
   selected_instrument_id: u32,
   selected_note_id: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ProgrammableSpeakerAlertParameters {
   show_alert: bool,
   show_on_map: bool,
@@ -882,14 +905,14 @@ pub struct ProgrammableSpeakerAlertParameters {
   alert_message: String,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ProgrammableSpeakerParameters {
   playback_volume: f64,
   playback_globally: bool,
   allow_polyphony: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ArithmeticCombinatorParameters {
   first_signal_id: SignalId,
   second_signal_id: SignalId,
@@ -917,45 +940,45 @@ pub enum ArithmeticCombinatorParametersOperation {
   XOR = 10,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ImportBlueprintsFilteredParameters {
   filter: Item,
   personal_shelf: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct EditBlueprintToolPreviewData {
   label: String,
   description: String,
   icons: Vec<SignalId>,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct UpgradeOpenedBlueprintByItemParameters {
   upgrade_record_id: BlueprintRecordId,
   upgrade: bool,
   setup_data: Option<SetupBlueprintData>,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SendSpidertronParameters {
   position: MapPosition,
   flags: u8,  // append, follow_command
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct GuiGenericChangedData<T: ReplayReadWrite> {
   gui_changed_data: GuiChangedData,
   value: T,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct MarketOfferData {
   slot_index: u32,
   count: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct GuiChangedData {
   pub gui_element_index: u32,
   pub button: u16,  // MouseButton bit field,
@@ -964,20 +987,20 @@ pub struct GuiChangedData {
   pub is_shift: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct LogisticFilterSignalData {
   signal: SignalId,
   filter_index: u16,
   count: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SignalData {
   pub signal_id: SignalId,
   pub signal_index: u16,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct AddTrainStationData {
   name: String,
   rail_position: MapPosition,
@@ -985,21 +1008,21 @@ pub struct AddTrainStationData {
   for_vehicle: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BlueprintRecordLocation {
   shelf_index: Option<u16>,
   #[conditional_or_default(shelf_index.is_none())] parent_book_id: BlueprintRecordId,
   slot_index: u16,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct LogisticFilterItemData {
   item: Item,
   filter_index: u16,
   count: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct TrainWaitCondition {
   action: TrainWaitConditionAction,
   add_type: WaitConditionType,
@@ -1030,20 +1053,20 @@ pub enum WaitConditionType {
   PassengerNotPresent = 9,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BuildRailData {
   mode: RailBuildingMode,
   path: RailPathSpecification,
   alternative_build: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct RailPathSpecification {
   starting_point: RailPlanFinderLocation,
   buffer: ExtendedBitBuffer,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ExtendedBitBuffer {
   bits: u32,
   data: Vec<u32>,
@@ -1059,13 +1082,13 @@ impl ReplayReadWrite for ExtendedBitBuffer {
   }
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct RailPlanFinderLocation {
   position: TilePosition,
   direction: Direction,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct RailPathFinderLocation {
 }
 
@@ -1075,7 +1098,7 @@ pub enum RailBuildingMode {
   Ghost = 1,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct RidingState {
   pub direction: RidingDirection,
   pub acceleration_state: RidingAccelerationState,
@@ -1098,7 +1121,7 @@ pub enum RidingAccelerationState {
   Reversing = 3,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BuildTerrainParameters {
   pub position: MapPosition,
   pub direction: Direction,
@@ -1108,25 +1131,25 @@ pub struct BuildTerrainParameters {
   pub skip_fog_of_war: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct TransferBlueprintData {
   record_id: BlueprintRecordId,
   raw_blueprint_data: Vec<u8>,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BlueprintTransferQueueUpdateData {
   #[vec_u32] records: Vec<BlueprintTransferQueueUpdateDataRecord>,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BlueprintTransferQueueUpdateDataRecord {
   id: u32,
   size: u32,
 }
 
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct DeciderCombinatorParameters {
   first_signal_id: SignalId,
   second_signal_id: SignalId,
@@ -1137,19 +1160,19 @@ pub struct DeciderCombinatorParameters {
   second_signal_is_constant: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BehaviorModeOfOperationParameters {
   mode_of_operation: u8,
   enabled: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct CircuitConditionParameters {
   pub circuit_index: u8,
   pub condition: CircuitCondition,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct CircuitCondition {
   pub comparator: Comparison,
   pub first_signal: SignalId,
@@ -1169,26 +1192,26 @@ pub enum Comparison {
   NotEqual = 5,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct TechnologyWithCount {
-  technology: Technology,
-  count: u32,
+  pub technology: Technology,
+  pub count: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct UpgradeOpenedBlueprintByRecordParameters {
   upgrade_record_id: BlueprintRecordId,
   upgrade: bool,
   setup_data: Option<SetupBlueprintData>,
 }
 
-#[derive(Debug, Default, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, Default, ReplayReadWriteStruct)]
 pub struct BlueprintRecordId {
   player_index: u16,
   id: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SetupBlueprintData {
   label_data: ItemLabelData,
   description: String,
@@ -1207,14 +1230,14 @@ pub struct SetupBlueprintData {
   #[assert_eq(0)] excluded_tiles: u8,  // loadCompactedSortedIndices
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ItemLabelData {
   label: String,
   label_color: Color,
   allow_manual_label_change: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct Color {
   r: f32,
   g: f32,
@@ -1222,7 +1245,7 @@ pub struct Color {
   a: f32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SelectSlotParameters<T: ReplayReadWrite> {
   id: T,
   index: u16,
@@ -1236,7 +1259,7 @@ pub enum GameRenderMode {
   ChartZoomedIn = 3,
 }
 
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct SetSplitterPriorityData {
   input_priority: SplitterPriority,
   output_priority: SplitterPriority,
@@ -1260,13 +1283,13 @@ pub enum SplitterPriority {
   Right = 2,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SelectAreaData {
   pub bounding_box: BoundingBox,
   pub skip_fog_of_war: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ShootingState {
   pub state: ShootingStateState,
   pub target: MapPosition,
@@ -1279,27 +1302,27 @@ pub enum ShootingStateState {
   ShootingSelected = 2,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct CancelCraftOrder {
   pub crafting_index: u16,
   pub count: u32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct QuickBarSetSlotParameters {
   pub target_quick_bar_slot: u16,
   pub item_to_use: ItemStackTargetSpecification,
   pub currently_selected_quick_bar_slot: u16,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct QuickBarPickSlotParameters {
   pub location: u16,
   pub pick_ghost_cursor: bool,
   pub cursor_split: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BuildParameters {
   pub position: MapPosition,
   pub direction: Direction,
@@ -1308,7 +1331,7 @@ pub struct BuildParameters {
   pub flags: u8,  // 0:allow_belt_power_replace, 1:shiftBuild, 2:skipFogOfWar, 3+4:flip, 5:smartBeltBuilding
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SmartPipetteData {
   pub entity_id: Entity,
   pub tile_id: Tile,
@@ -1322,26 +1345,39 @@ pub enum TransferDirection {
   In = 1,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SelectedEntityChangedVeryClosePreciseData {
   y: u8, // in 1/16 of a tile, starting from tileY(curpos) - 8
   x: u8, // in 1/16 of a tile, starting from tileX(curpos) - 8
 }
+impl SelectedEntityChangedVeryClosePreciseData {
+  /// Selected position, given the current position (of the player) it is relative to.
+  pub fn position(&self, current_position: MapPosition) -> MapPosition {
+    let origin = current_position.to_tile_position();
+    MapPosition::new(FixedPoint32_8(((origin.x - 8) << 8) + ((self.x as i32) << 4)), FixedPoint32_8(((origin.y - 8) << 8) + ((self.y as i32) << 4)))
+  }
+}
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct CraftData {
   pub recipe: Recipe,
   pub count: u32,
 }
 
 type FixedPoint16 = i16;
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SelectedEntityChangedRelativeData {
   y: FixedPoint16,
   x: FixedPoint16,
 }
+impl SelectedEntityChangedRelativeData {
+  /// Selected position, given the current position (of the player) it is relative to.
+  pub fn position(&self, current_position: MapPosition) -> MapPosition {
+    current_position + MapPosition::new(FixedPoint32_8(self.x as i32), FixedPoint32_8(self.y as i32))
+  }
+}
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct EquipmentData {
   pos: EquipmentPosition,
   typ: EquipmentDataType,
@@ -1357,19 +1393,19 @@ enum EquipmentDataType {
   Open = 5,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct EquipmentPosition {
   pub x: i32,
   pub y: i32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SetFilterParameters {
   pub target: ItemStackTargetSpecification,
   pub filter: Item,  // Item
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct ItemStackTargetSpecification {
   inventory_index: u8,
   slot_index: u16,
@@ -1402,6 +1438,19 @@ impl ItemStackTargetSpecification {
   #[allow(dead_code)] pub fn from_machine_output(slot_index: u16) -> Self {
     Self { inventory_index: 3, slot_index, source: SlotSource::EntityInventory, target: SlotTarget::Default, local_shelf_target: false, }
   }
+
+  pub fn inventory_index(&self) -> u8 {
+    self.inventory_index
+  }
+  pub fn slot_index(&self) -> u16 {
+    self.slot_index
+  }
+  pub fn source(&self) -> SlotSource {
+    self.source
+  }
+  pub fn target(&self) -> SlotTarget {
+    self.target
+  }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Primitive, ReplayReadWriteEnumU8)]
@@ -1434,12 +1483,20 @@ pub enum SlotTarget {
   TrashSlots = 3,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SelectedEntityChangedVeryCloseData {
   xy: u8, // 2 4-bit numbers format 0xXXXXYYYY in full tiles ({xy}*16 + 8), starting from tile(curpos) - 8
 }
+impl SelectedEntityChangedVeryCloseData {
+  /// Selected position, given the current position (of the player) it is relative to.
+  pub fn position(&self, current_position: MapPosition) -> MapPosition {
+    let origin = current_position.to_tile_position();
+    let (x, y) = ((self.xy >> 4) as i32, (self.xy & 0xf) as i32);
+    MapPosition::new(FixedPoint32_8(((origin.x - 8 + x) << 8) + 0x80), FixedPoint32_8(((origin.y - 8 + y) << 8) + 0x80))
+  }
+}
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct CrcData {
   pub crc: u32,
   pub tick_of_crc: u32,
@@ -1458,7 +1515,7 @@ pub enum Direction {
   None= 8,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct CustomInputData {
   custom_input_id: u16,
   cursor_position: MapPosition,
@@ -1466,20 +1523,20 @@ pub struct CustomInputData {
   selected_prototype_data: Option<SelectedPrototypeData>,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct PixelPosition {
   x: i32,
   y: i32,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SelectedPrototypeData {
   base_type: String,
   derived_type: String,
   name: String,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct UpdateBlueprintShelfData {
   shelf_player_index: u16,
   next_record_id: u32,
@@ -1491,7 +1548,7 @@ pub struct UpdateBlueprintShelfData {
   book_active_indexes_to_update: Vec<(u32, u16)>,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct AddBlueprintRecordData {
   item_id: u16,
   id: u32,
@@ -1500,13 +1557,13 @@ pub struct AddBlueprintRecordData {
   position: RecordPosition,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct RecordPosition {
   book_id: u32,
   index: u16,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum BlueprintRecordType {
   SingleBlueprint(SingleBlueprintSpecialData),
   BlueprintBook(BlueprintBookSpecialData),
@@ -1534,7 +1591,7 @@ impl ReplayReadWrite for BlueprintRecordType {
   }
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct SingleBlueprintSpecialData {
   description: String,
   preview_icons: PreviewIconsPersistent,
@@ -1542,13 +1599,13 @@ pub struct SingleBlueprintSpecialData {
   blueprint_empty: bool,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct PreviewIconsPersistent {
   data_backup: Vec<String>,
   icons: Vec<SignalId>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum SignalId {
   Item { item: Item },
   Fluid { fluid: Fluid, },
@@ -1572,13 +1629,13 @@ impl ReplayReadWrite for SignalId {
   }
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct BlueprintBookSpecialData {
   description: String,
   preview_icons: PreviewIconsPersistent,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct DeconstructionSpecialData {
   description: String,
   preview_icons: PreviewIconsPersistent,
@@ -1609,13 +1666,13 @@ pub enum TileSelectionMode {
   Only = 3,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct IdBackupWithLocation {
   index: u16,
   backup: String,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct UpgradeSpecialData {
   description: String,
   preview_icons: PreviewIconsPersistent,
@@ -1623,7 +1680,7 @@ pub struct UpgradeSpecialData {
   mappers: Vec<(UpgradeId, UpgradeId)>, 
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum UpgradeId {
   Entity { entity: u16 },
   Item { item: u16, },
@@ -1644,13 +1701,13 @@ impl ReplayReadWrite for UpgradeId {
   }
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct UpgradeIdBackupWithLocation {
   backup: String,
   mapper_index: u16,
 }
 
-#[derive(Debug, ReplayReadWriteStruct)]
+#[derive(Clone, Debug, ReplayReadWriteStruct)]
 pub struct PlayerJoinGameData {
   #[space_optimized] pub peer_id: u16, // consecutive player ids
   pub player_index: u16,
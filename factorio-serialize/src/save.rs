@@ -1,4 +1,4 @@
-use std::{fmt::Display, io::{BufReader, BufWriter, Read, Write}, path::Path};
+use std::{fmt::Display, io::{BufReader, BufWriter, Cursor, Read, Seek, Write}, path::Path};
 
 use zip::write::FileOptions;
 
@@ -38,12 +38,17 @@ impl Display for SaveFileError {
 
 pub struct SaveFile {
   pub level_init_dat: Vec<u8>,
+  pub level_dat: Option<Vec<u8>>,  // current state of a loaded save, written saves use level_init_dat instead
   pub replay_dat: Vec<u8>,
   pub script_init_dat: Vec<u8>,
 }
 impl SaveFile {
   pub fn from_raw_dat(level_init_dat: Vec<u8>, replay_dat: Vec<u8>, script_init_dat: Vec<u8>) -> SaveFile {
-    SaveFile { level_init_dat, replay_dat, script_init_dat }
+    SaveFile { level_init_dat, level_dat: None, replay_dat, script_init_dat }
+  }
+  /// The current state of the save, or its initial state if it has none.
+  pub fn current_level_dat(&self) -> &[u8] {
+    self.level_dat.as_deref().unwrap_or(&self.level_init_dat)
   }
 
   pub fn load_save_file(name: &str) -> std::result::Result<SaveFile, SaveFileError> {
@@ -72,7 +77,9 @@ impl SaveFile {
       buf
     };
 
-    Ok(SaveFile::from_raw_dat(level_init_dat, replay_dat, script_init_dat))
+    let level_dat = read_level_dat(&mut archive)?;
+
+    Ok(SaveFile { level_init_dat, level_dat, replay_dat, script_init_dat })
   }
 
   pub fn write_save_file(&self, name: &str) -> std::result::Result<(), SaveFileError> {
@@ -122,6 +129,19 @@ impl SaveFile {
   }
 
 
+  /// Writes an existing save under a new name or in place, with level-init.dat and level.dat replaced by the given map data and all
+  /// other files kept as they are.
+  pub fn write_patched_save_file(name: &str, new_name: &str, level_init_dat: &[u8], level_dat: &[u8]) -> std::result::Result<(), SaveFileError> {
+    let save_file_path = Path::new(SAVES_DIR).join(format!("{}.zip", name));
+    let new_save_file_path = Path::new(SAVES_DIR).join(format!("{}.zip", new_name));
+    // the original is read into memory first, so it can be overwritten
+    let mut archive = zip::ZipArchive::new(Cursor::new(std::fs::read(&save_file_path)?))?;
+    let mut save_file_zip = zip::ZipWriter::new(BufWriter::new(std::fs::File::create(&new_save_file_path)?));
+    patch_save_file(&mut archive, &mut save_file_zip, level_init_dat, level_dat)?;
+    save_file_zip.finish()?;
+
+    Ok(())
+  }
 
   pub fn write_scenario_folder(&self, name: &str) -> std::result::Result<(), SaveFileError> {
     let scenario_folder_path = Path::new(SCENARIOS_DIR).join(name);
@@ -144,3 +164,97 @@ impl SaveFile {
     Ok(())
   }
 }
+
+// level.dat0, level.dat1, ... are zlib compressed consecutive parts of level.dat
+fn level_dat_part_index(file_name: &str) -> Option<u32> {
+  let (_, suffix) = file_name.rsplit_once("/level.dat")?;
+  if suffix.is_empty() || !suffix.bytes().all(|b| b.is_ascii_digit()) { return None; }
+  suffix.parse().ok()
+}
+fn level_dat_part_folder(file_name: &str) -> Option<&str> {
+  level_dat_part_index(file_name)?;
+  file_name.rsplit_once("/level.dat").map(|(folder, _)| folder)
+}
+// copies all files of a save, replacing its map data
+fn patch_save_file<R: Read + Seek, W: Write + Seek>(archive: &mut zip::ZipArchive<R>, save_file_zip: &mut zip::ZipWriter<W>, level_init_dat: &[u8], level_dat: &[u8]) -> std::result::Result<(), SaveFileError> {
+  let has_plain_level_dat = archive.file_names().any(|name| name.ends_with("/level.dat"));
+  for i in 0..archive.len() {
+    let zip_file = archive.by_index(i)?;
+    let file_name = zip_file.name().to_owned();
+    if file_name.ends_with("/level-init.dat") {
+      save_file_zip.start_file(&file_name, FileOptions::default())?;
+      save_file_zip.write_all(level_init_dat)?;
+    } else if file_name.ends_with("/level.dat") {
+      save_file_zip.start_file(&file_name, FileOptions::default())?;
+      save_file_zip.write_all(level_dat)?;
+    } else if let Some(folder) = level_dat_part_folder(&file_name) {
+      // compressed level.dat parts are superseded by a plain level.dat
+      if level_dat_part_index(&file_name) == Some(0) && !has_plain_level_dat {
+        save_file_zip.start_file(format!("{folder}/level.dat"), FileOptions::default())?;
+        save_file_zip.write_all(level_dat)?;
+      }
+    } else {
+      save_file_zip.raw_copy_file(zip_file)?;
+    }
+  }
+  Ok(())
+}
+fn read_level_dat<R: Read + std::io::Seek>(archive: &mut zip::ZipArchive<R>) -> std::result::Result<Option<Vec<u8>>, SaveFileError> {
+  let level_dat_name = archive.file_names().find(|s| s.ends_with("/level.dat")).map(str::to_owned);
+  if let Some(file_name) = level_dat_name {
+    let mut zip_file = archive.by_name(&file_name)?;
+    let mut buf = Vec::with_capacity(zip_file.size() as usize);
+    zip_file.read_to_end(&mut buf)?;
+    return Ok(Some(buf));
+  }
+  let mut parts: Vec<(u32, String)> = archive.file_names().filter_map(|name| Some((level_dat_part_index(name)?, name.to_owned()))).collect();
+  if parts.is_empty() { return Ok(None); }
+  parts.sort();
+  let mut buf = Vec::new();
+  for (_, file_name) in parts {
+    flate2::read::ZlibDecoder::new(archive.by_name(&file_name)?).read_to_end(&mut buf)?;
+  }
+  Ok(Some(buf))
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_read_level_dat_parts() {
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, data) in [("save/level.dat1", &b"world"[..]), ("save/level-init.dat", b"init"), ("save/level.dat0", b"hello ")] {
+      let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+      encoder.write_all(data).unwrap();
+      zip.start_file(name, FileOptions::default()).unwrap();
+      zip.write_all(&if name.ends_with("level-init.dat") { data.to_vec() } else { encoder.finish().unwrap() }).unwrap();
+    }
+    let mut archive = zip::ZipArchive::new(zip.finish().unwrap()).unwrap();
+    assert_eq!(read_level_dat(&mut archive).unwrap().as_deref(), Some(&b"hello world"[..]));
+    assert_eq!(level_dat_part_folder("save/level.dat12"), Some("save"));
+    assert_eq!(level_dat_part_index("save/level.dat"), None);
+  }
+
+  #[test]
+  fn test_patch_save_file() {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in [("save/level-init.dat", &b"init"[..]), ("save/level.dat0", b"compressed"), ("save/level.dat1", b"parts"), ("save/control.lua", b"script")] {
+      zip.start_file(name, FileOptions::default()).unwrap();
+      zip.write_all(data).unwrap();
+    }
+    let mut archive = zip::ZipArchive::new(zip.finish().unwrap()).unwrap();
+    let mut patched_zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    patch_save_file(&mut archive, &mut patched_zip, b"new init", b"new level").unwrap();
+    let mut patched = zip::ZipArchive::new(patched_zip.finish().unwrap()).unwrap();
+
+    let mut file_names: Vec<_> = patched.file_names().map(str::to_owned).collect();
+    file_names.sort();
+    assert_eq!(file_names, ["save/control.lua", "save/level-init.dat", "save/level.dat"]);
+    assert_eq!(read_level_dat(&mut patched).unwrap().as_deref(), Some(&b"new level"[..]));
+    let mut control_lua = String::new();
+    patched.by_name("save/control.lua").unwrap().read_to_string(&mut control_lua).unwrap();
+    assert_eq!(control_lua, "script");
+  }
+}
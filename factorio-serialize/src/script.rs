@@ -12,11 +12,16 @@ use crate::map::MapSerialiser;
 use crate::map::MapVersion;
 
 
+/// Contents of script.dat.
+///
+/// The file is the LuaContext: per script (the level and each mod) its global table and whether it had a control.lua.
+/// Event handlers are not saved, scripts register them again in on_load. `remaining_data` holds any bytes after the
+/// LuaContext, which should be empty, and is written back unchanged.
 pub struct ScriptData {
   pub map_version: MapVersion,  // part of MapDeserializer
   pub lua_context: LuaContext,
 
-  pub remaining_data: Vec<u8>,  // unknown unparsed data
+  pub remaining_data: Vec<u8>,  // unparsed trailing data
 }
 impl ScriptData {
   pub fn parse_script_data(map_data: &[u8]) -> Result<ScriptData> {
@@ -44,6 +49,7 @@ impl std::fmt::Debug for ScriptData {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     writeln!(f, "map_version: ")?; self.map_version.fmt(f)?;
     writeln!(f, "lua_context: ")?; self.lua_context.fmt(f)?;
+    writeln!(f, "remaining_data: {} unparsed bytes", self.remaining_data.len())?;
     Ok(())
   }
 }
@@ -53,6 +59,18 @@ pub struct LuaContext {
   #[vec_u32] pub scripts: Vec<(String, LuaGameScript)>,
 }
 
+impl LuaContext {
+  /// The script of the scenario itself, which runs freeplay and silo-script in the base game scenarios.
+  pub const LEVEL_SCRIPT: &'static str = "level";
+
+  pub fn script(&self, name: &str) -> Option<&LuaGameScript> {
+    self.scripts.iter().find(|(n, _)| n == name).map(|(_, script)| script)
+  }
+  pub fn script_mut(&mut self, name: &str) -> Option<&mut LuaGameScript> {
+    self.scripts.iter_mut().find(|(n, _)| n == name).map(|(_, script)| script)
+  }
+}
+
 #[derive(Debug, MapReadWriteStruct)]
 pub struct LuaGameScript {
   pub script_state: LuaGameScriptState,
@@ -63,6 +81,85 @@ pub struct LuaGameScript {
 pub struct LuaGameScriptState {
   pub state_value: LuaValue,
 }
+// Accessors for the globals of the bundled data/freeplay.lua and of the game's silo-script, see data/freeplay_script_state.lua.
+impl LuaGameScriptState {
+  /// Chart distance freeplay uses if none is set.
+  pub const DEFAULT_CHART_DISTANCE: f64 = 200.0;
+
+  /// Looks up a global variable by name.
+  pub fn global(&self, name: &str) -> Option<&LuaValue> {
+    self.state_value.get(name)
+  }
+  /// Sets a global variable, keeping its position if it already exists. Setting it to nil removes it, as in Lua.
+  pub fn set_global(&mut self, name: &str, value: LuaValue) {
+    match value {
+      LuaValue::Nothing => { self.state_value.remove(name); },
+      value => { self.state_value.insert(name, value); },
+    }
+  }
+
+  pub fn skip_intro(&self) -> bool {
+    self.global("skip_intro").is_some_and(LuaValue::is_truthy)
+  }
+  pub fn set_skip_intro(&mut self, skip_intro: bool) {
+    self.set_global("skip_intro", LuaValue::from(skip_intro));
+  }
+  pub fn disable_crashsite(&self) -> bool {
+    self.global("disable_crashsite").is_some_and(LuaValue::is_truthy)
+  }
+  pub fn set_disable_crashsite(&mut self, disable_crashsite: bool) {
+    self.set_global("disable_crashsite", LuaValue::from(disable_crashsite));
+  }
+  /// Items inserted into each player on creation, as item name and count.
+  pub fn created_items(&self) -> Option<Vec<(String, u32)>> {
+    self.global("created_items").and_then(LuaValue::as_item_counts)
+  }
+  pub fn set_created_items(&mut self, items: &[(&str, u32)]) {
+    self.set_global("created_items", LuaValue::from_item_counts(items));
+  }
+  /// Items inserted into each player on respawn, as item name and count.
+  pub fn respawn_items(&self) -> Option<Vec<(String, u32)>> {
+    self.global("respawn_items").and_then(LuaValue::as_item_counts)
+  }
+  pub fn set_respawn_items(&mut self, items: &[(&str, u32)]) {
+    self.set_global("respawn_items", LuaValue::from_item_counts(items));
+  }
+  /// Radius of the area charted when the first player is created, DEFAULT_CHART_DISTANCE if not set.
+  pub fn chart_distance(&self) -> f64 {
+    self.global("chart_distance").and_then(LuaValue::as_number).unwrap_or(Self::DEFAULT_CHART_DISTANCE)
+  }
+  /// Sets the chart distance, or with None removes it so that the default is used.
+  pub fn set_chart_distance(&mut self, chart_distance: Option<f64>) {
+    self.set_global("chart_distance", chart_distance.map_or(LuaValue::Nothing, LuaValue::Number));
+  }
+
+  // silo-script keeps its state in global.silo_script
+  pub fn no_victory(&self) -> bool {
+    self.global("silo_script").and_then(|silo_script| silo_script.get("no_victory")).is_some_and(LuaValue::is_truthy)
+  }
+  pub fn set_no_victory(&mut self, no_victory: bool) {
+    self.silo_script_mut().insert("no_victory", LuaValue::from(no_victory));
+  }
+  /// Items whose rocket launches are counted, accepting both a list of names and a set of names as keys.
+  pub fn tracked_items(&self) -> Option<Vec<String>> {
+    let LuaValue::Table(entries) = self.global("silo_script")?.get("tracked_items")? else { return None };
+    entries.iter().map(|(key, value)| match (key, value) {
+      (LuaValue::Number(_), LuaValue::String(name)) | (LuaValue::String(name), LuaValue::BoolTrue) => Some(name.clone()),
+      _ => None,
+    }).collect()
+  }
+  /// Sets the tracked items as a set of names as keys.
+  pub fn set_tracked_items(&mut self, items: &[&str]) {
+    let tracked_items = LuaValue::Table(items.iter().map(|&item| (LuaValue::from(item), LuaValue::BoolTrue)).collect());
+    self.silo_script_mut().insert("tracked_items", tracked_items);
+  }
+  fn silo_script_mut(&mut self) -> &mut LuaValue {
+    if !matches!(self.global("silo_script"), Some(LuaValue::Table(_))) {
+      self.set_global("silo_script", LuaValue::Table(vec![]));
+    }
+    self.state_value.get_mut("silo_script").unwrap()
+  }
+}
 impl MapReadWrite for LuaGameScriptState {
   fn map_read<R: BufRead + Seek>(input: &mut crate::map::MapDeserialiser<R>) -> Result<Self> {
     let raw_data = Vec::<u8>::map_read(input)?;
@@ -85,7 +182,7 @@ impl MapReadWrite for LuaGameScriptState {
   }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LuaValue {
   Nothing,
   BoolFalse,
@@ -94,6 +191,63 @@ pub enum LuaValue {
   String(String),
   Table(Vec<(LuaValue, LuaValue)>),
 }
+impl LuaValue {
+  pub fn as_number(&self) -> Option<f64> {
+    if let LuaValue::Number(v) = self { Some(*v) } else { None }
+  }
+  pub fn as_str(&self) -> Option<&str> {
+    if let LuaValue::String(v) = self { Some(v) } else { None }
+  }
+  /// Whether the value counts as true in a Lua condition.
+  pub fn is_truthy(&self) -> bool {
+    !matches!(self, LuaValue::Nothing | LuaValue::BoolFalse)
+  }
+
+  /// Looks up a string key of a table.
+  pub fn get(&self, key: &str) -> Option<&LuaValue> {
+    let LuaValue::Table(entries) = self else { return None };
+    entries.iter().find(|(k, _)| k.as_str() == Some(key)).map(|(_, v)| v)
+  }
+  pub fn get_mut(&mut self, key: &str) -> Option<&mut LuaValue> {
+    let LuaValue::Table(entries) = self else { return None };
+    entries.iter_mut().find(|(k, _)| k.as_str() == Some(key)).map(|(_, v)| v)
+  }
+  /// Sets a string key of a table, replacing an existing entry in place or appending a new one. Turns non-tables into an empty table first.
+  pub fn insert(&mut self, key: &str, new_value: LuaValue) -> Option<LuaValue> {
+    if !matches!(self, LuaValue::Table(_)) { *self = LuaValue::Table(vec![]); }
+    let LuaValue::Table(entries) = self else { unreachable!() };
+    if let Some((_, v)) = entries.iter_mut().find(|(k, _)| k.as_str() == Some(key)) {
+      Some(std::mem::replace(v, new_value))
+    } else {
+      entries.push((LuaValue::from(key), new_value));
+      None
+    }
+  }
+  pub fn remove(&mut self, key: &str) -> Option<LuaValue> {
+    let LuaValue::Table(entries) = self else { return None };
+    let index = entries.iter().position(|(k, _)| k.as_str() == Some(key))?;
+    Some(entries.remove(index).1)
+  }
+
+  // item name -> count tables as used by util.insert_safe
+  fn as_item_counts(&self) -> Option<Vec<(String, u32)>> {
+    let LuaValue::Table(entries) = self else { return None };
+    entries.iter().map(|(key, value)| Some((key.as_str()?.to_owned(), value.as_number()? as u32))).collect()
+  }
+  fn from_item_counts(items: &[(&str, u32)]) -> LuaValue {
+    LuaValue::Table(items.iter().map(|&(item, count)| (LuaValue::from(item), LuaValue::Number(f64::from(count)))).collect())
+  }
+}
+impl From<bool> for LuaValue {
+  fn from(value: bool) -> Self {
+    if value { LuaValue::BoolTrue } else { LuaValue::BoolFalse }
+  }
+}
+impl From<&str> for LuaValue {
+  fn from(value: &str) -> Self {
+    LuaValue::String(value.to_owned())
+  }
+}
 impl MapReadWrite for LuaValue {
   fn map_read<R: BufRead + Seek>(input: &mut MapDeserialiser<R>) -> Result<Self> {
     let typ = u8::map_read(input)?;
@@ -119,3 +273,158 @@ impl MapReadWrite for LuaValue {
   }
 }
 
+
+const LUA_VALUE_JSON_TAG: &str = "$lua";
+
+impl LuaValue {
+  /// Converts into JSON, with tables keyed only by strings as objects in their stored order.
+  ///
+  /// Values without a plain JSON representation (other tables, non-finite numbers) are wrapped in an object tagged with "$lua".
+  pub fn to_json(&self) -> serde_json::Value {
+    use serde_json::Value;
+    let tagged = |type_name: &str, value: Value| {
+      let mut tagged = serde_json::Map::new();
+      tagged.insert(LUA_VALUE_JSON_TAG.to_owned(), Value::String(type_name.to_owned()));
+      tagged.insert("value".to_owned(), value);
+      Value::Object(tagged)
+    };
+    match self {
+      LuaValue::Nothing => Value::Null,
+      LuaValue::BoolFalse => Value::Bool(false),
+      LuaValue::BoolTrue => Value::Bool(true),
+      LuaValue::Number(v) => match serde_json::Number::from_f64(*v) {
+        Some(n) => Value::Number(n),
+        None => tagged("number", Value::String(v.to_string())),
+      },
+      LuaValue::String(v) => Value::String(v.clone()),
+      LuaValue::Table(entries) => {
+        let mut object = serde_json::Map::new();
+        for (key, value) in entries {
+          match key {
+            LuaValue::String(key) if key != LUA_VALUE_JSON_TAG && !object.contains_key(key) => { object.insert(key.clone(), value.to_json()); },
+            _ => return tagged("table", Value::Array(entries.iter().map(|(key, value)| Value::Array(vec![key.to_json(), value.to_json()])).collect())),
+          }
+        }
+        Value::Object(object)
+      },
+    }
+  }
+
+  pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+    use serde_json::Value;
+    let invalid = || crate::Error::custom(format!("invalid LuaValue {json}"), 0);
+    Ok(match json {
+      Value::Null => LuaValue::Nothing,
+      Value::Bool(false) => LuaValue::BoolFalse,
+      Value::Bool(true) => LuaValue::BoolTrue,
+      Value::Number(v) => LuaValue::Number(v.as_f64().ok_or_else(invalid)?),
+      Value::String(v) => LuaValue::String(v.clone()),
+      Value::Array(_) => return Err(invalid()),
+      Value::Object(object) => match object.get(LUA_VALUE_JSON_TAG) {
+        None => LuaValue::Table(object.iter().map(|(key, value)| Ok((LuaValue::String(key.clone()), LuaValue::from_json(value)?))).collect::<Result<_>>()?),
+        Some(type_name) => {
+          let inner = object.get("value").unwrap_or(&Value::Null);
+          match type_name.as_str().ok_or_else(invalid)? {
+            "number" => LuaValue::Number(inner.as_str().ok_or_else(invalid)?.parse().map_err(|_| invalid())?),
+            "table" => LuaValue::Table(inner.as_array().ok_or_else(invalid)?.iter().map(|entry| match entry.as_array().map(Vec::as_slice) {
+              Some([key, value]) => Ok((LuaValue::from_json(key)?, LuaValue::from_json(value)?)),
+              _ => Err(invalid()),
+            }).collect::<Result<_>>()?),
+            _ => return Err(invalid()),
+          }
+        },
+      },
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn level_script() -> LuaGameScript {
+    let mut script_state = LuaGameScriptState { state_value: LuaValue::Table(vec![]) };
+    script_state.set_created_items(&[("iron-plate", 8), ("wood", 1)]);
+    script_state.set_skip_intro(true);
+    script_state.set_chart_distance(Some(250.0));
+    script_state.set_tracked_items(&["satellite"]);
+    script_state.set_no_victory(true);
+    script_state.set_global("list", LuaValue::Table(vec![(LuaValue::Number(1.0), LuaValue::from("a")), (LuaValue::Number(2.0), LuaValue::BoolFalse)]));
+    LuaGameScript { script_state, had_control_lua: true }
+  }
+
+  #[test]
+  fn test_script_data_round_trip() {
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let script_data = ScriptData {
+      map_version,
+      lua_context: LuaContext { scripts: vec![(LuaContext::LEVEL_SCRIPT.to_owned(), level_script()), ("base".to_owned(), LuaGameScript { script_state: LuaGameScriptState { state_value: LuaValue::Table(vec![]) }, had_control_lua: false })] },
+      remaining_data: vec![],
+    };
+    let bytes = script_data.write_script_data().unwrap();
+    let read = ScriptData::parse_script_data(&bytes).unwrap();
+    assert!(read.remaining_data.is_empty());
+    assert_eq!(read.lua_context.scripts.len(), 2);
+    let level = read.lua_context.script(LuaContext::LEVEL_SCRIPT).unwrap();
+    assert_eq!(level.script_state.state_value, level_script().script_state.state_value);
+    assert!(level.had_control_lua);
+    assert!(!read.lua_context.script("base").unwrap().had_control_lua);
+    assert_eq!(read.write_script_data().unwrap(), bytes);
+
+    // trailing data is kept as is
+    let mut trailing = bytes.clone();
+    trailing.extend_from_slice(&[1, 2, 3]);
+    let read = ScriptData::parse_script_data(&trailing).unwrap();
+    assert_eq!(read.remaining_data, [1, 2, 3]);
+    assert_eq!(read.write_script_data().unwrap(), trailing);
+  }
+
+  #[test]
+  fn test_freeplay_script_state_accessors() {
+    let state_value = LuaValue::from_serpent(include_str!("../../data/freeplay_script_state.lua")).unwrap();
+    let mut script_state = LuaGameScriptState { state_value };
+    assert_eq!(script_state.created_items().unwrap()[..2], [("iron-plate".to_owned(), 8), ("wood".to_owned(), 1)]);
+    assert_eq!(script_state.respawn_items().unwrap(), [("pistol".to_owned(), 1), ("firearm-magazine".to_owned(), 10)]);
+    assert!(!script_state.skip_intro());
+    assert!(!script_state.disable_crashsite());
+    assert_eq!(script_state.chart_distance(), LuaGameScriptState::DEFAULT_CHART_DISTANCE);
+    assert!(!script_state.no_victory());
+    assert_eq!(script_state.tracked_items().unwrap(), ["satellite"]);
+
+    script_state.set_skip_intro(true);
+    script_state.set_disable_crashsite(true);
+    script_state.set_created_items(&[("iron-plate", 100)]);
+    script_state.set_respawn_items(&[]);
+    script_state.set_chart_distance(Some(50.0));
+    script_state.set_no_victory(true);
+    script_state.set_tracked_items(&["satellite", "raw-fish"]);
+    assert!(script_state.skip_intro());
+    assert!(script_state.disable_crashsite());
+    assert_eq!(script_state.created_items().unwrap(), [("iron-plate".to_owned(), 100)]);
+    assert_eq!(script_state.respawn_items().unwrap(), []);
+    assert_eq!(script_state.chart_distance(), 50.0);
+    assert!(script_state.no_victory());
+    assert_eq!(script_state.tracked_items().unwrap(), ["satellite", "raw-fish"]);
+    // the other silo-script state is kept, and existing globals keep their position
+    assert!(script_state.global("silo_script").unwrap().get("finished").is_some());
+    let LuaValue::Table(entries) = &script_state.state_value else { panic!("state is not a table") };
+    assert_eq!(entries[0].0.as_str(), Some("created_items"));
+
+    script_state.set_chart_distance(None);
+    assert!(script_state.global("chart_distance").is_none());
+    assert_eq!(script_state.chart_distance(), LuaGameScriptState::DEFAULT_CHART_DISTANCE);
+    // tracked items given as a list of names
+    script_state.state_value.get_mut("silo_script").unwrap().insert("tracked_items", LuaValue::Table(vec![(LuaValue::Number(1.0), LuaValue::from("satellite"))]));
+    assert_eq!(script_state.tracked_items().unwrap(), ["satellite"]);
+    assert!(LuaGameScriptState { state_value: LuaValue::Table(vec![]) }.tracked_items().is_none());
+  }
+
+  #[test]
+  fn test_unknown_lua_value_type() {
+    let map_version = MapVersion { version: 0x0001_0001_006b_0000, quality_version: false };
+    let mut bytes = MapSerialiser::new(map_version).unwrap().stream.into_inner().into_inner();
+    bytes.extend_from_slice(&[6, 0, 0, 0, 0]);
+    let mut r = MapDeserialiser::new(Cursor::new(&bytes[..])).unwrap();
+    assert!(LuaValue::map_read(&mut r).is_err());
+  }
+}
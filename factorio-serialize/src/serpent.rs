@@ -0,0 +1,355 @@
+use std::fmt::Write;
+
+use crate::Result;
+use crate::script::LuaValue;
+
+// Lua literals in the format of serpent (https://github.com/pkulchenko/serpent), as used by Factorio's serpent.line and serpent.block.
+// Table entries are printed in their stored order, with positional entries for keys 1, 2, ... where they appear in sequence, so that parsing the output yields the same entries again.
+
+const INDENT: &str = "  ";
+const KEYWORDS: [&str; 22] = ["and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto", "if", "in", "local", "nil", "not", "or", "repeat", "return", "then", "true", "until", "while"];
+
+impl LuaValue {
+  /// Single-line Lua literal, like serpent.line.
+  pub fn to_serpent_line(&self) -> String {
+    let mut out = String::new();
+    write_value(&mut out, self, None);
+    out
+  }
+  /// Multi-line Lua literal with nested tables indented, like serpent.block.
+  pub fn to_serpent_block(&self) -> String {
+    let mut out = String::new();
+    write_value(&mut out, self, Some(0));
+    out
+  }
+
+  /// Parses a Lua literal as written by serpent, optionally preceded by "return". Comments are skipped, keys and values may be any literal.
+  pub fn from_serpent(s: &str) -> Result<LuaValue> {
+    let mut parser = Parser { input: s.as_bytes(), pos: 0 };
+    parser.skip_whitespace()?;
+    if parser.peek_identifier() == Some("return") {
+      parser.pos += "return".len();
+    }
+    let value = parser.parse_value()?;
+    parser.skip_whitespace()?;
+    if parser.pos < parser.input.len() {
+      return Err(parser.error("unexpected trailing characters"));
+    }
+    Ok(value)
+  }
+}
+
+// indent is None for single-line output
+fn write_value(out: &mut String, value: &LuaValue, indent: Option<usize>) {
+  match value {
+    LuaValue::Nothing => out.push_str("nil"),
+    LuaValue::BoolFalse => out.push_str("false"),
+    LuaValue::BoolTrue => out.push_str("true"),
+    LuaValue::Number(v) => write_number(out, *v),
+    LuaValue::String(v) => write_string(out, v),
+    LuaValue::Table(entries) if entries.is_empty() => out.push_str("{}"),
+    LuaValue::Table(entries) => {
+      out.push('{');
+      let mut next_index = 1.0;
+      for (i, (key, value)) in entries.iter().enumerate() {
+        if i > 0 { out.push(','); }
+        match indent {
+          Some(indent) => { out.push('\n'); out.push_str(&INDENT.repeat(indent + 1)); },
+          None if i > 0 => out.push(' '),
+          None => {},
+        }
+        match key {
+          LuaValue::Number(k) if *k == next_index && k.is_sign_positive() => next_index += 1.0,
+          LuaValue::String(k) if is_identifier(k) => { out.push_str(k); out.push_str(" = "); },
+          _ => { out.push('['); write_value(out, key, None); out.push_str("] = "); },
+        }
+        write_value(out, value, indent.map(|indent| indent + 1));
+      }
+      if let Some(indent) = indent {
+        out.push('\n');
+        out.push_str(&INDENT.repeat(indent));
+      }
+      out.push('}');
+    },
+  }
+}
+
+fn write_number(out: &mut String, v: f64) {
+  if v.is_nan() {
+    out.push_str("0/0");
+  } else if v.is_infinite() {
+    out.push_str(if v > 0.0 { "math.huge" } else { "-math.huge" });
+  } else if v.fract() == 0.0 && v.abs() < 9007199254740992.0 && !(v == 0.0 && v.is_sign_negative()) {
+    write!(out, "{}", v as i64).unwrap();
+  } else {
+    // shortest representation which parses back to the same value
+    write!(out, "{v:?}").unwrap();
+  }
+}
+
+// like Lua's %q, but with all escapes on a single line
+fn write_string(out: &mut String, v: &str) {
+  out.push('"');
+  for c in v.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      c if c.is_ascii_control() => write!(out, "\\{:03}", c as u32).unwrap(),
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+}
+
+fn is_identifier(s: &str) -> bool {
+  let mut chars = s.chars();
+  matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    && !KEYWORDS.contains(&s)
+}
+
+
+struct Parser<'a> {
+  input: &'a [u8],
+  pos: usize,
+}
+impl Parser<'_> {
+  fn error(&self, msg: &str) -> crate::Error {
+    crate::Error::custom(format!("invalid Lua literal: {msg}"), self.pos as u64)
+  }
+  fn peek(&self) -> Option<u8> {
+    self.input.get(self.pos).copied()
+  }
+  fn starts_with(&self, s: &str) -> bool {
+    self.input[self.pos..].starts_with(s.as_bytes())
+  }
+  fn expect(&mut self, c: u8) -> Result<()> {
+    self.skip_whitespace()?;
+    if self.peek() != Some(c) {
+      return Err(self.error(&format!("expected '{}'", c as char)));
+    }
+    self.pos += 1;
+    Ok(())
+  }
+
+  fn skip_whitespace(&mut self) -> Result<()> {
+    loop {
+      while self.peek().is_some_and(|c| c.is_ascii_whitespace()) { self.pos += 1; }
+      if !self.starts_with("--") { return Ok(()); }
+      self.pos += 2;
+      if self.long_bracket_level().is_some() {
+        self.parse_long_bracket()?;
+      } else {
+        while self.peek().is_some_and(|c| c != b'\n') { self.pos += 1; }
+      }
+    }
+  }
+
+  fn peek_identifier(&self) -> Option<&str> {
+    let rest = &self.input[self.pos..];
+    if !rest.first().is_some_and(|c| c.is_ascii_alphabetic() || *c == b'_') { return None; }
+    let len = rest.iter().position(|c| !(c.is_ascii_alphanumeric() || *c == b'_')).unwrap_or(rest.len());
+    std::str::from_utf8(&rest[..len]).ok()
+  }
+
+  fn parse_value(&mut self) -> Result<LuaValue> {
+    self.skip_whitespace()?;
+    match self.peek() {
+      Some(b'{') => self.parse_table(),
+      Some(b'"' | b'\'') => Ok(LuaValue::String(self.parse_quoted_string()?)),
+      Some(b'[') if self.long_bracket_level().is_some() => Ok(LuaValue::String(self.parse_long_bracket()?)),
+      Some(b'-') => {
+        self.pos += 1;
+        match self.parse_value()? {
+          LuaValue::Number(v) => Ok(LuaValue::Number(-v)),
+          _ => Err(self.error("expected number after '-'")),
+        }
+      },
+      Some(c) if c.is_ascii_digit() || c == b'.' => {
+        let numerator = self.parse_number()?;
+        self.skip_whitespace()?;
+        if self.peek() == Some(b'/') {
+          // serpent writes NaN as 0/0
+          self.pos += 1;
+          self.skip_whitespace()?;
+          Ok(LuaValue::Number(numerator / self.parse_number()?))
+        } else {
+          Ok(LuaValue::Number(numerator))
+        }
+      },
+      _ => match self.peek_identifier() {
+        Some("nil") => { self.pos += 3; Ok(LuaValue::Nothing) },
+        Some("true") => { self.pos += 4; Ok(LuaValue::BoolTrue) },
+        Some("false") => { self.pos += 5; Ok(LuaValue::BoolFalse) },
+        Some("math") if self.starts_with("math.huge") => { self.pos += "math.huge".len(); Ok(LuaValue::Number(f64::INFINITY)) },
+        _ => Err(self.error("expected value")),
+      },
+    }
+  }
+
+  fn parse_table(&mut self) -> Result<LuaValue> {
+    self.expect(b'{')?;
+    let mut entries = vec![];
+    let mut next_index = 1.0;
+    loop {
+      self.skip_whitespace()?;
+      if self.peek() == Some(b'}') { break; }
+      if self.peek() == Some(b'[') && self.long_bracket_level().is_none() {
+        self.pos += 1;
+        let key = self.parse_value()?;
+        self.expect(b']')?;
+        self.expect(b'=')?;
+        entries.push((key, self.parse_value()?));
+      } else if let Some(name) = self.named_key() {
+        let key = LuaValue::String(name);
+        self.expect(b'=')?;
+        entries.push((key, self.parse_value()?));
+      } else {
+        entries.push((LuaValue::Number(next_index), self.parse_value()?));
+        next_index += 1.0;
+      }
+      self.skip_whitespace()?;
+      match self.peek() {
+        Some(b',' | b';') => self.pos += 1,
+        Some(b'}') => break,
+        _ => return Err(self.error("expected ',' or '}'")),
+      }
+    }
+    self.pos += 1;
+    Ok(LuaValue::Table(entries))
+  }
+
+  // identifier followed by a single '=', consumed up to the '='
+  fn named_key(&mut self) -> Option<String> {
+    let name = self.peek_identifier()?.to_owned();
+    let start = self.pos;
+    self.pos += name.len();
+    if self.skip_whitespace().is_ok() && self.starts_with("=") && !self.starts_with("==") {
+      Some(name)
+    } else {
+      self.pos = start;
+      None
+    }
+  }
+
+  fn parse_number(&mut self) -> Result<f64> {
+    let start = self.pos;
+    if self.starts_with("0x") || self.starts_with("0X") {
+      self.pos += 2;
+      while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) { self.pos += 1; }
+      let digits = std::str::from_utf8(&self.input[start + 2..self.pos]).unwrap();
+      return u64::from_str_radix(digits, 16).map(|v| v as f64).map_err(|_| self.error("invalid hex number"));
+    }
+    while let Some(c) = self.peek() {
+      let is_exponent_sign = (c == b'+' || c == b'-') && matches!(self.input[self.pos - 1], b'e' | b'E');
+      if !(c.is_ascii_digit() || c == b'.' || c == b'e' || c == b'E' || is_exponent_sign) { break; }
+      self.pos += 1;
+    }
+    std::str::from_utf8(&self.input[start..self.pos]).unwrap().parse().map_err(|_| self.error("invalid number"))
+  }
+
+  fn parse_quoted_string(&mut self) -> Result<String> {
+    let quote = self.input[self.pos];
+    self.pos += 1;
+    let mut bytes = vec![];
+    loop {
+      let c = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+      self.pos += 1;
+      match c {
+        c if c == quote => break,
+        b'\n' => return Err(self.error("unescaped newline in string")),
+        b'\\' => {
+          let escaped = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+          self.pos += 1;
+          match escaped {
+            b'a' => bytes.push(0x07),
+            b'b' => bytes.push(0x08),
+            b'f' => bytes.push(0x0c),
+            b'n' | b'\n' => bytes.push(b'\n'),
+            b'r' => bytes.push(b'\r'),
+            b't' => bytes.push(b'\t'),
+            b'v' => bytes.push(0x0b),
+            b'\\' | b'"' | b'\'' => bytes.push(escaped),
+            b'x' => {
+              let hex = self.input.get(self.pos..self.pos + 2).and_then(|hex| std::str::from_utf8(hex).ok()).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+              bytes.push(hex.ok_or_else(|| self.error("invalid \\x escape"))?);
+              self.pos += 2;
+            },
+            b'z' => while self.peek().is_some_and(|c| c.is_ascii_whitespace()) { self.pos += 1; },
+            c if c.is_ascii_digit() => {
+              let mut value = u32::from(c - b'0');
+              for _ in 0..2 {
+                match self.peek() {
+                  Some(c) if c.is_ascii_digit() => { value = value * 10 + u32::from(c - b'0'); self.pos += 1; },
+                  _ => break,
+                }
+              }
+              bytes.push(u8::try_from(value).map_err(|_| self.error("decimal escape out of range"))?);
+            },
+            _ => return Err(self.error("invalid escape sequence")),
+          }
+        },
+        c => bytes.push(c),
+      }
+    }
+    String::from_utf8(bytes).map_err(|e| crate::Error::from_utf8(e, self.pos as u64))
+  }
+
+  // level of a long bracket [[, [=[, ... starting at the current position
+  fn long_bracket_level(&self) -> Option<usize> {
+    if self.peek() != Some(b'[') { return None; }
+    let level = self.input[self.pos + 1..].iter().take_while(|c| **c == b'=').count();
+    (self.input.get(self.pos + 1 + level) == Some(&b'[')).then_some(level)
+  }
+  fn parse_long_bracket(&mut self) -> Result<String> {
+    let level = self.long_bracket_level().ok_or_else(|| self.error("expected long bracket"))?;
+    self.pos += level + 2;
+    // a newline directly after the opening bracket is skipped
+    if self.starts_with("\r\n") { self.pos += 2; } else if self.peek() == Some(b'\n') { self.pos += 1; }
+    let close = format!("]{}]", "=".repeat(level));
+    let len = self.input[self.pos..].windows(close.len()).position(|w| w == close.as_bytes()).ok_or_else(|| self.error("unterminated long bracket"))?;
+    let content = String::from_utf8(self.input[self.pos..self.pos + len].to_vec()).map_err(|e| crate::Error::from_utf8(e, self.pos as u64))?;
+    self.pos += len + close.len();
+    Ok(content)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_round_trip() {
+    let value = LuaValue::Table(vec![
+      (LuaValue::Number(1.0), LuaValue::String("first".to_owned())),
+      (LuaValue::Number(2.0), LuaValue::Number(0.1)),
+      (LuaValue::String("skip_intro".to_owned()), LuaValue::BoolTrue),
+      (LuaValue::Number(5.0), LuaValue::Number(-0.0)),
+      (LuaValue::String("not an identifier".to_owned()), LuaValue::String("quote \" backslash \\ newline \n bell \x07 ünïcode".to_owned())),
+      (LuaValue::String("end".to_owned()), LuaValue::Nothing),
+      (LuaValue::BoolFalse, LuaValue::Table(vec![])),
+      (LuaValue::String("nested".to_owned()), LuaValue::Table(vec![
+        (LuaValue::Number(1.0), LuaValue::Number(f64::INFINITY)),
+        (LuaValue::Number(2.0), LuaValue::Number(-1e300)),
+        (LuaValue::String("tick".to_owned()), LuaValue::Number(123456.0)),
+      ])),
+      (LuaValue::Number(3.0), LuaValue::BoolFalse),
+    ]);
+    let line = value.to_serpent_line();
+    assert_eq!(line, r#"{"first", 0.1, skip_intro = true, [5] = -0.0, ["not an identifier"] = "quote \" backslash \\ newline \n bell \007 ünïcode", ["end"] = nil, [false] = {}, nested = {math.huge, -1e300, tick = 123456}, false}"#);
+    assert_eq!(LuaValue::from_serpent(&line).unwrap(), value);
+    assert_eq!(LuaValue::from_serpent(&value.to_serpent_block()).unwrap(), value);
+    assert_eq!(LuaValue::from_json(&value.to_json()).unwrap(), value);
+
+    let parsed = LuaValue::from_serpent("return { -- comment\n  a = 0x10; [[long\nstring]], b = 'single', nan = 0/0, --[==[ block\n comment ]==] }").unwrap();
+    let LuaValue::Table(entries) = parsed else { panic!("expected table") };
+    assert_eq!(entries[0], (LuaValue::String("a".to_owned()), LuaValue::Number(16.0)));
+    assert_eq!(entries[1], (LuaValue::Number(1.0), LuaValue::String("long\nstring".to_owned())));
+    assert_eq!(entries[2], (LuaValue::String("b".to_owned()), LuaValue::String("single".to_owned())));
+    assert!(matches!(entries[3].1, LuaValue::Number(v) if v.is_nan()));
+  }
+}
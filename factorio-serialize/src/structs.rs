@@ -9,6 +9,8 @@ mod mapposition;
 pub use mapposition::MapPosition;
 mod randomgenerator;
 pub use randomgenerator::RandomGenerator;
+mod randomgeneratorcomponent;
+pub use randomgeneratorcomponent::RandomGeneratorComponent;
 mod vector;
 pub use vector::Vector;
 mod vectororientation;
@@ -20,7 +22,7 @@ pub struct ChunkPosition {
   pub y: i32,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, ReplayReadWriteStruct)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, ReplayReadWriteStruct)]
 pub struct TilePosition {
   pub x: i32,
   pub y: i32,
@@ -1,6 +1,8 @@
 use factorio_serialize_derive::MapReadWriteStruct;
 
-#[derive(Clone, Debug, MapReadWriteStruct)]
+use super::RandomGeneratorComponent;
+
+#[derive(Clone, Debug, Eq, Hash, MapReadWriteStruct, PartialEq)]
 pub struct RandomGenerator {
   pub seed1: u32,
   pub seed2: u32,
@@ -12,12 +14,62 @@ impl RandomGenerator {
   }
 
   fn next(&mut self) -> u32 {
-    self.seed1 = ((self.seed1 ^ self.seed1 << 13) >> 19) | (self.seed1 >> 1 << 13);
-    self.seed2 = ((self.seed2 ^ self.seed2 << 2) >> 25) | (self.seed2 >> 3 << 7);
-    self.seed3 = ((self.seed3 ^ self.seed3 << 3) >> 11) | (self.seed3 >> 4 << 21);
+    self.seed1 = RandomGeneratorComponent::Seed1.step(self.seed1);
+    self.seed2 = RandomGeneratorComponent::Seed2.step(self.seed2);
+    self.seed3 = RandomGeneratorComponent::Seed3.step(self.seed3);
     self.seed1 ^ self.seed2 ^ self.seed3
   }
 
+  fn seed(&self, component: RandomGeneratorComponent) -> u32 {
+    match component {
+      RandomGeneratorComponent::Seed1 => self.seed1,
+      RandomGeneratorComponent::Seed2 => self.seed2,
+      RandomGeneratorComponent::Seed3 => self.seed3,
+    }
+  }
+  fn seed_mut(&mut self, component: RandomGeneratorComponent) -> &mut u32 {
+    match component {
+      RandomGeneratorComponent::Seed1 => &mut self.seed1,
+      RandomGeneratorComponent::Seed2 => &mut self.seed2,
+      RandomGeneratorComponent::Seed3 => &mut self.seed3,
+    }
+  }
+
+  /// Number of generated values after which the state repeats. The component periods are coprime, so this is their product.
+  pub fn period(&self) -> u128 {
+    RandomGeneratorComponent::ALL.iter().map(|&component| u128::from(component.cycle_length(self.seed(component)))).product()
+  }
+
+  /// Advances the state as if `steps` values were generated.
+  pub fn jump(&mut self, steps: u128) {
+    for component in RandomGeneratorComponent::ALL {
+      let seed = self.seed(component);
+      *self.seed_mut(component) = component.jump(seed, (steps % u128::from(component.period())) as u64);
+    }
+  }
+  /// Reverts the state to before the last `steps` generated values.
+  pub fn jump_back(&mut self, steps: u128) {
+    for component in RandomGeneratorComponent::ALL {
+      let seed = self.seed(component);
+      *self.seed_mut(component) = component.jump_back(seed, (steps % u128::from(component.period())) as u64);
+    }
+  }
+
+  /// Smallest number of generated values after which this state turns into `other`, if it ever does.
+  pub fn distance_to(&self, other: &RandomGenerator) -> Option<u128> {
+    // combine the per-component distances using the chinese remainder theorem
+    let mut distance = 0u128;
+    let mut modulus = 1u128;
+    for component in RandomGeneratorComponent::ALL {
+      let component_distance = u128::from(component.distance(self.seed(component), other.seed(component))?);
+      let cycle_length = u128::from(component.cycle_length(self.seed(component)));
+      let t = (component_distance + cycle_length - distance % cycle_length) % cycle_length * mod_inverse(modulus % cycle_length, cycle_length) % cycle_length;
+      distance += t * modulus;
+      modulus *= cycle_length;
+    }
+    Some(distance)
+  }
+
   // from RandomGenerator::uniformInteger
   pub fn uniform_integer(&mut self, min: u32, max: u32) -> u32 {
     return min + self.next() % (max - min);
@@ -27,11 +79,16 @@ impl RandomGenerator {
   pub fn uniform_double(&mut self) -> f64 {
     return self.next() as f64 * (1.0 / 4294967296.0);
   }
+}
 
-  // from ItemProductPrototype::collect
-  pub fn get_huge_rock_items(&mut self) -> (u32, u32) { // (stone, coal)
-    let stone = (self.uniform_double() * (51.0-24.0) + 24.0) as u32;  // needs to be at least 4_135_894_434 (0xf684bda2) for 50 output
-    let coal = (self.uniform_double() * (51.0-24.0) + 24.0) as u32;
-    (stone, coal)
+// inverse of a modulo m, for coprime a and m
+fn mod_inverse(a: u128, m: u128) -> u128 {
+  let (mut old_r, mut r) = (a as i128, m as i128);
+  let (mut old_s, mut s) = (1i128, 0i128);
+  while r != 0 {
+    let quotient = old_r / r;
+    (old_r, r) = (r, old_r - quotient * r);
+    (old_s, s) = (s, old_s - quotient * s);
   }
+  old_s.rem_euclid(m as i128) as u128
 }
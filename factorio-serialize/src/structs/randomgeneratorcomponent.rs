@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+/// One of the three Tausworthe generators combined by RandomGenerator, each updating one of its seeds.
+///
+/// Each update is linear over GF(2), so n updates can be applied at once using powers of its bit matrix.
+/// Only the upper 31, 29 and 28 bits of the seeds affect later values, all states are compared on these bits only.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RandomGeneratorComponent {
+  Seed1,
+  Seed2,
+  Seed3,
+}
+impl RandomGeneratorComponent {
+  pub const ALL: [RandomGeneratorComponent; 3] = [RandomGeneratorComponent::Seed1, RandomGeneratorComponent::Seed2, RandomGeneratorComponent::Seed3];
+
+  #[inline(always)]
+  pub fn step(self, seed: u32) -> u32 {
+    match self {
+      RandomGeneratorComponent::Seed1 => ((seed ^ seed << 13) >> 19) | (seed >> 1 << 13),
+      RandomGeneratorComponent::Seed2 => ((seed ^ seed << 2) >> 25) | (seed >> 3 << 7),
+      RandomGeneratorComponent::Seed3 => ((seed ^ seed << 3) >> 11) | (seed >> 4 << 21),
+    }
+  }
+
+  pub fn significant_bits(self) -> u32 {
+    match self {
+      RandomGeneratorComponent::Seed1 => 31,
+      RandomGeneratorComponent::Seed2 => 29,
+      RandomGeneratorComponent::Seed3 => 28,
+    }
+  }
+  fn significant_mask(self) -> u32 {
+    u32::MAX << (32 - self.significant_bits())
+  }
+
+  /// Length of the cycle all states except the all-zero one are on, which is a fixed point.
+  pub fn period(self) -> u64 {
+    let matrix = self.matrix();
+    let max_period = (1u64 << self.significant_bits()) - 1;
+    // order of the matrix restricted to the significant bits, i.e. smallest d with M^(d+1) = M
+    let is_period = |d: u64| matrix.pow(d + 1) == matrix;
+    assert!(is_period(max_period), "{self:?} does not cycle through its states");
+    reduce_period(max_period, is_period)
+  }
+
+  /// Length of the cycle the given state is on.
+  pub fn cycle_length(self, seed: u32) -> u64 {
+    let matrix = self.matrix();
+    let mask = self.significant_mask();
+    reduce_period(self.period(), |d| matrix.pow(d).apply(seed) & mask == seed & mask)
+  }
+
+  /// State after `steps` updates.
+  pub fn jump(self, seed: u32, steps: u64) -> u32 {
+    self.matrix().pow(steps).apply(seed)
+  }
+  /// State `steps` updates earlier, with the insignificant bits set as the update would set them.
+  pub fn jump_back(self, seed: u32, steps: u64) -> u32 {
+    let period = self.period();
+    self.jump(seed, period - steps % period)
+  }
+
+  /// Smallest number of updates leading from `from` to `to`, if they are on the same cycle.
+  pub fn distance(self, from: u32, to: u32) -> Option<u64> {
+    let mask = self.significant_mask();
+    let cycle_length = self.cycle_length(from);
+    // baby-step giant-step
+    let giant_step_size = (cycle_length as f64).sqrt().ceil() as u64;
+    let step_matrix = self.matrix();
+    let mut baby_steps = HashMap::with_capacity(giant_step_size as usize);
+    let mut seed = from;
+    for j in 0..giant_step_size {
+      baby_steps.entry(seed & mask).or_insert(j);
+      seed = step_matrix.apply(seed);
+    }
+    let giant_step_back = step_matrix.pow(cycle_length - giant_step_size % cycle_length);
+    let mut seed = to;
+    for i in 0..=cycle_length / giant_step_size {
+      if let Some(&j) = baby_steps.get(&(seed & mask)) {
+        return Some((i * giant_step_size + j) % cycle_length);
+      }
+      seed = giant_step_back.apply(seed);
+    }
+    None
+  }
+
+  fn matrix(self) -> BitMatrix {
+    let mut columns = [0; 32];
+    for (bit, column) in columns.iter_mut().enumerate() {
+      *column = self.step(1 << bit);
+    }
+    BitMatrix(columns)
+  }
+}
+
+// smallest divisor d of period for which is_period(d) holds, given that it holds for period itself
+fn reduce_period(mut period: u64, is_period: impl Fn(u64) -> bool) -> u64 {
+  for factor in prime_factors(period) {
+    while period.is_multiple_of(factor) && is_period(period / factor) {
+      period /= factor;
+    }
+  }
+  period
+}
+fn prime_factors(mut n: u64) -> Vec<u64> {
+  let mut factors = vec![];
+  let mut factor = 2;
+  while factor * factor <= n {
+    if n.is_multiple_of(factor) {
+      factors.push(factor);
+      while n.is_multiple_of(factor) { n /= factor; }
+    }
+    factor += 1;
+  }
+  if n > 1 { factors.push(n); }
+  factors
+}
+
+// 32x32 matrix over GF(2), columns are the images of the single bits
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct BitMatrix([u32; 32]);
+impl BitMatrix {
+  const IDENTITY: BitMatrix = {
+    let mut columns = [0; 32];
+    let mut bit = 0;
+    while bit < 32 {
+      columns[bit] = 1 << bit;
+      bit += 1;
+    }
+    BitMatrix(columns)
+  };
+
+  fn apply(&self, mut v: u32) -> u32 {
+    let mut result = 0;
+    while v != 0 {
+      result ^= self.0[v.trailing_zeros() as usize];
+      v &= v - 1;
+    }
+    result
+  }
+  fn mul(&self, other: &BitMatrix) -> BitMatrix {
+    BitMatrix(other.0.map(|column| self.apply(column)))
+  }
+  fn pow(&self, mut n: u64) -> BitMatrix {
+    let mut result = BitMatrix::IDENTITY;
+    let mut base = *self;
+    while n > 0 {
+      if n & 1 != 0 { result = result.mul(&base); }
+      base = base.mul(&base);
+      n >>= 1;
+    }
+    result
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::RandomGenerator;
+
+  #[test]
+  fn test_jump_and_distance() {
+    assert_eq!(RandomGeneratorComponent::ALL.map(|component| component.period()), [(1 << 31) - 1, (1 << 29) - 1, (1 << 28) - 1]);
+
+    let start = RandomGenerator::new(80686, 3738370905, 872480768);
+    let mut stepped = start.clone();
+    for _ in 0..1000 {
+      stepped.uniform_double();
+    }
+    let mut jumped = start.clone();
+    jumped.jump(1000);
+    assert_eq!((jumped.seed1, jumped.seed2, jumped.seed3), (stepped.seed1, stepped.seed2, stepped.seed3));
+    assert_eq!(start.distance_to(&jumped), Some(1000));
+
+    jumped.jump_back(999);
+    let mut expected = start.clone();
+    expected.jump(1);
+    assert_eq!((jumped.seed1, jumped.seed2, jumped.seed3), (expected.seed1, expected.seed2, expected.seed3));
+
+    let far = 1u128 << 80;
+    let mut jumped = start.clone();
+    jumped.jump(far);
+    assert_eq!(start.distance_to(&jumped), Some(far));
+  }
+}
@@ -0,0 +1,240 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::path::Path;
+
+use factorio_serialize::{map::{ActiveMigrations, MapData, PrototypeMigrationList}, save::SaveFile};
+
+struct ConstantsEnum {
+  enum_name: &'static str,
+  file_name: &'static str,
+  id_type: &'static str,
+  data_raw_types: &'static [&'static str],
+}
+
+const CONSTANTS_ENUMS: [ConstantsEnum; 11] = [
+  ConstantsEnum { enum_name: "Achievement", file_name: "achievement", id_type: "U16", data_raw_types: &[
+    "achievement", "build-entity-achievement", "combat-robot-count", "construct-with-robots-achievement", "deconstruct-with-robots-achievement",
+    "deliver-by-robots-achievement", "dont-build-entity-achievement", "dont-craft-manually-achievement", "dont-use-entity-in-energy-production-achievement",
+    "finish-the-game-achievement", "group-attack-achievement", "kill-achievement", "player-damaged-achievement", "produce-achievement",
+    "produce-per-hour-achievement", "research-achievement", "train-path-achievement",
+  ] },
+  ConstantsEnum { enum_name: "Decorative", file_name: "decorative", id_type: "U8", data_raw_types: &["optimized-decorative"] },
+  ConstantsEnum { enum_name: "Entity", file_name: "entity", id_type: "U16", data_raw_types: &[
+    "accumulator", "ammo-turret", "arithmetic-combinator", "arrow", "artillery-flare", "artillery-projectile", "artillery-turret", "artillery-wagon",
+    "assembling-machine", "beacon", "beam", "boiler", "burner-generator", "car", "cargo-wagon", "character", "character-corpse", "cliff",
+    "combat-robot", "constant-combinator", "construction-robot", "container", "corpse", "curved-rail", "decider-combinator",
+    "deconstructible-tile-proxy", "electric-energy-interface", "electric-pole", "electric-turret", "entity-ghost", "explosion", "fire", "fish",
+    "flame-thrower-explosion", "fluid-turret", "fluid-wagon", "flying-text", "furnace", "gate", "generator", "heat-interface", "heat-pipe",
+    "highlight-box", "infinity-container", "infinity-pipe", "inserter", "item-entity", "item-request-proxy", "lab", "lamp", "land-mine",
+    "linked-belt", "linked-container", "loader", "loader-1x1", "locomotive", "logistic-container", "logistic-robot", "market", "mining-drill",
+    "offshore-pump", "particle-source", "pipe", "pipe-to-ground", "player-port", "power-switch", "programmable-speaker", "projectile", "pump",
+    "radar", "rail-chain-signal", "rail-remnants", "rail-signal", "reactor", "resource", "roboport", "rocket-silo", "rocket-silo-rocket",
+    "rocket-silo-rocket-shadow", "simple-entity", "simple-entity-with-force", "simple-entity-with-owner", "smoke-with-trigger", "solar-panel",
+    "speech-bubble", "spider-leg", "spider-vehicle", "splitter", "sticker", "storage-tank", "straight-rail", "stream", "tile-ghost", "train-stop",
+    "transport-belt", "tree", "turret", "underground-belt", "unit", "unit-spawner", "wall",
+  ] },
+  ConstantsEnum { enum_name: "Equipment", file_name: "equipment", id_type: "U16", data_raw_types: &[
+    "active-defense-equipment", "battery-equipment", "belt-immunity-equipment", "energy-shield-equipment", "generator-equipment",
+    "movement-bonus-equipment", "night-vision-equipment", "roboport-equipment", "solar-panel-equipment",
+  ] },
+  ConstantsEnum { enum_name: "Fluid", file_name: "fluid", id_type: "U16", data_raw_types: &["fluid"] },
+  ConstantsEnum { enum_name: "Item", file_name: "item", id_type: "U16", data_raw_types: &[
+    "item", "ammo", "armor", "blueprint", "blueprint-book", "capsule", "copy-paste-tool", "deconstruction-item", "gun", "item-with-entity-data",
+    "item-with-inventory", "item-with-label", "item-with-tags", "mining-tool", "module", "rail-planner", "repair-tool", "selection-tool",
+    "spidertron-remote", "tool", "upgrade-item",
+  ] },
+  ConstantsEnum { enum_name: "ItemGroup", file_name: "itemgroup", id_type: "U8", data_raw_types: &["item-group"] },
+  ConstantsEnum { enum_name: "Recipe", file_name: "recipe", id_type: "U16", data_raw_types: &["recipe"] },
+  ConstantsEnum { enum_name: "Technology", file_name: "technology", id_type: "U16", data_raw_types: &["technology"] },
+  ConstantsEnum { enum_name: "Tile", file_name: "tile", id_type: "U8", data_raw_types: &["tile"] },
+  ConstantsEnum { enum_name: "VirtualSignal", file_name: "virtualsignal", id_type: "U16", data_raw_types: &["virtual-signal"] },
+];
+
+/// Regenerates all constants enums in `constants_dir` (usually factorio-serialize/src/constants) using the prototype ids found in the given save.
+#[allow(dead_code)]
+pub fn generate_constants_from_save(name: &str, constants_dir: &Path) {
+  let save_file = SaveFile::load_save_file(name).unwrap();
+  let map_data = MapData::parse_map_data(&save_file.level_init_dat).unwrap();
+  let version = map_data.map_version.version;
+  let version = format!("{}.{}.{}", version >> 48, (version >> 32) & 0xffff, (version >> 16) & 0xffff);
+
+  for constants_enum in &CONSTANTS_ENUMS {
+    let ids = save_ids(constants_enum.enum_name, &map_data.map.prototype_migrations);
+    write_constants_enum(constants_dir, constants_enum, ids, &version, "constantsgenerator::generate_constants_from_save");
+  }
+}
+
+/// Regenerates all constants enums from the prototype names in a data.raw JSON dump.
+/// A data.raw dump contains no ids, so existing prototypes keep their current ids and new prototypes are appended after them.
+/// Only ids extracted from a save are guaranteed to match the ones the game assigns.
+#[allow(dead_code)]
+pub fn generate_constants_from_data_raw(file_name: &str, version: &str, constants_dir: &Path) {
+  let buf = std::fs::read(file_name).expect("couldn't read data.raw file");
+  let data_raw: serde_json::Value = serde_json::from_slice(&buf).expect("couldn't parse JSON data in data.raw file");
+
+  for constants_enum in &CONSTANTS_ENUMS {
+    if !constants_enum.data_raw_types.iter().any(|prototype_type| data_raw.get(prototype_type).is_some()) {
+      println!("{}: no prototypes in data.raw, keeping existing file", constants_enum.enum_name);
+      continue;
+    }
+    let mut names: Vec<(&str, &str)> = constants_enum.data_raw_types.iter()
+        .filter_map(|&prototype_type| Some((prototype_type, data_raw.get(prototype_type)?.as_object()?)))
+        .flat_map(|(prototype_type, prototypes)| prototypes.keys().map(move |name| (prototype_type, name.as_str())))
+        .collect();
+    names.sort();
+
+    let existing_ids = read_existing_ids(constants_dir, constants_enum);
+    let mut next_id = existing_ids.values().max().map_or(1, |id| id + 1);
+    let ids = names.into_iter().map(|(_, name)| {
+      let id = existing_ids.get(name).copied().unwrap_or_else(|| { next_id += 1; next_id - 1 });
+      (name.to_owned(), id)
+    }).collect();
+    write_constants_enum(constants_dir, constants_enum, ids, version, "constantsgenerator::generate_constants_from_data_raw");
+  }
+}
+
+/// Rewrites all constants enums with the ids they already have, e.g. after changes to the generated code.
+#[allow(dead_code)]
+pub fn regenerate_constants(version: &str, constants_dir: &Path) {
+  for constants_enum in &CONSTANTS_ENUMS {
+    let ids = read_existing_ids(constants_dir, constants_enum).into_iter().collect();
+    write_constants_enum(constants_dir, constants_enum, ids, version, "constantsgenerator::regenerate_constants");
+  }
+}
+
+fn save_ids(enum_name: &str, migrations: &PrototypeMigrationList) -> Vec<(String, u32)> {
+  match enum_name {
+    "Achievement" => migration_ids(&migrations.achievement_id_migrations),
+    "Decorative" => migration_ids(&migrations.decorative_id_migrations),
+    "Entity" => migration_ids(&migrations.entity_id_migrations),
+    "Equipment" => migration_ids(&migrations.equipment_id_migrations),
+    "Fluid" => migration_ids(&migrations.fluid_id_migrations),
+    "Item" => migration_ids(&migrations.item_id_migrations),
+    "ItemGroup" => migration_ids(&migrations.item_group_id_migrations),
+    "Recipe" => migration_ids(&migrations.recipe_id_migrations),
+    "Technology" => migration_ids(&migrations.technology_id_migrations),
+    "Tile" => migration_ids(&migrations.tile_id_migrations),
+    "VirtualSignal" => migration_ids(&migrations.virtual_signal_id_migrations),
+    _ => panic!("no prototype migrations for {enum_name}"),
+  }
+}
+fn migration_ids<V: Copy>(migrations: &ActiveMigrations<V>) -> Vec<(String, u32)> where u32: From<V> {
+  migrations.mappings.iter().flat_map(|(_, mappings)| mappings.iter().map(|(name, id)| (name.clone(), u32::from(*id)))).collect()
+}
+
+fn constants_file_path(constants_dir: &Path, constants_enum: &ConstantsEnum) -> std::path::PathBuf {
+  constants_dir.join(format!("{}.rs", constants_enum.file_name))
+}
+
+// reads the ids from the variant list and name() function of an existing constants file
+fn read_existing_ids(constants_dir: &Path, constants_enum: &ConstantsEnum) -> HashMap<String, u32> {
+  let Ok(source) = std::fs::read_to_string(constants_file_path(constants_dir, constants_enum)) else { return HashMap::new() };
+  let mut variant_ids = HashMap::new();
+  let mut variant_names = HashMap::new();
+  let name_arm_prefix = format!("{}::", constants_enum.enum_name);
+  for line in source.lines().map(str::trim) {
+    if let Some((variant, id)) = line.strip_suffix(',').and_then(|l| l.split_once(" = ")) {
+      if let Ok(id) = id.parse::<u32>() { variant_ids.insert(variant.to_owned(), id); }
+    } else if let Some((variant, name)) = line.strip_prefix(&name_arm_prefix).and_then(|l| l.strip_suffix("\",")).and_then(|l| l.split_once(" => \"")) {
+      variant_names.insert(variant.to_owned(), name.to_owned());
+    }
+  }
+  variant_names.into_iter().filter_map(|(variant, name)| Some((name, *variant_ids.get(&variant)?))).collect()
+}
+
+fn write_constants_enum(constants_dir: &Path, constants_enum: &ConstantsEnum, mut ids: Vec<(String, u32)>, version: &str, extraction_method: &str) {
+  ids.sort_by_key(|(_, id)| *id);
+  report_id_changes(constants_enum.enum_name, &read_existing_ids(constants_dir, constants_enum), &ids);
+  std::fs::write(constants_file_path(constants_dir, constants_enum), render_constants_enum(constants_enum, &ids, version, extraction_method)).expect("couldn't write constants file");
+}
+
+fn report_id_changes(enum_name: &str, existing_ids: &HashMap<String, u32>, ids: &[(String, u32)]) {
+  let new_names: HashSet<&str> = ids.iter().map(|(name, _)| name.as_str()).collect();
+  let added: Vec<_> = ids.iter().filter(|(name, _)| !existing_ids.contains_key(name)).collect();
+  let renumbered: Vec<_> = ids.iter().filter_map(|(name, id)| Some((name, existing_ids.get(name).filter(|&old_id| old_id != id)?, id))).collect();
+  let mut removed: Vec<_> = existing_ids.iter().filter(|(name, _)| !new_names.contains(name.as_str())).collect();
+  removed.sort_by_key(|(_, id)| **id);
+
+  println!("{enum_name}: {} prototypes, {} added, {} removed, {} renumbered", ids.len(), added.len(), removed.len(), renumbered.len());
+  for (name, id) in added { println!("  + {name} = {id}"); }
+  for (name, id) in removed { println!("  - {name} (was {id})"); }
+  for (name, old_id, id) in renumbered { println!("  ~ {name}: {old_id} -> {id}"); }
+}
+
+// identifiers can't start with a digit, so digits in a leading word are spelled out, e.g. "1x2-remnants" => OneXTwoRemnants
+fn variant_name(name: &str) -> String {
+  if !name.starts_with(|c: char| c.is_ascii_digit()) { return heck::AsUpperCamelCase(name).to_string(); }
+  const DIGITS: [&str; 10] = ["Zero", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine"];
+  let (first_word, rest) = name.split_once('-').unwrap_or((name, ""));
+  let first_word: String = first_word.chars().map(|c| c.to_digit(10).map_or_else(|| c.to_ascii_uppercase().to_string(), |d| DIGITS[d as usize].to_owned())).collect();
+  first_word + &heck::AsUpperCamelCase(rest).to_string()
+}
+
+fn render_constants_enum(constants_enum: &ConstantsEnum, ids: &[(String, u32)], version: &str, extraction_method: &str) -> String {
+  let enum_name = constants_enum.enum_name;
+  let id_type = constants_enum.id_type;
+  let variants: Vec<(String, &str, u32)> = ids.iter().map(|(name, id)| (variant_name(name), name.as_str(), *id)).collect();
+  let mut seen_variants = HashSet::new();
+  for (variant, name, _) in &variants {
+    assert!(seen_variants.insert(variant), "{enum_name} prototype {name} maps to duplicate variant {variant}");
+  }
+
+  let mut s = String::new();
+  writeln!(s, "use enum_primitive_derive::Primitive;").unwrap();
+  writeln!(s, "use factorio_serialize_derive::PrototypeId{id_type};").unwrap();
+  writeln!(s, "use num_traits::{{FromPrimitive, ToPrimitive}};").unwrap();
+  writeln!(s).unwrap();
+  writeln!(s, "use super::UnknownPrototypeName;").unwrap();
+  writeln!(s).unwrap();
+  writeln!(s).unwrap();
+  writeln!(s, "// Version: {version}").unwrap();
+  writeln!(s, "// Extraction method: {extraction_method}").unwrap();
+  writeln!(s, "#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Primitive, PrototypeId{id_type})]").unwrap();
+  writeln!(s, "#[prototype_type({enum_name})]").unwrap();
+  writeln!(s, "pub enum {enum_name} {{").unwrap();
+  for (variant, _, id) in &variants {
+    writeln!(s, "  {variant} = {id},").unwrap();
+  }
+  writeln!(s, "}}").unwrap();
+  writeln!(s, "impl {enum_name} {{").unwrap();
+  writeln!(s, "  pub const ALL: [{enum_name}; {}] = [", variants.len()).unwrap();
+  for (variant, _, _) in &variants {
+    writeln!(s, "    {enum_name}::{variant},").unwrap();
+  }
+  writeln!(s, "  ];").unwrap();
+  writeln!(s).unwrap();
+  writeln!(s, "  pub fn iter() -> impl Iterator<Item = {enum_name}> {{").unwrap();
+  writeln!(s, "    {enum_name}::ALL.into_iter()").unwrap();
+  writeln!(s, "  }}").unwrap();
+  writeln!(s, "  pub fn name(self) -> &'static str {{").unwrap();
+  writeln!(s, "    match self {{").unwrap();
+  for (variant, name, _) in &variants {
+    writeln!(s, "      {enum_name}::{variant} => \"{name}\",").unwrap();
+  }
+  writeln!(s, "    }}").unwrap();
+  writeln!(s, "  }}").unwrap();
+  writeln!(s, "  pub fn try_from_name(name: &str) -> Option<{enum_name}> {{").unwrap();
+  writeln!(s, "    match name {{").unwrap();
+  for (variant, name, _) in &variants {
+    writeln!(s, "      \"{name}\" => Some({enum_name}::{variant}),").unwrap();
+  }
+  writeln!(s, "      _ => None,").unwrap();
+  writeln!(s, "    }}").unwrap();
+  writeln!(s, "  }}").unwrap();
+  writeln!(s, "  pub fn from_name(name: &str) -> {enum_name} {{").unwrap();
+  writeln!(s, "    {enum_name}::try_from_name(name).unwrap_or_else(|| panic!(\"unknown {enum_name} \\\"{{name}}\\\"\"))").unwrap();
+  writeln!(s, "  }}").unwrap();
+  writeln!(s, "}}").unwrap();
+  writeln!(s, "impl std::fmt::Display for {enum_name} {{").unwrap();
+  writeln!(s, "  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{").unwrap();
+  writeln!(s, "    f.write_str(self.name())").unwrap();
+  writeln!(s, "  }}").unwrap();
+  writeln!(s, "}}").unwrap();
+  writeln!(s, "impl std::str::FromStr for {enum_name} {{").unwrap();
+  writeln!(s, "  type Err = UnknownPrototypeName;").unwrap();
+  writeln!(s, "  fn from_str(name: &str) -> Result<{enum_name}, UnknownPrototypeName> {{").unwrap();
+  writeln!(s, "    {enum_name}::try_from_name(name).ok_or_else(|| UnknownPrototypeName {{ prototype_type: \"{enum_name}\", name: name.to_owned() }})").unwrap();
+  writeln!(s, "  }}").unwrap();
+  writeln!(s, "}}").unwrap();
+  s
+}
@@ -1,72 +1,229 @@
 use std::collections::HashMap;
 
-use factorio_serialize::{constants::{Fluid, Item, Recipe}, BoundingBox, FixedPoint32_8};
+use factorio_serialize::{constants::{Entity, Fluid, Item, Recipe, Technology, UnknownPrototypeName}, BoundingBox, FixedPoint32_8, Vector};
 
 use crate::prototypes::{self, Prototypes};
 
-#[derive(Debug)]
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ProductConfig {
+  // amount is the minimum amount for products with a random amount, probability is the chance of being produced at all
   Item {
     id: Item,
     amount: u32,
+    amount_max: u32,
+    probability: f64,
   },
   Fluid {
     id: Fluid,
     amount: f64,
+    amount_max: f64,
+    probability: f64,
   },
 }
 impl ProductConfig {
-  fn from_ingredient(ingredient: &prototypes::Ingredient) -> Self {
-    match ingredient {
-      prototypes::Ingredient::Item { name, amount } => ProductConfig::Item { id: Item::from_name(name), amount: *amount },
-      prototypes::Ingredient::Fluid { name, amount } => ProductConfig::Fluid { id: Fluid::from_name(name), amount: *amount },
-    }
+  pub fn item(id: Item, amount: u32) -> Self {
+    ProductConfig::Item { id, amount, amount_max: amount, probability: 1.0 }
   }
-  fn from_product(product: &prototypes::Product) -> Self {
-    match product {
-      prototypes::Product::Item { name, amount } => ProductConfig::Item { id: Item::from_name(name), amount: *amount },
-      prototypes::Product::Fluid { name, amount } => ProductConfig::Fluid { id: Fluid::from_name(name), amount: *amount },
-    }
+  pub fn fluid(id: Fluid, amount: f64) -> Self {
+    ProductConfig::Fluid { id, amount, amount_max: amount, probability: 1.0 }
+  }
+
+  fn from_ingredient(ingredient: &prototypes::Ingredient) -> Result<Self, UnknownPrototypeName> {
+    Ok(match ingredient {
+      prototypes::Ingredient::Item { name, amount } => ProductConfig::item(name.parse()?, *amount),
+      prototypes::Ingredient::Fluid { name, amount } => ProductConfig::fluid(name.parse()?, *amount),
+    })
+  }
+  // amount takes precedence over amount_min and amount_max, as in ItemProductPrototype
+  fn from_product(product: &prototypes::Product) -> Result<Self, UnknownPrototypeName> {
+    Ok(match product {
+      prototypes::Product::Item { name, amount, amount_min, amount_max, probability } => {
+        let amount_min = amount.or(*amount_min).expect("item product without amount");
+        let amount_max = amount.or(*amount_max).expect("item product without amount");
+        ProductConfig::Item { id: name.parse()?, amount: amount_min, amount_max: amount_max.max(amount_min), probability: probability.unwrap_or(1.0) }
+      },
+      prototypes::Product::Fluid { name, amount, amount_min, amount_max, probability } => {
+        let amount_min = amount.or(*amount_min).expect("fluid product without amount");
+        let amount_max = amount.or(*amount_max).expect("fluid product without amount");
+        ProductConfig::Fluid { id: name.parse()?, amount: amount_min, amount_max: amount_max.max(amount_min), probability: probability.unwrap_or(1.0) }
+      },
+    })
   }
 }
 
 #[derive(Debug)]
 pub struct RecipeConfig {
+  pub category: String,
+  pub enabled: bool,  // available from the start, otherwise unlocked by research
   pub ingredients: Vec<ProductConfig>,
   pub results: Vec<ProductConfig>,
   pub energy_required: f64,
 }
 impl RecipeConfig {
-  fn from_prototype(recipe: &prototypes::Recipe) -> Self {
+  fn from_prototype(recipe: &prototypes::Recipe) -> Result<Self, UnknownPrototypeName> {
+    let category = recipe.category.clone().unwrap_or_else(|| String::from("crafting"));
     if let Some(normal) = &recipe.normal {
+      let enabled = normal.enabled.unwrap_or(true);
       let energy_required = normal.energy_required.unwrap_or(0.5);
-      let ingredients = normal.ingredients.iter().map(|i| ProductConfig::from_ingredient(i)).collect();
+      let ingredients = normal.ingredients.iter().map(ProductConfig::from_ingredient).collect::<Result<_, _>>()?;
       let results = if let Some(results) = &normal.results {
-        results.iter().map(|i| ProductConfig::from_product(i)).collect()
+        results.iter().map(ProductConfig::from_product).collect::<Result<_, _>>()?
       } else {
-        vec![ProductConfig::Item { id: Item::from_name(normal.result.as_ref().unwrap()), amount: normal.result_count.unwrap_or(1) }]
+        vec![ProductConfig::item(normal.result.as_ref().unwrap().parse()?, normal.result_count.unwrap_or(1))]
       };
-      RecipeConfig { ingredients, results, energy_required }
+      Ok(RecipeConfig { category, enabled, ingredients, results, energy_required })
     } else {
+      let enabled = recipe.enabled.unwrap_or(true);
       let energy_required = recipe.energy_required.unwrap_or(0.5);
-      let ingredients = recipe.ingredients.as_ref().unwrap().iter().map(|i| ProductConfig::from_ingredient(i)).collect();
+      let ingredients = recipe.ingredients.as_ref().unwrap().iter().map(ProductConfig::from_ingredient).collect::<Result<_, _>>()?;
       let results = if let Some(results) = &recipe.results {
-        results.iter().map(|i| ProductConfig::from_product(i)).collect()
+        results.iter().map(ProductConfig::from_product).collect::<Result<_, _>>()?
       } else {
-        vec![ProductConfig::Item { id: Item::from_name(recipe.result.as_ref().unwrap()), amount: recipe.result_count.unwrap_or(1) }]
+        vec![ProductConfig::item(recipe.result.as_ref().unwrap().parse()?, recipe.result_count.unwrap_or(1))]
       };
-      RecipeConfig { ingredients, results, energy_required }
+      Ok(RecipeConfig { category, enabled, ingredients, results, energy_required })
     }
   }
 }
 
+#[derive(Debug)]
+pub struct TechnologyConfig {
+  pub prerequisites: Vec<Technology>,
+  pub count: u64,
+  pub time: f64,  // seconds per unit
+  pub ingredients: Vec<(Item, u32)>,  // science packs per unit
+  pub unlocked_recipes: Vec<Recipe>,
+  pub inserter_stack_size_bonus: u32,
+  pub stack_inserter_capacity_bonus: u32,
+}
+impl TechnologyConfig {
+  fn from_prototype(technology: &prototypes::Technology) -> Result<Self, UnknownPrototypeName> {
+    Ok(TechnologyConfig {
+      prerequisites: technology.prerequisites.iter().flatten().map(|name| name.parse()).collect::<Result<_, _>>()?,
+      count: technology.unit.count.expect("technology without unit count"),
+      time: technology.unit.time,
+      ingredients: technology.unit.ingredients.iter().map(|ingredient| match ingredient {
+        prototypes::Ingredient::Item { name, amount } => Ok((name.parse()?, *amount)),
+        prototypes::Ingredient::Fluid { name, .. } => panic!("technology with fluid ingredient {name}"),
+      }).collect::<Result<_, _>>()?,
+      unlocked_recipes: technology.effects.iter().flatten().filter_map(|effect| match effect {
+        prototypes::TechnologyEffect::UnlockRecipe { recipe } => Some(recipe.parse()),
+        _ => None,
+      }).collect::<Result<_, _>>()?,
+      inserter_stack_size_bonus: technology.effects.iter().flatten().map(|effect| match effect {
+        prototypes::TechnologyEffect::InserterStackSizeBonus { modifier } => *modifier,
+        _ => 0,
+      }).sum(),
+      stack_inserter_capacity_bonus: technology.effects.iter().flatten().map(|effect| match effect {
+        prototypes::TechnologyEffect::StackInserterCapacityBonus { modifier } => *modifier,
+        _ => 0,
+      }).sum(),
+    })
+  }
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct MinableConfig {
+  pub mining_time: f64,
+  pub results: Vec<ProductConfig>,
+  pub collision_box: BoundingBox,
+}
+impl MinableConfig {
+  fn from_prototype(minable: &prototypes::Minable, collision_box: &prototypes::BoundingBox) -> Result<Self, UnknownPrototypeName> {
+    let results = if let Some(results) = &minable.results {
+      results.iter().map(ProductConfig::from_product).collect::<Result<_, _>>()?
+    } else if let Some(result) = &minable.result {
+      vec![ProductConfig::item(result.parse()?, minable.count.unwrap_or(1))]
+    } else {
+      vec![]
+    };
+    Ok(MinableConfig { mining_time: minable.mining_time, results, collision_box: collision_box.to_struct() })
+  }
+}
+
+#[derive(Debug)]
+pub struct ContainerConfig {
+  pub inventory_size: u32,
+}
+impl ContainerConfig {
+  fn from_prototype(container: &prototypes::Container) -> Self {
+    ContainerConfig { inventory_size: container.inventory_size }
+  }
+}
+
 #[derive(Debug)]
 pub struct ItemConfig {
   pub stack_size: u32,
+  pub sort_key: ItemSortKey,
+  pub item_type: ItemType,
 }
 impl ItemConfig {
-  fn from_prototype(item: &prototypes::Item) -> Self {
-    ItemConfig { stack_size: item.stack_size }
+  fn from_prototype(name: &str, item: &prototypes::Item, item_type: ItemType, prototypes: &Prototypes) -> Self {
+    ItemConfig { stack_size: item.stack_size, sort_key: ItemSortKey::new(name, item.subgroup.as_deref(), item.order.as_deref(), prototypes), item_type }
+  }
+  fn from_tool(name: &str, tool: &prototypes::Tool, prototypes: &Prototypes) -> Self {
+    ItemConfig { stack_size: tool.stack_size, sort_key: ItemSortKey::new(name, tool.subgroup.as_deref(), tool.order.as_deref(), prototypes), item_type: ItemType::Tool }
+  }
+}
+// prototype type of an item, guns and ammo inserted into a character go into its gun and ammo inventories instead of the main one
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ItemType {
+  Item,
+  Tool,
+  Ammo,
+  Armor,
+  Capsule,
+  Gun,
+  ItemWithEntityData,
+  Module,
+  RailPlanner,
+  RepairTool,
+  SpidertronRemote,
+  Blueprint,
+  BlueprintBook,
+  DeconstructionItem,
+  UpgradeItem,
+  CopyPasteTool,
+  SelectionTool,
+}
+
+// items are ordered by item group, subgroup and their own order string, each ordered by their order string first and name second
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ItemSortKey {
+  group_order: String,
+  group: String,
+  subgroup_order: String,
+  subgroup: String,
+  order: String,
+  name: String,
+}
+impl ItemSortKey {
+  fn new(name: &str, subgroup: Option<&str>, order: Option<&str>, prototypes: &Prototypes) -> Self {
+    // items without a subgroup default to the "other" subgroup
+    let subgroup = subgroup.unwrap_or("other");
+    let subgroup_prototype = prototypes.item_subgroup.get(subgroup).unwrap_or_else(|| panic!("unknown item subgroup {subgroup} of {name}"));
+    let group_prototype = prototypes.item_group.get(&subgroup_prototype.group).unwrap_or_else(|| panic!("unknown item group {} of {subgroup}", subgroup_prototype.group));
+    ItemSortKey {
+      group_order: group_prototype.order.clone(),
+      group: subgroup_prototype.group.clone(),
+      subgroup_order: subgroup_prototype.order.clone(),
+      subgroup: subgroup.to_owned(),
+      order: order.unwrap_or_default().to_owned(),
+      name: name.to_owned(),
+    }
+  }
+}
+
+// science packs, used up by labs
+#[derive(Debug)]
+pub struct ToolConfig {
+  pub durability: f64,
+}
+impl ToolConfig {
+  fn from_prototype(tool: &prototypes::Tool) -> Self {
+    ToolConfig { durability: tool.durability }
   }
 }
 
@@ -80,6 +237,189 @@ impl FuelConfig {
   }
 }
 
+#[derive(Debug)]
+pub struct FluidConfig {
+  pub default_temperature: f64,
+  pub heat_capacity: f64,  // energy per unit and degree
+}
+impl FluidConfig {
+  fn from_prototype(fluid: &prototypes::Fluid) -> Option<Self> {
+    Some(FluidConfig { default_temperature: fluid.default_temperature as f64, heat_capacity: fluid.heat_capacity.as_ref()?.parse() })
+  }
+}
+
+#[derive(Debug)]
+pub struct FluidBoxConfig {
+  pub base_area: f64,
+  pub capacity: f64,
+  pub pipe_connections: Vec<Vector>,  // facing north, relative to the entity position
+}
+impl FluidBoxConfig {
+  // from FluidBoxPrototype::getCapacity
+  fn from_prototype(fluid_box: &prototypes::FluidBox) -> Self {
+    FluidBoxConfig {
+      base_area: fluid_box.base_area,
+      capacity: fluid_box.base_area * fluid_box.height.unwrap_or(1.0) * 100.0,
+      pipe_connections: fluid_box.pipe_connections.iter().map(|connection| connection.position.to_vector()).collect(),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct BoilerConfig {
+  pub collision_box: BoundingBox,  // facing north
+  pub energy_consumption: f64,
+  pub target_temperature: f64,
+  pub fluid_box: FluidBoxConfig,
+  pub output_fluid_box: FluidBoxConfig,
+}
+impl BoilerConfig {
+  fn from_prototype(boiler: &prototypes::Boiler) -> Self {
+    BoilerConfig {
+      collision_box: boiler.collision_box.to_struct(),
+      energy_consumption: boiler.energy_consumption.parse(),
+      target_temperature: boiler.target_temperature,
+      fluid_box: FluidBoxConfig::from_prototype(&boiler.fluid_box),
+      output_fluid_box: FluidBoxConfig::from_prototype(&boiler.output_fluid_box),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct GeneratorConfig {
+  pub collision_box: BoundingBox,  // facing north
+  pub effectivity: f64,
+  pub fluid_usage_per_tick: f64,
+  pub maximum_temperature: f64,
+  pub fluid_box: FluidBoxConfig,
+}
+impl GeneratorConfig {
+  fn from_prototype(generator: &prototypes::Generator) -> Self {
+    GeneratorConfig {
+      collision_box: generator.collision_box.to_struct(),
+      effectivity: generator.effectivity,
+      fluid_usage_per_tick: generator.fluid_usage_per_tick,
+      maximum_temperature: generator.maximum_temperature as f64,
+      fluid_box: FluidBoxConfig::from_prototype(&generator.fluid_box),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct OffshorePumpConfig {
+  pub collision_box: BoundingBox,  // facing north
+  pub pumping_speed: f64,
+  pub fluid_box: FluidBoxConfig,
+}
+impl OffshorePumpConfig {
+  fn from_prototype(offshore_pump: &prototypes::OffshorePump) -> Self {
+    OffshorePumpConfig {
+      collision_box: offshore_pump.collision_box.to_struct(),
+      pumping_speed: offshore_pump.pumping_speed,
+      fluid_box: FluidBoxConfig::from_prototype(&offshore_pump.fluid_box),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct AssemblingMachineConfig {
+  pub collision_box: BoundingBox,
+  pub crafting_categories: Vec<String>,
+  pub crafting_speed: f64,
+  pub energy_usage: f64,
+  pub drain: f64,
+}
+impl AssemblingMachineConfig {
+  fn from_prototype(assembling_machine: &prototypes::AssemblingMachine) -> Self {
+    let energy_usage = assembling_machine.energy_usage.parse();
+    AssemblingMachineConfig {
+      collision_box: assembling_machine.collision_box.to_struct(),
+      crafting_categories: assembling_machine.crafting_categories.clone(),
+      crafting_speed: assembling_machine.crafting_speed,
+      energy_usage,
+      drain: energy_usage / 30.0,  // from CraftingMachinePrototype, default drain of electric crafting machines
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct LabConfig {
+  pub collision_box: BoundingBox,
+  pub energy_usage: f64,
+  pub researching_speed: f64,
+  pub inputs: Vec<Item>,
+}
+impl LabConfig {
+  fn from_prototype(lab: &prototypes::Lab) -> Result<Self, UnknownPrototypeName> {
+    Ok(LabConfig {
+      collision_box: lab.collision_box.to_struct(),
+      energy_usage: lab.energy_usage.parse(),
+      researching_speed: lab.researching_speed,
+      inputs: lab.inputs.iter().map(|name| name.parse()).collect::<Result<_, _>>()?,
+    })
+  }
+}
+
+#[derive(Debug)]
+pub struct InserterConfig {
+  pub collision_box: BoundingBox,
+  pub stack: bool,
+  pub burner: bool,  // burner inserters, all others are electric
+  pub energy_per_movement: f64,  // per tile of extension
+  pub energy_per_rotation: f64,  // per full turn
+  pub drain: f64,
+  pub extension_speed: f64,
+  pub rotation_speed: f64,
+  pub pickup_position: Vector,  // facing north
+  pub insert_position: Vector,  // facing north
+}
+impl InserterConfig {
+  fn from_prototype(inserter: &prototypes::Inserter) -> Self {
+    let (burner, drain) = match &inserter.energy_source {
+      prototypes::EnergySource::Burner { .. } => (true, 0.0),
+      prototypes::EnergySource::Electric { drain, .. } => (false, drain.as_ref().map_or(0.0, |drain| drain.parse())),
+      energy_source => panic!("inserter with unsupported energy source {energy_source:?}"),
+    };
+    InserterConfig {
+      collision_box: inserter.collision_box.to_struct(),
+      stack: inserter.stack.unwrap_or(false),
+      burner,
+      energy_per_movement: inserter.energy_per_movement.parse(),
+      energy_per_rotation: inserter.energy_per_rotation.parse(),
+      drain,
+      extension_speed: inserter.extension_speed,
+      rotation_speed: inserter.rotation_speed,
+      pickup_position: inserter.pickup_position.to_vector(),
+      insert_position: inserter.insert_position.to_vector(),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct TransportBeltConfig {
+  pub speed: f64,  // tiles per tick
+}
+impl TransportBeltConfig {
+  fn from_prototype(transport_belt: &prototypes::TransportBelt) -> Self {
+    TransportBeltConfig { speed: transport_belt.speed }
+  }
+}
+
+#[derive(Debug)]
+pub struct ElectricPoleConfig {
+  pub maximum_wire_distance: f64,
+  pub supply_area_distance: f64,
+}
+impl ElectricPoleConfig {
+  fn from_prototype(electric_pole: &prototypes::ElectricPole) -> Self {
+    ElectricPoleConfig {
+      maximum_wire_distance: electric_pole.maximum_wire_distance,
+      supply_area_distance: electric_pole.supply_area_distance,
+    }
+  }
+}
+
+#[allow(dead_code)]
 #[derive(Debug)]
 pub struct GameConfig {
   pub player_movement_speed: f64,
@@ -90,10 +430,6 @@ pub struct GameConfig {
   pub player_mining_speed: f64,
   pub player_inventory_size: u32,
 
-  pub dry_tree_bounding_box: BoundingBox,
-  pub dry_tree_mining_time: f64,
-  pub huge_rock_bounding_box: BoundingBox,
-  pub huge_rock_mining_time: f64,
   pub iron_ore_bounding_box: BoundingBox,
   pub iron_ore_mining_time: f64,
   pub copper_ore_bounding_box: BoundingBox,
@@ -101,12 +437,32 @@ pub struct GameConfig {
 
   pub burner_miner_energy_usage: f64,
   pub burner_miner_speed: f64,
+  pub burner_miner_vector_to_place_result: Vector,  // facing north
+  pub electric_miner_energy_usage: f64,
+  pub electric_miner_speed: f64,
+  pub electric_miner_collision_box: BoundingBox,
+  pub electric_miner_resource_searching_radius: f64,
+  pub electric_miner_vector_to_place_result: Vector,  // facing north
   pub stone_furnace_energy_usage: f64,
   pub stone_furnace_speed: f64,
+  pub pipe_fluid_box: FluidBoxConfig,
 
   pub fuels: HashMap<Item, FuelConfig>,
   pub items: HashMap<Item, ItemConfig>,
+  pub tools: HashMap<Item, ToolConfig>,
   pub recipes: HashMap<Recipe, RecipeConfig>,
+  pub technologies: HashMap<Technology, TechnologyConfig>,
+  pub minables: HashMap<Entity, MinableConfig>,
+  pub containers: HashMap<Entity, ContainerConfig>,
+  pub fluids: HashMap<Fluid, FluidConfig>,
+  pub boilers: HashMap<Entity, BoilerConfig>,
+  pub generators: HashMap<Entity, GeneratorConfig>,
+  pub offshore_pumps: HashMap<Entity, OffshorePumpConfig>,
+  pub electric_poles: HashMap<Entity, ElectricPoleConfig>,
+  pub assembling_machines: HashMap<Entity, AssemblingMachineConfig>,
+  pub labs: HashMap<Entity, LabConfig>,
+  pub inserters: HashMap<Entity, InserterConfig>,
+  pub transport_belts: HashMap<Entity, TransportBeltConfig>,
 }
 impl GameConfig {
   fn from_prototypes(prototypes: Prototypes) -> Self {
@@ -119,10 +475,6 @@ impl GameConfig {
       player_mining_speed: prototypes.character["character"].mining_speed,
       player_inventory_size: prototypes.character["character"].inventory_size,
 
-      dry_tree_bounding_box: prototypes.tree["dry-tree"].collision_box.to_struct(),
-      dry_tree_mining_time: prototypes.tree["dry-tree"].minable.mining_time,
-      huge_rock_bounding_box: prototypes.simple_entity["rock-huge"].collision_box.to_struct(),
-      huge_rock_mining_time: prototypes.simple_entity["rock-huge"].minable.as_ref().expect("huge rock not minable").mining_time,
       iron_ore_bounding_box: prototypes.resource["iron-ore"].collision_box.to_struct(),
       iron_ore_mining_time: prototypes.resource["iron-ore"].minable.mining_time,
       copper_ore_bounding_box: prototypes.resource["copper-ore"].collision_box.to_struct(),
@@ -130,12 +482,59 @@ impl GameConfig {
 
       burner_miner_energy_usage: prototypes.mining_drill["burner-mining-drill"].energy_usage.parse(),
       burner_miner_speed: prototypes.mining_drill["burner-mining-drill"].mining_speed,
+      burner_miner_vector_to_place_result: prototypes.mining_drill["burner-mining-drill"].vector_to_place_result.as_ref().expect("burner miner without result position").to_vector(),
+      electric_miner_energy_usage: prototypes.mining_drill["electric-mining-drill"].energy_usage.parse(),
+      electric_miner_speed: prototypes.mining_drill["electric-mining-drill"].mining_speed,
+      electric_miner_collision_box: prototypes.mining_drill["electric-mining-drill"].collision_box.to_struct(),
+      electric_miner_resource_searching_radius: prototypes.mining_drill["electric-mining-drill"].resource_searching_radius,
+      electric_miner_vector_to_place_result: prototypes.mining_drill["electric-mining-drill"].vector_to_place_result.as_ref().expect("electric miner without result position").to_vector(),
       stone_furnace_energy_usage: prototypes.furnace["stone-furnace"].energy_usage.parse(),
       stone_furnace_speed: prototypes.furnace["stone-furnace"].crafting_speed,
+      pipe_fluid_box: FluidBoxConfig::from_prototype(&prototypes.pipe["pipe"].fluid_box),
 
-      fuels: prototypes.item.iter().filter_map(|(name, item)| Some((Item::from_name(name), FuelConfig::from_prototype(item)?))).collect(),
-      items: prototypes.item.iter().map(|(name, item)| (Item::from_name(name), ItemConfig::from_prototype(item))).collect(),
-      recipes: prototypes.recipe.iter().map(|(name, recipe)| (Recipe::from_name(name), RecipeConfig::from_prototype(recipe))).collect(),
+      // prototypes unknown to the constants enums (e.g. from mods) are skipped, known ones which fail to convert are reported
+      fuels: prototypes.item.iter().filter_map(|(name, item)| Some((Item::try_from_name(name)?, FuelConfig::from_prototype(item)?))).collect(),
+      items: [
+        (&prototypes.item, ItemType::Item),
+        (&prototypes.ammo, ItemType::Ammo),
+        (&prototypes.armor, ItemType::Armor),
+        (&prototypes.capsule, ItemType::Capsule),
+        (&prototypes.gun, ItemType::Gun),
+        (&prototypes.item_with_entity_data, ItemType::ItemWithEntityData),
+        (&prototypes.module, ItemType::Module),
+        (&prototypes.rail_planner, ItemType::RailPlanner),
+        (&prototypes.repair_tool, ItemType::RepairTool),
+        (&prototypes.spidertron_remote, ItemType::SpidertronRemote),
+        (&prototypes.blueprint, ItemType::Blueprint),
+        (&prototypes.blueprint_book, ItemType::BlueprintBook),
+        (&prototypes.deconstruction_item, ItemType::DeconstructionItem),
+        (&prototypes.upgrade_item, ItemType::UpgradeItem),
+        (&prototypes.copy_paste_tool, ItemType::CopyPasteTool),
+        (&prototypes.selection_tool, ItemType::SelectionTool),
+      ].into_iter().flat_map(|(items, item_type)| items.iter().map(move |(name, item)| (name, item, item_type)))
+        .filter_map(|(name, item, item_type)| Some((Item::try_from_name(name)?, ItemConfig::from_prototype(name, item, item_type, &prototypes))))
+        .chain(prototypes.tool.iter().filter_map(|(name, tool)| Some((Item::try_from_name(name)?, ItemConfig::from_tool(name, tool, &prototypes))))).collect(),
+      tools: prototypes.tool.iter().filter_map(|(name, tool)| Some((Item::try_from_name(name)?, ToolConfig::from_prototype(tool)))).collect(),
+      recipes: prototypes.recipe.iter().filter_map(|(name, recipe)| Some((Recipe::try_from_name(name)?, RecipeConfig::from_prototype(recipe).unwrap_or_else(|e| panic!("recipe {name}: {e}"))))).collect(),
+      // infinite technologies with a count formula are not supported
+      technologies: prototypes.technology.iter().filter(|(_, technology)| technology.unit.count.is_some())
+        .filter_map(|(name, technology)| Some((Technology::try_from_name(name)?, TechnologyConfig::from_prototype(technology).unwrap_or_else(|e| panic!("technology {name}: {e}"))))).collect(),
+      minables: prototypes.simple_entity.iter().filter_map(|(name, simple_entity)| Some((name, simple_entity.minable.as_ref()?, &simple_entity.collision_box)))
+        .chain(prototypes.tree.iter().map(|(name, tree)| (name, &tree.minable, &tree.collision_box)))
+        .chain(prototypes.resource.iter().map(|(name, resource)| (name, &resource.minable, &resource.collision_box)))
+        .filter_map(|(name, minable, collision_box)| Some((Entity::try_from_name(name)?, MinableConfig::from_prototype(minable, collision_box).unwrap_or_else(|e| panic!("minable {name}: {e}"))))).collect(),
+      containers: prototypes.container.iter().filter_map(|(name, container)| Some((Entity::try_from_name(name)?, ContainerConfig::from_prototype(container)))).collect(),
+      fluids: prototypes.fluid.iter().filter_map(|(name, fluid)| Some((Fluid::try_from_name(name)?, FluidConfig::from_prototype(fluid)?))).collect(),
+      // heat exchangers are boilers with a heat energy source, which is not simulated
+      boilers: prototypes.boiler.iter().filter(|(_, boiler)| matches!(boiler.energy_source, prototypes::EnergySource::Burner { .. }))
+        .filter_map(|(name, boiler)| Some((Entity::try_from_name(name)?, BoilerConfig::from_prototype(boiler)))).collect(),
+      generators: prototypes.generator.iter().filter_map(|(name, generator)| Some((Entity::try_from_name(name)?, GeneratorConfig::from_prototype(generator)))).collect(),
+      offshore_pumps: prototypes.offshore_pump.iter().filter_map(|(name, offshore_pump)| Some((Entity::try_from_name(name)?, OffshorePumpConfig::from_prototype(offshore_pump)))).collect(),
+      electric_poles: prototypes.electric_pole.iter().filter_map(|(name, electric_pole)| Some((Entity::try_from_name(name)?, ElectricPoleConfig::from_prototype(electric_pole)))).collect(),
+      assembling_machines: prototypes.assembling_machine.iter().filter_map(|(name, assembling_machine)| Some((Entity::try_from_name(name)?, AssemblingMachineConfig::from_prototype(assembling_machine)))).collect(),
+      labs: prototypes.lab.iter().filter_map(|(name, lab)| Some((Entity::try_from_name(name)?, LabConfig::from_prototype(lab).unwrap_or_else(|e| panic!("lab {name}: {e}"))))).collect(),
+      inserters: prototypes.inserter.iter().filter_map(|(name, inserter)| Some((Entity::try_from_name(name)?, InserterConfig::from_prototype(inserter)))).collect(),
+      transport_belts: prototypes.transport_belt.iter().filter_map(|(name, transport_belt)| Some((Entity::try_from_name(name)?, TransportBeltConfig::from_prototype(transport_belt)))).collect(),
     }
   }
 }
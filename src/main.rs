@@ -1,22 +1,48 @@
+mod constantsgenerator;
 mod gameconfig;
 mod hexfloat;
 mod runner;
 mod prototypes;
 mod random;
+mod seedsearch;
 mod simulation;
 mod singleplayerrunner;
 mod util;
 
 use factorio_serialize::constants::*;
+use factorio_serialize::map::{FlowStatisticsType, MapData, MapForces, RandomGeneratorRole};
+use factorio_serialize::RandomGenerator;
+use factorio_serialize::save::SaveFile;
 use factorio_serialize::replay::Direction;
 use factorio_serialize::FixedPoint32_8;
 use factorio_serialize::MapPosition;
 use factorio_serialize::replay::ReplayData;
+use factorio_serialize::script::ScriptData;
 use factorio_serialize::TilePosition;
+use gameconfig::ProductConfig;
 use runner::Runner;
+use simulation::GameState;
 use crate::singleplayerrunner::*;
 
 fn main() {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  if let Some(command) = args.first() {
+    let result = match command.as_str() {
+      "set-rng" => set_rng_command(&args[1..]),
+      "flow-statistics" => flow_statistics_command(&args[1..]),
+      "simulate-replay" => simulate_replay_command(&args[1..]),
+      _ => Err(format!("unknown command {command}")),
+    };
+    if let Err(e) = result {
+      eprintln!("{e}");
+      eprintln!("usage: set-rng <save name> <general|ai|entities|map|triggers> <seed1> <seed2> <seed3> <output save name|--dry-run>");
+      eprintln!("       flow-statistics <save name> <item|fluid|kill|build> <precision index 0-7> [output file]");
+      eprintln!("       simulate-replay <save name>");
+      std::process::exit(1);
+    }
+    return;
+  }
+
   // assemble_test_tas();
   // assemble_automation_tas();
   // test_float();
@@ -25,7 +51,8 @@ fn main() {
   // crate::util::load_and_verify_map_test("11107scenarioreplay");
   // crate::util::load_and_save_replay_test("11107scenarioreplay");
   // crate::util::load_and_save_script_test("11107scenarioreplay");
-  // crate::util::export_prototypes("11107scenarioreplay");
+  // crate::util::dump_script_state("11107scenarioreplay", "script_state.lua");
+  // crate::constantsgenerator::generate_constants_from_save("11107scenarioreplay", std::path::Path::new("factorio-serialize/src/constants"));
   // crate::util::clean_up_save_file("11107scenarioreplay", "11107template");
   // crate::util::load_and_verify_map_test("test2");
   // crate::prototypes::create_minimized_prototypes();
@@ -33,6 +60,79 @@ fn main() {
   create_test_replay();
 }
 
+// Writes a save with one of its RNGs changed, in place or as a copy, or with --dry-run only shows what would change.
+fn set_rng_command(args: &[String]) -> Result<(), String> {
+  let [save_name, role, seed1, seed2, seed3, output] = args else { return Err("wrong number of arguments".to_owned()) };
+  let output_name = if output == "--dry-run" { None } else { Some(output) };
+  let role = RandomGeneratorRole::try_from_name(role).ok_or_else(|| format!("unknown RNG {role}"))?;
+  let parse_seed = |seed: &String| seed.parse::<u32>().map_err(|e| format!("invalid seed {seed}: {e}"));
+  let new_rng = RandomGenerator::new(parse_seed(seed1)?, parse_seed(seed2)?, parse_seed(seed3)?);
+
+  // only the start of the map is read, the entities of played saves can't all be parsed
+  let save_file = SaveFile::load_save_file(save_name).map_err(|e| e.to_string())?;
+  println!("current {role} RNG: {:?}", MapData::read_random_generator(save_file.current_level_dat(), role).map_err(|e| e.to_string())?);
+  println!("new {role} RNG: {new_rng:?}");
+  if role == RandomGeneratorRole::Entities {
+    for entity in [Entity::RockHuge, Entity::SandRockBig] {
+      for (i, results) in random::predict_mining_results(&new_rng, entity, 10).into_iter().enumerate() {
+        let results: Vec<_> = results.iter().map(|result| match result {
+          ProductConfig::Item { id, amount, .. } => format!("{amount} {id}"),
+          ProductConfig::Fluid { id, amount, .. } => format!("{amount} {id}"),
+        }).collect();
+        println!("{entity} {}: {}", i + 1, results.join(", "));
+      }
+    }
+  }
+  let Some(output_name) = output_name else { return Ok(()) };
+
+  // both the initial state replays start from and the current state the save continues from get the new RNG
+  let level_dat = MapData::patch_random_generator(save_file.current_level_dat(), role, &new_rng).map_err(|e| e.to_string())?;
+  let level_init_dat = MapData::patch_random_generator(&save_file.level_init_dat, role, &new_rng).map_err(|e| e.to_string())?;
+  SaveFile::write_patched_save_file(save_name, output_name, &level_init_dat, &level_dat).map_err(|e| e.to_string())?;
+  println!("wrote {output_name}");
+  Ok(())
+}
+
+// Exports the player force's production graph samples as CSV, to stdout or the given file.
+fn flow_statistics_command(args: &[String]) -> Result<(), String> {
+  let (save_name, statistics_type, precision_index, output_file) = match args {
+    [save_name, statistics_type, precision_index] => (save_name, statistics_type, precision_index, None),
+    [save_name, statistics_type, precision_index, output_file] => (save_name, statistics_type, precision_index, Some(output_file)),
+    _ => return Err("wrong number of arguments".to_owned()),
+  };
+  let statistics_type = FlowStatisticsType::try_from_name(statistics_type).ok_or_else(|| format!("unknown statistics {statistics_type}"))?;
+  let precision_index = precision_index.parse::<usize>().map_err(|e| format!("invalid precision index {precision_index}: {e}"))?;
+
+  let save_file = SaveFile::load_save_file(save_name).map_err(|e| e.to_string())?;
+  // the statistics as of when the save was made, not as of the start of the replay
+  let map_forces = MapForces::read_map_forces(save_file.current_level_dat()).map_err(|e| e.to_string())?;
+  let csv = map_forces.flow_statistics_csv(factorio_serialize::replay::ForceId::Player, statistics_type, precision_index).map_err(|e| e.to_string())?;
+  match output_file {
+    Some(output_file) => std::fs::write(output_file, csv).map_err(|e| format!("couldn't write {output_file}: {e}"))?,
+    None => print!("{csv}"),
+  }
+  Ok(())
+}
+
+// Replays the recorded input actions of a save in the simulation and prints the resulting player state.
+fn simulate_replay_command(args: &[String]) -> Result<(), String> {
+  let [save_name] = args else { return Err("wrong number of arguments".to_owned()) };
+
+  let save_file = SaveFile::load_save_file(save_name).map_err(|e| e.to_string())?;
+  let map_data = MapData::parse_map_data(&save_file.level_init_dat).map_err(|e| e.to_string())?;
+  let replay_data = ReplayData::parse_replay_data_with_registry(&save_file.replay_dat, map_data.prototype_registry()).map_err(|e| e.to_string())?;
+  let script_data = ScriptData::parse_script_data(&save_file.script_init_dat).map_err(|e| e.to_string())?;
+  let game_state = GameState::simulate_replay(&map_data, &script_data, &replay_data).map_err(|e| e.to_string())?;
+  println!("simulated {} input actions up to tick {}", replay_data.actions.len(), game_state.tick);
+  println!("player position: {:?}", game_state.player_position());
+  let mut inventory: Vec<_> = game_state.player_inventory().iter().collect();
+  inventory.sort_by_key(|(item, _)| item.name());
+  for (item, count) in inventory {
+    println!("{item}: {count}");
+  }
+  Ok(())
+}
+
 // Double a: 0.00416666666666666574148081281236954964697360992431640625   // 4803839602528528 / 2^60
 //           0.0041666666666666660745477201999165117740631103515625   //  2345624805922133 / 1000 * 2^49
 // Double b: 0.004166666666666666608842550800773096852935850620269775390625  //  4803839602528529 / 2^60
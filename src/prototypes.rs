@@ -1,5 +1,5 @@
-use factorio_serialize::FixedPoint32_8;
-use serde::{Deserialize, Deserializer, Serialize};
+use factorio_serialize::{FixedPoint32_8, Vector};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 use std::collections::HashMap;
@@ -11,6 +11,11 @@ pub struct Point {
   x: f64,
   y: f64,
 }
+impl Point {
+  pub fn to_vector(&self) -> Vector {
+    Vector::new(self.x, self.y)
+  }
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BoundingBox {
@@ -33,13 +38,20 @@ pub struct Minable {
   pub mining_time: f64,
   pub result: Option<String>,
   pub count: Option<u32>,
+  pub results: Option<Vec<Product>>,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FluidBox {
-  base_area: f64,
-  height: Option<f64>,
+  pub base_area: f64,
+  pub height: Option<f64>,
+  #[serde(default)] pub pipe_connections: Vec<PipeConnection>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PipeConnection {
+  pub position: Point,
 }
 
 #[skip_serializing_none]
@@ -48,11 +60,17 @@ pub struct FluidBox {
 pub enum Product {
   #[serde(rename = "item")] Item {
     name: String,
-    amount: u32,
+    amount: Option<u32>,
+    amount_min: Option<u32>,
+    amount_max: Option<u32>,
+    probability: Option<f64>,
   },
   #[serde(rename = "fluid")] Fluid {
     name: String,
-    amount: f64,
+    amount: Option<f64>,
+    amount_min: Option<f64>,
+    amount_max: Option<f64>,
+    probability: Option<f64>,
   },
 }
 impl<'de> Deserialize<'de> for Product {
@@ -61,7 +79,10 @@ impl<'de> Deserialize<'de> for Product {
     if let Some(array) = value.as_array() {
       Ok(Product::Item {
         name: serde_json::from_value(array[0].clone()).unwrap(),
-        amount: serde_json::from_value(array[1].clone()).unwrap(),
+        amount: Some(serde_json::from_value(array[1].clone()).unwrap()),
+        amount_min: None,
+        amount_max: None,
+        probability: None,
       })
     } else if let Some(object) = value.as_object() {
       let typ: String = object.get("type").map(|v| serde_json::from_value(v.clone()).unwrap()).unwrap_or_default();
@@ -69,13 +90,19 @@ impl<'de> Deserialize<'de> for Product {
         "fluid" => {
           Ok(Product::Fluid {
             name: serde_json::from_value(object["name"].clone()).unwrap(),
-            amount: serde_json::from_value(object["amount"].clone()).unwrap(),
+            amount: optional_field(object, "amount"),
+            amount_min: optional_field(object, "amount_min"),
+            amount_max: optional_field(object, "amount_max"),
+            probability: optional_field(object, "probability"),
           })
         },
         _ => {
           Ok(Product::Item {
             name: serde_json::from_value(object["name"].clone()).unwrap(),
-            amount: serde_json::from_value(object["amount"].clone()).unwrap(),
+            amount: optional_field(object, "amount"),
+            amount_min: optional_field(object, "amount_min"),
+            amount_max: optional_field(object, "amount_max"),
+            probability: optional_field(object, "probability"),
           })
         }
       }
@@ -84,6 +111,9 @@ impl<'de> Deserialize<'de> for Product {
     }
   }
 }
+fn optional_field<T: DeserializeOwned>(object: &serde_json::Map<String, Value>, key: &str) -> Option<T> {
+  object.get(key).map(|v| serde_json::from_value(v.clone()).unwrap())
+}
 
 #[skip_serializing_none]
 #[derive(Serialize, Debug)]
@@ -137,14 +167,36 @@ pub struct Item {
   pub fuel_acceleration_multiplier: Option<f64>,
   pub fuel_top_speed_multiplier: Option<f64>,
   pub place_result: Option<String>,
+  pub subgroup: Option<String>,
+  pub order: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Tool {
+  pub stack_size: u32,
+  pub durability: f64,
+  pub subgroup: Option<String>,
+  pub order: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ItemGroup {
+  pub order: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ItemSubGroup {
+  pub group: String,
+  pub order: String,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Fluid {
-  default_temperature: i32,
-  max_temperature: Option<i32>,
-  heat_capacity: Option<String>,
+  pub default_temperature: i32,
+  pub max_temperature: Option<i32>,
+  pub heat_capacity: Option<Energy>,
 }
 
 #[skip_serializing_none]
@@ -153,7 +205,7 @@ pub struct Container {
   minable: Option<Minable>,
   collision_box: BoundingBox,
   selection_box: BoundingBox,
-  inventory_size: u32,
+  pub inventory_size: u32,
   circuit_wire_max_distance: Option<f64>,
 }
 
@@ -183,11 +235,11 @@ impl Energy {
   pub fn parse(&self) -> f64 {
     let value = self.0.as_bytes();
     let len = value.len();
-    let unit = b"JW".into_iter().position(|&c| c == value[len - 1].to_ascii_uppercase()).expect("unknown unit") as f64 * 59.0 + 1.0;
+    let unit = b"JW".iter().position(|&c| c == value[len - 1].to_ascii_uppercase()).expect("unknown unit") as f64 * 59.0 + 1.0;
     (if value[len - 2].is_ascii_digit() {
       std::str::from_utf8(&value[..len-1]).unwrap().parse::<f64>().unwrap()
     } else {
-      std::str::from_utf8(&value[..len-2]).unwrap().parse::<f64>().unwrap() * 1000f64.powi(b"KMGTPEZY".into_iter().position(|&c| c == value[len - 2].to_ascii_uppercase()).expect("unknown modifier") as i32 + 1)
+      std::str::from_utf8(&value[..len-2]).unwrap().parse::<f64>().unwrap() * 1000f64.powi(b"KMGTPEZY".iter().position(|&c| c == value[len - 2].to_ascii_uppercase()).expect("unknown modifier") as i32 + 1)
     }) / unit
   }
 }
@@ -198,7 +250,7 @@ impl Energy {
 pub enum EnergySource {
   #[serde(rename = "electric")] Electric {
     usage_priority: String,
-    drain: Option<String>,
+    drain: Option<Energy>,
   },
   #[serde(rename = "burner")] Burner {
     fuel_category: String,
@@ -228,79 +280,84 @@ pub struct Furnace {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TransportBelt {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  speed: f64,
-  circuit_wire_max_distance: f64,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub speed: f64,
+  pub circuit_wire_max_distance: f64,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Boiler {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  target_temperature: f64,
-  energy_consumption: Energy,
-  energy_source: EnergySource,
-  burning_cooldown: u32,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub target_temperature: f64,
+  pub energy_consumption: Energy,
+  pub energy_source: EnergySource,
+  pub burning_cooldown: u32,
+  pub fluid_box: FluidBox,
+  pub output_fluid_box: FluidBox,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ElectricPole {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  maximum_wire_distance: f64,
-  supply_area_distance: f64,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub maximum_wire_distance: f64,
+  pub supply_area_distance: f64,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Generator {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  effectivity: f64,
-  fluid_usage_per_tick: f64,
-  maximum_temperature: i32,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub effectivity: f64,
+  pub fluid_usage_per_tick: f64,
+  pub maximum_temperature: i32,
+  pub fluid_box: FluidBox,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct OffshorePump {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  pumping_speed: f64,
-  circuit_wire_max_distance: f64,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub pumping_speed: f64,
+  pub circuit_wire_max_distance: f64,
+  pub fluid_box: FluidBox,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Inserter {
-  stack: Option<bool>,
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  energy_per_movement: Energy,
-  energy_per_rotation: Energy,
-  energy_source: EnergySource,
-  extension_speed: f64,
-  rotation_speed: f64,
-  pickup_position: Point,
-  insert_position: Point,
-  circuit_wire_max_distance: f64,
+  pub stack: Option<bool>,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub energy_per_movement: Energy,
+  pub energy_per_rotation: Energy,
+  pub energy_source: EnergySource,
+  pub extension_speed: f64,
+  pub rotation_speed: f64,
+  pub pickup_position: Point,
+  pub insert_position: Point,
+  pub circuit_wire_max_distance: f64,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Pipe {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub fluid_box: FluidBox,
 }
 
 #[skip_serializing_none]
@@ -314,24 +371,25 @@ pub struct PipeToGround {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AssemblingMachine {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  crafting_categories: Vec<String>,
-  crafting_speed: f64,
-  energy_source: EnergySource,
-  energy_usage: Energy,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub crafting_categories: Vec<String>,
+  pub crafting_speed: f64,
+  pub energy_source: EnergySource,
+  pub energy_usage: Energy,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Lab {
-  minable: Minable,
-  collision_box: BoundingBox,
-  selection_box: BoundingBox,
-  energy_source: EnergySource,
-  energy_usage: Energy,
-  researching_speed: f64,
+  pub minable: Minable,
+  pub collision_box: BoundingBox,
+  pub selection_box: BoundingBox,
+  pub energy_source: EnergySource,
+  pub energy_usage: Energy,
+  pub researching_speed: f64,
+  pub inputs: Vec<String>,
 }
 
 #[skip_serializing_none]
@@ -380,6 +438,7 @@ pub struct MiningDrill {
   pub energy_usage: Energy,
   pub mining_speed: f64,
   pub resource_searching_radius: f64,
+  pub vector_to_place_result: Option<Point>,
   pub circuit_wire_max_distance: f64,
 }
 
@@ -414,6 +473,7 @@ pub struct SimpleEntity {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RecipeData {
+  pub enabled: Option<bool>,
   pub energy_required: Option<f64>,
   pub ingredients: Vec<Ingredient>,
   pub result: Option<String>,
@@ -437,23 +497,56 @@ pub struct Recipe {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TechnologyUnit {
-  prerequisites: Option<Vec<String>>,
-  count: Option<u64>,
-  count_formula: Option<String>,
-  time: f64,
-  ingredients: Vec<Ingredient>,
+  pub prerequisites: Option<Vec<String>>,
+  pub count: Option<u64>,
+  pub count_formula: Option<String>,
+  pub time: f64,
+  pub ingredients: Vec<Ingredient>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum TechnologyEffect {
+  #[serde(rename = "unlock-recipe")] UnlockRecipe {
+    recipe: String,
+  },
+  #[serde(rename = "inserter-stack-size-bonus")] InserterStackSizeBonus {
+    modifier: u32,
+  },
+  #[serde(rename = "stack-inserter-capacity-bonus")] StackInserterCapacityBonus {
+    modifier: u32,
+  },
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Technology {
-  prerequisites: Option<Vec<String>>,
-  unit: TechnologyUnit,
-  upgrade: Option<bool>,
+  pub prerequisites: Option<Vec<String>>,
+  pub unit: TechnologyUnit,
+  pub upgrade: Option<bool>,
+  pub effects: Option<Vec<TechnologyEffect>>,  // only recipe unlocks and inserter capacity bonuses, other modifiers are not simulated
 }
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Prototypes {
   pub item: HashMap<String, Item>,
+  pub tool: HashMap<String, Tool>,
+  // other item types, only their stack size and ordering is used
+  #[serde(default)] pub ammo: HashMap<String, Item>,
+  #[serde(default)] pub armor: HashMap<String, Item>,
+  #[serde(default)] pub capsule: HashMap<String, Item>,
+  #[serde(default)] pub gun: HashMap<String, Item>,
+  #[serde(default, rename = "item-with-entity-data")] pub item_with_entity_data: HashMap<String, Item>,
+  #[serde(default)] pub module: HashMap<String, Item>,
+  #[serde(default, rename = "rail-planner")] pub rail_planner: HashMap<String, Item>,
+  #[serde(default, rename = "repair-tool")] pub repair_tool: HashMap<String, Item>,
+  #[serde(default, rename = "spidertron-remote")] pub spidertron_remote: HashMap<String, Item>,
+  #[serde(default)] pub blueprint: HashMap<String, Item>,
+  #[serde(default, rename = "blueprint-book")] pub blueprint_book: HashMap<String, Item>,
+  #[serde(default, rename = "deconstruction-item")] pub deconstruction_item: HashMap<String, Item>,
+  #[serde(default, rename = "upgrade-item")] pub upgrade_item: HashMap<String, Item>,
+  #[serde(default, rename = "copy-paste-tool")] pub copy_paste_tool: HashMap<String, Item>,
+  #[serde(default, rename = "selection-tool")] pub selection_tool: HashMap<String, Item>,
   pub fluid: HashMap<String, Fluid>,
   pub container: HashMap<String, Container>,
   pub character: HashMap<String, Character>,
@@ -477,6 +570,8 @@ pub struct Prototypes {
   pub tree: HashMap<String, Tree>,
   pub recipe: HashMap<String, Recipe>,
   pub technology: HashMap<String, Technology>,
+  #[serde(rename = "item-group")] pub item_group: HashMap<String, ItemGroup>,
+  #[serde(rename = "item-subgroup")] pub item_subgroup: HashMap<String, ItemSubGroup>,
 }
 
 
@@ -1,4 +1,7 @@
-use factorio_serialize::RandomGenerator;
+use factorio_serialize::{constants::{Entity, Item}, RandomGenerator, RandomGeneratorComponent};
+
+use crate::gameconfig::{ProductConfig, GAME_CONFIG};
+use crate::seedsearch::{seed_key, SeedSearch};
 
 #[allow(dead_code)]
 pub fn brute_force_rock_rng() {
@@ -6,26 +9,20 @@ pub fn brute_force_rock_rng() {
   // 598: RandomGenerator::new(1510092962, 2768646422, 1113768448)
   // 692: RandomGenerator { seed1: 1876885255, seed2: 2951610103, seed3: 4294836223 }
   // 960: RandomGenerator { seed1: 4225719483, seed2: 1869606895, seed3: 4294967295 }
-  let mut current_rng = RandomGenerator::new(u32::MAX, u32::MAX, u32::MAX);
-  let mut current_score = get_huge_rock_score(current_rng.clone());
-  loop {
-    let mut best_score = current_score;
-    let mut best_perm = 0;
-    for bit_flips in 1..=5 {
-      let mut perm = (1u128 << bit_flips) - 1;
-      while perm < (1u128 << 88) {
-        let test_score = get_huge_rock_score(nudge_rng(&current_rng, perm));
-        if test_score > best_score {
-          best_perm = perm;
-          best_score = test_score;
-        }
-        perm = next_perm(perm);
-      }
-    }
-    if best_score == current_score { break; }
-    current_score = best_score;
-    current_rng = nudge_rng(&current_rng, best_perm);
-    println!("found score {current_score} with {current_rng:?}")
+  let (rng, score) = SeedSearch::new(get_huge_rock_score).with_checkpoint("rock_rng.checkpoint").hill_climb(RandomGenerator::new(u32::MAX, u32::MAX, u32::MAX), 5);
+  println!("best score {score} with {rng:?}");
+}
+
+// first 3 Huge Rocks each give at least 45 coal, searching all values of seed1
+#[allow(dead_code)]
+pub fn search_coal_rock_rng() {
+  let base_key = seed_key(&RandomGenerator::new(0, u32::MAX, u32::MAX));
+  let objective = |mut rng: RandomGenerator| {
+    let coal: Vec<u32> = (0..3).map(|_| item_amount(&roll_mining_results(&mut rng, Entity::RockHuge), Item::Coal)).collect();
+    (coal.iter().filter(|&&coal| coal >= 45).count(), coal.iter().sum::<u32>())
+  };
+  if let Some((rng, score)) = SeedSearch::new(objective).with_checkpoint("coal_rock_rng.checkpoint").exhaustive(base_key..base_key + (1 << 31)) {
+    println!("best score {score:?} with {rng:?}");
   }
 }
 
@@ -33,40 +30,62 @@ pub fn next_perm(v: u128) -> u128{
   let t = v | (v - 1); // t gets v's least significant 0 bits set to 1
   // Next set to 1 the most significant bit to change, 
   // set to 0 the least significant ones, and add the necessary 1 bits.
-  return (t + 1) | (((!t & (t+1)) - 1) >> (v.trailing_zeros() + 1))
+  (t + 1) | (((!t & (t+1)) - 1) >> (v.trailing_zeros() + 1))
+}
+// from ItemProductPrototype::collect and FluidProductPrototype::collect
+pub fn roll_products(rng: &mut RandomGenerator, products: &[ProductConfig]) -> Vec<ProductConfig> {
+  let mut results = vec![];
+  for product in products {
+    match *product {
+      ProductConfig::Item { id, amount, amount_max, probability } => {
+        if probability < 1.0 && rng.uniform_double() >= probability { continue; }
+        let amount = if amount_max > amount { (rng.uniform_double() * f64::from(amount_max + 1 - amount) + f64::from(amount)) as u32 } else { amount };
+        if amount > 0 { results.push(ProductConfig::item(id, amount)); }
+      },
+      ProductConfig::Fluid { id, amount, amount_max, probability } => {
+        if probability < 1.0 && rng.uniform_double() >= probability { continue; }
+        let amount = if amount_max > amount { rng.uniform_double() * (amount_max - amount) + amount } else { amount };
+        if amount > 0.0 { results.push(ProductConfig::fluid(id, amount)); }
+      },
+    }
+  }
+  results
+}
+
+/// Products of mining one entity of the given type, advancing `rng`.
+pub fn roll_mining_results(rng: &mut RandomGenerator, entity: Entity) -> Vec<ProductConfig> {
+  let minable = GAME_CONFIG.minables.get(&entity).unwrap_or_else(|| panic!("{entity} is not minable"));
+  roll_products(rng, &minable.results)
 }
-fn nudge_rng(rng: &RandomGenerator, perm: u128) -> RandomGenerator {
-  // lower 1, 3 and 4 bits respectively don't matter
-  let mut seed = (rng.seed1 as u128 >> 1) | ((rng.seed2 as u128) >> 3 << 31) | ((rng.seed3 as u128) >> 4 << 60);
-  seed ^= perm;
-  RandomGenerator { seed1: (seed << 1) as u32, seed2: (seed >> 31 << 3) as u32, seed3: (seed >> 60 << 4) as u32 }
+fn item_amount(products: &[ProductConfig], item: Item) -> u32 {
+  products.iter().map(|product| match *product {
+    ProductConfig::Item { id, amount, .. } if id == item => amount,
+    _ => 0,
+  }).sum()
+}
+
+/// Products of each of the next `count` mined entities of the given type, if `rng` is used as the entities RNG.
+pub fn predict_mining_results(rng: &RandomGenerator, entity: Entity, count: usize) -> Vec<Vec<ProductConfig>> {
+  let mut rng = rng.clone();
+  let products = GAME_CONFIG.minables.get(&entity).map(|minable| minable.results.as_slice()).unwrap_or_default();
+  (0..count).map(|_| roll_products(&mut rng, products)).collect()
 }
 fn get_huge_rock_score(mut rng: RandomGenerator) -> u32 {
   let mut item_count = 0;
   for _ in 0..10 {
-    let (stone, coal) = rng.get_huge_rock_items();
-    item_count += stone + coal;
+    let results = roll_mining_results(&mut rng, Entity::RockHuge);
+    item_count += item_amount(&results, Item::Stone) + item_amount(&results, Item::Coal);
   }
   item_count
 }
 
 #[allow(dead_code)]
 pub fn check_rng_cycles() {
-  let mut bitmap = vec![0u8; 1 << 29];
-  for start_val in 0..=u32::MAX {
-    if bitmap[start_val as usize >> 3] & (1 << (start_val & 7)) == 0 {
-      let mut seed: u32 = start_val;
-      let mut len = 0;
-      while bitmap[seed as usize >> 3] & (1 << (seed & 7)) == 0 {
-        bitmap[seed as usize >> 3] |= 1 << (seed & 7);
-        len += 1;
-        // seed = ((seed ^ seed << 13) >> 19) | (seed >> 1 << 13);
-        seed = ((seed ^ seed << 2) >> 25) | (seed >> 3 << 7);
-        // seed = ((seed ^ seed << 3) >> 11) | (seed >> 4 << 21);
-      }
-      if len > 1 {
-        println!("start {start_val:x} cycle len: {len:x}");
-      }
-    }
+  for component in RandomGeneratorComponent::ALL {
+    let period = component.period();
+    let max_period = (1u64 << component.significant_bits()) - 1;
+    println!("{component:?}: {} significant bits, period {period:x}{}", component.significant_bits(), if period == max_period { " (maximal)" } else { "" });
   }
-}
\ No newline at end of file
+  let rng = RandomGenerator::new(80686, 3738370905, 872480768);
+  println!("period of {rng:?}: {:x}", rng.period());
+}
@@ -1,6 +1,6 @@
-use factorio_serialize::{constants::{Entity, Item, Recipe, Tile}, map::{Chunk, EntityCommon, EntityData, EntityWithHealth, MapData, ResourceEntity, SimpleEntity, Tree}, replay::{Direction, ForceId, InputAction, InputActionData, PlayerJoinGameData, ReplayData}, save::SaveFile, FixedPoint32_8, MapPosition, RandomGenerator, Result, TilePosition};
+use factorio_serialize::{constants::{Entity, Item, Recipe, Technology, Tile}, map::{Chunk, EntityCommon, EntityData, EntityWithHealth, MapData, RandomGeneratorRole, ResourceEntity, SimpleEntity, Tree}, replay::{Direction, ForceId, InputAction, InputActionData, PlayerJoinGameData, ReplayData}, save::SaveFile, FixedPoint32_8, MapPosition, RandomGenerator, Result, TilePosition};
 
-use crate::simulation::GameState;
+use crate::simulation::{GameState, SimulationError};
 
 
 const PID: u16 = 0;
@@ -56,19 +56,19 @@ impl Runner {
   }
   pub fn add_tree(&mut self, position: MapPosition) {
     self.entities.push((Entity::DryTree, position));
-    self.game_state.add_tree(position);
+    self.game_state.add_tree(Entity::DryTree, position);
   }  
   #[allow(dead_code)]
   pub fn mine_tree(&mut self) {
     self.entities.push((Entity::DryTree, DRY_TREE_FIXED_POSITION));
-    self.game_state.add_tree(DRY_TREE_FIXED_POSITION);
+    self.game_state.add_tree(Entity::DryTree, DRY_TREE_FIXED_POSITION);
     let ticks = self.game_state.mine_tree(DRY_TREE_FIXED_POSITION);
     self.n_tick(ticks);
   }
   #[allow(dead_code)]
   pub fn mine_rock(&mut self) {
     self.entities.push((Entity::RockHuge, HUGE_ROCK_FIXED_POSITION));
-    self.game_state.add_rock(HUGE_ROCK_FIXED_POSITION);
+    self.game_state.add_rock(Entity::RockHuge, HUGE_ROCK_FIXED_POSITION);
     let ticks = self.game_state.mine_rock(HUGE_ROCK_FIXED_POSITION);
     self.n_tick(ticks);
   }
@@ -83,9 +83,14 @@ impl Runner {
     self.n_tick(ticks);
   }
   #[allow(dead_code)]
-  pub fn craft(&mut self, recipe: Recipe, count: u32) {
-    let _ticks = self.game_state.craft(recipe, count);
+  pub fn craft(&mut self, recipe: Recipe, count: u32) -> std::result::Result<(), SimulationError> {
+    let _ticks = self.game_state.craft(recipe, count)?;
     // self.n_tick(ticks);
+    Ok(())
+  }
+  #[allow(dead_code)]
+  pub fn cancel_craft(&mut self, recipe: Recipe, count: u32) -> std::result::Result<(), SimulationError> {
+    self.game_state.cancel_craft(recipe, count)
   }
   #[allow(dead_code)]
   pub fn build_stone_furnace(&mut self, position: TilePosition) {
@@ -104,8 +109,92 @@ impl Runner {
     self.game_state.build_iron_miner(position, direction);
   }
   #[allow(dead_code)]
-  pub fn add_fuel_to_iron_miner(&mut self, item: Item, amount: u32, position: TilePosition) {
-    self.game_state.add_fuel_to_iron_miner(item, amount, position);
+  pub fn build_chest(&mut self, item: Item, position: TilePosition) {
+    self.game_state.build_chest(item, position);
+  }
+  #[allow(dead_code)]
+  pub fn build_electric_miner(&mut self, position: TilePosition, direction: Direction) {
+    self.game_state.build_electric_miner(position, direction);
+  }
+  #[allow(dead_code)]
+  pub fn build_offshore_pump(&mut self, position: TilePosition, direction: Direction) {
+    self.game_state.build_offshore_pump(position, direction);
+  }
+  #[allow(dead_code)]
+  pub fn build_boiler(&mut self, position: TilePosition, direction: Direction) {
+    self.game_state.build_boiler(position, direction);
+  }
+  #[allow(dead_code)]
+  pub fn build_steam_engine(&mut self, position: TilePosition, direction: Direction) {
+    self.game_state.build_steam_engine(position, direction);
+  }
+  #[allow(dead_code)]
+  pub fn build_pipe(&mut self, position: TilePosition) {
+    self.game_state.build_pipe(position);
+  }
+  #[allow(dead_code)]
+  pub fn build_electric_pole(&mut self, item: Item, position: TilePosition) {
+    self.game_state.build_electric_pole(item, position);
+  }
+  #[allow(dead_code)]
+  pub fn add_fuel_to_boiler(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.game_state.add_fuel_to_boiler(item, amount, position);
+  }
+  #[allow(dead_code)]
+  pub fn take_furnace_output(&mut self, position: TilePosition) -> Vec<(Item, u32)> {
+    self.game_state.take_contents(position.top_left_map_position())
+  }
+  #[allow(dead_code)]
+  pub fn take_chest_contents(&mut self, position: TilePosition) -> Vec<(Item, u32)> {
+    self.game_state.take_contents(position.center_map_position())
+  }
+  #[allow(dead_code)]
+  pub fn add_fuel_to_burner_miner(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.game_state.add_fuel_to_burner_miner(item, amount, position);
+  }
+  #[allow(dead_code)]
+  pub fn build_assembling_machine(&mut self, item: Item, position: TilePosition) {
+    self.game_state.build_assembling_machine(item, position);
+  }
+  #[allow(dead_code)]
+  pub fn set_assembling_machine_recipe(&mut self, position: TilePosition, recipe: Recipe) {
+    self.game_state.set_assembling_machine_recipe(position, recipe);
+  }
+  #[allow(dead_code)]
+  pub fn add_input_to_assembling_machine(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.game_state.add_input_to_assembling_machine(item, amount, position);
+  }
+  #[allow(dead_code)]
+  pub fn take_assembling_machine_output(&mut self, position: TilePosition) -> Vec<(Item, u32)> {
+    self.game_state.take_contents(position.center_map_position())
+  }
+  #[allow(dead_code)]
+  pub fn build_lab(&mut self, position: TilePosition) {
+    self.game_state.build_lab(position);
+  }
+  #[allow(dead_code)]
+  pub fn add_science_packs_to_lab(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.game_state.add_science_packs_to_lab(item, amount, position);
+  }
+  #[allow(dead_code)]
+  pub fn start_research(&mut self, technology: Technology) {
+    self.game_state.start_research(technology);
+  }
+  #[allow(dead_code)]
+  pub fn transfer_stack_to_entity(&mut self, item: Item, position: MapPosition) -> u32 {
+    self.game_state.transfer_stack_to_entity(item, position)
+  }
+  #[allow(dead_code)]
+  pub fn build_transport_belt(&mut self, item: Item, position: TilePosition, direction: Direction) {
+    self.game_state.build_transport_belt(item, position, direction);
+  }
+  #[allow(dead_code)]
+  pub fn build_inserter(&mut self, item: Item, position: TilePosition, direction: Direction) {
+    self.game_state.build_inserter(item, position, direction);
+  }
+  #[allow(dead_code)]
+  pub fn add_fuel_to_burner_inserter(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.game_state.add_fuel_to_burner_inserter(item, amount, position);
   }
 
 
@@ -114,13 +203,15 @@ impl Runner {
     let template_save_file = SaveFile::load_save_file(template_name).unwrap();
     let mut map_data = MapData::parse_map_data(&template_save_file.level_init_dat)?;
     let script_init_dat = template_save_file.script_init_dat;
+    let resources = self.game_state.resources_needed_on_map();
 
     // initialize input actions preamble to spawn player
-    let mut input_actions = Vec::new();
-    input_actions.push(InputAction::new(0, 255, InputActionData::SingleplayerInit));
-    input_actions.push(InputAction::new(0, 255, InputActionData::GameCreatedFromScenario));
-    input_actions.push(InputAction::new(0, 255, InputActionData::DisconnectAllPlayers));
-    input_actions.push(InputAction::new(0, 255, InputActionData::PlayerJoinGame(PlayerJoinGameData { peer_id: 0, player_index: PID, force_id: ForceId::Player, username: PLAYER_NAME.to_owned(), as_editor: false, admin: true })));
+    let mut input_actions = vec![
+      InputAction::new(0, 255, InputActionData::SingleplayerInit),
+      InputAction::new(0, 255, InputActionData::GameCreatedFromScenario),
+      InputAction::new(0, 255, InputActionData::DisconnectAllPlayers),
+      InputAction::new(0, 255, InputActionData::PlayerJoinGame(PlayerJoinGameData { peer_id: 0, player_index: PID, force_id: ForceId::Player, username: PLAYER_NAME.to_owned(), as_editor: false, admin: true })),
+    ];
     // copy over replay actions
     input_actions.extend(self.game_state.input_actions);
     // add dummy end action to extend runtime of the replay
@@ -133,22 +224,14 @@ impl Runner {
     for water_position in self.game_state.water_tiles {
       set_water_tile(&mut map_data, &water_position);
     }
-    for (iron_ore_position, count) in self.game_state.iron_ores {
-      add_resource_to_map(&mut map_data, Entity::IronOre, iron_ore_position, count);
-    }
-    for (copper_ore_position, count) in self.game_state.copper_ores {
-      add_resource_to_map(&mut map_data, Entity::CopperOre, copper_ore_position, count);
-    }
-    for (iron_ore_position, miner) in self.game_state.iron_miners {
-      add_resource_to_map(&mut map_data, Entity::IronOre, iron_ore_position, miner.num_resources_needed_on_map());
+    for (resource, position, count) in resources {
+      add_resource_to_map(&mut map_data, resource, position, count);
     }
 
-    map_data.map.entities_random_generator.seed1 = HUGE_ROCK_RNG.seed1;  // entities RNG determines Huge Rock contents
-    map_data.map.entities_random_generator.seed2 = HUGE_ROCK_RNG.seed2;  // entities RNG determines Huge Rock contents
-    map_data.map.entities_random_generator.seed3 = HUGE_ROCK_RNG.seed3;  // entities RNG determines Huge Rock contents
+    map_data.set_random_generator(RandomGeneratorRole::Entities, HUGE_ROCK_RNG);  // entities RNG determines Huge Rock contents
 
     let level_init_dat = map_data.write_map_data()?;
-    let replay_dat = ReplayData::from_input_actions(input_actions).write_replay_data()?;
+    let replay_dat = ReplayData::from_input_actions(input_actions).write_replay_data_with_registry(map_data.prototype_registry())?;
 
     SaveFile::from_raw_dat(level_init_dat, replay_dat, script_init_dat).write_save_file_instrumented(out_name).unwrap();
     Ok(())
@@ -182,11 +265,11 @@ fn get_or_create_chunk(map_data: &mut MapData, position: MapPosition) -> &mut Ch
       position: position.to_chunk_position(),
       tick_of_last_change_that_could_affect_charting: 0,
       tick_of_optional_activation: 0,
-      tiles: [[(Tile::LabWhite, 0x10); 32]; 32],
+      tiles: [[(Tile::LabWhite.into(), 0x10); 32]; 32],
     };
     for x in 0..32 {
       for y in 0..32 {
-        new_chunk.tiles[x][y].0 = [Tile::LabDark1, Tile::LabDark2][(x + y) & 1];
+        new_chunk.tiles[x][y].0 = [Tile::LabDark1, Tile::LabDark2][(x + y) & 1].into();
       }
     }
     map_data.map.surfaces[0].chunks.push(new_chunk);
@@ -200,7 +283,7 @@ fn add_entity_to_map(map_data: &mut MapData, entity: Entity, position: MapPositi
     Entity::RockHuge => EntityData::RockHuge(SimpleEntity { entity: EntityWithHealth { entity: EntityCommon { position, usage_bit_mask: 0, targeter: None }, health: 0.0, damage_to_be_taken: 0.0, upgrade_target: None }, variation: 0 }),
     _ => panic!("unsupported Entity {:?}", entity),
   };
-  chunk.entities_to_be_inserted_before_setup.push((entity, entity_data));
+  chunk.entities_to_be_inserted_before_setup.push((entity.into(), entity_data));
 }
 fn add_resource_to_map(map_data: &mut MapData, entity: Entity, tile_position: TilePosition, resource_amount: u32) {
   let position = tile_position.center_map_position();
@@ -208,11 +291,13 @@ fn add_resource_to_map(map_data: &mut MapData, entity: Entity, tile_position: Ti
   let entity_data = match entity {
     Entity::CopperOre => EntityData::CopperOre(ResourceEntity { entity: EntityCommon { position, usage_bit_mask: 0, targeter: None }, resource_amount, initial_amount: None, variation: 0 }),
     Entity::IronOre => EntityData::IronOre(ResourceEntity { entity: EntityCommon { position, usage_bit_mask: 0, targeter: None }, resource_amount, initial_amount: None, variation: 0 }),
+    Entity::Stone => EntityData::Stone(ResourceEntity { entity: EntityCommon { position, usage_bit_mask: 0, targeter: None }, resource_amount, initial_amount: None, variation: 0 }),
+    Entity::Coal => EntityData::Coal(ResourceEntity { entity: EntityCommon { position, usage_bit_mask: 0, targeter: None }, resource_amount, initial_amount: None, variation: 0 }),
     _ => panic!("unsupported resource {:?}", entity),
   };
-  chunk.entities_to_be_inserted_before_setup.push((entity, entity_data));
+  chunk.entities_to_be_inserted_before_setup.push((entity.into(), entity_data));
 }
 fn set_water_tile(map_data: &mut MapData, position: &TilePosition) {
   let chunk = get_or_create_chunk(map_data, position.top_left_map_position());
-  chunk.tiles[(position.x & 0x1f) as usize][(position.y & 0x1f) as usize] = (Tile::Water, 0x30);
+  chunk.tiles[(position.x & 0x1f) as usize][(position.y & 0x1f) as usize] = (Tile::Water.into(), 0x30);
 }
@@ -0,0 +1,172 @@
+use std::{fmt::Debug, ops::Range, path::PathBuf, sync::atomic::{AtomicU64, Ordering}, time::Instant};
+
+use factorio_serialize::RandomGenerator;
+
+use crate::random::next_perm;
+
+// lower 1, 3 and 4 bits of seed1, seed2 and seed3 respectively don't affect the generated numbers
+pub const SEED_KEY_BITS: u32 = 31 + 29 + 28;
+const EXHAUSTIVE_CHUNK_SIZE: u64 = 1 << 20;
+
+/// Packs the significant bits of all three seeds into a single number, so that equivalent RNGs share the same key.
+pub fn seed_key(rng: &RandomGenerator) -> u128 {
+  (rng.seed1 as u128 >> 1) | ((rng.seed2 as u128) >> 3 << 31) | ((rng.seed3 as u128) >> 4 << 60)
+}
+pub fn from_seed_key(key: u128) -> RandomGenerator {
+  RandomGenerator { seed1: (key << 1) as u32, seed2: (key >> 31 << 3) as u32, seed3: (key >> 60 << 4) as u32 }
+}
+
+/// Searches for RNG states maximizing an objective, e.g. the total items yielded by the next Huge Rocks.
+///
+/// Candidates are evaluated on all available cores. If a checkpoint file is set, the search state is written to it as it progresses, and a search started with an existing checkpoint file resumes from it.
+pub struct SeedSearch<F> {
+  objective: F,
+  threads: usize,
+  checkpoint_path: Option<PathBuf>,
+}
+impl<S: Ord + Copy + Debug + Send, F: Fn(RandomGenerator) -> S + Sync> SeedSearch<F> {
+  pub fn new(objective: F) -> Self {
+    let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    SeedSearch { objective, threads, checkpoint_path: None }
+  }
+  pub fn with_checkpoint(mut self, checkpoint_path: impl Into<PathBuf>) -> Self {
+    self.checkpoint_path = Some(checkpoint_path.into());
+    self
+  }
+
+  /// Repeatedly moves to the best RNG reachable by flipping up to `max_bit_flips` significant seed bits, until no improvement is found.
+  pub fn hill_climb(&self, start: RandomGenerator, max_bit_flips: u32) -> (RandomGenerator, S) {
+    let mut current_key = self.read_checkpoint().and_then(|checkpoint| checkpoint.current).unwrap_or_else(|| seed_key(&start));
+    let mut current_score = (self.objective)(from_seed_key(current_key));
+    println!("starting hill climb with score {current_score:?} at {:?}", from_seed_key(current_key));
+    loop {
+      let best = self.best_neighbour(current_key, max_bit_flips);
+      match best {
+        Some((score, perm)) if score > current_score => {
+          current_score = score;
+          current_key ^= perm;
+          println!("found score {current_score:?} with {:?}", from_seed_key(current_key));
+          self.write_checkpoint(&Checkpoint { current: Some(current_key), next: None, best: None });
+        },
+        _ => break,
+      }
+    }
+    (from_seed_key(current_key), current_score)
+  }
+
+  // best (score, perm) among all keys differing from key in 1 to max_bit_flips bits, ties broken by enumeration order
+  fn best_neighbour(&self, key: u128, max_bit_flips: u32) -> Option<(S, u128)> {
+    let start_time = Instant::now();
+    let results: Vec<Option<(S, u32, u128)>> = std::thread::scope(|scope| {
+      let handles: Vec<_> = (0..self.threads).map(|thread_index| scope.spawn(move || {
+        let mut best: Option<(S, u32, u128)> = None;
+        let mut counter = 0;
+        for bit_flips in 1..=max_bit_flips.min(SEED_KEY_BITS) {
+          let mut perm = (1u128 << bit_flips) - 1;
+          while perm < (1u128 << SEED_KEY_BITS) {
+            if counter % self.threads == thread_index {
+              let score = (self.objective)(from_seed_key(key ^ perm));
+              if best.is_none_or(|(best_score, _, _)| score > best_score) {
+                best = Some((score, bit_flips, perm));
+              }
+            }
+            counter += 1;
+            perm = next_perm(perm);
+          }
+        }
+        best
+      })).collect();
+      handles.into_iter().map(|handle| handle.join().expect("seed search thread panicked")).collect()
+    });
+    let best = results.into_iter().flatten().min_by(|(a_score, a_bit_flips, a_perm), (b_score, b_bit_flips, b_perm)| b_score.cmp(a_score).then(a_bit_flips.cmp(b_bit_flips)).then(a_perm.cmp(b_perm)));
+    println!("searched neighbourhood in {:.1}s", start_time.elapsed().as_secs_f64());
+    best.map(|(score, _, perm)| (score, perm))
+  }
+
+  /// Evaluates every seed key in the given range.
+  pub fn exhaustive(&self, keys: Range<u128>) -> Option<(RandomGenerator, S)> {
+    let checkpoint = self.read_checkpoint();
+    let mut next = checkpoint.as_ref().and_then(|checkpoint| checkpoint.next).unwrap_or(keys.start).max(keys.start);
+    let mut best = checkpoint.and_then(|checkpoint| checkpoint.best).map(|key| ((self.objective)(from_seed_key(key)), key));
+    let start_time = Instant::now();
+    let mut last_report = Instant::now();
+    while next < keys.end {
+      let batch_end = keys.end.min(next.saturating_add(EXHAUSTIVE_CHUNK_SIZE as u128 * self.threads as u128));
+      let batch_start = next;
+      let chunk_index = AtomicU64::new(0);
+      let results: Vec<Option<(S, u128)>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..self.threads).map(|_| scope.spawn(|| {
+          let mut best: Option<(S, u128)> = None;
+          loop {
+            let chunk_start = batch_start + chunk_index.fetch_add(1, Ordering::Relaxed) as u128 * EXHAUSTIVE_CHUNK_SIZE as u128;
+            if chunk_start >= batch_end { break; }
+            for key in chunk_start..batch_end.min(chunk_start + EXHAUSTIVE_CHUNK_SIZE as u128) {
+              let score = (self.objective)(from_seed_key(key));
+              if best.is_none_or(|(best_score, _)| score > best_score) {
+                best = Some((score, key));
+              }
+            }
+          }
+          best
+        })).collect();
+        handles.into_iter().map(|handle| handle.join().expect("seed search thread panicked")).collect()
+      });
+      for (score, key) in results.into_iter().flatten() {
+        if best.is_none_or(|(best_score, best_key)| score > best_score || (score == best_score && key < best_key)) {
+          best = Some((score, key));
+        }
+      }
+      next = batch_end;
+      self.write_checkpoint(&Checkpoint { current: None, next: Some(next), best: best.map(|(_, key)| key) });
+      if last_report.elapsed().as_secs() >= 10 || next == keys.end {
+        last_report = Instant::now();
+        let progress = (next - keys.start) as f64 / (keys.end - keys.start) as f64;
+        println!("searched {:.3}% after {:.0}s, best {:?}", progress * 100.0, start_time.elapsed().as_secs_f64(), best.map(|(score, key)| (score, from_seed_key(key))));
+      }
+    }
+    best.map(|(score, key)| (from_seed_key(key), score))
+  }
+
+  fn read_checkpoint(&self) -> Option<Checkpoint> {
+    let checkpoint = Checkpoint::parse(&std::fs::read_to_string(self.checkpoint_path.as_ref()?).ok()?);
+    println!("resuming from checkpoint {checkpoint:?}");
+    Some(checkpoint)
+  }
+  fn write_checkpoint(&self, checkpoint: &Checkpoint) {
+    if let Some(checkpoint_path) = &self.checkpoint_path {
+      std::fs::write(checkpoint_path, checkpoint.to_string()).expect("couldn't write checkpoint file");
+    }
+  }
+}
+
+// seed keys are stored in hex, one "name key" pair per line
+#[derive(Debug, Default)]
+struct Checkpoint {
+  current: Option<u128>,  // hill climb position
+  next: Option<u128>,  // first key not yet searched exhaustively
+  best: Option<u128>,  // best key found exhaustively so far
+}
+impl Checkpoint {
+  fn parse(s: &str) -> Checkpoint {
+    let mut checkpoint = Checkpoint::default();
+    for line in s.lines() {
+      let Some((name, value)) = line.split_once(' ') else { continue };
+      let Ok(value) = u128::from_str_radix(value.trim(), 16) else { continue };
+      match name {
+        "current" => checkpoint.current = Some(value),
+        "next" => checkpoint.next = Some(value),
+        "best" => checkpoint.best = Some(value),
+        _ => {},
+      }
+    }
+    checkpoint
+  }
+}
+impl std::fmt::Display for Checkpoint {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(current) = self.current { writeln!(f, "current {current:x}")?; }
+    if let Some(next) = self.next { writeln!(f, "next {next:x}")?; }
+    if let Some(best) = self.best { writeln!(f, "best {best:x}")?; }
+    Ok(())
+  }
+}
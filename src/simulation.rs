@@ -1,10 +1,44 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::{collections::{BTreeMap, BTreeSet, VecDeque}, hash::{Hash, Hasher}, rc::Rc};
 
-use factorio_serialize::{constants::{Item, Recipe}, replay::{BuildParameters, CraftData, Direction, InputAction, InputActionData, ItemStackTargetSpecification, QuickBarPickSlotParameters, QuickBarSetSlotParameters, SetFilterParameters}, BoundingBox, FixedPoint32_8, MapPosition, RandomGenerator, TilePosition, Vector};
+use factorio_serialize::{constants::{Entity, Item, Recipe, Technology, Tile}, map::{Chunk, EntityData, MapData, RandomGeneratorRole}, replay::{BuildParameters, CancelCraftOrder, CraftData, Direction, ForceId, InputAction, InputActionData, ItemStackTargetSpecification, QuickBarPickSlotParameters, QuickBarSetSlotParameters, ReplayData, SetFilterParameters, SlotSource, TechnologyWithCount, TransferDirection}, script::{LuaContext, ScriptData}, BoundingBox, FixedPoint32_8, MapPosition, RandomGenerator, TilePosition, Vector};
 
-use crate::{gameconfig::{ProductConfig, GAME_CONFIG}, hexfloat::HexFloat};
+use ordered_float::OrderedFloat;
+
+use crate::{gameconfig::{ItemType, ProductConfig, GAME_CONFIG}, hexfloat::HexFloat, random::roll_mining_results};
+
+mod inventory;
+mod logistics;
+mod power;
+mod production;
+
+use inventory::PlayerInventory;
+use logistics::{Inserter, InserterEnergySource, TransportBelt};
+use power::{Boiler, ElectricEnergySource, ElectricPole, FluidBox, OffshorePump, Pipe, SteamEngine};
+use production::{AssemblingMachine, Lab, Research};
 
 pub const PID: u16 = 0;
+// from freeplay.lua on_init, without pistol and ammo which go into their own inventories
+const FREEPLAY_CREATED_ITEMS: [(Item, u32); 4] = [(Item::IronPlate, 8), (Item::Wood, 1), (Item::BurnerMiningDrill, 1), (Item::StoneFurnace, 1)];
+
+// tiles covered by a 2x2 entity, centered on the top left corner of the given tile
+fn miner_area(position: TilePosition) -> [TilePosition; 4] {
+  [TilePosition::new(position.x - 1, position.y - 1), TilePosition::new(position.x - 1, position.y), TilePosition::new(position.x, position.y - 1), position]
+}
+// tiles mined by an electric miner, centered on the given tile
+fn electric_miner_area(position: TilePosition) -> Vec<TilePosition> {
+  let radius = GAME_CONFIG.electric_miner_resource_searching_radius.floor() as i32;
+  (-radius..=radius).flat_map(|x| (-radius..=radius).map(move |y| TilePosition::new(position.x + x, position.y + y))).collect()
+}
+// rotates an offset given for an entity facing north into the entity's direction
+fn rotate(Vector { x, y }: Vector, direction: Direction) -> Vector {
+  match direction {
+    Direction::North => Vector::new(x, y),
+    Direction::East => Vector::new(-y, x),
+    Direction::South => Vector::new(-x, -y),
+    Direction::West => Vector::new(y, -x),
+    direction => panic!("invalid entity direction {direction:?}"),
+  }
+}
 pub fn num_ticks_until(goal: f64, speed: f64) -> u32 {
   let step = speed / 60.0;
   let mut current = 0.0;
@@ -13,74 +47,89 @@ pub fn num_ticks_until(goal: f64, speed: f64) -> u32 {
     ticks += 1;
     current += step;
   }
-  return ticks;
+  ticks
 }
 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct CraftingOrder {
+  index: u16,
   recipe: Recipe,
   count: u32,
-  current_energy: f64,
+  current_energy: OrderedFloat<f64>,
+  parent: Option<u16>,  // index of the order this one crafts intermediates for, if it is a prerequisite
+  reserved_results: u32,  // results still owed to the parent order, any others go into the inventory
+}
+// single item result of a hand crafting recipe with its amount per craft
+fn crafted_item(recipe: Recipe) -> (Item, u32) {
+  match GAME_CONFIG.recipes[&recipe].results[..] {
+    [ProductConfig::Item { id, amount, .. }] => (id, amount),
+    ref results => panic!("recipe {recipe:?} has results {results:?} instead of a single item"),
+  }
 }
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct Burner {
   fuel_slot: Option<(Item, u32)>,
-  remaining_part_of_burning_fuel: f64,
-  heat_capacity: f64,
-  heat_energy: f64,
+  remaining_part_of_burning_fuel: OrderedFloat<f64>,
+  heat_capacity: OrderedFloat<f64>,
+  heat_energy: OrderedFloat<f64>,
 }
 impl Burner {
   fn with_buffer_size(heat_capacity: f64) -> Self {
-    Burner { fuel_slot: None, remaining_part_of_burning_fuel: 0.0, heat_capacity, heat_energy: 0.0 }
+    Burner { fuel_slot: None, remaining_part_of_burning_fuel: OrderedFloat(0.0), heat_capacity: OrderedFloat(heat_capacity), heat_energy: OrderedFloat(0.0) }
   }
   // from Burner::extractEnergyAndPollute
   fn extract_energy(&mut self, desired_energy: f64) -> f64 {
-    let provided_energy = desired_energy.min(self.heat_energy);
+    let provided_energy = desired_energy.min(*self.heat_energy);
     self.heat_energy -= provided_energy;
     provided_energy
   }
   // from Burner::transferHeat
   fn transfer_heat(&mut self) {
-    let mut heat_refill_amount = self.heat_capacity - self.heat_energy;
-    if heat_refill_amount > self.remaining_part_of_burning_fuel {
-      heat_refill_amount = self.remaining_part_of_burning_fuel;
+    let mut heat_refill_amount = *self.heat_capacity - *self.heat_energy;
+    if heat_refill_amount > *self.remaining_part_of_burning_fuel {
+      heat_refill_amount = *self.remaining_part_of_burning_fuel;
     }
     if heat_refill_amount != 0.0 {
-      self.heat_energy = self.heat_capacity.min(self.heat_energy + heat_refill_amount);
-      self.remaining_part_of_burning_fuel = 0f64.max(self.remaining_part_of_burning_fuel - heat_refill_amount);
+      *self.heat_energy = self.heat_capacity.0.min(*self.heat_energy + heat_refill_amount);
+      *self.remaining_part_of_burning_fuel = 0f64.max(*self.remaining_part_of_burning_fuel - heat_refill_amount);
     }
   }
   // from Burner::update
   fn tick(&mut self) {
-    if self.remaining_part_of_burning_fuel > 0.0 && self.heat_energy < self.heat_capacity {
+    if *self.remaining_part_of_burning_fuel > 0.0 && self.heat_energy < self.heat_capacity {
       self.transfer_heat();
     }
-    if self.heat_energy < self.heat_capacity && self.remaining_part_of_burning_fuel <= 0.0 {
+    if self.heat_energy < self.heat_capacity && *self.remaining_part_of_burning_fuel <= 0.0 {
       if let Some((item, amount)) = self.fuel_slot {
-        self.remaining_part_of_burning_fuel = GAME_CONFIG.fuels[&item].fuel_value;
+        *self.remaining_part_of_burning_fuel = GAME_CONFIG.fuels[&item].fuel_value;
         self.fuel_slot = if amount > 1 { Some((item, amount - 1)) } else { None };
         self.transfer_heat()
       }      
     }
   }
 }
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct BurnerMiner {
+  direction: Direction,
   energy_source: Burner,
-  mining_progress: f64,
-  num_resources_mined: u32,
+  mining_progress: OrderedFloat<f64>,
+  result: Option<Item>,  // mined item which couldn't be placed yet
 }
 impl BurnerMiner {
-  fn new() -> Self {
+  fn new(direction: Direction) -> Self {
     BurnerMiner {
+      direction,
       energy_source: Burner::with_buffer_size(GAME_CONFIG.burner_miner_energy_usage * (16.0 / 15.0)), // from MiningDrill::onEffectChanged
-      mining_progress: 0.0,
-      num_resources_mined: 0,
+      mining_progress: OrderedFloat(0.0),
+      result: None,
     }
   }
-  pub fn num_resources_needed_on_map(&self) -> u32 {
-    self.num_resources_mined + 1
+  // from MiningDrill::getDropPosition, vector_to_place_result rotated by the miner's direction
+  fn drop_position(&self, position: TilePosition) -> MapPosition {
+    position.top_left_map_position() + rotate(GAME_CONFIG.burner_miner_vector_to_place_result, self.direction)
   }
   // from MiningDrill::update
-  fn tick(&mut self) -> bool {  // true if resource mined this tick
-    // assuming there is space for the resulting item
+  fn tick(&mut self, mining_time: f64) -> bool {  // true if resource mined this tick
     let energy_consumed = self.energy_source.extract_energy(GAME_CONFIG.burner_miner_energy_usage);
     if energy_consumed != 0.0 {
       let energy_satisfaction = energy_consumed / GAME_CONFIG.burner_miner_energy_usage;
@@ -88,19 +137,124 @@ impl BurnerMiner {
     }
     self.energy_source.tick();
     // from MiningDrill::performMining
-    const MINING_TIME: f64 = 1.0; // hard-coded: same value for all basic resources
-    if self.mining_progress >= MINING_TIME {
-      self.mining_progress -= MINING_TIME;
-      self.num_resources_mined += 1;
+    if *self.mining_progress >= mining_time {
+      self.mining_progress -= mining_time;
+      return true;
+    }
+    false
+  }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ElectricMiner {
+  direction: Direction,
+  energy_source: ElectricEnergySource,
+  mining_progress: OrderedFloat<f64>,
+  result: Option<Item>,  // mined item which couldn't be placed yet
+}
+impl ElectricMiner {
+  fn new(direction: Direction) -> Self {
+    ElectricMiner {
+      direction,
+      energy_source: ElectricEnergySource::with_buffer_size(GAME_CONFIG.electric_miner_energy_usage),  // buffers one tick of energy usage
+      mining_progress: OrderedFloat(0.0),
+      result: None,
+    }
+  }
+  // 3x3 entity, centered on the given tile
+  fn collision_box(position: TilePosition) -> BoundingBox {
+    GAME_CONFIG.electric_miner_collision_box.offset(position.center_map_position())
+  }
+  // from MiningDrill::getDropPosition
+  fn drop_position(&self, position: TilePosition) -> MapPosition {
+    position.center_map_position() + rotate(GAME_CONFIG.electric_miner_vector_to_place_result, self.direction)
+  }
+  // from MiningDrill::update
+  fn tick(&mut self, mining_time: f64) -> bool {  // true if resource mined this tick
+    let energy_consumed = self.energy_source.extract_energy(GAME_CONFIG.electric_miner_energy_usage);
+    if energy_consumed != 0.0 {
+      let energy_satisfaction = energy_consumed / GAME_CONFIG.electric_miner_energy_usage;
+      self.mining_progress += (energy_satisfaction * GAME_CONFIG.electric_miner_speed) * (1.0 / 60.0);
+    }
+    // from MiningDrill::performMining
+    if *self.mining_progress >= mining_time {
+      self.mining_progress -= mining_time;
       return true;
     }
     false
   }
 }
+// takes up to count items from an inventory slot
+fn take_from_slot(slot: &mut Option<(Item, u32)>, count: u32) -> Option<(Item, u32)> {
+  let (item, slot_count) = slot.as_mut()?;
+  let (item, taken) = (*item, count.min(*slot_count));
+  *slot_count -= taken;
+  if *slot_count == 0 {
+    *slot = None;
+  }
+  Some((item, taken))
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Chest {
+  slots: Vec<Option<(Item, u32)>>,
+}
+impl Chest {
+  fn new(entity: Entity) -> Self {
+    Chest { slots: vec![None; GAME_CONFIG.containers[&entity].inventory_size as usize] }
+  }
+  // from Inventory::insert, existing stacks are topped up before empty slots are used
+  fn insert(&mut self, item: Item, count: u32) -> u32 {
+    let stack_size = GAME_CONFIG.items[&item].stack_size;
+    let mut remaining = count;
+    for slot in self.slots.iter_mut().filter_map(|slot| slot.as_mut()).filter(|(slot_item, _)| *slot_item == item) {
+      let inserted = remaining.min(stack_size - slot.1);
+      slot.1 += inserted;
+      remaining -= inserted;
+    }
+    for slot in self.slots.iter_mut().filter(|slot| slot.is_none()) {
+      if remaining == 0 { break; }
+      let inserted = remaining.min(stack_size);
+      *slot = Some((item, inserted));
+      remaining -= inserted;
+    }
+    count - remaining
+  }
+  fn can_insert(&self, item: Item) -> bool {
+    self.slots.iter().any(|slot| slot.is_none_or(|(slot_item, count)| slot_item == item && count < GAME_CONFIG.items[&item].stack_size))
+  }
+  // from Inventory::removeForInserter (loosely), inserters take from the last slot holding an accepted item
+  fn take(&mut self, count: u32, accepts: impl Fn(Item) -> bool) -> Option<(Item, u32)> {
+    take_from_slot(self.slots.iter_mut().rev().find(|slot| slot.is_some_and(|(item, _)| accepts(item)))?, count)
+  }
+}
+/// Resource entity on a tile, minable by the player and miners.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ResourceTile {
+  pub resource: Entity,
+  pub num_mined: u32,
+  pub amount: Option<u32>,  // remaining amount for resources imported from a map, others are unlimited
+}
+fn resource_product(resource: Entity) -> Item {
+  match GAME_CONFIG.minables[&resource].results.as_slice() {
+    &[ProductConfig::Item { id, amount: 1, .. }] => id,
+    results => panic!("resource {resource:?} has unsupported mining results {results:?}"),
+  }
+}
+// from FurnacePrototype::getRecipeForSource, for the smelting recipes in the base game, only recipes enabled for the force are used
+fn furnace_recipe(item: Item, research: &Research) -> Option<Recipe> {
+  let recipe = match item {
+    Item::IronOre => Recipe::IronPlate,
+    Item::CopperOre => Recipe::CopperPlate,
+    Item::Stone => Recipe::StoneBrick,
+    Item::IronPlate => Recipe::SteelPlate,
+    _ => return None,
+  };
+  Some(recipe).filter(|&recipe| research.is_recipe_enabled(recipe))
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct StoneFurnace {
   energy_source: Burner,
-  energy_stored_in_product: f64,
-  extra_energy_for_product: f64,
+  energy_stored_in_product: OrderedFloat<f64>,
+  extra_energy_for_product: OrderedFloat<f64>,
   recipe: Option<Recipe>,
   input_slot: Option<(Item, u32)>,
   output_slot: Option<(Item, u32)>,
@@ -109,8 +263,8 @@ impl StoneFurnace {
   fn new() -> Self {
     StoneFurnace {
       energy_source: Burner::with_buffer_size(GAME_CONFIG.stone_furnace_energy_usage * (16.0 / 15.0)), // from MiningDrill::onEffectChanged
-      energy_stored_in_product: 0.0,
-      extra_energy_for_product: 0.0,
+      energy_stored_in_product: OrderedFloat(0.0),
+      extra_energy_for_product: OrderedFloat(0.0),
       recipe: None,
       input_slot: None,
       output_slot: None,
@@ -126,16 +280,16 @@ impl StoneFurnace {
     effective_crafting_speed
   }
   // from CraftingMachine::update / CraftingMachine::useRecipeOnSource
-  pub fn tick(&mut self) {
-    if self.energy_stored_in_product > 0.0 {
+  pub fn tick(&mut self, research: &Research) {
+    if *self.energy_stored_in_product > 0.0 {
       let recipe = self.recipe.expect("progress but no recipe selected");
       let recipe_energy_required = GAME_CONFIG.recipes[&recipe].energy_required;
-      if self.energy_stored_in_product >= recipe_energy_required {
-        self.extra_energy_for_product = 0f64.max(self.energy_stored_in_product - recipe_energy_required);
-        self.energy_stored_in_product = 0.0;
+      if *self.energy_stored_in_product >= recipe_energy_required {
+        *self.extra_energy_for_product = 0f64.max(*self.energy_stored_in_product - recipe_energy_required);
+        self.energy_stored_in_product = OrderedFloat(0.0);
         // output products
         assert!(GAME_CONFIG.recipes[&recipe].results.len() == 1, "Stone Furnace Recipe {recipe:?} does not have exactly one product: {:?}", GAME_CONFIG.recipes[&recipe].results);
-        if let &ProductConfig::Item { id: output_item, amount: output_amount } = &GAME_CONFIG.recipes[&recipe].results[0] {
+        if let &ProductConfig::Item { id: output_item, amount: output_amount, .. } = &GAME_CONFIG.recipes[&recipe].results[0] {
           if let Some((item, amount)) = &mut self.output_slot {
             assert!(*item == output_item, "Stone Furnace can't hold {output_item:?} while already storing {item:?}");
             *amount += output_amount;
@@ -151,13 +305,7 @@ impl StoneFurnace {
     if new_craft {
       // change recipe
       if let Some((item, amount)) = self.input_slot {
-        self.recipe = Some(match item {
-          Item::IronOre => Recipe::IronPlate,
-          Item::CopperOre => Recipe::CopperPlate,
-          Item::Stone => Recipe::StoneBrick,
-          Item::IronPlate => Recipe::SteelPlate,
-          _ => panic!("unknown Furnace input item {item:?}"),
-        });
+        self.recipe = Some(furnace_recipe(item, research).unwrap_or_else(|| panic!("unknown Furnace input item {item:?}")));
         let (input_item, input_amount) = self.get_recipe_inputs();
         assert!(item == input_item, "recipe item {input_item:?} does not match {item:?}");
         if amount < input_amount {
@@ -165,37 +313,50 @@ impl StoneFurnace {
           self.recipe = None;
           return;
         }
+        if !self.can_hold_result() {
+          // Output full or holding a different product, the recipe stays selected
+          return;
+        }
       } else {
         // No input, no recipe
         self.recipe = None;
         return;
       }
     }
-    if self.extract_energy() > 0.0 {
-      if new_craft {
-        // take ingredients
-        let (item, amount) = self.input_slot.expect("Starting new recipe without input items");
-        let (input_item, input_amount) = self.get_recipe_inputs();
-        assert!(item == input_item, "recipe item {input_item:?} does not match {item:?}");
-        assert!(amount >= input_amount, "Starting new recipe with insufficient input items");
-        if amount > input_amount {
-          self.input_slot = Some((item, amount - input_amount));
-        } else {
-          self.input_slot = None;
-        }
-
-        // add extra energy left over from last craft
-        self.energy_stored_in_product += self.extra_energy_for_product;
-        self.extra_energy_for_product = 0.0;
+    if self.extract_energy() > 0.0 && new_craft {
+      // take ingredients
+      let (item, amount) = self.input_slot.expect("Starting new recipe without input items");
+      let (input_item, input_amount) = self.get_recipe_inputs();
+      assert!(item == input_item, "recipe item {input_item:?} does not match {item:?}");
+      assert!(amount >= input_amount, "Starting new recipe with insufficient input items");
+      if amount > input_amount {
+        self.input_slot = Some((item, amount - input_amount));
+      } else {
+        self.input_slot = None;
       }
+
+      // add extra energy left over from last craft
+      self.energy_stored_in_product += self.extra_energy_for_product;
+      self.extra_energy_for_product = OrderedFloat(0.0);
     }
     self.energy_source.tick()
   }
 
+  // from CraftingMachine::canInsertResults, the result has to fit into the output slot as a whole
+  fn can_hold_result(&self) -> bool {
+    let recipe = self.recipe.expect("no recipe set");
+    let &[ProductConfig::Item { id: result_item, amount: result_amount, .. }] = GAME_CONFIG.recipes[&recipe].results.as_slice() else {
+      panic!("Stone Furnace Recipe {recipe:?} does not have exactly one item product: {:?}", GAME_CONFIG.recipes[&recipe].results)
+    };
+    match self.output_slot {
+      None => true,
+      Some((item, amount)) => item == result_item && amount + result_amount <= GAME_CONFIG.items[&item].stack_size,
+    }
+  }
   fn get_recipe_inputs(&self) -> (Item, u32) {
     let recipe = self.recipe.expect("no recipe set");
     assert!(GAME_CONFIG.recipes[&recipe].ingredients.len() == 1, "Stone Furnace Recipe {recipe:?} does not have exactly one ingredient: {:?}", GAME_CONFIG.recipes[&recipe].ingredients);
-    if let &ProductConfig::Item { id, amount } = &GAME_CONFIG.recipes[&recipe].ingredients[0] {
+    if let &ProductConfig::Item { id, amount, .. } = &GAME_CONFIG.recipes[&recipe].ingredients[0] {
       (id, amount)
     } else {
       panic!("Stone Furnace Recipe {recipe:?} has fluid input {:?}", GAME_CONFIG.recipes[&recipe].ingredients[0])
@@ -204,174 +365,669 @@ impl StoneFurnace {
   // From CraftingMachine::getActivityProgress
   fn get_crafting_progress(&self) -> f64 {
     if let Some(recipe) = self.recipe {
-      1f64.min(self.energy_stored_in_product / GAME_CONFIG.recipes[&recipe].energy_required)
+      1f64.min(*self.energy_stored_in_product / GAME_CONFIG.recipes[&recipe].energy_required)
     } else { 0.0 }
   }
 }
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 enum PlayerSelectedEntity {
-  DryTree(usize),
-  HugeRock(usize),
-  IronOre(TilePosition),
-  CopperOre(TilePosition),
+  Tree(usize),
+  Rock(usize),
+  Resource(TilePosition),
+  StoneFurnace(TilePosition),
+  BurnerMiner(TilePosition),
+  Chest(TilePosition),
+  ElectricMiner(TilePosition),
+  OffshorePump(TilePosition),
+  Boiler(TilePosition),
+  SteamEngine(TilePosition),
+  Pipe(TilePosition),
+  ElectricPole(TilePosition),
+  AssemblingMachine(TilePosition),
+  Lab(TilePosition),
+  TransportBelt(TilePosition),
+  Inserter(TilePosition),
+}
+impl PlayerSelectedEntity {
+  fn is_minable(self) -> bool {
+    matches!(self, PlayerSelectedEntity::Tree(_) | PlayerSelectedEntity::Rock(_) | PlayerSelectedEntity::Resource(_))
+  }
+}
+// entity holding a fluid box, boilers have separate boxes for water and steam
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum FluidBoxOwner {
+  OffshorePump(TilePosition),
+  Boiler(TilePosition),
+  BoilerOutput(TilePosition),
+  SteamEngine(TilePosition),
+  Pipe(TilePosition),
+}
+/// An input action of a replay which the simulation can't reproduce, e.g. building an entity which isn't simulated.
+#[derive(Debug)]
+pub struct SimulationError {
+  pub tick: u32,
+  pub action: Box<InputAction>,
+  pub reason: String,
+}
+impl std::fmt::Display for SimulationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "can't simulate {:?} at tick {}: {}", self.action, self.tick, self.reason)
+  }
 }
+
+/// Simulated game state, cheap to clone to branch off alternatives.
+///
+/// Equality and hashing cover the simulated state only, not the input actions which led to it.
+#[derive(Clone)]
 pub struct GameState {
   pub tick: u32,
 
   player_position: MapPosition,
   player_walking_direction: Direction,
   player_selected_entity: Option<PlayerSelectedEntity>,
-  player_mining_progress: Option<(f64, f64, u32)>,
-  player_inventory: HashMap<Item, u32>,
+  player_opened_entity: Option<PlayerSelectedEntity>,
+  player_mining_progress: Option<(OrderedFloat<f64>, OrderedFloat<f64>, Option<u32>)>,  // (progress, goal, remaining count or None to mine until stopped)
+  player_inventory: PlayerInventory,
   player_crafting_queue: VecDeque<CraftingOrder>,
+  next_crafting_index: u16,
 
-  pub water_tiles: HashSet<TilePosition>,
+  pub water_tiles: BTreeSet<TilePosition>,
 
-  dry_trees: Vec<MapPosition>,
-  huge_rocks: Vec<MapPosition>,
-  huge_rock_rng: RandomGenerator,
-  pub iron_ores: HashMap<TilePosition, u32>,
-  pub copper_ores: HashMap<TilePosition, u32>,
-  pub iron_miners: HashMap<TilePosition, BurnerMiner>,
-  pub stone_furnaces: HashMap<TilePosition, StoneFurnace>,
+  trees: Vec<(Entity, MapPosition)>,
+  rocks: Vec<(Entity, MapPosition)>,  // minable simple entities
+  entities_rng: RandomGenerator,
+  pub resources: BTreeMap<TilePosition, ResourceTile>,
+  pub burner_miners: BTreeMap<TilePosition, BurnerMiner>,
+  pub stone_furnaces: BTreeMap<TilePosition, StoneFurnace>,
+  pub chests: BTreeMap<TilePosition, Chest>,
+  pub electric_miners: BTreeMap<TilePosition, ElectricMiner>,
+  pub offshore_pumps: BTreeMap<TilePosition, OffshorePump>,
+  pub boilers: BTreeMap<TilePosition, Boiler>,
+  pub steam_engines: BTreeMap<TilePosition, SteamEngine>,
+  pub pipes: BTreeMap<TilePosition, Pipe>,
+  pub electric_poles: BTreeMap<TilePosition, ElectricPole>,
+  pub assembling_machines: BTreeMap<TilePosition, AssemblingMachine>,
+  pub labs: BTreeMap<TilePosition, Lab>,
+  pub transport_belts: BTreeMap<TilePosition, TransportBelt>,
+  pub inserters: BTreeMap<TilePosition, Inserter>,
+  pub items_on_ground: Vec<(MapPosition, Item)>,
+  pub research: Research,
+  fluid_box_ends: Vec<(FluidBoxOwner, TilePosition, TilePosition)>,  // pipe connections of all fluid boxes, as the tile they start from and the one they point at
+  fluid_box_connections: Vec<(FluidBoxOwner, FluidBoxOwner, OrderedFloat<f64>)>,  // in the order they were made, with the flow from the first to the second in the last tick
 
   instrumented: bool,
   pub input_actions: Vec<InputAction>,
+
+  checkpoint_interval: u32,
+  max_checkpoints: usize,
+  checkpoints: VecDeque<Rc<Checkpoint>>,  // oldest first, shared between clones
+  operations: Vec<(u32, Operation)>,  // calls changing the state since the oldest checkpoint, with their tick
+  pruned_operation_count: usize,  // recorded calls dropped with the checkpoints before them
+}
+// a call changing the state, re-run when rewinding past it
+type Operation = Rc<dyn Fn(&mut GameState)>;
+struct Checkpoint {
+  state: GameState,  // without the recorded input actions, operations and checkpoints, only the counts of the first two are kept
+  input_action_count: usize,
+  operation_count: usize,  // including pruned ones
+}
+impl PartialEq for GameState {
+  fn eq(&self, other: &Self) -> bool {
+    self.tick == other.tick
+      && self.player_position == other.player_position
+      && self.player_walking_direction == other.player_walking_direction
+      && self.player_selected_entity == other.player_selected_entity
+      && self.player_opened_entity == other.player_opened_entity
+      && self.player_mining_progress == other.player_mining_progress
+      && self.player_inventory == other.player_inventory
+      && self.player_crafting_queue == other.player_crafting_queue
+      && self.next_crafting_index == other.next_crafting_index
+      && self.water_tiles == other.water_tiles
+      && self.trees == other.trees
+      && self.rocks == other.rocks
+      && self.entities_rng == other.entities_rng
+      && self.resources == other.resources
+      && self.burner_miners == other.burner_miners
+      && self.stone_furnaces == other.stone_furnaces
+      && self.chests == other.chests
+      && self.electric_miners == other.electric_miners
+      && self.offshore_pumps == other.offshore_pumps
+      && self.boilers == other.boilers
+      && self.steam_engines == other.steam_engines
+      && self.pipes == other.pipes
+      && self.electric_poles == other.electric_poles
+      && self.assembling_machines == other.assembling_machines
+      && self.labs == other.labs
+      && self.transport_belts == other.transport_belts
+      && self.inserters == other.inserters
+      && self.items_on_ground == other.items_on_ground
+      && self.research == other.research
+      && self.fluid_box_connections == other.fluid_box_connections
+  }
+}
+impl Eq for GameState {}
+impl Hash for GameState {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.tick.hash(state);
+    self.player_position.hash(state);
+    self.player_walking_direction.hash(state);
+    self.player_selected_entity.hash(state);
+    self.player_opened_entity.hash(state);
+    self.player_mining_progress.hash(state);
+    self.player_inventory.hash(state);
+    self.player_crafting_queue.hash(state);
+    self.next_crafting_index.hash(state);
+    self.water_tiles.hash(state);
+    self.trees.hash(state);
+    self.rocks.hash(state);
+    self.entities_rng.hash(state);
+    self.resources.hash(state);
+    self.burner_miners.hash(state);
+    self.stone_furnaces.hash(state);
+    self.chests.hash(state);
+    self.electric_miners.hash(state);
+    self.offshore_pumps.hash(state);
+    self.boilers.hash(state);
+    self.steam_engines.hash(state);
+    self.pipes.hash(state);
+    self.electric_poles.hash(state);
+    self.assembling_machines.hash(state);
+    self.labs.hash(state);
+    self.transport_belts.hash(state);
+    self.inserters.hash(state);
+    self.items_on_ground.hash(state);
+    self.research.hash(state);
+    self.fluid_box_connections.hash(state);
+  }
 }
 impl GameState {
-  pub fn new(huge_rock_rng: RandomGenerator) -> Self {
+  pub fn new(entities_rng: RandomGenerator) -> Self {
     Self {
       tick: 0,
       player_position: MapPosition::new(FixedPoint32_8(0), FixedPoint32_8(0)),
       player_walking_direction: Direction::None,
       player_selected_entity: None,
+      player_opened_entity: None,
       player_mining_progress: None,
-      player_inventory: [(Item::IronPlate, 8), (Item::Wood, 1), (Item::BurnerMiningDrill, 1), (Item::StoneFurnace, 1)].into_iter().collect(),
+      player_inventory: PlayerInventory::new(FREEPLAY_CREATED_ITEMS),
       player_crafting_queue: VecDeque::new(),
+      next_crafting_index: 1,
 
-      water_tiles: HashSet::new(),
+      water_tiles: BTreeSet::new(),
 
-      dry_trees: Vec::new(),
-      huge_rocks: Vec::new(),
-      huge_rock_rng,
-      iron_ores: HashMap::new(),
-      copper_ores: HashMap::new(),
-      iron_miners: HashMap::new(),
-      stone_furnaces: HashMap::new(),
+      trees: Vec::new(),
+      rocks: Vec::new(),
+      entities_rng,
+      resources: BTreeMap::new(),
+      burner_miners: BTreeMap::new(),
+      stone_furnaces: BTreeMap::new(),
+      chests: BTreeMap::new(),
+      electric_miners: BTreeMap::new(),
+      offshore_pumps: BTreeMap::new(),
+      boilers: BTreeMap::new(),
+      steam_engines: BTreeMap::new(),
+      pipes: BTreeMap::new(),
+      electric_poles: BTreeMap::new(),
+      assembling_machines: BTreeMap::new(),
+      labs: BTreeMap::new(),
+      transport_belts: BTreeMap::new(),
+      inserters: BTreeMap::new(),
+      items_on_ground: Vec::new(),
+      research: Research::default(),
+      fluid_box_ends: Vec::new(),
+      fluid_box_connections: Vec::new(),
 
       instrumented: false,
-      input_actions: Vec::new()
+      input_actions: Vec::new(),
+
+      checkpoint_interval: 0,
+      max_checkpoints: 0,
+      checkpoints: VecDeque::new(),
+      operations: Vec::new(),
+      pruned_operation_count: 0,
+    }
+  }
+  /// Sets up the simulation from the state of a template save.
+  ///
+  /// Imports water tiles, trees, minable rocks and iron, copper, stone and coal resources with their amounts, the entities RNG, the research
+  /// state of the player force and the freeplay starting items. The player spawns at the origin, as templates have no custom spawn positions.
+  pub fn from_map(map_data: &MapData, script_data: &ScriptData) -> Self {
+    let mut game_state = GameState::from_chunks(map_data.random_generator(RandomGeneratorRole::Entities).clone(), &map_data.map.surfaces[0].chunks, script_data);
+    // technologies missing from the map's prototypes aren't researched, current research of technologies which aren't simulated is dropped
+    let researched = GAME_CONFIG.technologies.keys().copied().filter(|&technology| map_data.is_technology_researched(ForceId::Player, technology).unwrap_or(false));
+    let current = map_data.current_research(ForceId::Player).ok().flatten().filter(|(technology, _)| GAME_CONFIG.technologies.contains_key(technology));
+    game_state.research = Research::from_force(researched, current);
+    game_state
+  }
+  fn from_chunks(entities_rng: RandomGenerator, chunks: &[Chunk], script_data: &ScriptData) -> Self {
+    let mut game_state = GameState::new(entities_rng);
+    for chunk in chunks {
+      for (x, column) in chunk.tiles.iter().enumerate() {
+        for (y, &(tile, _)) in column.iter().enumerate() {
+          if matches!(tile.known(), Some(Tile::Water | Tile::Deepwater | Tile::WaterGreen | Tile::DeepwaterGreen | Tile::WaterWube)) {  // shallow and mud water are walkable
+            game_state.make_water_tile(TilePosition::new(chunk.position.x * 32 + x as i32, chunk.position.y * 32 + y as i32));
+          }
+        }
+      }
+      for (prototype, entity_data) in &chunk.entities_to_be_inserted_before_setup {
+        let entity = prototype.known();
+        let is_minable = entity.is_some_and(|entity| GAME_CONFIG.minables.contains_key(&entity));
+        match entity_data {
+          EntityData::DryTree(tree) => game_state.add_tree(Entity::DryTree, tree.entity.entity.position),
+          EntityData::RockHuge(rock) => game_state.add_rock(Entity::RockHuge, rock.entity.entity.position),
+          EntityData::Tree(tree) if is_minable => game_state.add_tree(entity.unwrap(), tree.entity.entity.position),
+          EntityData::SimpleEntity(rock) if is_minable => game_state.add_rock(entity.unwrap(), rock.entity.entity.position),
+          EntityData::IronOre(resource) => game_state.add_resource(Entity::IronOre, resource.entity.position.to_tile_position(), Some(resource.resource_amount)),
+          EntityData::CopperOre(resource) => game_state.add_resource(Entity::CopperOre, resource.entity.position.to_tile_position(), Some(resource.resource_amount)),
+          EntityData::Stone(resource) => game_state.add_resource(Entity::Stone, resource.entity.position.to_tile_position(), Some(resource.resource_amount)),
+          EntityData::Coal(resource) => game_state.add_resource(Entity::Coal, resource.entity.position.to_tile_position(), Some(resource.resource_amount)),
+          EntityData::Tree(_) | EntityData::SimpleEntity(_) => {},  // entities of mods and simple entities which can't be mined are not simulated
+          EntityData::CrudeOil(_) | EntityData::Resource(_) => {},  // fluid resources and resources of mods are not simulated
+        }
+      }
+    }
+
+    // freeplay fills created_items in on_init, so it's only present if the script already ran
+    let created_items = script_data.lua_context.script(LuaContext::LEVEL_SCRIPT).and_then(|script| script.script_state.created_items());
+    if let Some(created_items) = created_items {
+      game_state.player_inventory = PlayerInventory::default();
+      for (name, count) in created_items {
+        let Some(item) = Item::try_from_name(&name) else { continue };  // items of mods are not simulated
+        if GAME_CONFIG.items.get(&item).is_some_and(|config| !matches!(config.item_type, ItemType::Gun | ItemType::Ammo)) && count > 0 {  // guns and ammo go into their own inventories
+          game_state.add_to_inventory(item, count);
+        }
+      }
     }
+    game_state
+  }
+  pub fn player_position(&self) -> MapPosition {
+    self.player_position
+  }
+  pub fn player_inventory(&self) -> &BTreeMap<Item, u32> {
+    self.player_inventory.items()
   }
   pub fn with_instrumentation(self) -> Self {
     Self { instrumented: true, ..self }
   }
+  /// Keeps a snapshot of the state every `interval` ticks, starting now, to allow rewinding.
+  ///
+  /// Only the last `count` snapshots are kept, older ones and the calls recorded before them are dropped as they can no longer be
+  /// rewound to.
+  #[allow(dead_code)]
+  pub fn with_checkpoints(mut self, interval: u32, count: usize) -> Self {
+    assert!(interval > 0, "checkpoint interval must be positive");
+    assert!(count > 0, "at least one checkpoint must be kept");
+    self.checkpoint_interval = interval;
+    self.max_checkpoints = count;
+    self.take_checkpoint();
+    self
+  }
+  fn take_checkpoint(&mut self) {
+    let input_actions = std::mem::take(&mut self.input_actions);
+    let operations = std::mem::take(&mut self.operations);
+    let checkpoints = std::mem::take(&mut self.checkpoints);
+    let checkpoint = Checkpoint { state: self.clone(), input_action_count: input_actions.len(), operation_count: self.pruned_operation_count + operations.len() };
+    self.input_actions = input_actions;
+    self.operations = operations;
+    self.checkpoints = checkpoints;
+    self.checkpoints.push_back(Rc::new(checkpoint));
+    while self.checkpoints.len() > self.max_checkpoints {
+      self.checkpoints.pop_front();
+      let pruned = self.checkpoints[0].operation_count - self.pruned_operation_count;
+      self.operations.drain(..pruned);
+      self.pruned_operation_count += pruned;
+    }
+  }
+  fn record(&mut self, operation: impl Fn(&mut GameState) + 'static) {
+    if self.checkpoint_interval > 0 {
+      self.operations.push((self.tick, Rc::new(operation)));
+    }
+  }
+  /// Returns the simulation to the given past tick.
+  ///
+  /// Restores the last checkpoint at or before the tick, and re-simulates from there by re-running the calls changing the state
+  /// recorded since. Calls made at the tick or later are dropped. Changes made directly to the public fields aren't recorded.
+  #[allow(dead_code)]
+  pub fn rewind_to(&mut self, tick: u32) {
+    assert!(tick <= self.tick, "can't rewind to future tick {tick} from tick {}", self.tick);
+    let mut checkpoints = std::mem::take(&mut self.checkpoints);
+    while checkpoints.back().is_some_and(|checkpoint| checkpoint.state.tick > tick) {
+      checkpoints.pop_back();
+    }
+    let checkpoint = checkpoints.back().cloned().unwrap_or_else(|| panic!("no checkpoint at or before tick {tick}"));
+
+    let mut input_actions = std::mem::take(&mut self.input_actions);
+    let mut operations = std::mem::take(&mut self.operations);
+    let pruned_operation_count = self.pruned_operation_count;
+    input_actions.truncate(checkpoint.input_action_count);
+    let replayed_operations = operations.split_off(checkpoint.operation_count - pruned_operation_count);
+    *self = checkpoint.state.clone();
+    self.input_actions = input_actions;
+    self.operations = operations;
+    self.checkpoints = checkpoints;
+    self.pruned_operation_count = pruned_operation_count;
+    // checkpoints are taken at the end of a tick, so the operations recorded after them start with the ones made at that tick
+    for (operation_tick, operation) in replayed_operations.into_iter().take_while(|&(operation_tick, _)| operation_tick < tick) {
+      while self.tick < operation_tick {
+        self.tick();
+      }
+      operation(self);
+    }
+    while self.tick < tick {
+      self.tick();
+    }
+  }
   pub fn make_water_tile(&mut self, position: TilePosition) {
+    self.record(move |state| state.make_water_tile(position));
     self.water_tiles.insert(position);
   }
-  pub fn add_tree(&mut self, position: MapPosition) {
-    self.dry_trees.push(position);
+  pub fn add_tree(&mut self, tree: Entity, position: MapPosition) {
+    assert!(GAME_CONFIG.minables.contains_key(&tree), "{tree:?} is not minable");
+    self.record(move |state| state.add_tree(tree, position));
+    self.trees.push((tree, position));
   }
   fn pick_tree_at_position(&self, position: MapPosition) -> Option<usize> {
-    let bb = GAME_CONFIG.dry_tree_bounding_box.with_direction(Direction::South).offset(position); // get inverse bounding box for check
-    self.dry_trees.iter().position(|p| bb.collide_point(&p))
+    self.trees.iter().position(|&(tree, p)| GAME_CONFIG.minables[&tree].collision_box.with_direction(Direction::South).offset(position).collide_point(&p)) // inverse bounding box for check
   }
   pub fn mine_tree(&mut self, position: MapPosition) -> u32 {
+    self.record(move |state| { state.mine_tree(position); });
     assert!(self.player_mining_progress.is_none(), "player is already mining {:?} progress {:?}", self.player_selected_entity, self.player_mining_progress);
     let tree_index = self.pick_tree_at_position(position).expect("cannot find tree at position");
-    self.player_selected_entity = Some(PlayerSelectedEntity::DryTree(tree_index));
-    self.player_mining_progress = Some((0.0, GAME_CONFIG.dry_tree_mining_time, 1));
+    let mining_time = self.mining_time(PlayerSelectedEntity::Tree(tree_index));
+    self.player_selected_entity = Some(PlayerSelectedEntity::Tree(tree_index));
+    self.player_mining_progress = Some((OrderedFloat(0.0), OrderedFloat(mining_time), Some(1)));
 
     self.add_input_action(InputActionData::SelectedEntityChanged(position)); // Select entity
     self.add_input_action(InputActionData::BeginMining); // Begin mining
-    num_ticks_until(GAME_CONFIG.dry_tree_mining_time, GAME_CONFIG.player_mining_speed)
+    num_ticks_until(mining_time, GAME_CONFIG.player_mining_speed)
   }
-  pub fn add_rock(&mut self, position: MapPosition) {
-    self.huge_rocks.push(position);
+  pub fn add_rock(&mut self, rock: Entity, position: MapPosition) {
+    assert!(GAME_CONFIG.minables.contains_key(&rock), "{rock:?} is not minable");
+    self.record(move |state| state.add_rock(rock, position));
+    self.rocks.push((rock, position));
   }
   fn pick_rock_at_position(&self, position: MapPosition) -> Option<usize> {
-    let bb = GAME_CONFIG.huge_rock_bounding_box.with_direction(Direction::South).offset(position); // get inverse bounding box for check
-    self.huge_rocks.iter().position(|p| bb.collide_point(&p))
+    self.rocks.iter().position(|&(rock, p)| GAME_CONFIG.minables[&rock].collision_box.with_direction(Direction::South).offset(position).collide_point(&p)) // inverse bounding box for check
   }
   pub fn mine_rock(&mut self, position: MapPosition) -> u32 {
+    self.record(move |state| { state.mine_rock(position); });
     assert!(self.player_mining_progress.is_none(), "player is already mining {:?} progress {:?}", self.player_selected_entity, self.player_mining_progress);
     let rock_index = self.pick_rock_at_position(position).expect("cannot find rock at position");
-    self.player_selected_entity = Some(PlayerSelectedEntity::HugeRock(rock_index));
-    self.player_mining_progress = Some((0.0, GAME_CONFIG.huge_rock_mining_time, 1));
+    let mining_time = self.mining_time(PlayerSelectedEntity::Rock(rock_index));
+    self.player_selected_entity = Some(PlayerSelectedEntity::Rock(rock_index));
+    self.player_mining_progress = Some((OrderedFloat(0.0), OrderedFloat(mining_time), Some(1)));
 
     self.add_input_action(InputActionData::SelectedEntityChanged(position)); // Select entity
     self.add_input_action(InputActionData::BeginMining); // Begin mining
-    num_ticks_until(GAME_CONFIG.huge_rock_mining_time, GAME_CONFIG.player_mining_speed)
+    num_ticks_until(mining_time, GAME_CONFIG.player_mining_speed)
+  }
+  pub fn add_resource(&mut self, resource: Entity, position: TilePosition, amount: Option<u32>) {
+    self.record(move |state| state.add_resource(resource, position, amount));
+    self.insert_resource(resource, position, amount);
+  }
+  fn insert_resource(&mut self, resource: Entity, position: TilePosition, amount: Option<u32>) {
+    assert!(matches!(resource, Entity::IronOre | Entity::CopperOre | Entity::Stone | Entity::Coal), "unsupported resource {resource:?}");
+    self.resources.insert(position, ResourceTile { resource, num_mined: 0, amount });
+  }
+  /// Resources to put on the map to support the simulated mining, for all resources without a given amount.
+  pub fn resources_needed_on_map(&self) -> Vec<(Entity, TilePosition, u32)> {
+    self.resources.iter().filter(|(_, tile)| tile.amount.is_none()).map(|(&position, tile)| {
+      // miners need one more resource left, to keep on mining after the last one
+      let under_miner = self.burner_miners.keys().any(|&miner_position| miner_area(miner_position).contains(&position))
+        || self.electric_miners.keys().any(|&miner_position| electric_miner_area(miner_position).contains(&position));
+      (tile.resource, position, tile.num_mined + u32::from(under_miner))
+    }).collect()
   }
   pub fn mine_iron_ore(&mut self, position: TilePosition, count: u32) -> u32 {
-    assert!(self.player_mining_progress.is_none(), "player is already mining {:?} progress {:?}", self.player_selected_entity, self.player_mining_progress);
-    self.iron_ores.entry(position).or_insert(0);
-
-    self.player_selected_entity = Some(PlayerSelectedEntity::IronOre(position));
-    self.player_mining_progress = Some((0.0, GAME_CONFIG.iron_ore_mining_time, count));
-
-    self.add_input_action(InputActionData::SelectedEntityChanged(position.center_map_position())); // Select entity
-    self.add_input_action(InputActionData::BeginMining); // Begin mining
-    num_ticks_until(GAME_CONFIG.iron_ore_mining_time, GAME_CONFIG.player_mining_speed) * count
+    self.mine_resource(Entity::IronOre, position, count)
   }
   pub fn mine_copper_ore(&mut self, position: TilePosition, count: u32) -> u32 {
+    self.mine_resource(Entity::CopperOre, position, count)
+  }
+  pub fn mine_resource(&mut self, resource: Entity, position: TilePosition, count: u32) -> u32 {
+    self.record(move |state| { state.mine_resource(resource, position, count); });
     assert!(self.player_mining_progress.is_none(), "player is already mining {:?} progress {:?}", self.player_selected_entity, self.player_mining_progress);
-    self.copper_ores.entry(position).or_insert(0);
+    if !self.resources.contains_key(&position) {
+      self.insert_resource(resource, position, None);
+    }
+    assert!(self.resources[&position].resource == resource, "resource at {position:?} is {:?}, not {resource:?}", self.resources[&position].resource);
+    let mining_time = GAME_CONFIG.minables[&resource].mining_time;
 
-    self.player_selected_entity = Some(PlayerSelectedEntity::CopperOre(position));
-    self.player_mining_progress = Some((0.0, GAME_CONFIG.copper_ore_mining_time, count));
+    self.player_selected_entity = Some(PlayerSelectedEntity::Resource(position));
+    self.player_mining_progress = Some((OrderedFloat(0.0), OrderedFloat(mining_time), Some(count)));
 
     self.add_input_action(InputActionData::SelectedEntityChanged(position.center_map_position())); // Select entity
     self.add_input_action(InputActionData::BeginMining); // Begin mining
-    num_ticks_until(GAME_CONFIG.iron_ore_mining_time, GAME_CONFIG.player_mining_speed) * count
+    num_ticks_until(mining_time, GAME_CONFIG.player_mining_speed) * count
   }
   pub fn set_walking_direction(&mut self, player_walking_direction: Direction) {
+    self.record(move |state| state.set_walking_direction(player_walking_direction));
     match player_walking_direction {
         Direction::None => self.add_input_action(InputActionData::StopWalking),
         _ => self.add_input_action(InputActionData::StartWalking(player_walking_direction)),
     }
     self.player_walking_direction = player_walking_direction;
   }
-  pub fn craft(&mut self, recipe: Recipe, count: u32) -> u32 {
-    for ingredient in &GAME_CONFIG.recipes[&recipe].ingredients {
-      match ingredient {
-        &ProductConfig::Item { id, amount } => self.remove_from_inventory(id, amount * count),
-        ProductConfig::Fluid { .. } => panic!("recipe {recipe:?} contains fluid input {ingredient:?} and can't be hand crafted")
-      }
+  /// Queues crafts of the recipe and their missing intermediates, returns the number of ticks to craft them.
+  pub fn craft(&mut self, recipe: Recipe, count: u32) -> Result<u32, SimulationError> {
+    let action = InputActionData::Craft(CraftData { recipe, count });
+    let ticks = self.queue_craft(recipe, count).map_err(|reason| self.simulation_error(action.clone(), reason))?;
+    self.record(move |state| { state.craft(recipe, count).unwrap_or_else(|e| panic!("{e}")); });
+    self.add_input_action(action); // begin crafting
+    Ok(ticks)
+  }
+  /// Cancels crafts of the last queued order of the recipe which isn't a prerequisite of another order.
+  pub fn cancel_craft(&mut self, recipe: Recipe, count: u32) -> Result<(), SimulationError> {
+    let Some(order) = self.player_crafting_queue.iter().rev().find(|order| order.recipe == recipe && order.parent.is_none()) else {
+      let action = InputActionData::CancelCraft(CancelCraftOrder { crafting_index: 0, count });  // there is no order to give the index of
+      return Err(self.simulation_error(action, format!("no crafting order of {recipe:?} to cancel")));
+    };
+    let crafting_index = order.index;
+    self.record(move |state| state.cancel_craft(recipe, count).unwrap_or_else(|e| panic!("{e}")));
+    self.cancel_crafting_order(crafting_index, count);
+    self.add_input_action(InputActionData::CancelCraft(CancelCraftOrder { crafting_index, count }));
+    Ok(())
+  }
+  // from CraftingQueue::craft (loosely), returns the number of ticks to craft the order including its prerequisites
+  fn queue_craft(&mut self, recipe: Recipe, count: u32) -> Result<u32, String> {
+    let mut available = self.player_inventory.items().clone();
+    let mut orders = Vec::new();
+    self.plan_craft(recipe, count, 0, &mut available, &mut orders)?;
+    let taken_items = self.player_inventory.items().iter()
+        .map(|(&item, &count)| (item, count - available.get(&item).copied().unwrap_or(0)))
+        .filter(|&(_, taken)| taken > 0).collect::<Vec<_>>();
+    for (item, taken) in taken_items {
+      self.remove_from_inventory(item, taken);
     }
-    if self.player_crafting_queue.back().is_some_and(|c| c.recipe == recipe) {
+    let ticks = orders.iter().map(|order| num_ticks_until(GAME_CONFIG.recipes[&order.recipe].energy_required, 1.0) * order.count).sum();
+    if orders.len() == 1 && self.player_crafting_queue.back().is_some_and(|c| c.recipe == recipe && c.parent.is_none()) {
       self.player_crafting_queue.back_mut().unwrap().count += count;
     } else {
-      self.player_crafting_queue.push_back(CraftingOrder { recipe, count, current_energy: 0.0 });
+      // crafting indices are u16 in the input actions, so they wrap around like the game's
+      self.next_crafting_index = self.next_crafting_index.wrapping_add(orders.len() as u16);
+      self.player_crafting_queue.extend(orders);
     }
-
-    self.add_input_action(InputActionData::Craft(CraftData { recipe, count })); // begin crafting
-    num_ticks_until(GAME_CONFIG.recipes[&recipe].energy_required, 1.0) * count
+    Ok(ticks)
   }
-  fn remove_from_inventory(&mut self, item: Item, count: u32) {
-    let inventory_count = self.player_inventory.get_mut(&item).unwrap_or_else(|| panic!("no {item:?} in inventory to remove"));
-    assert!(*inventory_count >= count, "not enough {item:?} in inventory: needed {} but found {}", count, *inventory_count);
-    *inventory_count -= count;
-    if *inventory_count == 0 {
-      self.player_inventory.remove(&item);
+  // takes the ingredients from the available items, and queues intermediates for the missing ones before the order itself
+  fn plan_craft(&self, recipe: Recipe, count: u32, reserved_results: u32, available: &mut BTreeMap<Item, u32>, orders: &mut Vec<CraftingOrder>) -> Result<(), String> {
+    if !self.research.is_recipe_enabled(recipe) { return Err(format!("recipe {recipe:?} is not unlocked yet")); }
+    if GAME_CONFIG.recipes[&recipe].category != "crafting" { return Err(format!("recipe {recipe:?} of category {} can't be hand crafted", GAME_CONFIG.recipes[&recipe].category)); }
+    if !matches!(GAME_CONFIG.recipes[&recipe].results[..], [ProductConfig::Item { .. }]) { return Err(format!("recipe {recipe:?} doesn't have a single item result and can't be hand crafted")); }
+    let first_prerequisite = orders.len();
+    for ingredient in &GAME_CONFIG.recipes[&recipe].ingredients {
+      match *ingredient {
+        ProductConfig::Item { id, amount, .. } => {
+          let needed = amount * count;
+          let available_count = available.entry(id).or_insert(0);
+          let taken = needed.min(*available_count);
+          *available_count -= taken;
+          if taken < needed {
+            let intermediate = self.intermediate_recipe(id).ok_or_else(|| format!("not enough {id:?} to craft {recipe:?} x{count}: needed {needed} but found {taken}, and it can't be hand crafted"))?;
+            let (_, amount_per_craft) = crafted_item(intermediate);
+            self.plan_craft(intermediate, (needed - taken).div_ceil(amount_per_craft), needed - taken, available, orders)?;
+          }
+        },
+        ProductConfig::Fluid { .. } => return Err(format!("recipe {recipe:?} contains fluid input {ingredient:?} and can't be hand crafted")),
+      }
+    }
+    let index = self.next_crafting_index.wrapping_add(orders.len() as u16);
+    for order in &mut orders[first_prerequisite..] {
+      if order.parent.is_none() {
+        order.parent = Some(index);
+      }
+    }
+    orders.push(CraftingOrder { index, recipe, count, current_energy: OrderedFloat(0.0), parent: None, reserved_results });
+    Ok(())
+  }
+  // recipe to hand craft a missing intermediate with; unverified: which recipe the game picks when several unlocked hand crafting recipes
+  // make the item isn't ported, the one with the lowest prototype id is assumed (none of the vanilla intermediates have more than one)
+  fn intermediate_recipe(&self, item: Item) -> Option<Recipe> {
+    GAME_CONFIG.recipes.iter()
+        .filter(|(&recipe, config)| config.category == "crafting" && self.research.is_recipe_enabled(recipe))
+        .filter(|(_, config)| matches!(config.results[..], [ProductConfig::Item { id, .. }] if id == item))
+        .map(|(&recipe, _)| recipe)
+        .min()
+  }
+  // from CraftingQueue::cancelCraft (loosely), refunds the ingredients of the cancelled crafts, cancels prerequisites which are no longer
+  // needed, and cancels crafts of the parent which can no longer be supplied
+  fn cancel_crafting_order(&mut self, crafting_index: u16, count: u32) {
+    let order = self.player_crafting_queue.iter_mut().find(|order| order.index == crafting_index).unwrap_or_else(|| panic!("no crafting order with index {crafting_index}"));
+    let cancelled = count.min(order.count);
+    order.count -= cancelled;
+    let (recipe, remaining_count, parent, reserved_results) = (order.recipe, order.count, order.parent, order.reserved_results);
+    for ingredient in &GAME_CONFIG.recipes[&recipe].ingredients {
+      let &ProductConfig::Item { id, amount, .. } = ingredient else { continue };
+      let mut refund = amount * cancelled;
+      let prerequisite = self.player_crafting_queue.iter_mut().find(|order| order.parent == Some(crafting_index) && crafted_item(order.recipe).0 == id);
+      if let Some(prerequisite) = prerequisite {
+        let released = refund.min(prerequisite.reserved_results);
+        prerequisite.reserved_results -= released;
+        refund -= released;
+        let surplus = prerequisite.count.saturating_sub(prerequisite.reserved_results.div_ceil(crafted_item(prerequisite.recipe).1));
+        let prerequisite_index = prerequisite.index;
+        if surplus > 0 {
+          self.cancel_crafting_order(prerequisite_index, surplus);
+        }
+      }
+      if refund > 0 {
+        self.add_to_inventory(id, refund);
+      }
+    }
+    if let Some(parent) = parent {
+      let (item, amount_per_craft) = crafted_item(recipe);
+      let shortfall = reserved_results.saturating_sub(remaining_count * amount_per_craft);
+      if shortfall > 0 {
+        let parent_recipe = self.player_crafting_queue.iter().find(|order| order.index == parent).expect("prerequisite without parent order").recipe;
+        let amount_per_parent_craft = GAME_CONFIG.recipes[&parent_recipe].ingredients.iter().find_map(|ingredient| match *ingredient {
+          ProductConfig::Item { id, amount, .. } if id == item => Some(amount),
+          _ => None,
+        }).expect("prerequisite crafts no ingredient of its parent");
+        self.cancel_crafting_order(parent, shortfall.div_ceil(amount_per_parent_craft));
+      }
     }
+    self.player_crafting_queue.retain(|order| order.count > 0);
+  }
+  fn remove_from_inventory(&mut self, item: Item, count: u32) {
+    self.player_inventory.remove(item, count);
   }
   fn add_to_inventory(&mut self, item: Item, count: u32) {
-    *self.player_inventory.entry(item).or_insert(0) += count;
-
-    assert!(self.inventory_slots_used() <= GAME_CONFIG.player_inventory_size, "inventory overflow after adding {item:?} x{count}");
+    self.player_inventory.add(item, count);
   }
-  fn inventory_slots_used(&self) -> u32 {
-    self.player_inventory.iter().map(|(item, count)| count.div_ceil(GAME_CONFIG.items[item].stack_size)).sum()
+  // from Character::mineEntity (loosely), mined items which don't fit into the inventory are spilled on the ground at the player
+  fn add_mined_to_inventory(&mut self, item: Item, count: u32) {
+    let inserted = self.player_inventory.insert(item, count);
+    for _ in inserted..count {
+      self.items_on_ground.push((self.player_position, item));
+    }
+  }
+  // the results of a craft which aren't handed to its parent order have to fit into the inventory
+  fn has_room_for_craft_results(&self, order: &CraftingOrder) -> bool {
+    let mut reserved_results = order.reserved_results;
+    GAME_CONFIG.recipes[&order.recipe].results.iter().all(|result| match *result {
+      ProductConfig::Item { id, amount, .. } => {
+        let reserved = amount.min(reserved_results);
+        reserved_results -= reserved;
+        amount - reserved <= self.player_inventory.room_for(id)
+      },
+      ProductConfig::Fluid { .. } => true,
+    })
   }
   pub fn build_stone_furnace(&mut self, position: TilePosition) {
+    self.record(move |state| state.build_stone_furnace(position));
     self.stone_furnaces.insert(position, StoneFurnace::new());
     self.build(Item::StoneFurnace, position.top_left_map_position(), Direction::North);
   }
+  /// Builds a burner miner on an unlimited iron ore tile, unless there are resources below it already.
   pub fn build_iron_miner(&mut self, position: TilePosition, direction: Direction) {
-    self.iron_miners.insert(position, BurnerMiner::new());
+    self.record(move |state| state.build_iron_miner(position, direction));
+    if !miner_area(position).iter().any(|p| self.resources.contains_key(p)) {
+      self.insert_resource(Entity::IronOre, position, None);
+    }
+    self.place_burner_miner(position, direction);
+  }
+  #[allow(dead_code)]
+  pub fn build_burner_miner(&mut self, position: TilePosition, direction: Direction) {
+    self.record(move |state| state.build_burner_miner(position, direction));
+    self.place_burner_miner(position, direction);
+  }
+  fn place_burner_miner(&mut self, position: TilePosition, direction: Direction) {
+    self.burner_miners.insert(position, BurnerMiner::new(direction));
     self.build(Item::BurnerMiningDrill, position.top_left_map_position(), direction);
   }
+  pub fn build_chest(&mut self, item: Item, position: TilePosition) {
+    self.record(move |state| state.build_chest(item, position));
+    self.chests.insert(position, Chest::new(Entity::from_name(item.name())));
+    self.build(item, position.center_map_position(), Direction::North);
+  }
+  pub fn build_electric_miner(&mut self, position: TilePosition, direction: Direction) {
+    self.record(move |state| state.build_electric_miner(position, direction));
+    self.build_entity(Item::ElectricMiningDrill, position.center_map_position(), direction);
+  }
+  pub fn build_offshore_pump(&mut self, position: TilePosition, direction: Direction) {
+    self.record(move |state| state.build_offshore_pump(position, direction));
+    self.build_entity(Item::OffshorePump, position.center_map_position(), direction);
+  }
+  /// Builds a boiler centered on the top edge of the given tile when facing north or south, or on its left edge when facing east or west.
+  pub fn build_boiler(&mut self, position: TilePosition, direction: Direction) {
+    self.record(move |state| state.build_boiler(position, direction));
+    let offset = if matches!(direction, Direction::North | Direction::South) { Vector::new(0.5, 0.0) } else { Vector::new(0.0, 0.5) };
+    self.build_entity(Item::Boiler, position.top_left_map_position() + offset, direction);
+  }
+  pub fn build_steam_engine(&mut self, position: TilePosition, direction: Direction) {
+    self.record(move |state| state.build_steam_engine(position, direction));
+    self.build_entity(Item::SteamEngine, position.center_map_position(), direction);
+  }
+  pub fn build_pipe(&mut self, position: TilePosition) {
+    self.record(move |state| state.build_pipe(position));
+    self.build_entity(Item::Pipe, position.center_map_position(), Direction::North);
+  }
+  pub fn build_electric_pole(&mut self, item: Item, position: TilePosition) {
+    self.record(move |state| state.build_electric_pole(item, position));
+    self.build_entity(item, position.center_map_position(), Direction::North);
+  }
+  pub fn build_assembling_machine(&mut self, item: Item, position: TilePosition) {
+    self.record(move |state| state.build_assembling_machine(item, position));
+    self.build_entity(item, position.center_map_position(), Direction::North);
+  }
+  pub fn build_lab(&mut self, position: TilePosition) {
+    self.record(move |state| state.build_lab(position));
+    self.build_entity(Item::Lab, position.center_map_position(), Direction::North);
+  }
+  /// Builds a transport belt moving items in the given direction.
+  pub fn build_transport_belt(&mut self, item: Item, position: TilePosition, direction: Direction) {
+    self.record(move |state| state.build_transport_belt(item, position, direction));
+    self.build_entity(item, position.center_map_position(), direction);
+  }
+  /// Builds an inserter picking up from the neighbouring tile in the given direction, and dropping on the opposite side.
+  pub fn build_inserter(&mut self, item: Item, position: TilePosition, direction: Direction) {
+    self.record(move |state| state.build_inserter(item, position, direction));
+    self.build_entity(item, position.center_map_position(), direction);
+  }
+  fn build_entity(&mut self, item: Item, position: MapPosition, direction: Direction) {
+    self.place_entity(item, position, direction).unwrap_or_else(|e| panic!("{e}"));
+    self.build(item, position, direction);
+  }
   fn build(&mut self, item: Item, position: MapPosition, direction: Direction) {
     self.remove_from_inventory(item, 1);
     self.add_input_action(InputActionData::SetFilter(SetFilterParameters { target: ItemStackTargetSpecification::from_quick_bar(0, 0), filter: item, })); // Configure quickbar slot
@@ -380,39 +1036,114 @@ impl GameState {
     self.add_input_action(InputActionData::ClearCursor); // Clear cursor
     self.add_input_action(InputActionData::QuickBarSetSlot(QuickBarSetSlotParameters { target_quick_bar_slot: 0, item_to_use: ItemStackTargetSpecification::from_nothing(), currently_selected_quick_bar_slot: 65535 })); // Clear quickbar slot
   }
-  pub fn add_fuel_to_iron_miner(&mut self, item: Item, amount: u32, position: TilePosition) {
-    self.drop_item_at(item, amount, position.top_left_map_position());
-    if let Some((i, a)) = &mut self.iron_miners.get_mut(&position).unwrap_or_else(|| panic!("burner at position {position:?} not found")).energy_source.fuel_slot {
-      assert!(*i == item, "burner at {position:?} already has incompatible fuel {i:?}, can't add {item:?}");
-      *a += amount;
-    } else {
-      self.iron_miners.get_mut(&position).unwrap().energy_source.fuel_slot = Some((item, amount));
-    }
+  pub fn add_fuel_to_burner_miner(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.record(move |state| state.add_fuel_to_burner_miner(item, amount, position));
+    assert!(self.burner_miners.contains_key(&position), "burner at position {position:?} not found");
+    self.drop_items(item, amount, position.top_left_map_position());
+    let inserted = self.insert_into_entity(Some(PlayerSelectedEntity::BurnerMiner(position)), Some(1), item, amount);
+    assert!(inserted == amount, "burner at {position:?} only takes {inserted} of {amount} {item:?} as fuel");
   }
   pub fn add_fuel_to_stone_furnace(&mut self, item: Item, amount: u32, position: TilePosition) {
-    self.drop_item_at(item, amount, position.top_left_map_position());
-    if let Some((i, a)) = &mut self.stone_furnaces.get_mut(&position).unwrap_or_else(|| panic!("furnace at position {position:?} not found")).energy_source.fuel_slot {
-      assert!(*i == item, "furnace at {position:?} already has incompatible fuel {i:?}, can't add {item:?}");
-      *a += amount;
-    } else {
-      self.stone_furnaces.get_mut(&position).unwrap().energy_source.fuel_slot = Some((item, amount));
-    }
+    self.record(move |state| state.add_fuel_to_stone_furnace(item, amount, position));
+    assert!(self.stone_furnaces.contains_key(&position), "furnace at position {position:?} not found");
+    self.drop_items(item, amount, position.top_left_map_position());
+    let inserted = self.insert_into_entity(Some(PlayerSelectedEntity::StoneFurnace(position)), Some(1), item, amount);
+    assert!(inserted == amount, "furnace at {position:?} only takes {inserted} of {amount} {item:?} as fuel");
+  }
+  pub fn add_fuel_to_boiler(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.record(move |state| state.add_fuel_to_boiler(item, amount, position));
+    let boiler_position = self.boilers.get(&position).unwrap_or_else(|| panic!("boiler at position {position:?} not found")).position;
+    self.drop_items(item, amount, boiler_position);
+    let inserted = self.insert_into_entity(Some(PlayerSelectedEntity::Boiler(position)), Some(1), item, amount);
+    assert!(inserted == amount, "boiler at {position:?} only takes {inserted} of {amount} {item:?} as fuel");
+  }
+  pub fn add_fuel_to_burner_inserter(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.record(move |state| state.add_fuel_to_burner_inserter(item, amount, position));
+    assert!(self.inserters.contains_key(&position), "inserter at position {position:?} not found");
+    self.drop_items(item, amount, position.center_map_position());
+    let inserted = self.insert_into_entity(Some(PlayerSelectedEntity::Inserter(position)), Some(1), item, amount);
+    assert!(inserted == amount, "inserter at {position:?} only takes {inserted} of {amount} {item:?} as fuel");
   }
   pub fn add_input_to_stone_furnace(&mut self, item: Item, amount: u32, position: TilePosition) {
-    self.drop_item_at(item, amount, position.top_left_map_position());
-    if let Some((i, a)) = &mut self.stone_furnaces.get_mut(&position).unwrap_or_else(|| panic!("furnace at position {position:?} not found")).input_slot {
-      assert!(*i == item, "furnace at {position:?} already has incompatible input {i:?}, can't add {item:?}");
-      *a += amount;
-    } else {
-      self.stone_furnaces.get_mut(&position).unwrap().input_slot = Some((item, amount));
-    }
+    self.record(move |state| state.add_input_to_stone_furnace(item, amount, position));
+    assert!(self.stone_furnaces.contains_key(&position), "furnace at position {position:?} not found");
+    self.drop_items(item, amount, position.top_left_map_position());
+    let inserted = self.insert_into_entity(Some(PlayerSelectedEntity::StoneFurnace(position)), Some(2), item, amount);
+    assert!(inserted == amount, "furnace at {position:?} only takes {inserted} of {amount} {item:?} as input");
+  }
+  pub fn add_input_to_assembling_machine(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.record(move |state| state.add_input_to_assembling_machine(item, amount, position));
+    assert!(self.assembling_machines.contains_key(&position), "assembling machine at position {position:?} not found");
+    self.drop_items(item, amount, position.center_map_position());
+    let inserted = self.insert_into_entity(Some(PlayerSelectedEntity::AssemblingMachine(position)), Some(2), item, amount);
+    assert!(inserted == amount, "assembling machine at {position:?} only takes {inserted} of {amount} {item:?} as input");
+  }
+  pub fn add_science_packs_to_lab(&mut self, item: Item, amount: u32, position: TilePosition) {
+    self.record(move |state| state.add_science_packs_to_lab(item, amount, position));
+    assert!(self.labs.contains_key(&position), "lab at position {position:?} not found");
+    self.drop_items(item, amount, position.center_map_position());
+    let inserted = self.insert_into_entity(Some(PlayerSelectedEntity::Lab(position)), Some(2), item, amount);
+    assert!(inserted == amount, "lab at {position:?} only takes {inserted} of {amount} {item:?}");
+  }
+  /// Sets the recipe of an assembling machine through its GUI, any contents go back into the inventory.
+  pub fn set_assembling_machine_recipe(&mut self, position: TilePosition, recipe: Recipe) {
+    self.record(move |state| state.set_assembling_machine_recipe(position, recipe));
+    assert!(self.assembling_machines.contains_key(&position), "assembling machine at position {position:?} not found");
+    self.setup_assembling_machine(Some(PlayerSelectedEntity::AssemblingMachine(position)), Some(recipe)).unwrap_or_else(|e| panic!("{e}"));
+
+    self.add_input_action(InputActionData::SelectedEntityChanged(position.center_map_position())); // Select entity
+    self.add_input_action(InputActionData::OpenGui); // Open entity GUI
+    self.add_input_action(InputActionData::SetupAssemblingMachine(recipe)); // Select recipe
+    self.add_input_action(InputActionData::CloseGui); // Close entity GUI
+    self.add_input_action(InputActionData::SelectedEntityCleared); // Clear selection
+  }
+  pub fn start_research(&mut self, technology: Technology) {
+    self.record(move |state| state.start_research(technology));
+    self.research.start(technology).unwrap_or_else(|e| panic!("{e}"));
+    self.add_input_action(InputActionData::StartResearch(technology));
+  }
+  /// Takes the products out of a furnace or assembling machine or the contents of a chest, returns the items taken.
+  pub fn take_contents(&mut self, position: MapPosition) -> Vec<(Item, u32)> {
+    self.record(move |state| { state.take_contents(position); });
+    let entity = self.entity_at(position);
+    assert!(matches!(entity, Some(PlayerSelectedEntity::StoneFurnace(_) | PlayerSelectedEntity::AssemblingMachine(_) | PlayerSelectedEntity::Chest(_))), "no furnace, assembling machine or chest at {position:?}");
+    let items = self.transfer_contents_to_inventory(entity).unwrap_or_else(|e| panic!("{e}"));
+
+    self.add_input_action(InputActionData::SelectedEntityChanged(position)); // Select entity
+    self.add_input_action(InputActionData::FastEntityTransfer(TransferDirection::Out)); // Take items
+    self.add_input_action(InputActionData::SelectedEntityCleared); // Clear selection
+    items
+  }
+  /// Moves the first stack of the item in the inventory into the entity at the position through its GUI, returns the number of items moved.
+  pub fn transfer_stack_to_entity(&mut self, item: Item, position: MapPosition) -> u32 {
+    self.record(move |state| { state.transfer_stack_to_entity(item, position); });
+    let entity = self.entity_at(position);
+    let slot_index = self.player_inventory.find_slot(item).unwrap_or_else(|| panic!("no {item:?} in inventory to transfer"));
+    let (_, count) = self.player_inventory.slot(slot_index).unwrap();
+    let inserted = self.insert_into_entity(entity, None, item, count);
+    self.remove_from_inventory(item, inserted);
+
+    self.add_input_action(InputActionData::SelectedEntityChanged(position)); // Select entity
+    self.add_input_action(InputActionData::OpenGui); // Open entity GUI
+    self.add_input_action(InputActionData::StackTransfer(ItemStackTargetSpecification::from_player_inventory(slot_index))); // Shift-click the inventory slot
+    self.add_input_action(InputActionData::CloseGui); // Close entity GUI
+    self.add_input_action(InputActionData::SelectedEntityCleared); // Clear selection
+    inserted
   }
+  #[allow(dead_code)]
   pub fn drop_item_at(&mut self, item: Item, amount: u32, position: MapPosition) {
+    self.record(move |state| state.drop_item_at(item, amount, position));
+    self.drop_items(item, amount, position);
+  }
+  fn drop_items(&mut self, item: Item, amount: u32, position: MapPosition) {
     self.remove_from_inventory(item, amount);
     self.add_input_action(InputActionData::SetFilter(SetFilterParameters { target: ItemStackTargetSpecification::from_quick_bar(0, 0), filter: item, })); // Configure quickbar slot
     self.add_input_action(InputActionData::QuickBarPickSlot(QuickBarPickSlotParameters { location: 0, pick_ghost_cursor: false, cursor_split: false, })); // Select quickbar into cursor
     self.add_input_action(InputActionData::SelectedEntityChanged(position)); // Select entity
-    for _ in 0..amount {
+    for dropped in 0..amount {
+      if dropped > 0 && dropped % GAME_CONFIG.items[&item].stack_size == 0 {
+        self.add_input_action(InputActionData::QuickBarPickSlot(QuickBarPickSlotParameters { location: 0, pick_ghost_cursor: false, cursor_split: false, })); // Pick the next stack into the emptied cursor
+      }
       self.add_input_action(InputActionData::DropItem(position));
     }
     self.add_input_action(InputActionData::SelectedEntityCleared); // Clear selection
@@ -421,6 +1152,363 @@ impl GameState {
   }
 
 
+  /// Runs the actions of a replay against a simulation of the save it was recorded on.
+  pub fn simulate_replay(map_data: &MapData, script_data: &ScriptData, replay_data: &ReplayData) -> Result<Self, SimulationError> {
+    let mut game_state = GameState::from_map(map_data, script_data);
+    game_state.run_input_actions(&replay_data.actions)?;
+    Ok(game_state)
+  }
+  /// Applies input actions at their tick, simulating all ticks in between. Stops at the first action which can't be simulated.
+  pub fn run_input_actions(&mut self, actions: &[InputAction]) -> Result<(), SimulationError> {
+    for action in actions {
+      if action.update_tick() < self.tick {
+        return Err(SimulationError { tick: self.tick, action: Box::new(action.clone()), reason: "the action lies before the current tick".to_owned() });
+      }
+      while self.tick < action.update_tick() {
+        self.tick();
+      }
+      self.apply(action)?;
+    }
+    Ok(())
+  }
+  /// Interprets an input action of the player, as done at the start of the update of its tick.
+  ///
+  /// Actions of other players and actions which don't affect the simulated state are ignored. Actions which the simulation can't
+  /// reproduce fail without changing the state.
+  pub fn apply(&mut self, action: &InputAction) -> Result<(), SimulationError> {
+    if action.player_index() != PID { return Ok(()); }
+    self.apply_action(action.action()).map_err(|reason| SimulationError { tick: self.tick, action: Box::new(action.clone()), reason })?;
+    let recorded_action = action.clone();
+    self.record(move |state| state.apply(&recorded_action).unwrap_or_else(|e| panic!("{e}")));
+    Ok(())
+  }
+  fn apply_action(&mut self, action: &InputActionData) -> Result<(), String> {
+    match action {
+      InputActionData::StartWalking(direction) => self.player_walking_direction = *direction,
+      InputActionData::StopWalking => self.player_walking_direction = Direction::None,
+      InputActionData::SelectedEntityChanged(position) => self.select_entity_at(*position),
+      InputActionData::SelectedEntityChangedVeryClose(data) => self.select_entity_at(data.position(self.player_position)),
+      InputActionData::SelectedEntityChangedVeryClosePrecise(data) => self.select_entity_at(data.position(self.player_position)),
+      InputActionData::SelectedEntityChangedRelative(data) => self.select_entity_at(data.position(self.player_position)),
+      InputActionData::SelectedEntityCleared => {
+        self.player_selected_entity = None;
+        self.player_mining_progress = None;
+      },
+      InputActionData::BeginMining => {
+        if let Some(entity) = self.player_selected_entity.filter(|e| e.is_minable()) {
+          if self.player_mining_progress.is_none() {
+            self.player_mining_progress = Some((OrderedFloat(0.0), OrderedFloat(self.mining_time(entity)), None));
+          }
+        }
+      },
+      InputActionData::StopMining => self.player_mining_progress = None,
+      InputActionData::SetAutosortInventory(autosort) => self.player_inventory.set_autosort(*autosort),
+      InputActionData::Craft(CraftData { recipe, count }) => { self.queue_craft(*recipe, *count)?; },
+      InputActionData::CancelCraft(CancelCraftOrder { crafting_index, count }) => {
+        if !self.player_crafting_queue.iter().any(|order| order.index == *crafting_index) {
+          return Err(format!("no crafting order with index {crafting_index}"));
+        }
+        self.cancel_crafting_order(*crafting_index, *count);
+      },
+      InputActionData::SetFilter(SetFilterParameters { target, filter }) if target.source() == SlotSource::PlayerQuickBar => {
+        self.player_inventory.set_quick_bar_filter(target.slot_index(), Some(*filter));
+      },
+      InputActionData::QuickBarSetSlot(QuickBarSetSlotParameters { target_quick_bar_slot, item_to_use, .. }) => {
+        let item = match item_to_use.source() {
+          SlotSource::Empty => None,
+          SlotSource::PlayerCursor => self.player_inventory.cursor().map(|(item, _)| item),
+          SlotSource::PlayerInventory => self.player_inventory_slot(item_to_use.slot_index())?.map(|(item, _)| item),
+          source => return Err(format!("setting quick bar slot from {source:?} is not supported")),
+        };
+        if item.is_some() || item_to_use.source() == SlotSource::Empty {
+          self.player_inventory.set_quick_bar_filter(*target_quick_bar_slot, item);
+        }
+      },
+      InputActionData::QuickBarPickSlot(QuickBarPickSlotParameters { location, cursor_split, .. }) => self.player_inventory.pick_quick_bar_slot(*location, *cursor_split),
+      InputActionData::ClearCursor => self.player_inventory.clear_cursor(),
+      InputActionData::Build(BuildParameters { position, direction, .. }) => self.build_from_cursor(*position, *direction)?,
+      InputActionData::DropItem(position) => {
+        if self.player_inventory.cursor().is_none() { return Err("dropping an item with an empty cursor".to_owned()); }
+        let item = self.player_inventory.take_from_cursor(1);
+        let target = self.player_selected_entity.filter(|_| self.entity_at(*position) == self.player_selected_entity);
+        if self.insert_into_entity(target, None, item, 1) == 0 {
+          self.items_on_ground.push((*position, item));
+        }
+      },
+      InputActionData::OpenGui => self.player_opened_entity = self.player_selected_entity,
+      InputActionData::CloseGui => self.player_opened_entity = None,
+      InputActionData::CursorTransfer(target) if target.source() == SlotSource::PlayerInventory => {
+        self.player_inventory_slot(target.slot_index())?;
+        self.player_inventory.cursor_transfer(target.slot_index());
+      },
+      InputActionData::CursorSplit(target) if target.source() == SlotSource::PlayerInventory => {
+        self.player_inventory_slot(target.slot_index())?;
+        self.player_inventory.cursor_split(target.slot_index());
+      },
+      InputActionData::CursorSplit(target) | InputActionData::CursorTransfer(target) if target.source() == SlotSource::EntityInventory => {
+        let split = matches!(action, InputActionData::CursorSplit(_));
+        match self.player_inventory.cursor() {
+          // the cursor puts down its stack, or a single item when splitting
+          Some((item, count)) => {
+            let inserted = self.insert_into_entity(self.player_opened_entity, Some(target.inventory_index()), item, if split { 1 } else { count });
+            self.player_inventory.take_from_cursor(inserted);
+          },
+          // the empty cursor picks up the stack, or half of it when splitting
+          None => {
+            let (entity, inventory_index, slot_index) = (self.player_opened_entity, target.inventory_index(), target.slot_index());
+            if let Some((item, count)) = self.with_entity_slot(entity, inventory_index, slot_index, |slot| *slot)? {
+              let count = if split { count.div_ceil(2) } else { count };
+              self.with_entity_slot(entity, inventory_index, slot_index, |slot| take_from_slot(slot, count))?;
+              self.player_inventory.put_into_cursor(item, count);
+            }
+          },
+        }
+      },
+      InputActionData::StackTransfer(target) | InputActionData::InventoryTransfer(target) if target.source() == SlotSource::PlayerInventory => {
+        if let Some((item, count)) = self.player_inventory_slot(target.slot_index())? {
+          // moving the whole inventory moves all items of the kind
+          let count = if matches!(action, InputActionData::InventoryTransfer(_)) { self.player_inventory.count(item) } else { count };
+          let inserted = self.insert_into_entity(self.player_opened_entity, None, item, count);
+          self.remove_from_inventory(item, inserted);
+        }
+      },
+      InputActionData::StackTransfer(target) | InputActionData::InventoryTransfer(target) if target.source() == SlotSource::EntityInventory => {
+        let (entity, inventory_index, slot_index) = (self.player_opened_entity, target.inventory_index(), target.slot_index());
+        if let Some((item, _)) = self.with_entity_slot(entity, inventory_index, slot_index, |slot| *slot)? {
+          if matches!(action, InputActionData::InventoryTransfer(_)) {
+            self.transfer_entity_inventory_to_inventory(entity, inventory_index, Some(item))?;
+          } else {
+            self.transfer_entity_slot_to_inventory(entity, inventory_index, slot_index)?;
+          }
+        }
+      },
+      InputActionData::SetupAssemblingMachine(recipe) => self.setup_assembling_machine(self.player_opened_entity, Some(*recipe))?,
+      InputActionData::ResetAssemblingMachine => self.setup_assembling_machine(self.player_opened_entity, None)?,
+      InputActionData::StartResearch(technology) => self.research.start(*technology)?,
+      InputActionData::CancelResearch(TechnologyWithCount { technology, .. }) => self.research.cancel(*technology),
+      InputActionData::FastEntityTransfer(TransferDirection::Out) => { self.transfer_contents_to_inventory(self.player_selected_entity)?; },
+      InputActionData::FastEntityTransfer(TransferDirection::In) => if let Some((item, count)) = self.player_inventory.cursor() {
+        let inserted = self.insert_into_entity(self.player_selected_entity, None, item, count);
+        self.player_inventory.take_from_cursor(inserted);
+      },
+      _ => {},
+    }
+    Ok(())
+  }
+  fn player_inventory_slot(&self, index: u16) -> Result<Option<(Item, u32)>, String> {
+    if u32::from(index) >= GAME_CONFIG.player_inventory_size { return Err(format!("player inventory has no slot {index}")); }
+    Ok(self.player_inventory.slot(index))
+  }
+  fn setup_assembling_machine(&mut self, entity: Option<PlayerSelectedEntity>, recipe: Option<Recipe>) -> Result<(), String> {
+    let Some(PlayerSelectedEntity::AssemblingMachine(position)) = entity else { return Err(format!("setting recipe {recipe:?} without an opened assembling machine, but {entity:?}")) };
+    if let Some(recipe) = recipe {
+      if !self.research.is_recipe_enabled(recipe) { return Err(format!("recipe {recipe:?} is not unlocked yet")); }
+      if !self.assembling_machines[&position].can_craft(recipe) { return Err(format!("assembling machine at {position:?} can't craft {recipe:?}")); }
+    }
+    for (item, count) in self.assembling_machines.get_mut(&position).unwrap().set_recipe(recipe) {
+      self.add_to_inventory(item, count);
+    }
+    Ok(())
+  }
+  // from EntityWithOwner::fastTransferOut, crafting machines only give up their products
+  fn transfer_contents_to_inventory(&mut self, entity: Option<PlayerSelectedEntity>) -> Result<Vec<(Item, u32)>, String> {
+    match entity {
+      Some(PlayerSelectedEntity::StoneFurnace(_) | PlayerSelectedEntity::AssemblingMachine(_)) => self.transfer_entity_inventory_to_inventory(entity, 3, None),
+      Some(PlayerSelectedEntity::Chest(_)) => self.transfer_entity_inventory_to_inventory(entity, 1, None),
+      _ => Ok(vec![]),
+    }
+  }
+  // moves the stacks of an entity inventory, or only those of the given item, into the player inventory as far as they fit
+  fn transfer_entity_inventory_to_inventory(&mut self, entity: Option<PlayerSelectedEntity>, inventory_index: u8, filter: Option<Item>) -> Result<Vec<(Item, u32)>, String> {
+    let mut items = vec![];
+    for slot_index in 0..self.entity_inventory_size(entity, inventory_index) {
+      let matches_filter = match filter {
+        Some(filter) => self.with_entity_slot(entity, inventory_index, slot_index, |slot| slot.is_some_and(|(item, _)| item == filter))?,
+        None => true,
+      };
+      if matches_filter {
+        items.extend(self.transfer_entity_slot_to_inventory(entity, inventory_index, slot_index)?);
+      }
+    }
+    Ok(items)
+  }
+  // moves as much of the stack in an entity inventory slot into the player inventory as fits, the rest stays in the entity
+  fn transfer_entity_slot_to_inventory(&mut self, entity: Option<PlayerSelectedEntity>, inventory_index: u8, slot_index: u16) -> Result<Option<(Item, u32)>, String> {
+    let Some((item, count)) = self.with_entity_slot(entity, inventory_index, slot_index, |slot| *slot)? else { return Ok(None) };
+    let count = count.min(self.player_inventory.room_for(item));
+    if count == 0 { return Ok(None); }
+    self.with_entity_slot(entity, inventory_index, slot_index, |slot| take_from_slot(slot, count))?;
+    self.add_to_inventory(item, count);
+    Ok(Some((item, count)))
+  }
+  fn entity_inventory_size(&self, entity: Option<PlayerSelectedEntity>, inventory_index: u8) -> u16 {
+    match (entity, inventory_index) {
+      (Some(PlayerSelectedEntity::Chest(position)), 1) => self.chests[&position].slots.len() as u16,
+      (Some(PlayerSelectedEntity::AssemblingMachine(position)), 2 | 3) => self.assembling_machines[&position].slot_count(inventory_index == 3) as u16,
+      _ => 1,
+    }
+  }
+  // runs f on a slot of an entity inventory (1: fuel or chest, 2: furnace source or machine input, 3: output), fails for slots which aren't simulated
+  fn with_entity_slot<R>(&mut self, entity: Option<PlayerSelectedEntity>, inventory_index: u8, slot_index: u16, f: impl FnOnce(&mut Option<(Item, u32)>) -> R) -> Result<R, String> {
+    let slot = match (entity, inventory_index, slot_index) {
+      (Some(PlayerSelectedEntity::Chest(position)), 1, _) => self.chests.get_mut(&position).unwrap().slots.get_mut(slot_index as usize),
+      (Some(PlayerSelectedEntity::StoneFurnace(position)), 1, 0) => Some(&mut self.stone_furnaces.get_mut(&position).unwrap().energy_source.fuel_slot),
+      (Some(PlayerSelectedEntity::StoneFurnace(position)), 2, 0) => Some(&mut self.stone_furnaces.get_mut(&position).unwrap().input_slot),
+      (Some(PlayerSelectedEntity::StoneFurnace(position)), 3, 0) => Some(&mut self.stone_furnaces.get_mut(&position).unwrap().output_slot),
+      (Some(PlayerSelectedEntity::BurnerMiner(position)), 1, 0) => Some(&mut self.burner_miners.get_mut(&position).unwrap().energy_source.fuel_slot),
+      (Some(PlayerSelectedEntity::Boiler(position)), 1, 0) => Some(&mut self.boilers.get_mut(&position).unwrap().energy_source.fuel_slot),
+      (Some(PlayerSelectedEntity::Inserter(position)), 1, 0) => match &mut self.inserters.get_mut(&position).unwrap().energy_source {
+        InserterEnergySource::Burner(burner) => Some(&mut burner.fuel_slot),
+        InserterEnergySource::Electric(_) => None,
+      },
+      (Some(PlayerSelectedEntity::AssemblingMachine(position)), 2 | 3, _) => {
+        let machine = self.assembling_machines.get_mut(&position).unwrap();
+        if (slot_index as usize) < machine.slot_count(inventory_index == 3) {
+          return Ok(machine.with_slot(inventory_index == 3, slot_index as usize, f));
+        }
+        None
+      },
+      _ => None,
+    };
+    slot.map(f).ok_or_else(|| format!("slot {slot_index} of inventory {inventory_index} of {entity:?} is not supported"))
+  }
+  fn mining_time(&self, entity: PlayerSelectedEntity) -> f64 {
+    match entity {
+      PlayerSelectedEntity::Tree(index) => GAME_CONFIG.minables[&self.trees[index].0].mining_time,
+      PlayerSelectedEntity::Rock(index) => GAME_CONFIG.minables[&self.rocks[index].0].mining_time,
+      PlayerSelectedEntity::Resource(position) => GAME_CONFIG.minables[&self.resources[&position].resource].mining_time,
+      e => panic!("{e:?} is not minable"),
+    }
+  }
+  // takes one unit from a resource, which is gone once its amount is used up; returns the mined item
+  fn take_resource(&mut self, position: TilePosition) -> Item {
+    let tile = self.resources.get_mut(&position).unwrap();
+    tile.num_mined += 1;
+    let resource = tile.resource;
+    if let Some(amount) = &mut tile.amount {
+      *amount -= 1;
+      if *amount == 0 {
+        self.resources.remove(&position);
+        // the player stops mining a resource depleted by a drill as well
+        if self.player_selected_entity == Some(PlayerSelectedEntity::Resource(position)) {
+          self.player_selected_entity = None;
+          self.player_mining_progress = None;
+        }
+      }
+    }
+    resource_product(resource)
+  }
+  // entities take precedence over resources below them
+  fn entity_at(&self, position: MapPosition) -> Option<PlayerSelectedEntity> {
+    let covers = |&entity_position: &TilePosition| {  // 2x2 entities, centered on the top left corner of their position tile
+      let center = entity_position.top_left_map_position();
+      (position.x - center.x).0.abs() < 0x100 && (position.y - center.y).0.abs() < 0x100
+    };
+    if let Some(&position) = self.stone_furnaces.keys().find(|p| covers(p)) { return Some(PlayerSelectedEntity::StoneFurnace(position)); }
+    if let Some(&position) = self.burner_miners.keys().find(|p| covers(p)) { return Some(PlayerSelectedEntity::BurnerMiner(position)); }
+    if self.chests.contains_key(&position.to_tile_position()) { return Some(PlayerSelectedEntity::Chest(position.to_tile_position())); }
+    if let Some(&position) = self.electric_miners.keys().find(|&&p| ElectricMiner::collision_box(p).collide_point(&position)) { return Some(PlayerSelectedEntity::ElectricMiner(position)); }
+    if let Some((&position, _)) = self.offshore_pumps.iter().find(|(_, pump)| pump.collision_box().collide_point(&position)) { return Some(PlayerSelectedEntity::OffshorePump(position)); }
+    if let Some((&position, _)) = self.boilers.iter().find(|(_, boiler)| boiler.collision_box().collide_point(&position)) { return Some(PlayerSelectedEntity::Boiler(position)); }
+    if let Some((&position, _)) = self.steam_engines.iter().find(|(_, engine)| engine.collision_box().collide_point(&position)) { return Some(PlayerSelectedEntity::SteamEngine(position)); }
+    if self.pipes.contains_key(&position.to_tile_position()) { return Some(PlayerSelectedEntity::Pipe(position.to_tile_position())); }
+    if self.electric_poles.contains_key(&position.to_tile_position()) { return Some(PlayerSelectedEntity::ElectricPole(position.to_tile_position())); }
+    if let Some((&position, _)) = self.assembling_machines.iter().find(|(&p, machine)| machine.collision_box(p).collide_point(&position)) { return Some(PlayerSelectedEntity::AssemblingMachine(position)); }
+    if let Some((&position, _)) = self.labs.iter().find(|(&p, lab)| lab.collision_box(p).collide_point(&position)) { return Some(PlayerSelectedEntity::Lab(position)); }
+    if self.transport_belts.contains_key(&position.to_tile_position()) { return Some(PlayerSelectedEntity::TransportBelt(position.to_tile_position())); }
+    if self.inserters.contains_key(&position.to_tile_position()) { return Some(PlayerSelectedEntity::Inserter(position.to_tile_position())); }
+    if let Some(index) = self.pick_tree_at_position(position) { return Some(PlayerSelectedEntity::Tree(index)); }
+    if let Some(index) = self.pick_rock_at_position(position) { return Some(PlayerSelectedEntity::Rock(index)); }
+    Some(PlayerSelectedEntity::Resource(position.to_tile_position())).filter(|_| self.resources.contains_key(&position.to_tile_position()))
+  }
+  fn select_entity_at(&mut self, position: MapPosition) {
+    let entity = self.entity_at(position);
+    if entity != self.player_selected_entity {
+      // mining restarts on the newly selected entity
+      self.player_mining_progress = match (self.player_mining_progress, entity) {
+        (Some(_), Some(entity)) if entity.is_minable() => Some((OrderedFloat(0.0), OrderedFloat(self.mining_time(entity)), None)),
+        _ => None,
+      };
+    }
+    self.player_selected_entity = entity;
+  }
+  fn build_from_cursor(&mut self, position: MapPosition, direction: Direction) -> Result<(), String> {
+    let Some((item, _)) = self.player_inventory.cursor() else { return Err("building with an empty cursor".to_owned()) };
+    self.place_entity(item, position, direction)?;
+    self.player_inventory.take_from_cursor(1);
+    self.player_inventory.refill_cursor(item);
+    Ok(())
+  }
+  // entities are keyed by the tile their position lies in
+  fn place_entity(&mut self, item: Item, position: MapPosition, direction: Direction) -> Result<(), String> {
+    if !matches!(direction, Direction::North | Direction::East | Direction::South | Direction::West) {
+      return Err(format!("building {item:?} at {position:?} facing {direction:?} is not supported"));
+    }
+    let tile = position.to_tile_position();
+    match item {
+      Item::StoneFurnace => { self.stone_furnaces.insert(tile, StoneFurnace::new()); },
+      Item::BurnerMiningDrill => { self.burner_miners.insert(tile, BurnerMiner::new(direction)); },
+      Item::WoodenChest | Item::IronChest => { self.chests.insert(tile, Chest::new(Entity::from_name(item.name()))); },
+      Item::ElectricMiningDrill => { self.electric_miners.insert(tile, ElectricMiner::new(direction)); },
+      Item::OffshorePump => { self.offshore_pumps.insert(tile, OffshorePump::new(Entity::OffshorePump, position, direction)); },
+      Item::Boiler => { self.boilers.insert(tile, Boiler::new(Entity::Boiler, position, direction)); },
+      Item::SteamEngine => { self.steam_engines.insert(tile, SteamEngine::new(Entity::SteamEngine, position, direction)); },
+      Item::Pipe => { self.pipes.insert(tile, Pipe::new()); },
+      Item::SmallElectricPole | Item::MediumElectricPole => {
+        self.electric_poles.insert(tile, ElectricPole::new(Entity::from_name(item.name())));
+        power::connect_electric_pole(&mut self.electric_poles, tile);
+      },
+      Item::AssemblingMachine1 | Item::AssemblingMachine2 => { self.assembling_machines.insert(tile, AssemblingMachine::new(Entity::from_name(item.name()))); },
+      Item::Lab => { self.labs.insert(tile, Lab::new(Entity::Lab)); },
+      Item::TransportBelt | Item::FastTransportBelt | Item::ExpressTransportBelt => { self.transport_belts.insert(tile, TransportBelt::new(Entity::from_name(item.name()), direction)); },
+      Item::BurnerInserter | Item::Inserter | Item::LongHandedInserter | Item::FastInserter | Item::StackInserter => {
+        self.inserters.insert(tile, Inserter::new(Entity::from_name(item.name()), direction));
+      },
+      _ => return Err(format!("building {item:?} at {position:?} facing {direction:?} is not supported")),
+    }
+    self.connect_fluid_boxes(item, tile);
+    Ok(())
+  }
+  // inserts up to count items into the inventory with the given index (1: fuel, 2: furnace source, machine or lab input) or wherever they fit, returns the number inserted
+  fn insert_into_entity(&mut self, entity: Option<PlayerSelectedEntity>, inventory_index: Option<u8>, item: Item, count: u32) -> u32 {
+    let is_fuel = GAME_CONFIG.fuels.contains_key(&item);
+    let slot = match entity {
+      Some(PlayerSelectedEntity::Chest(position)) => return self.chests.get_mut(&position).unwrap().insert(item, count),
+      Some(PlayerSelectedEntity::AssemblingMachine(position)) if inventory_index.unwrap_or(2) == 2 => return self.assembling_machines.get_mut(&position).unwrap().insert(item, count),
+      Some(PlayerSelectedEntity::Lab(position)) if inventory_index.unwrap_or(2) == 2 => return self.labs.get_mut(&position).unwrap().insert(item, count),
+      Some(PlayerSelectedEntity::StoneFurnace(position)) => {
+        let furnace = self.stone_furnaces.get_mut(&position).unwrap();
+        match inventory_index.unwrap_or(if is_fuel { 1 } else { 2 }) {
+          1 if is_fuel => &mut furnace.energy_source.fuel_slot,
+          2 if furnace_recipe(item, &self.research).is_some() => &mut furnace.input_slot,
+          _ => return 0,
+        }
+      },
+      Some(PlayerSelectedEntity::BurnerMiner(position)) if is_fuel && inventory_index.unwrap_or(1) == 1 => &mut self.burner_miners.get_mut(&position).unwrap().energy_source.fuel_slot,
+      Some(PlayerSelectedEntity::Boiler(position)) if is_fuel && inventory_index.unwrap_or(1) == 1 => &mut self.boilers.get_mut(&position).unwrap().energy_source.fuel_slot,
+      Some(PlayerSelectedEntity::Inserter(position)) if is_fuel && inventory_index.unwrap_or(1) == 1 => match &mut self.inserters.get_mut(&position).unwrap().energy_source {
+        InserterEnergySource::Burner(burner) => &mut burner.fuel_slot,
+        InserterEnergySource::Electric(_) => return 0,
+      },
+      _ => return 0,
+    };
+    let stack_size = GAME_CONFIG.items[&item].stack_size;
+    match slot {
+      None => {
+        let inserted = count.min(stack_size);
+        *slot = Some((item, inserted));
+        inserted
+      },
+      Some((slot_item, slot_count)) if *slot_item == item => {
+        let inserted = count.min(stack_size - *slot_count);
+        *slot_count += inserted;
+        inserted
+      },
+      Some(_) => 0,
+    }
+  }
+
+
   pub fn tick(&mut self) {
     self.tick += 1;
 
@@ -434,70 +1522,352 @@ impl GameState {
         self.player_mining_progress = Some((current, goal, count))
       } else {
         match &self.player_selected_entity {
-          &Some(PlayerSelectedEntity::DryTree(index)) => {
-            self.dry_trees.swap_remove(index);
-            self.add_to_inventory(Item::Wood, 4);
-          },
-          &Some(PlayerSelectedEntity::HugeRock(index)) => {
-            self.huge_rocks.swap_remove(index);
-            let (stone, coal) = self.huge_rock_rng.get_huge_rock_items();
-            self.add_to_inventory(Item::Stone, stone);
-            self.add_to_inventory(Item::Coal, coal);
+          &Some(PlayerSelectedEntity::Tree(index)) => {
+            let (tree, _) = self.trees.swap_remove(index);
+            for product in roll_mining_results(&mut self.entities_rng, tree) {
+              if let ProductConfig::Item { id, amount, .. } = product { self.add_mined_to_inventory(id, amount); }
+            }
           },
-          &Some(PlayerSelectedEntity::IronOre(position)) => {
-            *self.iron_ores.get_mut(&position).unwrap() += 1;
-            self.add_to_inventory(Item::IronOre, 1);
+          &Some(PlayerSelectedEntity::Rock(index)) => {
+            let (rock, _) = self.rocks.swap_remove(index);
+            for product in roll_mining_results(&mut self.entities_rng, rock) {
+              if let ProductConfig::Item { id, amount, .. } = product { self.add_mined_to_inventory(id, amount); }
+            }
           },
-          &Some(PlayerSelectedEntity::CopperOre(position)) => {
-            *self.copper_ores.get_mut(&position).unwrap() += 1;
-            self.add_to_inventory(Item::CopperOre, 1);
+          &Some(PlayerSelectedEntity::Resource(position)) => {
+            let item = self.take_resource(position);
+            self.add_mined_to_inventory(item, 1);
           },
           e => panic!("mining completed of unknown entity {e:?}")
         }
-        if count > 1 {
-          self.player_mining_progress = Some((0.0, goal, count - 1))
-        } else {
-          self.player_selected_entity = None;
-          self.player_mining_progress = None;
-          self.add_input_action(InputActionData::StopMining);
-          self.add_input_action(InputActionData::SelectedEntityCleared);
+        match count {
+          Some(count) if count > 1 && self.player_selected_entity.is_some() => self.player_mining_progress = Some((OrderedFloat(0.0), goal, Some(count - 1))),
+          Some(_) => {
+            self.player_selected_entity = None;
+            self.player_mining_progress = None;
+            self.add_input_action(InputActionData::StopMining);
+            self.add_input_action(InputActionData::SelectedEntityCleared);
+          },
+          // mining by input actions continues on resources until stopped, mined trees and rocks are gone
+          None => if matches!(self.player_selected_entity, Some(PlayerSelectedEntity::Resource(_))) {
+            self.player_mining_progress = Some((OrderedFloat(0.0), goal, None));
+          } else {
+            self.player_selected_entity = None;
+            self.player_mining_progress = None;
+          },
         }
       }
     }
 
     // Crafting update
     if let Some(mut order) = self.player_crafting_queue.pop_front() {
-      order.current_energy += 1.0 / 60.0;
-      if order.current_energy < GAME_CONFIG.recipes[&order.recipe].energy_required {
+      let energy_required = GAME_CONFIG.recipes[&order.recipe].energy_required;
+      if *order.current_energy < energy_required {
+        order.current_energy += 1.0 / 60.0;
+      }
+      if *order.current_energy < energy_required {
+        self.player_crafting_queue.push_front(order);
+      } else if !self.has_room_for_craft_results(&order) {
+        // a finished craft waits for room in the inventory
         self.player_crafting_queue.push_front(order);
       } else {
         for result in &GAME_CONFIG.recipes[&order.recipe].results {
-          match result {
-            &ProductConfig::Item { id, amount } => self.add_to_inventory(id, amount),
-            &ProductConfig::Fluid { .. } => panic!("hand-crafted recipe {:?} contains fluid result {result:?}", order.recipe),
+          match *result {
+            ProductConfig::Item { id, amount, .. } => {
+              // prerequisites hand their results to the parent order first
+              let reserved = amount.min(order.reserved_results);
+              order.reserved_results -= reserved;
+              if amount > reserved {
+                self.add_to_inventory(id, amount - reserved);
+              }
+            },
+            ProductConfig::Fluid { .. } => panic!("hand-crafted recipe {:?} contains fluid result {result:?}", order.recipe),
           }
         }
         if order.count > 1 {
           order.count -= 1;
-          order.current_energy = 0.0;
+          order.current_energy = OrderedFloat(0.0);
           self.player_crafting_queue.push_front(order);
         }
       }
     }
 
+    // Power update
+    self.fluid_update();
+    self.electric_network_update();
+
     // Furnace update
     for (_, furnace) in self.stone_furnaces.iter_mut() {
-      furnace.tick();
+      furnace.tick(&self.research);
     }
     // Miner update
-    for (_, miner) in self.iron_miners.iter_mut() {
-      miner.tick();
+    let miner_positions: Vec<_> = self.burner_miners.keys().copied().collect();
+    for position in miner_positions {
+      self.burner_miner_update(position);
+    }
+    let miner_positions: Vec<_> = self.electric_miners.keys().copied().collect();
+    for position in miner_positions {
+      self.electric_miner_update(position);
+    }
+    // Belt and inserter update
+    logistics::update_transport_belts(&mut self.transport_belts);
+    let inserter_positions: Vec<_> = self.inserters.keys().copied().collect();
+    for position in inserter_positions {
+      self.inserter_update(position);
+    }
+    // Assembling machine and lab update
+    for machine in self.assembling_machines.values_mut() {
+      machine.tick();
+    }
+    for lab in self.labs.values_mut() {
+      lab.tick(&mut self.research);
     }
 
     if self.instrumented {
       self.generate_debug_commands();
       self.generate_assert_commands();
     }
+    if self.checkpoint_interval > 0 && self.tick.is_multiple_of(self.checkpoint_interval) {
+      self.take_checkpoint();
+    }
+  }
+
+  // from MiningDrill::update
+  fn burner_miner_update(&mut self, position: TilePosition) {
+    let drop_position = self.burner_miners[&position].drop_position(position);
+    // a mined item which couldn't be placed stalls the miner until there is space for it
+    if let Some(item) = self.burner_miners[&position].result {
+      if !self.place_mining_result(drop_position, item) { return; }
+      self.burner_miners.get_mut(&position).unwrap().result = None;
+    }
+    // unverified: which of the resources below it the game's miner picks next isn't ported, assuming they are mined in tile order
+    let Some(resource_position) = miner_area(position).into_iter().find(|p| self.resources.contains_key(p)) else { return };
+    let mining_time = GAME_CONFIG.minables[&self.resources[&resource_position].resource].mining_time;
+    if self.burner_miners.get_mut(&position).unwrap().tick(mining_time) {
+      let item = self.take_resource(resource_position);
+      if !self.place_mining_result(drop_position, item) {
+        self.burner_miners.get_mut(&position).unwrap().result = Some(item);
+      }
+    }
+  }
+  // from MiningDrill::update
+  fn electric_miner_update(&mut self, position: TilePosition) {
+    let drop_position = self.electric_miners[&position].drop_position(position);
+    if let Some(item) = self.electric_miners[&position].result {
+      if !self.place_mining_result(drop_position, item) { return; }
+      self.electric_miners.get_mut(&position).unwrap().result = None;
+    }
+    // unverified, as for burner miners
+    let Some(resource_position) = electric_miner_area(position).into_iter().find(|p| self.resources.contains_key(p)) else { return };
+    let mining_time = GAME_CONFIG.minables[&self.resources[&resource_position].resource].mining_time;
+    if self.electric_miners.get_mut(&position).unwrap().tick(mining_time) {
+      let item = self.take_resource(resource_position);
+      if !self.place_mining_result(drop_position, item) {
+        self.electric_miners.get_mut(&position).unwrap().result = Some(item);
+      }
+    }
+  }
+  // offshore pumps fill up first, then fluid flows between connected boxes, before the boilers turn water into steam
+  fn fluid_update(&mut self) {
+    for pump in self.offshore_pumps.values_mut() {
+      pump.tick();
+    }
+    // flows are computed from the fluid levels at the start of the tick, then moved in connection order
+    let flows: Vec<f64> = self.fluid_box_connections.iter().map(|&(a, b, last_flow)| power::connection_flow(self.fluid_box(a), self.fluid_box(b), *last_flow)).collect();
+    for (i, flow) in flows.into_iter().enumerate() {
+      let (a, b, _) = self.fluid_box_connections[i];
+      let (mut fluid_box_a, mut fluid_box_b) = (*self.fluid_box(a), *self.fluid_box(b));
+      let moved = power::move_fluid(&mut fluid_box_a, &mut fluid_box_b, flow);
+      *self.fluid_box_mut(a) = fluid_box_a;
+      *self.fluid_box_mut(b) = fluid_box_b;
+      self.fluid_box_connections[i].2 = OrderedFloat(moved);
+    }
+    for boiler in self.boilers.values_mut() {
+      boiler.tick();
+    }
+  }
+  fn fluid_box(&self, owner: FluidBoxOwner) -> &FluidBox {
+    match owner {
+      FluidBoxOwner::OffshorePump(position) => &self.offshore_pumps[&position].fluid_box,
+      FluidBoxOwner::Boiler(position) => &self.boilers[&position].fluid_box,
+      FluidBoxOwner::BoilerOutput(position) => &self.boilers[&position].output_fluid_box,
+      FluidBoxOwner::SteamEngine(position) => &self.steam_engines[&position].fluid_box,
+      FluidBoxOwner::Pipe(position) => &self.pipes[&position].fluid_box,
+    }
+  }
+  fn fluid_box_mut(&mut self, owner: FluidBoxOwner) -> &mut FluidBox {
+    match owner {
+      FluidBoxOwner::OffshorePump(position) => &mut self.offshore_pumps.get_mut(&position).unwrap().fluid_box,
+      FluidBoxOwner::Boiler(position) => &mut self.boilers.get_mut(&position).unwrap().fluid_box,
+      FluidBoxOwner::BoilerOutput(position) => &mut self.boilers.get_mut(&position).unwrap().output_fluid_box,
+      FluidBoxOwner::SteamEngine(position) => &mut self.steam_engines.get_mut(&position).unwrap().fluid_box,
+      FluidBoxOwner::Pipe(position) => &mut self.pipes.get_mut(&position).unwrap().fluid_box,
+    }
+  }
+  // fluid boxes are connected if their pipe connections point at each other
+  fn connect_fluid_boxes(&mut self, item: Item, tile: TilePosition) {
+    let ends = match item {
+      Item::OffshorePump => {
+        let pump = &self.offshore_pumps[&tile];
+        power::pipe_connections(&GAME_CONFIG.offshore_pumps[&pump.entity].fluid_box, pump.position, pump.direction).into_iter().map(|(from, to)| (FluidBoxOwner::OffshorePump(tile), from, to)).collect()
+      },
+      Item::Boiler => {
+        let (boiler, config) = (&self.boilers[&tile], &GAME_CONFIG.boilers[&self.boilers[&tile].entity]);
+        let mut ends: Vec<_> = power::pipe_connections(&config.fluid_box, boiler.position, boiler.direction).into_iter().map(|(from, to)| (FluidBoxOwner::Boiler(tile), from, to)).collect();
+        ends.extend(power::pipe_connections(&config.output_fluid_box, boiler.position, boiler.direction).into_iter().map(|(from, to)| (FluidBoxOwner::BoilerOutput(tile), from, to)));
+        ends
+      },
+      Item::SteamEngine => {
+        let engine = &self.steam_engines[&tile];
+        power::pipe_connections(&GAME_CONFIG.generators[&engine.entity].fluid_box, engine.position, engine.direction).into_iter().map(|(from, to)| (FluidBoxOwner::SteamEngine(tile), from, to)).collect()
+      },
+      Item::Pipe => power::pipe_connections(&GAME_CONFIG.pipe_fluid_box, tile.center_map_position(), Direction::North).into_iter().map(|(from, to)| (FluidBoxOwner::Pipe(tile), from, to)).collect(),
+      _ => vec![],
+    };
+    for &(a, from, to) in &ends {
+      for &(b, other_from, other_to) in &self.fluid_box_ends {
+        if from == other_to && to == other_from {
+          self.fluid_box_connections.push((b, a, OrderedFloat(0.0)));
+        }
+      }
+    }
+    self.fluid_box_ends.extend(ends);
+  }
+  // entities belong to the first network with a pole supply area overlapping them
+  fn electric_network_update(&mut self) {
+    let supply_areas: Vec<Vec<BoundingBox>> = power::electric_networks(&self.electric_poles).into_iter()
+      .map(|network| network.into_iter().map(|position| self.electric_poles[&position].supply_area(position)).collect()).collect();
+    let network_of = |collision_box: &BoundingBox| supply_areas.iter().position(|areas| areas.iter().any(|area| power::overlaps(area, collision_box)));
+    let mut generators: Vec<Vec<&mut SteamEngine>> = supply_areas.iter().map(|_| vec![]).collect();
+    for engine in self.steam_engines.values_mut() {
+      if let Some(network) = network_of(&engine.collision_box()) {
+        generators[network].push(engine);
+      }
+    }
+    let mut consumers: Vec<Vec<&mut ElectricEnergySource>> = supply_areas.iter().map(|_| vec![]).collect();
+    for (&position, miner) in self.electric_miners.iter_mut() {
+      if let Some(network) = network_of(&ElectricMiner::collision_box(position)) {
+        consumers[network].push(&mut miner.energy_source);
+      }
+    }
+    for (&position, machine) in self.assembling_machines.iter_mut() {
+      if let Some(network) = network_of(&machine.collision_box(position)) {
+        consumers[network].push(&mut machine.energy_source);
+      }
+    }
+    for (&position, lab) in self.labs.iter_mut() {
+      if let Some(network) = network_of(&lab.collision_box(position)) {
+        consumers[network].push(&mut lab.energy_source);
+      }
+    }
+    for (&position, inserter) in self.inserters.iter_mut() {
+      let collision_box = inserter.collision_box(position);
+      if let (InserterEnergySource::Electric(energy_source), Some(network)) = (&mut inserter.energy_source, network_of(&collision_box)) {
+        consumers[network].push(energy_source);
+      }
+    }
+    for (generators, consumers) in generators.iter_mut().zip(consumers.iter_mut()) {
+      power::update_electric_network(generators, consumers);
+    }
+  }
+  // from MiningDrill::insertResult, inserts into the entity at the drop position, or onto the ground; returns whether the item was placed
+  fn place_mining_result(&mut self, drop_position: MapPosition, item: Item) -> bool {
+    match self.entity_at(drop_position) {
+      Some(PlayerSelectedEntity::TransportBelt(position)) => {
+        let (lane, lane_position) = TransportBelt::lane_position(&self.transport_belts, position, drop_position);
+        self.transport_belts.get_mut(&position).unwrap().insert(lane, item, lane_position)
+      },
+      Some(entity @ (PlayerSelectedEntity::StoneFurnace(_) | PlayerSelectedEntity::BurnerMiner(_) | PlayerSelectedEntity::Chest(_) | PlayerSelectedEntity::Boiler(_) | PlayerSelectedEntity::AssemblingMachine(_) | PlayerSelectedEntity::Lab(_))) => self.insert_into_entity(Some(entity), None, item, 1) == 1,
+      _ if self.items_on_ground.iter().any(|&(position, _)| position == drop_position) => false,  // can't drop onto another item
+      _ => {
+        self.items_on_ground.push((drop_position, item));
+        true
+      },
+    }
+  }
+  // whether an inserter may drop the item into the entity, crafting machines only take ingredients up to their automated insertion limit
+  fn inserter_can_insert(&self, entity: Option<PlayerSelectedEntity>, item: Item) -> bool {
+    let has_room = |slot: Option<(Item, u32)>, limit: u32| slot.is_none_or(|(slot_item, count)| slot_item == item && count < limit);
+    let is_fuel = GAME_CONFIG.fuels.contains_key(&item);
+    let stack_size = GAME_CONFIG.items[&item].stack_size;
+    match entity {
+      None | Some(PlayerSelectedEntity::Resource(_)) | Some(PlayerSelectedEntity::TransportBelt(_)) => true,
+      Some(PlayerSelectedEntity::Chest(position)) => self.chests[&position].can_insert(item),
+      Some(PlayerSelectedEntity::StoneFurnace(position)) => {
+        let furnace = &self.stone_furnaces[&position];
+        if is_fuel { return has_room(furnace.energy_source.fuel_slot, stack_size); }
+        let Some(recipe) = furnace_recipe(item, &self.research) else { return false };
+        let &[ProductConfig::Item { amount, .. }] = GAME_CONFIG.recipes[&recipe].ingredients.as_slice() else { return false };
+        has_room(furnace.input_slot, production::automated_insertion_limit(amount, GAME_CONFIG.recipes[&recipe].energy_required, GAME_CONFIG.stone_furnace_speed))
+      },
+      Some(PlayerSelectedEntity::BurnerMiner(position)) => is_fuel && has_room(self.burner_miners[&position].energy_source.fuel_slot, stack_size),
+      Some(PlayerSelectedEntity::Boiler(position)) => is_fuel && has_room(self.boilers[&position].energy_source.fuel_slot, stack_size),
+      Some(PlayerSelectedEntity::AssemblingMachine(position)) => self.assembling_machines[&position].accepts_automated(item),
+      Some(PlayerSelectedEntity::Lab(position)) => self.labs[&position].can_insert(item),
+      _ => false,
+    }
+  }
+  // from Inserter::update (loosely), the hand swings to the pickup position, waits for items the drop target accepts, and swings back to drop them
+  fn inserter_update(&mut self, position: TilePosition) {
+    let inserter = &self.inserters[&position];
+    let (pickup_position, drop_position) = (inserter.pickup_position(position), inserter.drop_position(position));
+    let (source, target) = (self.entity_at(pickup_position), self.entity_at(drop_position));
+    // the hand picks up or drops in the tick it arrives, so a swing takes as long as the slower of rotation and extension
+    let to_drop = inserter.hand.is_some();
+    if !inserter.is_at(to_drop) {
+      self.inserters.get_mut(&position).unwrap().move_hand(to_drop);
+    }
+    let inserter = &self.inserters[&position];
+    match inserter.hand {
+      None if inserter.is_at(false) => {
+        let capacity = inserter.capacity(&self.research);
+        // burner inserters without fuel also grab fuel for themselves
+        let needs_fuel = matches!(&inserter.energy_source, InserterEnergySource::Burner(burner) if burner.fuel_slot.is_none());
+        let accepted: Vec<Item> = GAME_CONFIG.items.keys().copied()
+          .filter(|&item| self.inserter_can_insert(target, item) || (needs_fuel && GAME_CONFIG.fuels.contains_key(&item))).collect();
+        let accepts = |item| accepted.contains(&item);
+        let picked_up = match source {
+          Some(PlayerSelectedEntity::Chest(source)) => self.chests.get_mut(&source).unwrap().take(capacity, accepts),
+          Some(PlayerSelectedEntity::StoneFurnace(source)) => {
+            let output_slot = &mut self.stone_furnaces.get_mut(&source).unwrap().output_slot;
+            if output_slot.is_some_and(|(item, _)| accepts(item)) { take_from_slot(output_slot, capacity) } else { None }
+          },
+          Some(PlayerSelectedEntity::AssemblingMachine(source)) => self.assembling_machines.get_mut(&source).unwrap().take_result(capacity, accepts),
+          Some(PlayerSelectedEntity::TransportBelt(source)) => self.transport_belts.get_mut(&source).unwrap().take(capacity, accepts),
+          None | Some(PlayerSelectedEntity::Resource(_)) => {
+            let pickup_tile = pickup_position.to_tile_position();
+            let index = self.items_on_ground.iter().position(|&(item_position, item)| item_position.to_tile_position() == pickup_tile && accepts(item));
+            index.map(|index| (self.items_on_ground.remove(index).1, 1))
+          },
+          _ => None,
+        };
+        let inserter = self.inserters.get_mut(&position).unwrap();
+        inserter.hand = picked_up.filter(|&(_, count)| count > 0);
+        inserter.refuel_from_hand();
+      },
+      Some((item, count)) if inserter.is_at(true) => {
+        let dropped = match target {
+          Some(PlayerSelectedEntity::TransportBelt(target)) => {
+            // belts take a single item per tick
+            let (lane, lane_position) = TransportBelt::lane_position(&self.transport_belts, target, drop_position);
+            u32::from(self.transport_belts.get_mut(&target).unwrap().insert(lane, item, lane_position))
+          },
+          None | Some(PlayerSelectedEntity::Resource(_)) => {
+            if self.items_on_ground.iter().any(|&(item_position, _)| item_position == drop_position) {
+              0
+            } else {
+              self.items_on_ground.push((drop_position, item));
+              1
+            }
+          },
+          Some(target) if self.inserter_can_insert(Some(target), item) => self.insert_into_entity(Some(target), None, item, count),
+          _ => 0,
+        };
+        self.inserters.get_mut(&position).unwrap().hand = if count > dropped { Some((item, count - dropped)) } else { None };
+      },
+      _ => {},
+    }
+    self.inserters.get_mut(&position).unwrap().tick_energy_source();
   }
 
   // from Character::update
@@ -535,7 +1905,7 @@ impl GameState {
     if movement.x * movement.x + movement.y * movement.y <= 0.01 {
       return None;
     }
-    return self.calculate_new_position_internal(movement * 0.5)
+    self.calculate_new_position_internal(movement * 0.5)
   }
 
   // from Character::calculateShortMovement
@@ -596,7 +1966,7 @@ impl GameState {
   }
   fn all_entity_collision_boxes(&self) -> impl IntoIterator<Item = BoundingBox> {
     // TODO: add entities to check collisions for
-    self.dry_trees.iter().map(|&mp| GAME_CONFIG.dry_tree_bounding_box.offset(mp)).collect::<Vec<_>>()
+    self.trees.iter().map(|&(tree, mp)| GAME_CONFIG.minables[&tree].collision_box.offset(mp)).collect::<Vec<_>>()
   }
   // from Character::calculateSlideOverCorner
   fn calculate_slide_over_corner(&self, movement: Vector) -> Option<MapPosition> {
@@ -687,26 +2057,77 @@ impl GameState {
     self.run_command(format!(r#"assert_tick({})"#, self.tick));
     self.run_command(format!(r#"assert_player_position({}, {})"#, self.player_position.x.0, self.player_position.y.0));
     if let Some((current, goal, _)) = self.player_mining_progress {
-      self.run_command(format!(r#"assert_player_mining_progress({})"#, HexFloat(current / goal)));
+      self.run_command(format!(r#"assert_player_mining_progress({})"#, HexFloat(*current / *goal)));
     }
     self.run_command(format!(r#"assert_player_crafting_queue_size({})"#, self.player_crafting_queue.len()));
+    for (position, order) in self.player_crafting_queue.clone().iter().enumerate() {
+      self.run_command(format!(r#"assert_player_crafting_queue_item({}, {}, "{}", {}, {})"#, position + 1, order.index, order.recipe.name(), order.count, order.parent.is_some()));
+    }
     if let Some(order) = self.player_crafting_queue.front() {
-      self.run_command(format!(r#"assert_player_crafting_progress({})"#, HexFloat(order.current_energy / GAME_CONFIG.recipes[&order.recipe].energy_required)));
+      self.run_command(format!(r#"assert_player_crafting_progress({})"#, HexFloat(*order.current_energy / GAME_CONFIG.recipes[&order.recipe].energy_required)));
     }
-    for (item, count) in self.player_inventory.clone() {
-      self.run_command(format!(r#"assert_player_inventory_item_count("{}", {})"#, item.name(), count));
+    let inventory_commands = self.player_inventory.items().iter()
+        .map(|(item, count)| format!(r#"assert_player_inventory_item_count("{}", {})"#, item.name(), count)).collect::<Vec<_>>();
+    for command in inventory_commands {
+      self.run_command(command);
     }
+    let (cursor_item, cursor_count) = self.player_inventory.cursor().map_or((String::from("nil"), 0), |(item, count)| (format!(r#""{}""#, item.name()), count));
+    self.run_command(format!(r#"assert_player_cursor_stack({}, {})"#, cursor_item, cursor_count));
 
-    for (position, miner) in self.iron_miners.iter() {
-      self.input_actions.push(self.build_command(format!(r#"assert_miner_mining_progress({}, {}, {})"#, position.x, position.y, HexFloat(miner.mining_progress))));
-      self.input_actions.push(self.build_command(format!(r#"assert_miner_remaining_burning_fuel({}, {}, {})"#, position.x, position.y, HexFloat(miner.energy_source.remaining_part_of_burning_fuel))));
-      self.input_actions.push(self.build_command(format!(r#"assert_miner_heat({}, {}, {})"#, position.x, position.y, HexFloat(miner.energy_source.heat_energy))));
+    for (position, miner) in self.burner_miners.iter() {
+      self.input_actions.push(self.build_command(format!(r#"assert_miner_mining_progress({}, {}, {})"#, position.x, position.y, HexFloat(*miner.mining_progress))));
+      self.input_actions.push(self.build_command(format!(r#"assert_miner_remaining_burning_fuel({}, {}, {})"#, position.x, position.y, HexFloat(*miner.energy_source.remaining_part_of_burning_fuel))));
+      self.input_actions.push(self.build_command(format!(r#"assert_miner_heat({}, {}, {})"#, position.x, position.y, HexFloat(*miner.energy_source.heat_energy))));
     }
     for (position, furnace) in self.stone_furnaces.iter() {
       self.input_actions.push(self.build_command(format!(r#"assert_furnace_crafting_progress({}, {}, {})"#, position.x, position.y, HexFloat(furnace.get_crafting_progress()))));
-      self.input_actions.push(self.build_command(format!(r#"assert_furnace_remaining_burning_fuel({}, {}, {})"#, position.x, position.y, HexFloat(furnace.energy_source.remaining_part_of_burning_fuel))));
-      self.input_actions.push(self.build_command(format!(r#"assert_furnace_heat({}, {}, {})"#, position.x, position.y, HexFloat(furnace.energy_source.heat_energy))));
+      self.input_actions.push(self.build_command(format!(r#"assert_furnace_remaining_burning_fuel({}, {}, {})"#, position.x, position.y, HexFloat(*furnace.energy_source.remaining_part_of_burning_fuel))));
+      self.input_actions.push(self.build_command(format!(r#"assert_furnace_heat({}, {}, {})"#, position.x, position.y, HexFloat(*furnace.energy_source.heat_energy))));
+      let (output_item, output_amount) = furnace.output_slot.map_or((String::from("nil"), 0), |(item, amount)| (format!(r#""{}""#, item.name()), amount));
+      self.input_actions.push(self.build_command(format!(r#"assert_furnace_output({}, {}, {}, {})"#, position.x, position.y, output_item, output_amount)));
+    }
+    for (position, miner) in self.electric_miners.iter() {
+      let center = position.center_map_position();
+      self.input_actions.push(self.build_command(format!(r#"assert_mining_progress("electric-mining-drill", {}, {}, {})"#, center.x.0, center.y.0, HexFloat(*miner.mining_progress))));
+      self.input_actions.push(self.build_command(format!(r#"assert_energy("electric-mining-drill", {}, {}, {})"#, center.x.0, center.y.0, HexFloat(*miner.energy_source.buffer))));
+    }
+    for pump in self.offshore_pumps.values() {
+      self.input_actions.push(self.build_command(format!(r#"assert_fluid_amount("offshore-pump", {}, {}, 1, {})"#, pump.position.x.0, pump.position.y.0, HexFloat(*pump.fluid_box.amount))));
+    }
+    for boiler in self.boilers.values() {
+      self.input_actions.push(self.build_command(format!(r#"assert_fluid_amount("boiler", {}, {}, 1, {})"#, boiler.position.x.0, boiler.position.y.0, HexFloat(*boiler.fluid_box.amount))));
+      self.input_actions.push(self.build_command(format!(r#"assert_fluid_amount("boiler", {}, {}, 2, {})"#, boiler.position.x.0, boiler.position.y.0, HexFloat(*boiler.output_fluid_box.amount))));
+      self.input_actions.push(self.build_command(format!(r#"assert_burner_heat("boiler", {}, {}, {})"#, boiler.position.x.0, boiler.position.y.0, HexFloat(*boiler.energy_source.heat_energy))));
+    }
+    for engine in self.steam_engines.values() {
+      self.input_actions.push(self.build_command(format!(r#"assert_fluid_amount("steam-engine", {}, {}, 1, {})"#, engine.position.x.0, engine.position.y.0, HexFloat(*engine.fluid_box.amount))));
+    }
+    for (position, machine) in self.assembling_machines.iter() {
+      let center = position.center_map_position();
+      self.input_actions.push(self.build_command(format!(r#"assert_crafting_progress("{}", {}, {}, {})"#, machine.entity.name(), center.x.0, center.y.0, HexFloat(machine.get_crafting_progress()))));
+      self.input_actions.push(self.build_command(format!(r#"assert_energy("{}", {}, {}, {})"#, machine.entity.name(), center.x.0, center.y.0, HexFloat(*machine.energy_source.buffer))));
+    }
+    for (position, lab) in self.labs.iter() {
+      let center = position.center_map_position();
+      self.input_actions.push(self.build_command(format!(r#"assert_energy("lab", {}, {}, {})"#, center.x.0, center.y.0, HexFloat(*lab.energy_source.buffer))));
+    }
+    for (position, belt) in self.transport_belts.iter() {
+      let center = position.center_map_position();
+      for (lane, items) in belt.lanes.iter().enumerate() {
+        self.input_actions.push(self.build_command(format!(r#"assert_belt_item_count("{}", {}, {}, {}, {})"#, belt.entity.name(), center.x.0, center.y.0, lane + 1, items.len())));
+      }
     }
+    for (position, inserter) in self.inserters.iter() {
+      let center = position.center_map_position();
+      let (hand_item, hand_count) = inserter.hand.map_or((String::from("nil"), 0), |(item, count)| (format!(r#""{}""#, item.name()), count));
+      self.input_actions.push(self.build_command(format!(r#"assert_inserter_hand("{}", {}, {}, {}, {})"#, inserter.entity.name(), center.x.0, center.y.0, hand_item, hand_count)));
+      match &inserter.energy_source {
+        InserterEnergySource::Burner(burner) => self.input_actions.push(self.build_command(format!(r#"assert_burner_heat("{}", {}, {}, {})"#, inserter.entity.name(), center.x.0, center.y.0, HexFloat(*burner.heat_energy)))),
+        InserterEnergySource::Electric(electric) => self.input_actions.push(self.build_command(format!(r#"assert_energy("{}", {}, {}, {})"#, inserter.entity.name(), center.x.0, center.y.0, HexFloat(*electric.buffer)))),
+      }
+    }
+    let current_research = self.research.current.map_or(String::from("nil"), |technology| format!(r#""{}""#, technology.name()));
+    self.run_command(format!(r#"assert_research({}, {})"#, current_research, HexFloat(self.research.progress())));
   }
 
   fn build_command<S: AsRef<str>>(&self, command: S) -> InputAction {
@@ -718,5 +2139,438 @@ impl GameState {
   fn add_input_action(&mut self, action: InputActionData) {
     self.input_actions.push(InputAction::new(self.tick, PID, action))
   }
+  fn simulation_error(&self, action: InputActionData, reason: String) -> SimulationError {
+    SimulationError { tick: self.tick, action: Box::new(InputAction::new(self.tick, PID, action)), reason }
+  }
+
+}
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn action(tick: u32, action: InputActionData) -> InputAction {
+    InputAction::new(tick, PID, action)
+  }
+  fn pick_into_cursor(tick: u32, item: Item) -> [InputAction; 2] {
+    [
+      action(tick, InputActionData::SetFilter(SetFilterParameters { target: ItemStackTargetSpecification::from_quick_bar(0, 0), filter: item })),
+      action(tick, InputActionData::QuickBarPickSlot(QuickBarPickSlotParameters { location: 0, pick_ghost_cursor: false, cursor_split: false })),
+    ]
+  }
+  fn build(tick: u32, position: MapPosition) -> InputAction {
+    action(tick, InputActionData::Build(BuildParameters { position, direction: Direction::North, created_by_moving: false, build_by_moving_start_position: None, flags: 0 }))
+  }
+
+  #[test]
+  fn test_from_chunks() {
+    use factorio_serialize::{map::{EntityCommon, EntityWithHealth, MapVersion, ResourceEntity, SimpleEntity, Tree}, registry::Prototype, script::{LuaGameScript, LuaGameScriptState, LuaValue}, ChunkPosition};
+    let position = |x: f64, y: f64| MapPosition::new(FixedPoint32_8::from_double(x), FixedPoint32_8::from_double(y));
+    let common = |x: f64, y: f64| EntityCommon { position: position(x, y), usage_bit_mask: 0, targeter: None };
+    let with_health = |x: f64, y: f64| EntityWithHealth { entity: common(x, y), health: 0.0, damage_to_be_taken: 0.0, upgrade_target: None };
+    let resource = |x: f64, y: f64, resource_amount: u32| ResourceEntity { entity: common(x, y), resource_amount, initial_amount: None, variation: 0 };
+
+    let mut tiles = [[(Prototype::Known(Tile::Grass1), 0); 32]; 32];
+    tiles[2][3].0 = Prototype::Known(Tile::Water);
+    tiles[4][5].0 = Prototype::Known(Tile::WaterShallow);
+    let chunk = Chunk {
+      position: ChunkPosition { x: -1, y: 0 },
+      generated_status: 0,
+      military_targets_len: 0,
+      active_entities_serialisation_helper: 0,
+      planned_update_counts_to_be_loaded: vec![],
+      active_when_enemy_is_around: 0,
+      tiles,
+      entities_to_be_inserted_before_setup: vec![
+        (Entity::DryTree.into(), EntityData::DryTree(Tree { entity: with_health(-20.5, 2.5), tree_data: 0, burn_progress: 0 })),
+        (Entity::RockHuge.into(), EntityData::RockHuge(SimpleEntity { entity: with_health(-10.0, 10.0), variation: 0 })),
+        (Entity::Tree01.into(), EntityData::Tree(Tree { entity: with_health(-18.5, 2.5), tree_data: 0, burn_progress: 0 })),
+        (Prototype::Unknown(998), EntityData::Tree(Tree { entity: with_health(-16.5, 2.5), tree_data: 0, burn_progress: 0 })),
+        (Entity::SandRockBig.into(), EntityData::SimpleEntity(SimpleEntity { entity: with_health(-6.0, 10.0), variation: 0 })),
+        (Entity::IronOre.into(), EntityData::IronOre(resource(-27.5, 4.5, 300))),
+        (Entity::CrudeOil.into(), EntityData::CrudeOil(resource(-25.5, 4.5, 300000))),
+        (Prototype::Unknown(999), EntityData::Resource(resource(-23.5, 4.5, 100))),
+      ],
+      tick_of_optional_activation: 0,
+      tick_of_last_change_that_could_affect_charting: 0,
+      pollution: 0.0,
+    };
+    let mut script_state = LuaGameScriptState { state_value: LuaValue::Table(vec![]) };
+    script_state.set_created_items(&[("iron-plate", 8), ("pistol", 1), ("modded-item", 5), ("wood", 1), ("burner-mining-drill", 1), ("stone-furnace", 1)]);
+    let script_data = ScriptData {
+      map_version: MapVersion { version: 0x0001_0001_006b_0000, quality_version: false },
+      lua_context: LuaContext { scripts: vec![(LuaContext::LEVEL_SCRIPT.to_owned(), LuaGameScript { script_state, had_control_lua: true })] },
+      remaining_data: vec![],
+    };
+
+    let game_state = GameState::from_chunks(RandomGenerator::new(1, 2, 3), &[chunk], &script_data);
+    assert_eq!(game_state.water_tiles, BTreeSet::from([TilePosition::new(-30, 3)]));
+    assert_eq!(game_state.trees, [(Entity::DryTree, position(-20.5, 2.5)), (Entity::Tree01, position(-18.5, 2.5))]);
+    assert_eq!(game_state.rocks, [(Entity::RockHuge, position(-10.0, 10.0)), (Entity::SandRockBig, position(-6.0, 10.0))]);
+    assert_eq!(game_state.resources, BTreeMap::from([(TilePosition::new(-28, 4), ResourceTile { resource: Entity::IronOre, num_mined: 0, amount: Some(300) })]));
+    assert_eq!(game_state.player_inventory(), &BTreeMap::from([(Item::IronPlate, 8), (Item::Wood, 1), (Item::BurnerMiningDrill, 1), (Item::StoneFurnace, 1)]));
+  }
+
+  #[test]
+  fn test_run_input_actions() {
+    let furnace_position = TilePosition::new(5, 5);
+    let mut actions = vec![
+      action(0, InputActionData::StartWalking(Direction::East)),
+      action(10, InputActionData::StopWalking),
+      action(10, InputActionData::Craft(CraftData { recipe: Recipe::IronGearWheel, count: 1 })),
+    ];
+    actions.extend(pick_into_cursor(50, Item::StoneFurnace));
+    actions.push(build(50, furnace_position.top_left_map_position()));
+    actions.push(action(50, InputActionData::ClearCursor));
+
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.run_input_actions(&actions).unwrap();
+
+    let step = MapPosition::new(FixedPoint32_8(0), FixedPoint32_8(0)) + Vector::new(GAME_CONFIG.player_movement_speed, 0.0);
+    assert_eq!(game_state.player_position(), MapPosition::new(FixedPoint32_8(step.x.0 * 10), FixedPoint32_8(0)));
+    assert_eq!(game_state.player_inventory(), &BTreeMap::from([(Item::IronPlate, 6), (Item::Wood, 1), (Item::BurnerMiningDrill, 1), (Item::IronGearWheel, 1)]));
+    assert!(game_state.stone_furnaces.contains_key(&furnace_position));
+  }
+
+  #[test]
+  fn test_unsupported_input_actions() {
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    let error = game_state.run_input_actions(&[build(3, MapPosition::new(FixedPoint32_8(0), FixedPoint32_8(0)))]).unwrap_err();
+    assert_eq!((error.tick, error.reason.as_str()), (3, "building with an empty cursor"));
+
+    // the failed action leaves the wood in the cursor
+    let mut actions = pick_into_cursor(5, Item::Wood).to_vec();
+    actions.push(build(5, MapPosition::new(FixedPoint32_8(0), FixedPoint32_8(0))));
+    let error = game_state.run_input_actions(&actions).unwrap_err();
+    assert_eq!(error.tick, 5);
+    assert_eq!(game_state.player_inventory.cursor(), Some((Item::Wood, 1)));
+
+    // smelting recipes can't be hand crafted
+    let error = game_state.run_input_actions(&[action(8, InputActionData::Craft(CraftData { recipe: Recipe::IronPlate, count: 1 }))]).unwrap_err();
+    assert_eq!((error.tick, error.reason.as_str()), (8, "recipe IronPlate of category smelting can't be hand crafted"));
+
+    // entities can't be built facing diagonally
+    let mut actions = pick_into_cursor(9, Item::StoneFurnace).to_vec();
+    actions.push(action(9, InputActionData::Build(BuildParameters { position: MapPosition::new(FixedPoint32_8(0), FixedPoint32_8(0)), direction: Direction::NorthEast, created_by_moving: false, build_by_moving_start_position: None, flags: 0 })));
+    let error = game_state.run_input_actions(&actions).unwrap_err();
+    assert_eq!(error.tick, 9);
+    assert!(game_state.stone_furnaces.is_empty());
+  }
+
+  #[test]
+  fn test_resource_depleted_while_player_mines_it() {
+    let position = TilePosition::new(0, 0);
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.add_resource(Entity::IronOre, position, Some(2));
+    game_state.run_input_actions(&[
+      action(1, InputActionData::SelectedEntityChanged(position.top_left_map_position())),
+      action(1, InputActionData::BeginMining),
+    ]).unwrap();
+    assert_eq!(game_state.player_selected_entity, Some(PlayerSelectedEntity::Resource(position)));
+    assert!(game_state.player_mining_progress.is_some());
+
+    // a drill takes the remaining ore before the player finishes mining it
+    game_state.take_resource(position);
+    game_state.take_resource(position);
+    assert!(!game_state.resources.contains_key(&position));
+    assert_eq!(game_state.player_selected_entity, None);
+    assert_eq!(game_state.player_mining_progress, None);
+    for _ in 0..200 {
+      game_state.tick();
+    }
+    assert_eq!(game_state.player_inventory().get(&Item::IronOre), None);
+  }
+
+  #[test]
+  fn test_full_inventory() {
+    let position = TilePosition::new(0, 0);
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.player_inventory = PlayerInventory::new([(Item::IronPlate, 100 * GAME_CONFIG.player_inventory_size)]);
+
+    // the finished gear waits for a free slot
+    game_state.run_input_actions(&[action(1, InputActionData::Craft(CraftData { recipe: Recipe::IronGearWheel, count: 1 }))]).unwrap();
+    for _ in 0..60 {
+      game_state.tick();
+    }
+    assert_eq!(game_state.player_inventory.count(Item::IronGearWheel), 0);
+    assert_eq!(game_state.player_crafting_queue.len(), 1);
+    game_state.remove_from_inventory(Item::IronPlate, 100);
+    game_state.tick();
+    assert_eq!(game_state.player_inventory.count(Item::IronGearWheel), 1);
+    assert!(game_state.player_crafting_queue.is_empty());
+
+    // mined stone is spilled at the player
+    game_state.add_resource(Entity::Stone, position, Some(1));
+    game_state.run_input_actions(&[
+      action(game_state.tick + 1, InputActionData::SelectedEntityChanged(position.top_left_map_position())),
+      action(game_state.tick + 1, InputActionData::BeginMining),
+    ]).unwrap();
+    for _ in 0..300 {
+      game_state.tick();
+    }
+    assert_eq!(game_state.player_inventory.count(Item::Stone), 0);
+    assert_eq!(game_state.items_on_ground, [(game_state.player_position, Item::Stone)]);
+  }
+
+  #[test]
+  fn test_freeplay_inventory_slots() {
+    let game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    let slots = game_state.player_inventory.slots();
+    assert_eq!(slots[..5], [Some((Item::BurnerMiningDrill, 1)), Some((Item::StoneFurnace, 1)), Some((Item::Wood, 1)), Some((Item::IronPlate, 8)), None]);
+    assert!(slots[4..].iter().all(Option::is_none));
+  }
 
-}
\ No newline at end of file
+  #[test]
+  fn test_craft_intermediates() {
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.add_to_inventory(Item::IronPlate, 15);
+    game_state.add_to_inventory(Item::CopperPlate, 5);
+    let ticks = game_state.craft(Recipe::ElectricMiningDrill, 1).unwrap();
+
+    // the missing circuits are crafted first, after the cables they need
+    let queue = game_state.player_crafting_queue.iter().map(|order| (order.index, order.recipe, order.count, order.parent, order.reserved_results)).collect::<Vec<_>>();
+    assert_eq!(queue, [
+      (1, Recipe::CopperCable, 5, Some(2), 9),
+      (2, Recipe::ElectronicCircuit, 3, Some(4), 3),
+      (3, Recipe::IronGearWheel, 5, Some(4), 5),
+      (4, Recipe::ElectricMiningDrill, 1, None, 0),
+    ]);
+    assert_eq!(ticks, 5 * 31 + 3 * 31 + 5 * 31 + 121);
+    assert_eq!(game_state.player_inventory.count(Item::IronPlate), 0);
+    assert_eq!(game_state.player_inventory.count(Item::CopperPlate), 0);
+
+    for _ in 0..ticks {
+      game_state.tick();
+    }
+    assert!(game_state.player_crafting_queue.is_empty());
+    assert_eq!(game_state.player_inventory.count(Item::ElectricMiningDrill), 1);
+    // the cable left over from the last craft goes into the inventory
+    assert_eq!(game_state.player_inventory.count(Item::CopperCable), 1);
+  }
+
+  #[test]
+  fn test_cancel_craft_refunds_intermediates() {
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.add_to_inventory(Item::IronPlate, 1);
+    game_state.craft(Recipe::BurnerMiningDrill, 1).unwrap();
+    assert_eq!(game_state.player_crafting_queue.iter().map(|order| (order.recipe, order.count)).collect::<Vec<_>>(), [(Recipe::IronGearWheel, 3), (Recipe::BurnerMiningDrill, 1)]);
+
+    // one gear is done, its plates are refunded as the gear itself
+    for _ in 0..31 {
+      game_state.tick();
+    }
+    game_state.cancel_craft(Recipe::BurnerMiningDrill, 1).unwrap();
+    assert!(game_state.player_crafting_queue.is_empty());
+    assert_eq!(game_state.player_inventory.items(), &BTreeMap::from([(Item::BurnerMiningDrill, 1), (Item::IronGearWheel, 1), (Item::IronPlate, 7), (Item::StoneFurnace, 1), (Item::Wood, 1)]));
+
+    let error = game_state.cancel_craft(Recipe::BurnerMiningDrill, 1).unwrap_err();
+    assert_eq!((error.tick, error.reason.as_str()), (31, "no crafting order of BurnerMiningDrill to cancel"));
+    let error = game_state.craft(Recipe::ElectricMiningDrill, 1).unwrap_err();
+    assert_eq!(error.tick, 31);
+  }
+
+  #[test]
+  fn test_rewind_to() {
+    let run_until = |game_state: &mut GameState, tick: u32| while game_state.tick < tick { game_state.tick(); };
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0)).with_checkpoints(10, 7);
+    game_state.mine_iron_ore(TilePosition::new(3, 3), 2);
+    run_until(&mut game_state, 25);
+    let mut state_at_25 = game_state.clone();
+
+    game_state.craft(Recipe::IronGearWheel, 1).unwrap();
+    game_state.build_stone_furnace(TilePosition::new(5, 5));
+    run_until(&mut game_state, 60);
+    game_state.set_walking_direction(Direction::East);
+    run_until(&mut game_state, 80);
+    game_state.rewind_to(25);
+    assert!(game_state == state_at_25);
+    assert_eq!(format!("{:?}", game_state.input_actions), format!("{:?}", state_at_25.input_actions));
+
+    // mining started before the checkpoint still stops after the requested count
+    run_until(&mut game_state, 400);
+    run_until(&mut state_at_25, 400);
+    assert!(game_state == state_at_25);
+    assert_eq!(game_state.player_inventory()[&Item::IronOre], 2);
+  }
+
+  #[test]
+  #[should_panic(expected = "no checkpoint at or before tick 15")]
+  fn test_checkpoints_pruned() {
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0)).with_checkpoints(10, 2);
+    game_state.mine_iron_ore(TilePosition::new(3, 3), 2);
+    while game_state.tick < 35 { game_state.tick(); }
+    // the checkpoints at ticks 20 and 30 are kept, the mining call made before them is dropped
+    assert_eq!(game_state.checkpoints.iter().map(|checkpoint| checkpoint.state.tick).collect::<Vec<_>>(), [20, 30]);
+    assert!(game_state.operations.is_empty());
+    game_state.rewind_to(20);
+    assert_eq!(game_state.checkpoints.len(), 1);
+    game_state.rewind_to(15);
+  }
+
+  #[test]
+  fn test_furnace_recipe_requires_research() {
+    let research = Research::default();
+    assert_eq!(furnace_recipe(Item::IronOre, &research), Some(Recipe::IronPlate));
+    assert_eq!(furnace_recipe(Item::IronPlate, &research), None);
+    assert_eq!(furnace_recipe(Item::Coal, &research), None);
+    let research = Research::from_force([Technology::SteelProcessing], None);
+    assert_eq!(furnace_recipe(Item::IronPlate, &research), Some(Recipe::SteelPlate));
+  }
+
+  #[test]
+  fn test_stone_furnace_can_hold_result() {
+    let mut furnace = StoneFurnace::new();
+    furnace.recipe = Some(Recipe::IronPlate);
+    assert!(furnace.can_hold_result());
+    furnace.output_slot = Some((Item::IronPlate, 99));
+    assert!(furnace.can_hold_result());
+    furnace.output_slot = Some((Item::IronPlate, 100));
+    assert!(!furnace.can_hold_result());
+    furnace.output_slot = Some((Item::CopperPlate, 1));
+    assert!(!furnace.can_hold_result());
+  }
+
+  #[test]
+  fn test_stone_furnace_output_full() {
+    let research = Research::default();
+    let mut furnace = StoneFurnace::new();
+    furnace.energy_source.fuel_slot = Some((Item::Coal, 5));
+    furnace.input_slot = Some((Item::IronOre, 5));
+    furnace.output_slot = Some((Item::IronPlate, 100));
+    for _ in 0..600 {
+      furnace.tick(&research);
+    }
+    // the recipe stays selected, but no ore is used up while the output is full
+    assert_eq!(furnace.recipe, Some(Recipe::IronPlate));
+    assert_eq!(furnace.input_slot, Some((Item::IronOre, 5)));
+    assert_eq!(furnace.output_slot, Some((Item::IronPlate, 100)));
+    assert_eq!(*furnace.energy_stored_in_product, 0.0);
+
+    furnace.output_slot = Some((Item::IronPlate, 99));
+    for _ in 0..600 {
+      furnace.tick(&research);
+    }
+    assert_eq!(furnace.input_slot, Some((Item::IronOre, 4)));
+    assert_eq!(furnace.output_slot, Some((Item::IronPlate, 100)));
+  }
+
+  #[test]
+  fn test_stone_furnace_switches_recipe_once_products_are_taken() {
+    let position = TilePosition::new(5, 5);
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.add_to_inventory(Item::StoneFurnace, 1);
+    game_state.add_to_inventory(Item::Coal, 5);
+    game_state.add_to_inventory(Item::IronOre, 1);
+    game_state.add_to_inventory(Item::CopperOre, 1);
+    game_state.build_stone_furnace(position);
+    game_state.add_fuel_to_stone_furnace(Item::Coal, 5, position);
+    game_state.add_input_to_stone_furnace(Item::IronOre, 1, position);
+    for _ in 0..300 {
+      game_state.tick();
+    }
+    assert_eq!(game_state.stone_furnaces[&position].output_slot, Some((Item::IronPlate, 1)));
+
+    // copper plates can't go on top of the iron plate, so the furnace waits with the copper recipe selected
+    game_state.add_input_to_stone_furnace(Item::CopperOre, 1, position);
+    for _ in 0..300 {
+      game_state.tick();
+    }
+    let furnace = &game_state.stone_furnaces[&position];
+    assert_eq!((furnace.recipe, furnace.input_slot, furnace.output_slot), (Some(Recipe::CopperPlate), Some((Item::CopperOre, 1)), Some((Item::IronPlate, 1))));
+
+    let iron_plates = game_state.player_inventory().get(&Item::IronPlate).copied().unwrap_or(0);
+    assert_eq!(game_state.take_contents(position.top_left_map_position()), [(Item::IronPlate, 1)]);
+    assert_eq!(game_state.player_inventory()[&Item::IronPlate], iron_plates + 1);
+    assert_eq!(game_state.stone_furnaces[&position].output_slot, None);
+    for _ in 0..300 {
+      game_state.tick();
+    }
+    let furnace = &game_state.stone_furnaces[&position];
+    assert_eq!((furnace.input_slot, furnace.output_slot), (None, Some((Item::CopperPlate, 1))));
+  }
+
+  #[test]
+  fn test_burner_miner_output_blocked() {
+    let position = TilePosition::new(0, 0);
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.add_to_inventory(Item::Coal, 1);
+    game_state.build_iron_miner(position, Direction::North);
+    game_state.add_fuel_to_burner_miner(Item::Coal, 1, position);
+    for _ in 0..1000 {
+      game_state.tick();
+    }
+    // the second ore can't be dropped onto the first one, which stalls the miner
+    assert_eq!(game_state.items_on_ground.len(), 1);
+    assert_eq!(game_state.burner_miners[&position].result, Some(Item::IronOre));
+    assert_eq!(game_state.resources[&position].num_mined, 2);
+
+    game_state.items_on_ground.clear();
+    game_state.tick();
+    assert_eq!(game_state.items_on_ground.len(), 1);
+    assert_eq!(game_state.burner_miners[&position].result, None);
+  }
+
+  #[test]
+  fn test_burner_miners_feeding_each_other_coal() {
+    let (west, east) = (TilePosition::new(0, 0), TilePosition::new(2, 0));
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    for tile in miner_area(west).into_iter().chain(miner_area(east)) {
+      game_state.add_resource(Entity::Coal, tile, None);
+    }
+    game_state.add_to_inventory(Item::Coal, 2);
+    game_state.add_to_inventory(Item::BurnerMiningDrill, 1);
+    game_state.build_burner_miner(west, Direction::East);
+    game_state.build_burner_miner(east, Direction::West);
+    game_state.add_fuel_to_burner_miner(Item::Coal, 1, west);
+    game_state.add_fuel_to_burner_miner(Item::Coal, 1, east);
+    for _ in 0..10000 {
+      game_state.tick();
+    }
+    // a single coal lasts for far fewer mined coal than the miners produced, so each keeps the other running
+    for position in [west, east] {
+      let num_mined: u32 = miner_area(position).iter().map(|tile| game_state.resources[tile].num_mined).sum();
+      assert!(num_mined > 30, "miner at {position:?} only mined {num_mined} coal");
+      assert!(game_state.burner_miners[&position].energy_source.fuel_slot.is_some_and(|(_, count)| count > 20));
+    }
+    assert!(game_state.items_on_ground.is_empty());
+  }
+
+  #[test]
+  fn test_fluid_box_connections() {
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.add_to_inventory(Item::Pipe, 3);
+    game_state.build_pipe(TilePosition::new(0, 0));
+    game_state.build_pipe(TilePosition::new(2, 0));
+    assert!(game_state.fluid_box_connections.is_empty());
+    game_state.build_pipe(TilePosition::new(1, 0));
+    assert_eq!(game_state.fluid_box_connections, [
+      (FluidBoxOwner::Pipe(TilePosition::new(2, 0)), FluidBoxOwner::Pipe(TilePosition::new(1, 0)), OrderedFloat(0.0)),
+      (FluidBoxOwner::Pipe(TilePosition::new(0, 0)), FluidBoxOwner::Pipe(TilePosition::new(1, 0)), OrderedFloat(0.0)),
+    ]);
+  }
+
+  #[test]
+  fn test_inserter_swing_timing() {
+    let (source, inserter, target) = (TilePosition::new(0, 0), TilePosition::new(0, 1), TilePosition::new(0, 2));
+    let mut game_state = GameState::new(RandomGenerator::new(0, 0, 0));
+    game_state.add_to_inventory(Item::WoodenChest, 2);
+    game_state.add_to_inventory(Item::BurnerInserter, 1);
+    game_state.add_to_inventory(Item::Coal, 5);
+    game_state.build_chest(Item::WoodenChest, source);
+    game_state.build_chest(Item::WoodenChest, target);
+    game_state.build_inserter(Item::BurnerInserter, inserter, Direction::North);
+    game_state.add_fuel_to_burner_inserter(Item::Coal, 5, inserter);
+    game_state.chests.get_mut(&source).unwrap().insert(Item::IronPlate, 10);
+
+    let mut drop_ticks = vec![];
+    while drop_ticks.len() < 3 {
+      game_state.tick();
+      let dropped = game_state.chests[&target].slots.iter().flatten().map(|&(_, count)| count).sum::<u32>();
+      if dropped as usize > drop_ticks.len() {
+        drop_ticks.push(game_state.tick);
+      }
+    }
+    // each swing takes half a turn at 0.01 turns per tick, picking up and dropping take no extra ticks
+    let half_turn_ticks = (0.5 / GAME_CONFIG.inserters[&Entity::BurnerInserter].rotation_speed).round() as u32;
+    assert_eq!([drop_ticks[1] - drop_ticks[0], drop_ticks[2] - drop_ticks[1]], [2 * half_turn_ticks; 2]);
+  }
+}
@@ -0,0 +1,305 @@
+use std::collections::BTreeMap;
+
+use factorio_serialize::constants::Item;
+
+use crate::gameconfig::GAME_CONFIG;
+
+fn stack_size(item: Item) -> u32 {
+  GAME_CONFIG.items[&item].stack_size
+}
+
+/// Main inventory, cursor and quick bar filters of the player.
+///
+/// With automatic inventory sorting the main inventory is kept sorted and merged, so its slots follow from the item counts: full stacks
+/// ordered by item group, subgroup and order string, each item followed by its partial stack. With sorting turned off the slots are kept
+/// as they are and items go into the first slots they fit. The slot a cursor stack was picked up from stays free for it to return to.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PlayerInventory {
+  items: BTreeMap<Item, u32>,
+  cursor: Option<(Item, u32)>,
+  hand: Option<u16>,  // slot the cursor stack was taken from, only set while the cursor holds it
+  unsorted_slots: Option<Vec<Option<(Item, u32)>>>,  // slots while automatic sorting is turned off
+  quick_bar_filters: BTreeMap<u16, Item>,
+}
+impl PlayerInventory {
+  pub fn new(items: impl IntoIterator<Item = (Item, u32)>) -> Self {
+    let mut inventory = PlayerInventory::default();
+    for (item, count) in items {
+      inventory.add(item, count);
+    }
+    inventory
+  }
+  /// Item counts in the main inventory, not including the cursor.
+  pub fn items(&self) -> &BTreeMap<Item, u32> {
+    &self.items
+  }
+  pub fn count(&self, item: Item) -> u32 {
+    self.items.get(&item).copied().unwrap_or(0)
+  }
+  pub fn cursor(&self) -> Option<(Item, u32)> {
+    self.cursor
+  }
+  /// Turns automatic sorting on or off, the inventory keeps its current slots when it is turned off.
+  pub fn set_autosort(&mut self, autosort: bool) {
+    if autosort {
+      self.unsorted_slots = None;
+    } else if self.unsorted_slots.is_none() {
+      self.unsorted_slots = Some(self.slots());
+    }
+  }
+  // from Inventory::sort (loosely)
+  pub fn slots(&self) -> Vec<Option<(Item, u32)>> {
+    if let Some(slots) = &self.unsorted_slots { return slots.clone(); }
+    let mut items = self.items.iter().collect::<Vec<_>>();
+    items.sort_by_key(|(item, _)| &GAME_CONFIG.items[item].sort_key);
+    let mut stacks = items.into_iter().flat_map(|(&item, &count)| {
+      let stack_size = stack_size(item);
+      (0..count.div_ceil(stack_size)).map(move |index| (item, (count - index * stack_size).min(stack_size)))
+    });
+    (0..GAME_CONFIG.player_inventory_size as u16).map(|index| if self.hand == Some(index) { None } else { stacks.next() }).collect()
+  }
+  pub fn slot(&self, index: u16) -> Option<(Item, u32)> {
+    *self.slots().get(index as usize).unwrap_or_else(|| panic!("player inventory has no slot {index}"))
+  }
+  pub fn find_slot(&self, item: Item) -> Option<u16> {
+    self.slots().iter().position(|slot| slot.is_some_and(|(slot_item, _)| slot_item == item)).map(|index| index as u16)
+  }
+  fn slots_used(&self) -> u32 {
+    self.items.iter().map(|(&item, count)| count.div_ceil(stack_size(item))).sum::<u32>() + u32::from(self.hand.is_some())
+  }
+  // room left in an unsorted slot for the item, the slot the cursor stack was taken from has none
+  fn slot_room(&self, index: usize, slot: Option<(Item, u32)>, item: Item) -> u32 {
+    match slot {
+      _ if self.hand == Some(index as u16) => 0,
+      None => stack_size(item),
+      Some((slot_item, slot_count)) if slot_item == item => stack_size(item) - slot_count,
+      Some(_) => 0,
+    }
+  }
+  /// Number of items which fit into the free slots and the partial stacks of the item.
+  pub fn room_for(&self, item: Item) -> u32 {
+    if let Some(slots) = &self.unsorted_slots {
+      return slots.iter().enumerate().map(|(index, &slot)| self.slot_room(index, slot, item)).sum();
+    }
+    let stack_size = stack_size(item);
+    let free_slots = GAME_CONFIG.player_inventory_size.saturating_sub(self.slots_used());
+    free_slots * stack_size + (stack_size - self.count(item) % stack_size) % stack_size
+  }
+  /// Inserts as many of the items as fit, returns the number inserted.
+  pub fn insert(&mut self, item: Item, count: u32) -> u32 {
+    let inserted = count.min(self.room_for(item));
+    if inserted > 0 {
+      *self.items.entry(item).or_insert(0) += inserted;
+    }
+    if let Some(mut slots) = self.unsorted_slots.take() {
+      // from Inventory::insert (loosely), partial stacks of the item are topped up before empty slots are filled
+      let mut left = inserted;
+      for fill_empty in [false, true] {
+        for (index, slot) in slots.iter_mut().enumerate() {
+          if left == 0 || slot.is_none() != fill_empty { continue; }
+          let moved = left.min(self.slot_room(index, *slot, item));
+          if moved > 0 {
+            *slot = Some((item, slot.map_or(0, |(_, slot_count)| slot_count) + moved));
+            left -= moved;
+          }
+        }
+      }
+      self.unsorted_slots = Some(slots);
+    }
+    inserted
+  }
+  /// Inserts all items, panics if they don't fit.
+  pub fn add(&mut self, item: Item, count: u32) {
+    let inserted = self.insert(item, count);
+    assert!(inserted == count, "inventory overflow after adding {item:?} x{count}, {} didn't fit", count - inserted);
+  }
+  pub fn remove(&mut self, item: Item, count: u32) {
+    self.remove_count(item, count);
+    if let Some(slots) = &mut self.unsorted_slots {
+      // from Inventory::remove (loosely), the last stacks are taken first
+      let mut left = count;
+      for slot in slots.iter_mut().rev() {
+        let Some((slot_item, slot_count)) = slot else { continue };
+        if left == 0 { break; }
+        if *slot_item != item { continue; }
+        let taken = left.min(*slot_count);
+        *slot_count -= taken;
+        left -= taken;
+        if *slot_count == 0 {
+          *slot = None;
+        }
+      }
+    }
+  }
+  fn remove_count(&mut self, item: Item, count: u32) {
+    let inventory_count = self.items.get_mut(&item).unwrap_or_else(|| panic!("no {item:?} in inventory to remove"));
+    assert!(*inventory_count >= count, "not enough {item:?} in inventory: needed {} but found {}", count, *inventory_count);
+    *inventory_count -= count;
+    if *inventory_count == 0 {
+      self.items.remove(&item);
+    }
+  }
+  // takes items from the stack of a slot, which is any stack of the item while the inventory is sorted
+  fn remove_from_slot(&mut self, index: u16, item: Item, count: u32) {
+    let Some(slots) = &mut self.unsorted_slots else { return self.remove(item, count) };
+    let slot = &mut slots[index as usize];
+    let slot_count = match slot {
+      Some((slot_item, slot_count)) if *slot_item == item && *slot_count >= count => slot_count,
+      _ => panic!("not enough {item:?} in slot {index} to remove {count}: {slot:?}"),
+    };
+    *slot_count -= count;
+    if *slot_count == 0 {
+      *slot = None;
+    }
+    self.remove_count(item, count);
+  }
+  // puts items onto the stack of a slot, which is wherever they sort to while the inventory is sorted
+  fn put_into_slot(&mut self, index: u16, item: Item, count: u32) {
+    let Some(slots) = &mut self.unsorted_slots else { return self.add(item, count) };
+    let slot = &mut slots[index as usize];
+    let slot_count = match *slot {
+      None => 0,
+      Some((slot_item, slot_count)) if slot_item == item && slot_count + count <= stack_size(item) => slot_count,
+      _ => panic!("no room for {item:?} x{count} in slot {index}: {slot:?}"),
+    };
+    *slot = Some((item, slot_count + count));
+    *self.items.entry(item).or_insert(0) += count;
+  }
+
+  pub fn quick_bar_filter(&self, slot: u16) -> Option<Item> {
+    self.quick_bar_filters.get(&slot).copied()
+  }
+  pub fn set_quick_bar_filter(&mut self, slot: u16, item: Option<Item>) {
+    match item {
+      Some(item) => { self.quick_bar_filters.insert(slot, item); },
+      None => { self.quick_bar_filters.remove(&slot); },
+    }
+  }
+  // from Character::pickFromQuickBar (loosely), picks the first stack of the filtered item from the inventory, or half of it when splitting
+  pub fn pick_quick_bar_slot(&mut self, location: u16, split: bool) {
+    self.clear_cursor();
+    let Some(index) = self.quick_bar_filter(location).and_then(|item| self.find_slot(item)) else { return };
+    self.pick_up_slot(index, split);
+  }
+  // takes the stack of a slot into the empty cursor, or half of it rounded up
+  fn pick_up_slot(&mut self, index: u16, split: bool) {
+    assert!(self.cursor.is_none(), "picking up slot {index} with {:?} in the cursor", self.cursor);
+    let Some((item, count)) = self.slot(index) else { return };
+    let taken = if split { count.div_ceil(2) } else { count };
+    self.remove_from_slot(index, item, taken);
+    self.cursor = Some((item, taken));
+    if taken == count {
+      self.hand = Some(index);
+    }
+  }
+  /// Puts a stack taken from an entity into the empty cursor.
+  pub fn put_into_cursor(&mut self, item: Item, count: u32) {
+    assert!(self.cursor.is_none(), "putting {item:?} x{count} into the cursor holding {:?}", self.cursor);
+    self.cursor = Some((item, count));
+  }
+  // from Character::clearCursor, the cursor stack goes back into the inventory, into the slot it was taken from if it's unsorted
+  pub fn clear_cursor(&mut self) {
+    let hand = self.hand.take();
+    if let Some((item, count)) = self.cursor.take() {
+      match hand {
+        Some(index) if self.unsorted_slots.is_some() => self.put_into_slot(index, item, count),
+        _ => self.add(item, count),
+      }
+    }
+  }
+  /// Removes items from the cursor stack, returns their item.
+  pub fn take_from_cursor(&mut self, count: u32) -> Item {
+    let (item, cursor_count) = self.cursor.as_mut().expect("no item in cursor");
+    let item = *item;
+    assert!(*cursor_count >= count, "not enough {item:?} in cursor: needed {count} but found {cursor_count}");
+    *cursor_count -= count;
+    if *cursor_count == 0 {
+      self.cursor = None;
+      self.hand = None;
+    }
+    item
+  }
+  // from Character::refillCursor (loosely), an emptied cursor picks up the next stack of the item it held
+  pub fn refill_cursor(&mut self, item: Item) {
+    if self.cursor.is_some() { return; }
+    if let Some(index) = self.find_slot(item) {
+      self.pick_up_slot(index, false);
+    }
+  }
+  // from ItemStack::transferStack (loosely), clicking a slot picks up its stack, puts down the cursor stack, tops the slot up, or swaps the two
+  pub fn cursor_transfer(&mut self, index: u16) {
+    match (self.cursor, self.slot(index)) {
+      (None, Some(_)) => self.pick_up_slot(index, false),
+      (None, None) => {},
+      (Some((item, count)), None) => {
+        self.take_from_cursor(count);
+        self.put_into_slot(index, item, count);
+      },
+      (Some((item, count)), Some((slot_item, slot_count))) if item == slot_item => {
+        let moved = count.min(stack_size(item) - slot_count);
+        self.take_from_cursor(moved);
+        self.put_into_slot(index, item, moved);
+      },
+      (Some((item, count)), Some((slot_item, slot_count))) => {
+        self.remove_from_slot(index, slot_item, slot_count);
+        self.put_into_slot(index, item, count);
+        self.cursor = Some((slot_item, slot_count));
+      },
+    }
+  }
+  // from ItemStack::splitStack (loosely), right-clicking a slot picks up half of its stack, or puts down a single item from the cursor
+  pub fn cursor_split(&mut self, index: u16) {
+    match (self.cursor, self.slot(index)) {
+      (None, Some(_)) => self.pick_up_slot(index, true),
+      (Some((item, _)), None) => {
+        self.take_from_cursor(1);
+        self.put_into_slot(index, item, 1);
+      },
+      (Some((item, _)), Some((slot_item, slot_count))) if item == slot_item && slot_count < stack_size(item) => {
+        self.take_from_cursor(1);
+        self.put_into_slot(index, item, 1);
+      },
+      _ => {},
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_unsorted_inventory() {
+    let mut inventory = PlayerInventory::new([(Item::IronPlate, 150), (Item::Coal, 10)]);
+    let sorted_slots = inventory.slots();
+    inventory.set_autosort(false);
+    assert_eq!(inventory.slots(), sorted_slots);
+
+    // the coal, sorted before the iron plates, is moved to slot 10 and its old slot stays empty
+    assert_eq!(inventory.slot(0), Some((Item::Coal, 10)));
+    inventory.cursor_transfer(0);
+    inventory.cursor_transfer(10);
+    assert_eq!(inventory.slot(0), None);
+    assert_eq!(inventory.slot(10), Some((Item::Coal, 10)));
+
+    // inserted items top up partial stacks before filling the first empty slot
+    inventory.insert(Item::Coal, 5);
+    assert_eq!(inventory.slot(10), Some((Item::Coal, 15)));
+    inventory.insert(Item::IronPlate, 60);
+    assert_eq!(inventory.slots()[..3], [Some((Item::IronPlate, 10)), Some((Item::IronPlate, 100)), Some((Item::IronPlate, 100))]);
+
+    // removed items are taken from the last stacks first
+    inventory.remove(Item::IronPlate, 20);
+    assert_eq!(inventory.slots()[..3], [Some((Item::IronPlate, 10)), Some((Item::IronPlate, 100)), Some((Item::IronPlate, 80))]);
+    assert_eq!(inventory.count(Item::IronPlate), 190);
+
+    // an emptied cursor stack returns to the slot it was taken from
+    inventory.cursor_transfer(10);
+    assert_eq!(inventory.slot(10), None);
+    inventory.clear_cursor();
+    assert_eq!(inventory.slot(10), Some((Item::Coal, 15)));
+
+    inventory.set_autosort(true);
+    assert_eq!(inventory.slots(), PlayerInventory::new([(Item::IronPlate, 190), (Item::Coal, 15)]).slots());
+  }
+}
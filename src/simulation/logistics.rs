@@ -0,0 +1,326 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ordered_float::OrderedFloat;
+
+use factorio_serialize::{constants::{Entity, Item}, replay::Direction, BoundingBox, MapPosition, TilePosition};
+
+use crate::gameconfig::GAME_CONFIG;
+
+use super::{power::ElectricEnergySource, production::Research, rotate, Burner};
+
+// positions along transport lines are in 1/256 of a tile, as in TransportLine
+pub const LANE_LENGTH: u32 = 256;
+const ITEM_SPACING: u32 = 64;  // items on a lane keep a quarter tile apart
+const SIDE_LOAD_POSITION: u32 = LANE_LENGTH / 2;  // items coming in from the side join the lane in the middle of the belt
+// lanes of curved belts follow quarter circles a quarter tile inside and outside the belt's center line, unverified against the game
+const INNER_CURVE_LANE_LENGTH: u32 = 101;
+const OUTER_CURVE_LANE_LENGTH: u32 = 302;
+
+fn direction_offset(direction: Direction) -> (i32, i32) {
+  match direction {
+    Direction::North => (0, -1),
+    Direction::East => (1, 0),
+    Direction::South => (0, 1),
+    Direction::West => (-1, 0),
+    _ => panic!("belts can't face {direction:?}"),
+  }
+}
+fn clockwise(direction: Direction) -> Direction {
+  match direction {
+    Direction::North => Direction::East,
+    Direction::East => Direction::South,
+    Direction::South => Direction::West,
+    Direction::West => Direction::North,
+    _ => panic!("belts can't face {direction:?}"),
+  }
+}
+fn counterclockwise(direction: Direction) -> Direction {
+  clockwise(clockwise(clockwise(direction)))
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TransportBelt {
+  pub entity: Entity,
+  pub direction: Direction,
+  pub lanes: [Vec<(Item, u32)>; 2],  // left and right lane, items by position along the belt, front first
+}
+impl TransportBelt {
+  pub fn new(entity: Entity, direction: Direction) -> Self {
+    assert!(matches!(direction, Direction::North | Direction::East | Direction::South | Direction::West), "belts can't face {direction:?}");
+    TransportBelt { entity, direction, lanes: [vec![], vec![]] }
+  }
+  // belt speeds are whole 1/256 tiles per tick
+  fn speed(&self) -> u32 {
+    (GAME_CONFIG.transport_belts[&self.entity].speed * LANE_LENGTH as f64).round() as u32
+  }
+  // lane on the side of the belt the point lies on and how far along the belt it is, points on the center line go to the right lane,
+  // on curves the distance along the belt is scaled to the length of the lane
+  pub fn lane_position(belts: &BTreeMap<TilePosition, TransportBelt>, position: TilePosition, point: MapPosition) -> (usize, u32) {
+    let offset = point - position.center_map_position();
+    let (dx, dy) = direction_offset(belts[&position].direction);
+    let lane = if dx * offset.y.0 - dy * offset.x.0 < 0 { 0 } else { 1 };
+    let along = (dx * offset.x.0 + dy * offset.y.0 + (LANE_LENGTH / 2) as i32).clamp(0, LANE_LENGTH as i32 - 1) as u32;
+    (lane, along * lane_length(belts, position, lane) / LANE_LENGTH)
+  }
+  fn has_space(&self, lane: usize, position: u32) -> bool {
+    self.lanes[lane].iter().all(|&(_, other)| other.abs_diff(position) >= ITEM_SPACING)
+  }
+  // returns whether there was space for the item
+  pub fn insert(&mut self, lane: usize, item: Item, position: u32) -> bool {
+    if !self.has_space(lane, position) { return false; }
+    let index = self.lanes[lane].iter().position(|&(_, other)| other < position).unwrap_or(self.lanes[lane].len());
+    self.lanes[lane].insert(index, (item, position));
+    true
+  }
+  // takes up to count items accepted by the filter, front-most first, from either lane
+  pub fn take(&mut self, count: u32, accepts: impl Fn(Item) -> bool) -> Option<(Item, u32)> {
+    let (lane, index) = (0..2).flat_map(|lane| self.lanes[lane].iter().enumerate().map(move |(index, &(item, position))| (lane, index, item, position)))
+      .filter(|&(_, _, item, _)| accepts(item)).max_by_key(|&(lane, _, _, position)| (position, lane)).map(|(lane, index, _, _)| (lane, index))?;
+    let item = self.lanes[lane].remove(index).0;
+    let mut taken = 1;
+    while taken < count {
+      let Some(index) = (0..2).find_map(|lane| self.lanes[lane].iter().position(|&(other, _)| other == item).map(|index| (lane, index))) else { break };
+      self.lanes[index.0].remove(index.1);
+      taken += 1;
+    }
+    Some((item, taken))
+  }
+}
+// inner lane of a belt turning in from its only input, which comes from the side, None for straight belts
+fn curve_inner_lane(belts: &BTreeMap<TilePosition, TransportBelt>, position: TilePosition) -> Option<usize> {
+  let direction = belts[&position].direction;
+  let feeds_in = |input_direction: Direction| {
+    let (dx, dy) = direction_offset(input_direction);
+    belts.get(&TilePosition::new(position.x - dx, position.y - dy)).is_some_and(|belt| belt.direction == input_direction)
+  };
+  if feeds_in(direction) { return None; }
+  match (feeds_in(clockwise(direction)), feeds_in(counterclockwise(direction))) {
+    (true, false) => Some(0),  // coming from the left, turning left
+    (false, true) => Some(1),  // coming from the right, turning right
+    _ => None,
+  }
+}
+fn lane_length(belts: &BTreeMap<TilePosition, TransportBelt>, position: TilePosition, lane: usize) -> u32 {
+  match curve_inner_lane(belts, position) {
+    Some(inner_lane) if inner_lane == lane => INNER_CURVE_LANE_LENGTH,
+    Some(_) => OUTER_CURVE_LANE_LENGTH,
+    None => LANE_LENGTH,
+  }
+}
+// where the items leaving a belt lane go: straight onto the next belt, or onto a lane of a belt they run into from the side
+#[derive(Clone, Copy, Debug)]
+enum LaneTarget {
+  Straight(TilePosition),
+  SideLoad(TilePosition, usize),
+}
+fn lane_target(belts: &BTreeMap<TilePosition, TransportBelt>, position: TilePosition) -> Option<LaneTarget> {
+  let direction = belts[&position].direction;
+  let (dx, dy) = direction_offset(direction);
+  let next_position = TilePosition::new(position.x + dx, position.y + dy);
+  let next = belts.get(&next_position)?;
+  let side_lane = if next.direction == direction {
+    return Some(LaneTarget::Straight(next_position));
+  } else if clockwise(next.direction) == direction {
+    0  // coming from the left
+  } else if clockwise(direction) == next.direction {
+    1  // coming from the right
+  } else {
+    return None;  // belts facing each other
+  };
+  // a belt fed only from this side curves around, keeping the lanes
+  if curve_inner_lane(belts, next_position) == Some(side_lane) {
+    Some(LaneTarget::Straight(next_position))
+  } else {
+    Some(LaneTarget::SideLoad(next_position, side_lane))
+  }
+}
+// from TransportLine::update (loosely), belts running into the side of another belt side-load onto it, unless it curves around to them
+pub(super) fn update_transport_belts(belts: &mut BTreeMap<TilePosition, TransportBelt>) {
+  // belts are updated after the belts they feed into, so items move up into the space freed up in front of them
+  let mut order = vec![];
+  let mut visited = BTreeSet::new();
+  for &start in belts.keys() {
+    let mut chain = vec![];
+    let mut next = Some(start);
+    while let Some(position) = next.filter(|&position| visited.insert(position)) {
+      chain.push(position);
+      next = lane_target(belts, position).map(|target| match target { LaneTarget::Straight(position) | LaneTarget::SideLoad(position, _) => position });
+    }
+    order.extend(chain.into_iter().rev());
+  }
+  for position in order {
+    let speed = belts[&position].speed();
+    let target = lane_target(belts, position);
+    for lane in 0..2 {
+      let length = lane_length(belts, position, lane);
+      // the front item moves up to the last item of the next belt, or to the end of the belt
+      let mut limit = match target {
+        Some(LaneTarget::Straight(next)) => belts[&next].lanes[lane].last().map(|&(_, last)| (length + last).saturating_sub(ITEM_SPACING)),
+        Some(LaneTarget::SideLoad(..)) => Some(length),
+        None => Some(length - 1),
+      };
+      let mut items = std::mem::take(&mut belts.get_mut(&position).unwrap().lanes[lane]);
+      for (_, item_position) in items.iter_mut() {
+        let new_position = limit.map_or(*item_position + speed, |limit| (*item_position + speed).min(limit)).max(*item_position);
+        *item_position = new_position;
+        limit = Some(new_position.saturating_sub(ITEM_SPACING));
+      }
+      while let Some(&(item, item_position)) = items.first().filter(|&&(_, item_position)| item_position >= length) {
+        let moved = match target {
+          Some(LaneTarget::Straight(next)) => {
+            belts.get_mut(&next).unwrap().lanes[lane].push((item, item_position - length));
+            true
+          },
+          Some(LaneTarget::SideLoad(next, next_lane)) => belts.get_mut(&next).unwrap().insert(next_lane, item, SIDE_LOAD_POSITION),
+          None => unreachable!("items stop before the end of a belt without a target"),
+        };
+        if !moved {
+          items[0].1 = length - 1;  // waits at the end of the belt for space on the other belt
+          break;
+        }
+        items.remove(0);
+      }
+      belts.get_mut(&position).unwrap().lanes[lane] = items;
+    }
+  }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(super) enum InserterEnergySource {
+  Burner(Burner),
+  Electric(ElectricEnergySource),
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Inserter {
+  pub entity: Entity,
+  pub direction: Direction,
+  pub(super) energy_source: InserterEnergySource,
+  hand_rotation: OrderedFloat<f64>,  // part of a turn away from the pickup position, the drop position is half a turn away
+  hand_extension: OrderedFloat<f64>,  // distance of the hand from the inserter, in tiles
+  pub hand: Option<(Item, u32)>,
+}
+impl Inserter {
+  pub fn new(entity: Entity, direction: Direction) -> Self {
+    let config = &GAME_CONFIG.inserters[&entity];
+    let energy_source = if config.burner {
+      InserterEnergySource::Burner(Burner::with_buffer_size(Self::max_energy_per_tick(entity)))
+    } else {
+      InserterEnergySource::Electric(ElectricEnergySource::with_buffer_size(Self::max_energy_per_tick(entity) + config.drain))
+    };
+    // placed inserters hold their hand over the drop position
+    Inserter { entity, direction, energy_source, hand_rotation: OrderedFloat(0.5), hand_extension: OrderedFloat(Self::length(config.insert_position.x, config.insert_position.y)), hand: None }
+  }
+  fn length(x: f64, y: f64) -> f64 {
+    (x * x + y * y).sqrt()
+  }
+  // energy used in a tick of rotating and extending at full speed
+  fn max_energy_per_tick(entity: Entity) -> f64 {
+    let config = &GAME_CONFIG.inserters[&entity];
+    config.energy_per_rotation * config.rotation_speed + config.energy_per_movement * config.extension_speed
+  }
+  pub fn collision_box(&self, position: TilePosition) -> BoundingBox {
+    GAME_CONFIG.inserters[&self.entity].collision_box.offset(position.center_map_position())
+  }
+  pub fn pickup_position(&self, position: TilePosition) -> MapPosition {
+    position.center_map_position() + rotate(GAME_CONFIG.inserters[&self.entity].pickup_position, self.direction)
+  }
+  pub fn drop_position(&self, position: TilePosition) -> MapPosition {
+    position.center_map_position() + rotate(GAME_CONFIG.inserters[&self.entity].insert_position, self.direction)
+  }
+  // hand size, raised by the inserter capacity research
+  pub fn capacity(&self, research: &Research) -> u32 {
+    if GAME_CONFIG.inserters[&self.entity].stack {
+      1 + research.stack_inserter_capacity_bonus()
+    } else {
+      1 + research.inserter_stack_size_bonus()
+    }
+  }
+  fn hand_target(&self, to_drop: bool) -> (f64, f64) {
+    let config = &GAME_CONFIG.inserters[&self.entity];
+    let target = if to_drop { config.insert_position } else { config.pickup_position };
+    (if to_drop { 0.5 } else { 0.0 }, Self::length(target.x, target.y))
+  }
+  pub fn is_at(&self, to_drop: bool) -> bool {
+    (*self.hand_rotation, *self.hand_extension) == self.hand_target(to_drop)
+  }
+  // from Inserter::moveHand (loosely), rotation and extension progress at the same time, slowed down by missing energy
+  pub fn move_hand(&mut self, to_drop: bool) {
+    let config = &GAME_CONFIG.inserters[&self.entity];
+    let (target_rotation, target_extension) = self.hand_target(to_drop);
+    let rotation = (target_rotation - *self.hand_rotation).abs().min(config.rotation_speed);
+    let extension = (target_extension - *self.hand_extension).abs().min(config.extension_speed);
+    let desired_energy = config.energy_per_rotation * rotation + config.energy_per_movement * extension;
+    let energy = match &mut self.energy_source {
+      InserterEnergySource::Burner(burner) => burner.extract_energy(desired_energy),
+      InserterEnergySource::Electric(electric) => electric.extract_energy(desired_energy),
+    };
+    if energy <= 0.0 { return; }
+    let satisfaction = energy / desired_energy;
+    let step = |current: f64, target: f64, amount: f64| if (target - current).abs() <= amount { target } else { current + (target - current).signum() * amount };
+    *self.hand_rotation = step(*self.hand_rotation, target_rotation, rotation * satisfaction);
+    *self.hand_extension = step(*self.hand_extension, target_extension, extension * satisfaction);
+  }
+  // burner inserters fuel themselves from the fuel they pick up
+  pub fn refuel_from_hand(&mut self) {
+    let (InserterEnergySource::Burner(burner), Some((item, count))) = (&mut self.energy_source, self.hand) else { return };
+    if burner.fuel_slot.is_none() && GAME_CONFIG.fuels.contains_key(&item) {
+      burner.fuel_slot = Some((item, 1));
+      self.hand = if count > 1 { Some((item, count - 1)) } else { None };
+    }
+  }
+  pub fn tick_energy_source(&mut self) {
+    match &mut self.energy_source {
+      InserterEnergySource::Burner(burner) => burner.tick(),
+      InserterEnergySource::Electric(electric) => { electric.extract_energy(GAME_CONFIG.inserters[&self.entity].drain); },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn belt(direction: Direction) -> TransportBelt {
+    TransportBelt::new(Entity::TransportBelt, direction)
+  }
+
+  #[test]
+  fn test_belt_curve() {
+    // east into south is a right turn, keeping the lanes
+    let (start, curve, end) = (TilePosition::new(0, 0), TilePosition::new(1, 0), TilePosition::new(1, 1));
+    let mut belts = BTreeMap::from([(start, belt(Direction::East)), (curve, belt(Direction::South)), (end, belt(Direction::South))]);
+    assert_eq!(curve_inner_lane(&belts, curve), Some(1));
+    assert_eq!((lane_length(&belts, curve, 0), lane_length(&belts, curve, 1)), (OUTER_CURVE_LANE_LENGTH, INNER_CURVE_LANE_LENGTH));
+    belts.get_mut(&start).unwrap().lanes = [vec![(Item::IronPlate, 0)], vec![(Item::CopperPlate, 0)]];
+
+    // at 8 per tick, the inner lane item is through the curve after 45 ticks, and the outer lane item after 70
+    let mut ticks = 0;
+    while belts[&end].lanes[1].is_empty() {
+      update_transport_belts(&mut belts);
+      ticks += 1;
+    }
+    assert_eq!(ticks, (LANE_LENGTH + INNER_CURVE_LANE_LENGTH).div_ceil(8));
+    while belts[&end].lanes[0].is_empty() {
+      update_transport_belts(&mut belts);
+      ticks += 1;
+    }
+    assert_eq!(ticks, (LANE_LENGTH + OUTER_CURVE_LANE_LENGTH).div_ceil(8));
+    assert_eq!(belts[&end].lanes, [vec![(Item::IronPlate, 2)], vec![(Item::CopperPlate, 203)]]);
+  }
+
+  #[test]
+  fn test_belt_side_loading() {
+    // with a second input from the other side, the belt stays straight and both inputs side-load onto it
+    let (left, right, target) = (TilePosition::new(0, 0), TilePosition::new(2, 0), TilePosition::new(1, 0));
+    let mut belts = BTreeMap::from([(left, belt(Direction::East)), (right, belt(Direction::West)), (target, belt(Direction::South))]);
+    assert_eq!(curve_inner_lane(&belts, target), None);
+    assert!(matches!(lane_target(&belts, left), Some(LaneTarget::SideLoad(position, 1)) if position == target));
+    assert!(matches!(lane_target(&belts, right), Some(LaneTarget::SideLoad(position, 0)) if position == target));
+    belts.get_mut(&left).unwrap().lanes[0].push((Item::IronPlate, 0));
+    for _ in 0..LANE_LENGTH.div_ceil(8) {
+      update_transport_belts(&mut belts);
+    }
+    assert_eq!(belts[&target].lanes[1], [(Item::IronPlate, SIDE_LOAD_POSITION)]);
+  }
+}
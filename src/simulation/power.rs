@@ -0,0 +1,306 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ordered_float::OrderedFloat;
+
+use factorio_serialize::{constants::{Entity, Fluid}, replay::Direction, BoundingBox, MapPosition, TilePosition, Vector};
+
+use crate::gameconfig::{FluidBoxConfig, GAME_CONFIG};
+
+use super::{rotate, Burner};
+
+// from ElectricPole::connectToNeighbours
+const MAX_POLE_CONNECTIONS: usize = 5;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FluidBox {
+  pub fluid: Option<Fluid>,
+  pub amount: OrderedFloat<f64>,
+  pub temperature: OrderedFloat<f64>,
+  filter: Option<Fluid>,
+  base_area: OrderedFloat<f64>,
+  capacity: OrderedFloat<f64>,
+}
+impl FluidBox {
+  fn new(config: &FluidBoxConfig, filter: Option<Fluid>) -> Self {
+    FluidBox { fluid: None, amount: OrderedFloat(0.0), temperature: OrderedFloat(0.0), filter, base_area: OrderedFloat(config.base_area), capacity: OrderedFloat(config.capacity) }
+  }
+  fn level(&self) -> f64 {
+    *self.amount / *self.base_area
+  }
+  fn free_space(&self) -> f64 {
+    *self.capacity - *self.amount
+  }
+  fn accepts(&self, fluid: Fluid) -> bool {
+    self.filter.is_none_or(|filter| filter == fluid) && self.fluid.is_none_or(|current| current == fluid)
+  }
+  // the temperatures of the contained and added fluid mix by amount
+  fn insert(&mut self, fluid: Fluid, amount: f64, temperature: f64) {
+    assert!(self.accepts(fluid), "fluid box with {:?} can't take {fluid:?}", self.fluid);
+    self.temperature = (self.temperature * self.amount + temperature * amount) / (self.amount + amount);
+    self.amount += amount;
+    self.fluid = Some(fluid);
+  }
+  fn remove(&mut self, amount: f64) {
+    self.amount -= amount;
+    if *self.amount <= 0.0 {
+      *self = FluidBox { fluid: None, amount: OrderedFloat(0.0), temperature: OrderedFloat(0.0), ..*self };
+    }
+  }
+}
+// from FluidBox::update, the flow through a connection is driven by the difference in fluid levels and keeps part of the flow of the last
+// tick, it can't take more than the source holds or the target has room for
+const FLOW_LEVEL_FACTOR: f64 = 0.4;
+const FLOW_MOMENTUM_FACTOR: f64 = 0.59;
+// the flow from a to b, negative if the fluid goes from b to a
+pub(super) fn connection_flow(a: &FluidBox, b: &FluidBox, last_flow: f64) -> f64 {
+  let flow = FLOW_LEVEL_FACTOR * (a.level() - b.level()) * a.base_area.0.min(*b.base_area) + FLOW_MOMENTUM_FACTOR * last_flow;
+  if flow >= 0.0 { limit_flow(a, b, flow) } else { -limit_flow(b, a, -flow) }
+}
+fn limit_flow(source: &FluidBox, target: &FluidBox, flow: f64) -> f64 {
+  match source.fluid {
+    Some(fluid) if target.accepts(fluid) => flow.min(*source.amount).min(target.free_space()),
+    _ => 0.0,
+  }
+}
+// moves the flow computed for the tick, as far as the fluid boxes allow after the connections moved before; returns the flow moved
+pub(super) fn move_fluid(a: &mut FluidBox, b: &mut FluidBox, flow: f64) -> f64 {
+  if flow >= 0.0 { transfer(a, b, flow) } else { -transfer(b, a, -flow) }
+}
+fn transfer(source: &mut FluidBox, target: &mut FluidBox, flow: f64) -> f64 {
+  let amount = limit_flow(source, target, flow);
+  if amount > 0.0 {
+    target.insert(source.fluid.unwrap(), amount, *source.temperature);
+    source.remove(amount);
+  }
+  amount
+}
+// pipe connections of a fluid box as pairs of the tile they start from and the neighbouring tile they connect to
+pub(super) fn pipe_connections(config: &FluidBoxConfig, position: MapPosition, direction: Direction) -> Vec<(TilePosition, TilePosition)> {
+  config.pipe_connections.iter().map(|&connection| {
+    let offset = rotate(connection, direction);
+    let inward = if offset.x.abs() > offset.y.abs() { Vector::new(-offset.x.signum(), 0.0) } else { Vector::new(0.0, -offset.y.signum()) };
+    ((position + offset + inward).to_tile_position(), (position + offset).to_tile_position())
+  }).collect()
+}
+// entities touching a bounding box only at its edges don't overlap it
+pub(super) fn overlaps(a: &BoundingBox, b: &BoundingBox) -> bool {
+  a.left_top.x < b.right_bottom.x && b.left_top.x < a.right_bottom.x && a.left_top.y < b.right_bottom.y && b.left_top.y < a.right_bottom.y
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OffshorePump {
+  pub entity: Entity,
+  pub position: MapPosition,
+  pub direction: Direction,
+  pub fluid_box: FluidBox,
+}
+impl OffshorePump {
+  pub fn new(entity: Entity, position: MapPosition, direction: Direction) -> Self {
+    OffshorePump { entity, position, direction, fluid_box: FluidBox::new(&GAME_CONFIG.offshore_pumps[&entity].fluid_box, Some(Fluid::Water)) }
+  }
+  pub fn collision_box(&self) -> BoundingBox {
+    GAME_CONFIG.offshore_pumps[&self.entity].collision_box.with_direction(self.direction).offset(self.position)
+  }
+  // from OffshorePump::update, the pump needs no energy and fills its fluid box each tick
+  pub fn tick(&mut self) {
+    let amount = GAME_CONFIG.offshore_pumps[&self.entity].pumping_speed.min(self.fluid_box.free_space());
+    if amount > 0.0 {
+      self.fluid_box.insert(Fluid::Water, amount, GAME_CONFIG.fluids[&Fluid::Water].default_temperature);
+    }
+  }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Boiler {
+  pub entity: Entity,
+  pub position: MapPosition,
+  pub direction: Direction,
+  pub(super) energy_source: Burner,
+  pub fluid_box: FluidBox,
+  pub output_fluid_box: FluidBox,
+}
+impl Boiler {
+  pub fn new(entity: Entity, position: MapPosition, direction: Direction) -> Self {
+    let config = &GAME_CONFIG.boilers[&entity];
+    Boiler {
+      entity,
+      position,
+      direction,
+      energy_source: Burner::with_buffer_size(config.energy_consumption),
+      fluid_box: FluidBox::new(&config.fluid_box, Some(Fluid::Water)),
+      output_fluid_box: FluidBox::new(&config.output_fluid_box, Some(Fluid::Steam)),
+    }
+  }
+  pub fn collision_box(&self) -> BoundingBox {
+    GAME_CONFIG.boilers[&self.entity].collision_box.with_direction(self.direction).offset(self.position)
+  }
+  // from Boiler::update (loosely), heats as much water to the target temperature as the energy, water and steam space allow
+  pub fn tick(&mut self) {
+    let config = &GAME_CONFIG.boilers[&self.entity];
+    if *self.fluid_box.amount > 0.0 {
+      let energy_per_unit = (config.target_temperature - *self.fluid_box.temperature) * GAME_CONFIG.fluids[&Fluid::Water].heat_capacity;
+      let amount = self.fluid_box.amount.0.min(self.output_fluid_box.free_space()).min(config.energy_consumption / energy_per_unit);
+      if amount > 0.0 {
+        let converted_amount = self.energy_source.extract_energy(amount * energy_per_unit) / energy_per_unit;
+        if converted_amount > 0.0 {
+          self.fluid_box.remove(converted_amount);
+          self.output_fluid_box.insert(Fluid::Steam, converted_amount, config.target_temperature);
+        }
+      }
+    }
+    self.energy_source.tick();
+  }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SteamEngine {
+  pub entity: Entity,
+  pub position: MapPosition,
+  pub direction: Direction,
+  pub fluid_box: FluidBox,
+}
+impl SteamEngine {
+  pub fn new(entity: Entity, position: MapPosition, direction: Direction) -> Self {
+    SteamEngine { entity, position, direction, fluid_box: FluidBox::new(&GAME_CONFIG.generators[&entity].fluid_box, Some(Fluid::Steam)) }
+  }
+  pub fn collision_box(&self) -> BoundingBox {
+    GAME_CONFIG.generators[&self.entity].collision_box.with_direction(self.direction).offset(self.position)
+  }
+  // from Generator::getEnergyPerFluidUnit, steam above the maximum temperature gives no extra energy
+  fn energy_per_unit(&self) -> f64 {
+    let config = &GAME_CONFIG.generators[&self.entity];
+    let steam = &GAME_CONFIG.fluids[&Fluid::Steam];
+    0f64.max(self.fluid_box.temperature.0.min(config.maximum_temperature) - steam.default_temperature) * steam.heat_capacity * config.effectivity
+  }
+  // from Generator::getMaxEnergyProduction
+  fn max_energy_output(&self) -> f64 {
+    self.fluid_box.amount.0.min(GAME_CONFIG.generators[&self.entity].fluid_usage_per_tick) * self.energy_per_unit()
+  }
+  // consumes the steam needed for the produced energy
+  fn produce(&mut self, energy: f64) {
+    if energy > 0.0 {
+      self.fluid_box.remove(energy / self.energy_per_unit());
+    }
+  }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ElectricEnergySource {
+  pub buffer: OrderedFloat<f64>,
+  buffer_capacity: OrderedFloat<f64>,
+}
+impl ElectricEnergySource {
+  pub fn with_buffer_size(buffer_capacity: f64) -> Self {
+    ElectricEnergySource { buffer: OrderedFloat(0.0), buffer_capacity: OrderedFloat(buffer_capacity) }
+  }
+  fn demand(&self) -> f64 {
+    *self.buffer_capacity - *self.buffer
+  }
+  // from ElectricEnergySource::extractEnergy
+  pub fn extract_energy(&mut self, desired_energy: f64) -> f64 {
+    let provided_energy = desired_energy.min(*self.buffer);
+    self.buffer -= provided_energy;
+    provided_energy
+  }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ElectricPole {
+  pub entity: Entity,
+  pub connections: BTreeSet<TilePosition>,
+}
+impl ElectricPole {
+  pub fn new(entity: Entity) -> Self {
+    assert!(matches!(entity, Entity::SmallElectricPole | Entity::MediumElectricPole), "only 1x1 electric poles are supported, not {entity:?}");
+    ElectricPole { entity, connections: BTreeSet::new() }
+  }
+  // the supply area is centered on the pole, which sits in the middle of its tile
+  pub fn supply_area(&self, position: TilePosition) -> BoundingBox {
+    BoundingBox::tile_box(position, GAME_CONFIG.electric_poles[&self.entity].supply_area_distance - 0.5)
+  }
+}
+// from ElectricPole::connectToNeighbours (loosely), wires go to the closest poles in reach of both, up to the connection limit of each
+pub(super) fn connect_electric_pole(poles: &mut BTreeMap<TilePosition, ElectricPole>, position: TilePosition) {
+  let reach = GAME_CONFIG.electric_poles[&poles[&position].entity].maximum_wire_distance;
+  let distance_squared = |other: &TilePosition| ((other.x - position.x).pow(2) + (other.y - position.y).pow(2)) as f64;
+  let mut neighbours: Vec<TilePosition> = poles.iter()
+    .filter(|&(other, pole)| *other != position && pole.connections.len() < MAX_POLE_CONNECTIONS)
+    .filter(|&(other, pole)| distance_squared(other) <= reach.min(GAME_CONFIG.electric_poles[&pole.entity].maximum_wire_distance).powi(2))
+    .map(|(&other, _)| other).collect();
+  neighbours.sort_by(|a, b| distance_squared(a).total_cmp(&distance_squared(b)).then(a.cmp(b)));
+  for neighbour in neighbours.into_iter().take(MAX_POLE_CONNECTIONS) {
+    poles.get_mut(&neighbour).unwrap().connections.insert(position);
+    poles.get_mut(&position).unwrap().connections.insert(neighbour);
+  }
+}
+// poles connected by wires, ordered by their first pole
+pub(super) fn electric_networks(poles: &BTreeMap<TilePosition, ElectricPole>) -> Vec<Vec<TilePosition>> {
+  let mut visited = BTreeSet::new();
+  let mut networks = vec![];
+  for &start in poles.keys() {
+    if !visited.insert(start) { continue; }
+    let mut network = vec![start];
+    let mut next = 0;
+    while next < network.len() {
+      for &neighbour in &poles[&network[next]].connections {
+        if visited.insert(neighbour) {
+          network.push(neighbour);
+        }
+      }
+      next += 1;
+    }
+    networks.push(network);
+  }
+  networks
+}
+// from ElectricNetwork::update (loosely), consumers get their demand scaled by the satisfaction, and generators run at the same fraction of their maximum output
+pub(super) fn update_electric_network(generators: &mut [&mut SteamEngine], consumers: &mut [&mut ElectricEnergySource]) {
+  let demand: f64 = consumers.iter().map(|consumer| consumer.demand()).sum();
+  let available: f64 = generators.iter().map(|generator| generator.max_energy_output()).sum();
+  if demand <= 0.0 || available <= 0.0 { return; }
+  let satisfaction = (available / demand).min(1.0);
+  let load = (demand / available).min(1.0);
+  for consumer in consumers.iter_mut() {
+    consumer.buffer += consumer.demand() * satisfaction;
+  }
+  for generator in generators.iter_mut() {
+    let energy = generator.max_energy_output() * load;
+    generator.produce(energy);
+  }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Pipe {
+  pub fluid_box: FluidBox,
+}
+impl Pipe {
+  pub fn new() -> Self {
+    Pipe { fluid_box: FluidBox::new(&GAME_CONFIG.pipe_fluid_box, None) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pipe_fluid_box(fluid: Fluid, amount: f64) -> FluidBox {
+    let mut fluid_box = Pipe::new().fluid_box;
+    fluid_box.insert(fluid, amount, 15.0);
+    fluid_box
+  }
+
+  #[test]
+  fn test_connection_flow() {
+    let (mut a, mut b) = (pipe_fluid_box(Fluid::Water, 100.0), Pipe::new().fluid_box);
+    assert_eq!(connection_flow(&a, &b, 0.0), FLOW_LEVEL_FACTOR * 100.0);
+    assert_eq!(connection_flow(&b, &a, 0.0), -FLOW_LEVEL_FACTOR * 100.0);
+    // the flow of the last tick keeps fluid moving once the levels are equal, as far as the target has room
+    assert_eq!(move_fluid(&mut a, &mut b, 50.0), 50.0);
+    assert_eq!(connection_flow(&a, &b, 20.0), FLOW_MOMENTUM_FACTOR * 20.0);
+    assert_eq!(connection_flow(&pipe_fluid_box(Fluid::Water, 95.0), &pipe_fluid_box(Fluid::Water, 95.0), 20.0), 5.0);
+    // different fluids don't mix
+    assert_eq!(connection_flow(&a, &pipe_fluid_box(Fluid::Steam, 10.0), 0.0), 0.0);
+    assert_eq!(move_fluid(&mut a, &mut b, -10.0), -10.0);
+    assert_eq!((*a.amount, *b.amount), (60.0, 40.0));
+  }
+}
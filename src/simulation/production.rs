@@ -0,0 +1,355 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ordered_float::OrderedFloat;
+
+use factorio_serialize::{constants::{Entity, Item, Recipe, Technology}, BoundingBox, TilePosition};
+
+use crate::gameconfig::{ProductConfig, GAME_CONFIG};
+
+use super::{power::ElectricEnergySource};
+
+// ingredients and results of recipes used in assembling machines, which only handle items with fixed amounts
+fn recipe_items(recipe: Recipe, products: &[ProductConfig]) -> Vec<(Item, u32)> {
+  products.iter().map(|product| match *product {
+    ProductConfig::Item { id, amount, amount_max, probability } if amount == amount_max && probability == 1.0 => (id, amount),
+    _ => panic!("recipe {recipe:?} has unsupported product {product:?}"),
+  }).collect()
+}
+
+// from CraftingMachine::getAutomatedInsertionLimit (loosely), inserters fill up ingredients for two crafts, or enough for 1.166 seconds of faster crafts
+pub(super) fn automated_insertion_limit(amount: u32, energy_required: f64, crafting_speed: f64) -> u32 {
+  amount * 2f64.max(1.0 + (1.166 / (energy_required / crafting_speed)).ceil()) as u32
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AssemblingMachine {
+  pub entity: Entity,
+  pub energy_source: ElectricEnergySource,
+  pub recipe: Option<Recipe>,
+  energy_stored_in_product: OrderedFloat<f64>,
+  extra_energy_for_product: OrderedFloat<f64>,
+  input_slots: Vec<(Item, u32)>,  // one per recipe ingredient
+  output_slots: Vec<(Item, u32)>,  // one per recipe result
+}
+impl AssemblingMachine {
+  pub fn new(entity: Entity) -> Self {
+    let config = &GAME_CONFIG.assembling_machines[&entity];
+    AssemblingMachine {
+      entity,
+      energy_source: ElectricEnergySource::with_buffer_size(config.energy_usage + config.drain),  // buffers one tick of energy usage
+      recipe: None,
+      energy_stored_in_product: OrderedFloat(0.0),
+      extra_energy_for_product: OrderedFloat(0.0),
+      input_slots: vec![],
+      output_slots: vec![],
+    }
+  }
+  // 3x3 entity, centered on the given tile
+  pub fn collision_box(&self, position: TilePosition) -> BoundingBox {
+    GAME_CONFIG.assembling_machines[&self.entity].collision_box.offset(position.center_map_position())
+  }
+  pub fn can_craft(&self, recipe: Recipe) -> bool {
+    GAME_CONFIG.assembling_machines[&self.entity].crafting_categories.contains(&GAME_CONFIG.recipes[&recipe].category)
+  }
+  // from AssemblingMachine::setupRecipe, changing the recipe gives back all contents, including the ingredients of an unfinished craft
+  pub fn set_recipe(&mut self, recipe: Option<Recipe>) -> Vec<(Item, u32)> {
+    let mut contents: Vec<_> = self.input_slots.drain(..).chain(self.output_slots.drain(..)).collect();
+    if let Some(current_recipe) = self.recipe.filter(|_| *self.energy_stored_in_product > 0.0) {
+      contents.extend(recipe_items(current_recipe, &GAME_CONFIG.recipes[&current_recipe].ingredients));
+    }
+    self.recipe = recipe;
+    self.energy_stored_in_product = OrderedFloat(0.0);
+    self.extra_energy_for_product = OrderedFloat(0.0);
+    if let Some(recipe) = recipe {
+      assert!(self.can_craft(recipe), "{:?} can't craft {recipe:?} of category {}", self.entity, GAME_CONFIG.recipes[&recipe].category);
+      self.input_slots = recipe_items(recipe, &GAME_CONFIG.recipes[&recipe].ingredients).into_iter().map(|(item, _)| (item, 0)).collect();
+      self.output_slots = recipe_items(recipe, &GAME_CONFIG.recipes[&recipe].results).into_iter().map(|(item, _)| (item, 0)).collect();
+    }
+    contents.into_iter().filter(|&(_, count)| count > 0).collect()
+  }
+  // ingredients go into the slot of the recipe ingredient, up to a stack; returns the number inserted
+  pub fn insert(&mut self, item: Item, count: u32) -> u32 {
+    let Some((_, slot_count)) = self.input_slots.iter_mut().find(|(slot_item, _)| *slot_item == item) else { return 0 };
+    let inserted = count.min(GAME_CONFIG.items[&item].stack_size - *slot_count);
+    *slot_count += inserted;
+    inserted
+  }
+  // whether inserters would add the item to the ingredients
+  pub fn accepts_automated(&self, item: Item) -> bool {
+    let Some(recipe) = self.recipe else { return false };
+    let crafting_speed = GAME_CONFIG.assembling_machines[&self.entity].crafting_speed;
+    self.input_slots.iter().zip(recipe_items(recipe, &GAME_CONFIG.recipes[&recipe].ingredients))
+      .any(|(&(slot_item, count), (_, amount))| slot_item == item && count < automated_insertion_limit(amount, GAME_CONFIG.recipes[&recipe].energy_required, crafting_speed))
+  }
+  // takes up to count of the first result accepted by the filter
+  pub fn take_result(&mut self, count: u32, accepts: impl Fn(Item) -> bool) -> Option<(Item, u32)> {
+    let (item, slot_count) = self.output_slots.iter_mut().find(|(item, count)| *count > 0 && accepts(*item))?;
+    let taken = count.min(*slot_count);
+    *slot_count -= taken;
+    Some((*item, taken))
+  }
+  pub fn slot_count(&self, results: bool) -> usize {
+    if results { self.output_slots.len() } else { self.input_slots.len() }
+  }
+  // runs f on an ingredient or result slot, which keeps its item when emptied
+  pub fn with_slot<R>(&mut self, results: bool, index: usize, f: impl FnOnce(&mut Option<(Item, u32)>) -> R) -> R {
+    let slots = if results { &mut self.output_slots } else { &mut self.input_slots };
+    let (item, count) = slots.get_mut(index).unwrap_or_else(|| panic!("{:?} has no {} slot {index}", self.entity, if results { "result" } else { "ingredient" }));
+    let mut slot = Some((*item, *count)).filter(|&(_, count)| count > 0);
+    let result = f(&mut slot);
+    match slot {
+      Some((slot_item, slot_count)) => {
+        assert!(slot_item == *item, "{:?} slot for {item:?} can't hold {slot_item:?}", self.entity);
+        *count = slot_count;
+      },
+      None => *count = 0,
+    }
+    result
+  }
+  // from CraftingMachine::extractEnergyAndPollute
+  fn extract_energy(&mut self) -> f64 {
+    let config = &GAME_CONFIG.assembling_machines[&self.entity];
+    let energy_consumed = self.energy_source.extract_energy(config.energy_usage);
+    let effective_crafting_speed = energy_consumed / config.energy_usage * config.crafting_speed;
+    if effective_crafting_speed > 0.0 {
+      self.energy_stored_in_product += effective_crafting_speed / 60.0;
+    }
+    effective_crafting_speed
+  }
+  // from CraftingMachine::update / CraftingMachine::useRecipeOnSource
+  pub fn tick(&mut self) {
+    // the drain is consumed whether the machine works or not
+    self.energy_source.extract_energy(GAME_CONFIG.assembling_machines[&self.entity].drain);
+    let Some(recipe) = self.recipe else { return };
+    let recipe_config = &GAME_CONFIG.recipes[&recipe];
+    if *self.energy_stored_in_product >= recipe_config.energy_required {
+      *self.extra_energy_for_product = 0f64.max(*self.energy_stored_in_product - recipe_config.energy_required);
+      self.energy_stored_in_product = OrderedFloat(0.0);
+      for ((_, count), (_, amount)) in self.output_slots.iter_mut().zip(recipe_items(recipe, &recipe_config.results)) {
+        *count += amount;
+      }
+    }
+    let new_craft = self.energy_stored_in_product == 0.0;
+    if new_craft && !(self.has_ingredients() && self.can_hold_results()) {
+      // missing ingredients or output full, the machine waits without using energy
+      return;
+    }
+    if self.extract_energy() > 0.0 && new_craft {
+      for ((_, count), (_, amount)) in self.input_slots.iter_mut().zip(recipe_items(recipe, &recipe_config.ingredients)) {
+        *count -= amount;
+      }
+      self.energy_stored_in_product += self.extra_energy_for_product;
+      self.extra_energy_for_product = OrderedFloat(0.0);
+    }
+  }
+  fn has_ingredients(&self) -> bool {
+    let recipe = self.recipe.expect("no recipe set");
+    self.input_slots.iter().zip(recipe_items(recipe, &GAME_CONFIG.recipes[&recipe].ingredients)).all(|(&(_, count), (_, amount))| count >= amount)
+  }
+  // from CraftingMachine::canInsertResults, each result has to fit into its output slot as a whole
+  fn can_hold_results(&self) -> bool {
+    let recipe = self.recipe.expect("no recipe set");
+    self.output_slots.iter().zip(recipe_items(recipe, &GAME_CONFIG.recipes[&recipe].results)).all(|(&(item, count), (_, amount))| count + amount <= GAME_CONFIG.items[&item].stack_size)
+  }
+  // from CraftingMachine::getActivityProgress
+  pub fn get_crafting_progress(&self) -> f64 {
+    if let Some(recipe) = self.recipe {
+      1f64.min(*self.energy_stored_in_product / GAME_CONFIG.recipes[&recipe].energy_required)
+    } else { 0.0 }
+  }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Lab {
+  pub entity: Entity,
+  pub energy_source: ElectricEnergySource,
+  inputs: BTreeMap<Item, u32>,
+  durabilities: BTreeMap<Item, OrderedFloat<f64>>,  // remaining durability of the science pack in use, taken from the inputs
+  unit: Option<(Technology, OrderedFloat<f64>)>,  // research unit in progress with its done part
+}
+impl Lab {
+  pub fn new(entity: Entity) -> Self {
+    Lab {
+      entity,
+      energy_source: ElectricEnergySource::with_buffer_size(GAME_CONFIG.labs[&entity].energy_usage),  // buffers one tick of energy usage
+      inputs: BTreeMap::new(),
+      durabilities: BTreeMap::new(),
+      unit: None,
+    }
+  }
+  // 3x3 entity, centered on the given tile
+  pub fn collision_box(&self, position: TilePosition) -> BoundingBox {
+    GAME_CONFIG.labs[&self.entity].collision_box.offset(position.center_map_position())
+  }
+  // each science pack type has its own slot, holding up to a stack
+  pub fn insert(&mut self, item: Item, count: u32) -> u32 {
+    if !GAME_CONFIG.labs[&self.entity].inputs.contains(&item) { return 0; }
+    let slot_count = self.inputs.entry(item).or_insert(0);
+    let inserted = count.min(GAME_CONFIG.items[&item].stack_size - *slot_count);
+    *slot_count += inserted;
+    if *slot_count == 0 {
+      self.inputs.remove(&item);
+    }
+    inserted
+  }
+  pub fn can_insert(&self, item: Item) -> bool {
+    GAME_CONFIG.labs[&self.entity].inputs.contains(&item) && self.inputs.get(&item).is_none_or(|&count| count < GAME_CONFIG.items[&item].stack_size)
+  }
+  fn has_science_pack(&self, item: Item) -> bool {
+    self.durabilities.get(&item).is_some_and(|durability| durability.0 > 0.0) || self.inputs.contains_key(&item)
+  }
+  // uses up a science pack from the inputs once the durability of the one in use runs out
+  fn consume(&mut self, item: Item, amount: f64) {
+    let durability = self.durabilities.entry(item).or_insert(OrderedFloat(0.0));
+    while durability.0 < amount {
+      let Some(count) = self.inputs.get_mut(&item) else { break };
+      *count -= 1;
+      if *count == 0 {
+        self.inputs.remove(&item);
+      }
+      *durability += GAME_CONFIG.tools[&item].durability;
+    }
+    durability.0 = 0f64.max(durability.0 - amount);
+  }
+  // from Lab::update (loosely), the science packs in use lose durability with the progress made each tick, a unit is done after its time
+  // and a unit of a different technology in progress is abandoned
+  pub fn tick(&mut self, research: &mut Research) {
+    let Some(technology) = research.current else { return };
+    let technology_config = &GAME_CONFIG.technologies[&technology];
+    if !technology_config.ingredients.iter().all(|&(item, _)| self.has_science_pack(item)) { return; }
+    let config = &GAME_CONFIG.labs[&self.entity];
+    let energy_satisfaction = self.energy_source.extract_energy(config.energy_usage) / config.energy_usage;
+    if energy_satisfaction <= 0.0 { return; }
+    if self.unit.is_none_or(|(unit_technology, _)| unit_technology != technology) {
+      self.unit = Some((technology, OrderedFloat(0.0)));
+    }
+    let progress = config.researching_speed * energy_satisfaction / (technology_config.time * 60.0);
+    for &(item, amount) in &technology_config.ingredients {
+      self.consume(item, progress * amount as f64);
+    }
+    let (_, done) = self.unit.as_mut().unwrap();
+    *done += progress;
+    if done.0 >= 1.0 {
+      self.unit = None;
+      research.finish_unit();
+    }
+  }
+}
+
+// from ResearchManager, the research state of the player's force
+#[derive(Clone, Debug, Eq, Default, Hash, PartialEq)]
+pub struct Research {
+  pub current: Option<Technology>,
+  units_done: BTreeMap<Technology, u64>,  // finished units of started technologies, kept when switching research
+  pub researched: BTreeSet<Technology>,
+  unlocked_recipes: BTreeSet<Recipe>,
+}
+impl Research {
+  // from the ResearchManager of a saved force, progress of the current research is kept in whole units as labs hold the partial one
+  pub fn from_force(researched: impl IntoIterator<Item = Technology>, current: Option<(Technology, f64)>) -> Self {
+    let mut research = Research::default();
+    for technology in researched {
+      research.finish(technology);
+    }
+    if let Some((technology, progress)) = current {
+      let units_done = (progress * GAME_CONFIG.technologies[&technology].count as f64).floor() as u64;
+      if units_done > 0 {
+        research.units_done.insert(technology, units_done);
+      }
+      research.current = Some(technology);
+    }
+    research
+  }
+  pub fn is_recipe_enabled(&self, recipe: Recipe) -> bool {
+    GAME_CONFIG.recipes.get(&recipe).is_some_and(|config| config.enabled) || self.unlocked_recipes.contains(&recipe)
+  }
+  pub fn inserter_stack_size_bonus(&self) -> u32 {
+    self.researched.iter().map(|technology| GAME_CONFIG.technologies[technology].inserter_stack_size_bonus).sum()
+  }
+  pub fn stack_inserter_capacity_bonus(&self) -> u32 {
+    self.researched.iter().map(|technology| GAME_CONFIG.technologies[technology].stack_inserter_capacity_bonus).sum()
+  }
+  // from ResearchManager::startResearch
+  pub fn start(&mut self, technology: Technology) -> Result<(), String> {
+    if self.researched.contains(&technology) { return Err(format!("{technology:?} is already researched")); }
+    let config = GAME_CONFIG.technologies.get(&technology).ok_or_else(|| format!("{technology:?} is not simulated"))?;
+    let missing: Vec<_> = config.prerequisites.iter().filter(|prerequisite| !self.researched.contains(prerequisite)).collect();
+    if !missing.is_empty() { return Err(format!("can't research {technology:?} without prerequisites {missing:?}")); }
+    self.current = Some(technology);
+    Ok(())
+  }
+  pub fn cancel(&mut self, technology: Technology) {
+    if self.current == Some(technology) {
+      self.current = None;
+    }
+  }
+  // progress of the current research as in LuaForce::research_progress, without the units labs are working on
+  pub fn progress(&self) -> f64 {
+    self.current.map_or(0.0, |technology| self.units_done.get(&technology).map_or(0, |&units| units) as f64 / GAME_CONFIG.technologies[&technology].count as f64)
+  }
+  // from ResearchManager::researchFinished once all units are done, finishing a technology unlocks its recipes
+  fn finish_unit(&mut self) {
+    let technology = self.current.expect("no research in progress");
+    let units_done = self.units_done.entry(technology).or_insert(0);
+    *units_done += 1;
+    if *units_done >= GAME_CONFIG.technologies[&technology].count {
+      self.units_done.remove(&technology);
+      self.finish(technology);
+      self.current = None;
+    }
+  }
+  fn finish(&mut self, technology: Technology) {
+    self.researched.insert(technology);
+    self.unlocked_recipes.extend(GAME_CONFIG.technologies[&technology].unlocked_recipes.iter().copied());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_lab_finishes_research() {
+    let mut lab = Lab::new(Entity::Lab);
+    let mut research = Research::default();
+    research.start(Technology::Automation).unwrap();
+    assert_eq!(lab.insert(Item::AutomationSciencePack, 10), 10);
+    let mut ticks = 0;
+    while research.current.is_some() && ticks < 7000 {
+      lab.energy_source.buffer = OrderedFloat(GAME_CONFIG.labs[&Entity::Lab].energy_usage);
+      lab.tick(&mut research);
+      ticks += 1;
+    }
+    // 10 units of 10 seconds each
+    assert_eq!(ticks, 6000);
+    assert!(research.researched.contains(&Technology::Automation));
+    assert!(research.is_recipe_enabled(Recipe::AssemblingMachine1));
+    assert!(lab.inputs.is_empty());
+  }
+
+  #[test]
+  fn test_lab_drains_science_packs_per_tick() {
+    let mut lab = Lab::new(Entity::Lab);
+    let mut research = Research::default();
+    research.start(Technology::Automation).unwrap();
+    assert_eq!(lab.insert(Item::AutomationSciencePack, 2), 2);
+    for _ in 0..300 {
+      lab.energy_source.buffer = OrderedFloat(GAME_CONFIG.labs[&Entity::Lab].energy_usage);
+      lab.tick(&mut research);
+    }
+    // half way through the first unit, the pack in use has half of its durability left
+    assert_eq!(lab.inputs, BTreeMap::from([(Item::AutomationSciencePack, 1)]));
+    assert!((lab.durabilities[&Item::AutomationSciencePack].0 - 0.5).abs() < 1e-9);
+    assert_eq!(research.progress(), 0.0);
+  }
+
+  #[test]
+  fn test_research_from_force() {
+    let research = Research::from_force([Technology::Automation], Some((Technology::Logistics, 0.5)));
+    assert!(research.is_recipe_enabled(Recipe::AssemblingMachine1));
+    assert!(!research.is_recipe_enabled(Recipe::UndergroundBelt));
+    assert_eq!(research.current, Some(Technology::Logistics));
+    let count = GAME_CONFIG.technologies[&Technology::Logistics].count;
+    assert_eq!(research.progress(), (count / 2) as f64 / count as f64);
+  }
+}
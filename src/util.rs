@@ -1,6 +1,4 @@
-use std::fmt::Display;
-
-use factorio_serialize::{constants::Tile, map::{ActiveMigrations, MapData}, replay::ReplayData, save::SaveFile, script::{LuaGameScript, LuaGameScriptState, LuaValue, ScriptData}};
+use factorio_serialize::{constants::Tile, map::MapData, replay::ReplayData, save::SaveFile, script::{LuaContext, LuaGameScript, LuaGameScriptState, LuaValue, ScriptData}};
 
 
 
@@ -23,10 +21,12 @@ pub fn load_and_verify_map_test(name: &str) {
 pub fn load_and_verify_replay_test(name: &str) {
   let save_file = SaveFile::load_save_file(name).unwrap();
 
-  let replay_data = ReplayData::parse_replay_data(&save_file.replay_dat).unwrap();
+  let prototype_registry = MapData::parse_map_data(&save_file.level_init_dat).unwrap().prototype_registry();
+
+  let replay_data = ReplayData::parse_replay_data_with_registry(&save_file.replay_dat, prototype_registry.clone()).unwrap();
   println!("Replay data: {:?}", replay_data);
 
-  let serialized_replay_data = replay_data.write_replay_data().unwrap();
+  let serialized_replay_data = replay_data.write_replay_data_with_registry(prototype_registry).unwrap();
   assert_eq!(serialized_replay_data, save_file.replay_dat);
 }
 
@@ -36,64 +36,40 @@ pub fn load_and_verify_script_test(name: &str) {
 
   let script_data = ScriptData::parse_script_data(&save_file.script_init_dat).unwrap();
   println!("Script data: {:#?}", script_data);
-  println!("Unparsed script data size: {}", script_data.remaining_data.len());
-  println!("Next non-null at index: {:?}", script_data.remaining_data.iter().position(|&x| x != 0));
-  if let Some(non_null_pos) = script_data.remaining_data.iter().position(|&x| x != 0) {
-    println!("Next non-null bytes: {:?}", &script_data.remaining_data[non_null_pos..non_null_pos+20]);
+  assert!(script_data.remaining_data.is_empty(), "{} unparsed bytes after the script states", script_data.remaining_data.len());
+
+  for (script_name, script) in &script_data.lua_context.scripts {
+    let state_value = &script.script_state.state_value;
+    assert_eq!(&LuaValue::from_serpent(&state_value.to_serpent_block()).unwrap(), state_value, "serpent round trip of script {script_name} failed");
   }
 
   let serialized_script_data = script_data.write_script_data().unwrap();
   assert_eq!(serialized_script_data, save_file.script_init_dat);
 }
 
-
+// Writes the script states as a serpent block table keyed by script name, to be edited and read back by load_script_state.
 #[allow(dead_code)]
-pub fn export_prototypes(name: &str) {
+pub fn dump_script_state(name: &str, out_file: &str) {
   let save_file = SaveFile::load_save_file(name).unwrap();
-  let map_data = MapData::parse_map_data(&save_file.level_init_dat).unwrap();
+  let script_data = ScriptData::parse_script_data(&save_file.script_init_dat).unwrap();
 
-  // print_prototype_ids(&map_data.map.prototype_migrations.achievement_id_migrations, "Achievement");
-  // print_prototype_ids(&map_data.map.prototype_migrations.decorative_id_migrations, "Decorative");
-  // print_prototype_ids(&map_data.map.prototype_migrations.entity_id_migrations, "Entity");
-  // print_prototype_ids(&map_data.map.prototype_migrations.equipment_id_migrations, "Equipment");
-  // print_prototype_ids(&map_data.map.prototype_migrations.fluid_id_migrations, "Fluid");
-  // print_prototype_ids(&map_data.map.prototype_migrations.item_id_migrations, "Item");
-  // print_prototype_ids(&map_data.map.prototype_migrations.item_group_id_migrations, "ItemGroup");
-  // print_prototype_ids(&map_data.map.prototype_migrations.recipe_id_migrations, "Recipe");
-  // print_prototype_ids(&map_data.map.prototype_migrations.technology_id_migrations, "Technology");
-  // print_prototype_ids(&map_data.map.prototype_migrations.tile_id_migrations, "Tile");
-  print_prototype_ids(&map_data.map.prototype_migrations.virtual_signal_id_migrations, "VirtualSignal");
+  let states = LuaValue::Table(script_data.lua_context.scripts.iter().map(|(script_name, script)| (LuaValue::String(script_name.clone()), script.script_state.state_value.clone())).collect());
+  std::fs::write(out_file, states.to_serpent_block()).unwrap();
 }
+#[allow(dead_code)]
+pub fn load_script_state(name: &str, in_file: &str, outname: &str) {
+  let mut save_file = SaveFile::load_save_file(name).unwrap();
+  let mut script_data = ScriptData::parse_script_data(&save_file.script_init_dat).unwrap();
 
-fn print_prototype_ids<T: Copy + Display + Ord>(migrations: &ActiveMigrations<T>, enum_name: &str) {
-  let mut sorted_mappings = vec![];
-  for (_, mappings) in &migrations.mappings {
-    sorted_mappings.extend_from_slice(mappings);
-  }
-  sorted_mappings.sort_by_key(|(_, i)| *i);
-  println!("");
-  println!("pub enum {} {{", enum_name);
-  for (name, id) in sorted_mappings.iter() {
-    println!("  {} = {},", heck::AsUpperCamelCase(name), id);
-  }
-  println!("}}");
-  println!("impl {} {{", enum_name);
-  println!("  pub fn name(self) -> &'static str {{");
-  println!("    match self {{");
-  for (name, _) in sorted_mappings.iter() {
-    println!("      {enum_name}::{} => \"{name}\",", heck::AsUpperCamelCase(name));
-  }
-  println!("    }}");
-  println!("  }}");
-  println!("  pub fn from_name(name: &str) -> {enum_name} {{");
-  println!("    match name {{");
-  for (name, _) in sorted_mappings.iter() {
-    println!("      \"{name}\" => {enum_name}::{},", heck::AsUpperCamelCase(name));
+  let LuaValue::Table(states) = LuaValue::from_serpent(&std::fs::read_to_string(in_file).unwrap()).unwrap() else { panic!("{in_file} does not contain a table") };
+  for (script_name, state_value) in states {
+    let LuaValue::String(script_name) = script_name else { panic!("invalid script name {script_name:?}") };
+    let script = script_data.lua_context.script_mut(&script_name).unwrap_or_else(|| panic!("script {script_name} not found"));
+    script.script_state.state_value = state_value;
   }
-  println!("      name => panic!(\"unknown {enum_name} \\\"{{name}}\\\"\"),");
-  println!("    }}");
-  println!("  }}");
-  println!("}}");
+
+  save_file.script_init_dat = script_data.write_script_data().unwrap();
+  save_file.write_save_file(outname).unwrap()
 }
 
 
@@ -122,7 +98,7 @@ pub fn clean_up_save_file(name: &str, outname: &str) {
     chunk.tick_of_last_change_that_could_affect_charting = 0;
     for x in 0..32 {  // set tiles to Lab floor pattern
       for y in 0..32 {
-        chunk.tiles[x][y] = ([Tile::LabDark1, Tile::LabDark2][(x + y) & 1], 0x10);
+        chunk.tiles[x][y] = ([Tile::LabDark1, Tile::LabDark2][(x + y) & 1].into(), 0x10);
       }
     }
   }
@@ -183,12 +159,12 @@ pub fn clean_up_save_file(name: &str, outname: &str) {
 
   // Set up script data to skip crash site
   let mut script_data = ScriptData::parse_script_data(&save_file.script_init_dat).unwrap();
-  script_data.lua_context.scripts = vec![(String::from("level"), LuaGameScript {
+  let mut script_state = LuaGameScriptState { state_value: LuaValue::Table(vec![]) };
+  script_state.set_skip_intro(true);  // disable freeplay intro message
+  script_state.set_disable_crashsite(true);  // disable crash site and cutscene, keep all starting items in player inventory
+  script_data.lua_context.scripts = vec![(String::from(LuaContext::LEVEL_SCRIPT), LuaGameScript {
     had_control_lua: false,  // force initialization of control.lua scripts, including globals for items on player creation and silo script
-    script_state: LuaGameScriptState { state_value: LuaValue::Table(vec![
-      (LuaValue::String(String::from("skip_intro")), LuaValue::BoolTrue),  // disable freeplay intro message
-      (LuaValue::String(String::from("disable_crashsite")), LuaValue::BoolTrue),  // disable crash site and cutscene, keep all starting items in player inventory
-    ]) } })];
+    script_state })];
 
   // println!("Map data: {:?}", map_data);
 